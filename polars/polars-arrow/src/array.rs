@@ -86,7 +86,9 @@ impl ValueSize for ArrayData {
             DataType::LargeList(_) | DataType::List(_) => {
                 self.child_data()[0].len() - self.offset()
             }
-            DataType::LargeUtf8 | DataType::Utf8 => self.buffers()[1].len() - self.offset(),
+            DataType::LargeUtf8 | DataType::Utf8 | DataType::LargeBinary | DataType::Binary => {
+                self.buffers()[1].len() - self.offset()
+            }
             _ => unimplemented!(),
         }
     }
@@ -0,0 +1,262 @@
+//! Operations on columns of raw bytes, e.g. serialized messages or hashes.
+use crate::prelude::*;
+use crate::utils::NoNull;
+
+macro_rules! apply_closure {
+    ($self:expr, $f:expr) => {{
+        let mut ca: ChunkedArray<_> = if $self.null_count() == 0 {
+            $self.into_no_null_iter().map($f).collect()
+        } else {
+            $self.into_iter().map(|opt_v| opt_v.map($f)).collect()
+        };
+        ca.rename($self.name());
+        ca
+    }};
+}
+
+impl BinaryChunked {
+    /// Get the number of bytes of the values.
+    pub fn bin_lengths(&self) -> UInt32Chunked {
+        let f = |v: &[u8]| v.len() as u32;
+        let mut ca: UInt32Chunked = if self.null_count() == 0 {
+            let ca: NoNull<_> = self.into_no_null_iter().map(f).collect();
+            ca.into_inner()
+        } else {
+            self.into_iter().map(|opt_v| opt_v.map(f)).collect()
+        };
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Check if the values contain the byte sequence `pat`.
+    pub fn contains(&self, pat: &[u8]) -> BooleanChunked {
+        let f = |v: &[u8]| pat.is_empty() || v.windows(pat.len()).any(|w| w == pat);
+        apply_closure!(self, f)
+    }
+
+    /// Check if the values start with `prefix`.
+    pub fn starts_with(&self, prefix: &[u8]) -> BooleanChunked {
+        let f = |v: &[u8]| v.starts_with(prefix);
+        apply_closure!(self, f)
+    }
+
+    /// Check if the values end with `suffix`.
+    pub fn ends_with(&self, suffix: &[u8]) -> BooleanChunked {
+        let f = |v: &[u8]| v.ends_with(suffix);
+        apply_closure!(self, f)
+    }
+
+    /// Encode the values as lowercase hexadecimal strings.
+    pub fn encode_hex(&self) -> Utf8Chunked {
+        apply_closure!(self, hex_encode)
+    }
+
+    /// Encode the values as base64 strings, using the standard alphabet with padding.
+    pub fn encode_base64(&self) -> Utf8Chunked {
+        apply_closure!(self, base64_encode)
+    }
+}
+
+impl Utf8Chunked {
+    /// Decode hexadecimal strings to bytes. Both lowercase and uppercase digits are accepted.
+    ///
+    /// If `strict`, values that cannot be decoded return an error, otherwise they are null.
+    pub fn decode_hex(&self, strict: bool) -> Result<BinaryChunked> {
+        self.decode(strict, "hex", hex_decode)
+    }
+
+    /// Decode base64 strings, using the standard alphabet with padding, to bytes.
+    ///
+    /// If `strict`, values that cannot be decoded return an error, otherwise they are null.
+    pub fn decode_base64(&self, strict: bool) -> Result<BinaryChunked> {
+        self.decode(strict, "base64", base64_decode)
+    }
+
+    fn decode<F>(&self, strict: bool, encoding: &str, f: F) -> Result<BinaryChunked>
+    where
+        F: Fn(&str) -> Option<Vec<u8>>,
+    {
+        let mut ca: BinaryChunked = self
+            .into_iter()
+            .enumerate()
+            .map(|(idx, opt_s)| match opt_s {
+                Some(s) => match f(s) {
+                    Some(v) => Ok(Some(v)),
+                    None if strict => Err(PolarsError::ValueError(
+                        format!(
+                            "could not decode '{}' at row {} of column '{}' as {}",
+                            s,
+                            idx,
+                            self.name(),
+                            encoding
+                        )
+                        .into(),
+                    )),
+                    None => Ok(None),
+                },
+                None => Ok(None),
+            })
+            .collect::<Result<_>>()?;
+        ca.rename(self.name());
+        Ok(ca)
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn hex_encode(v: &[u8]) -> String {
+    let mut s = String::with_capacity(v.len() * 2);
+    for b in v {
+        s.push(HEX_DIGITS[(b >> 4) as usize] as char);
+        s.push(HEX_DIGITS[(b & 0xf) as usize] as char);
+    }
+    s
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    let s = s.as_bytes();
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    s.chunks(2)
+        .map(|pair| Some(hex_value(pair[0])? << 4 | hex_value(pair[1])?))
+        .collect()
+}
+
+fn base64_encode(v: &[u8]) -> String {
+    let mut s = String::with_capacity((v.len() + 2) / 3 * 4);
+    for chunk in v.chunks(3) {
+        let n = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(BASE64_ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+    s
+}
+
+fn base64_value(c: u8) -> Option<u32> {
+    match c {
+        b'A'..=b'Z' => Some((c - b'A') as u32),
+        b'a'..=b'z' => Some((c - b'a' + 26) as u32),
+        b'0'..=b'9' => Some((c - b'0' + 52) as u32),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    let s = s.as_bytes();
+    if s.len() % 4 != 0 {
+        return None;
+    }
+    let n_chunks = s.len() / 4;
+    let mut out = Vec::with_capacity(n_chunks * 3);
+    for (i, chunk) in s.chunks(4).enumerate() {
+        // padding is only allowed at the end
+        let n_pad = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if n_pad > 2 || (n_pad > 0 && i + 1 != n_chunks) {
+            return None;
+        }
+        let mut n = 0u32;
+        for &c in &chunk[..4 - n_pad] {
+            n = n << 6 | base64_value(c)?;
+        }
+        n <<= 6 * n_pad;
+        let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+        out.extend_from_slice(&bytes[..3 - n_pad]);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_binary_namespace() {
+        let s = Series::new("bin", &[Some(&b"\x00\xffab"[..]), None, Some(&b"abc"[..])]);
+        let ca = s.binary().unwrap();
+        assert_eq!(Vec::from(&ca.bin_lengths()), &[Some(4), None, Some(3)]);
+        assert_eq!(
+            Vec::from(&ca.contains(b"ab")),
+            &[Some(true), None, Some(true)]
+        );
+        assert_eq!(
+            Vec::from(&ca.starts_with(b"\x00")),
+            &[Some(true), None, Some(false)]
+        );
+
+        let hex = ca.encode_hex();
+        assert_eq!(Vec::from(&hex), &[Some("00ff6162"), None, Some("616263")]);
+        let b64 = ca.encode_base64();
+        assert_eq!(Vec::from(&b64), &[Some("AP9hYg=="), None, Some("YWJj")]);
+        assert!(hex
+            .decode_hex(true)
+            .unwrap()
+            .into_series()
+            .series_equal_missing(&s));
+        assert!(b64
+            .decode_base64(true)
+            .unwrap()
+            .into_series()
+            .series_equal_missing(&s));
+
+        let invalid = Utf8Chunked::new_from_slice("a", &["YW=j", "0g"]);
+        assert!(invalid.decode_base64(true).is_err());
+        assert!(invalid.decode_hex(true).is_err());
+        assert_eq!(invalid.decode_hex(false).unwrap().null_count(), 2);
+    }
+
+    #[test]
+    fn test_binary_unsupported_ops() {
+        let s = Series::new("bin", &[Some(&b"a"[..]), None]);
+        assert!(s.rank(RankOptions::default()).is_err());
+        assert!(s.diff(1, NullBehavior::Ignore).is_err());
+        assert!(s.pct_change(1).is_err());
+        let options = RollingOptions::new(2);
+        assert!(s.rolling_mean(&options).is_err());
+        assert!(s.rolling_quantile(0.5, &options).is_err());
+        assert!(s.rolling_count(&options).is_err());
+        assert!(s.ewm_mean(&EWMOptions::default()).is_err());
+        assert!(s.ewm_std(&EWMOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_binary_groupby_join() -> Result<()> {
+        let keys: &[&[u8]] = &[b"a", b"\x01", b"a"];
+        let df = DataFrame::new(vec![
+            Series::new("key", keys),
+            Series::new("val", &[1, 2, 3]),
+        ])?;
+        let out = df.groupby("key")?.select("val").sum()?;
+        assert_eq!(out.height(), 2);
+
+        let keys: &[&[u8]] = &[b"\x01", b"b"];
+        let other = DataFrame::new(vec![
+            Series::new("key", keys),
+            Series::new("other", &[10, 20]),
+        ])?;
+        let out = df.inner_join(&other, "key", "key")?;
+        assert_eq!(Vec::from(out.column("other")?.i32()?), &[Some(10)]);
+        Ok(())
+    }
+}
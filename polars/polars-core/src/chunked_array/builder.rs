@@ -4,7 +4,9 @@ use crate::{
     utils::{get_iter_capacity, NoNull},
 };
 use ahash::AHashMap;
-use arrow::array::{ArrayDataBuilder, ArrayRef, LargeListBuilder};
+use arrow::array::{
+    ArrayDataBuilder, ArrayRef, DecimalBuilder, LargeBinaryBuilder, LargeListBuilder,
+};
 use arrow::datatypes::ToByteSlice;
 pub use arrow::memory;
 use arrow::{
@@ -124,6 +126,8 @@ pub struct CategoricalChunkedBuilder {
     field: Field,
     mapping: AHashMap<String, u32>,
     reverse_mapping: AHashMap<u32, String>,
+    // generation of the global string cache the codes were taken from
+    cache_generation: Option<u32>,
 }
 
 impl CategoricalChunkedBuilder {
@@ -136,17 +140,47 @@ impl CategoricalChunkedBuilder {
             field: Field::new(name, DataType::Categorical),
             mapping,
             reverse_mapping,
+            cache_generation: None,
         }
     }
 }
 impl CategoricalChunkedBuilder {
+    /// Registers categories without appending values, so that they are part of the mapping even
+    /// if they don't occur. Categories that are new get their codes in the given order.
+    pub(crate) fn register_categories<'a, I>(&mut self, i: I)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut cache;
+        let mapping = if use_string_cache() {
+            cache = crate::STRING_CACHE.lock_map();
+            self.cache_generation = Some(cache.generation);
+            &mut cache.map
+        } else {
+            &mut self.mapping
+        };
+        for s in i {
+            let idx = match mapping.get(s) {
+                Some(idx) => *idx,
+                None => {
+                    let idx = mapping.len() as u32;
+                    mapping.insert(s.to_string(), idx);
+                    idx
+                }
+            };
+            self.reverse_mapping.insert(idx, s.to_string());
+        }
+    }
+
     /// Appends all the values in a single lock of the global string cache.
     pub fn append_values<'a, I>(&mut self, i: I)
     where
         I: IntoIterator<Item = Option<&'a str>>,
     {
         if use_string_cache() {
-            let mut mapping = crate::STRING_CACHE.lock_map();
+            let mut cache = crate::STRING_CACHE.lock_map();
+            self.cache_generation = Some(cache.generation);
+            let mapping = &mut cache.map;
 
             for opt_s in i {
                 match opt_s {
@@ -194,7 +228,9 @@ impl CategoricalChunkedBuilder {
 impl ChunkedBuilder<&str, CategoricalType> for CategoricalChunkedBuilder {
     fn append_value(&mut self, val: &str) {
         let idx = if use_string_cache() {
-            let mut mapping = crate::STRING_CACHE.lock_map();
+            let mut cache = crate::STRING_CACHE.lock_map();
+            self.cache_generation = Some(cache.generation);
+            let mapping = &mut cache.map;
             match mapping.get(val) {
                 Some(idx) => *idx,
                 None => {
@@ -228,12 +264,16 @@ impl ChunkedBuilder<&str, CategoricalType> for CategoricalChunkedBuilder {
         let arr = Arc::new(self.array_builder.finish());
         let len = arr.len();
         self.reverse_mapping.shrink_to_fit();
+        let rev_map = match self.cache_generation {
+            Some(generation) => RevMapping::global(self.reverse_mapping, generation),
+            None => RevMapping::local(self.reverse_mapping),
+        };
         ChunkedArray {
             field: Arc::new(self.field),
             chunks: vec![arr],
             chunk_id: vec![len],
             phantom: PhantomData,
-            categorical_map: Some(Arc::new(self.reverse_mapping)),
+            categorical_map: Some(Arc::new(rev_map)),
         }
     }
 }
@@ -289,6 +329,109 @@ impl Utf8ChunkedBuilder {
     }
 }
 
+pub struct BinaryChunkedBuilder {
+    pub builder: LargeBinaryBuilder,
+    pub capacity: usize,
+    field: Field,
+}
+
+impl BinaryChunkedBuilder {
+    /// Create a new BinaryChunkedBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of elements in the final array.
+    /// * `bytes_capacity` - Number of bytes needed to store the values.
+    pub fn new(name: &str, capacity: usize, bytes_capacity: usize) -> Self {
+        BinaryChunkedBuilder {
+            builder: LargeBinaryBuilder::new(bytes_capacity),
+            capacity,
+            field: Field::new(name, DataType::Binary),
+        }
+    }
+
+    /// Appends a value into the builder
+    pub fn append_value<B: AsRef<[u8]>>(&mut self, v: B) {
+        self.builder
+            .append_value(v.as_ref())
+            .expect("should not fail");
+    }
+
+    /// Appends a null slot into the builder
+    pub fn append_null(&mut self) {
+        self.builder.append_null().expect("should not fail");
+    }
+
+    pub fn append_option<B: AsRef<[u8]>>(&mut self, opt: Option<B>) {
+        match opt {
+            Some(v) => self.append_value(v),
+            None => self.append_null(),
+        }
+    }
+
+    pub fn finish(mut self) -> BinaryChunked {
+        let arr = Arc::new(self.builder.finish());
+        let len = arr.len();
+        ChunkedArray {
+            field: Arc::new(self.field),
+            chunks: vec![arr],
+            chunk_id: vec![len],
+            phantom: PhantomData,
+            categorical_map: None,
+        }
+    }
+}
+
+pub struct DecimalChunkedBuilder {
+    pub builder: DecimalBuilder,
+    field: Field,
+}
+
+impl DecimalChunkedBuilder {
+    /// Create a new DecimalChunkedBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of elements in the final array.
+    /// * `precision` - Total number of digits of the values.
+    /// * `scale` - Number of digits after the decimal point.
+    pub fn new(name: &str, capacity: usize, precision: usize, scale: usize) -> Self {
+        DecimalChunkedBuilder {
+            builder: DecimalBuilder::new(capacity, precision, scale),
+            field: Field::new(name, DataType::Decimal(precision, scale)),
+        }
+    }
+
+    /// Appends a value, already multiplied by `10^scale`, into the builder
+    pub fn append_value(&mut self, v: i128) {
+        self.builder.append_value(v).expect("should not fail");
+    }
+
+    /// Appends a null slot into the builder
+    pub fn append_null(&mut self) {
+        self.builder.append_null().expect("should not fail");
+    }
+
+    pub fn append_option(&mut self, opt: Option<i128>) {
+        match opt {
+            Some(v) => self.append_value(v),
+            None => self.append_null(),
+        }
+    }
+
+    pub fn finish(mut self) -> DecimalChunked {
+        let arr = Arc::new(self.builder.finish());
+        let len = arr.len();
+        ChunkedArray {
+            field: Arc::new(self.field),
+            chunks: vec![arr],
+            chunk_id: vec![len],
+            phantom: PhantomData,
+            categorical_map: None,
+        }
+    }
+}
+
 pub struct Utf8ChunkedBuilderCow {
     builder: Utf8ChunkedBuilder,
 }
@@ -661,6 +804,21 @@ impl ListUtf8ChunkedBuilder {
 
         ListUtf8ChunkedBuilder { builder, field }
     }
+
+    pub fn append_values_iter<'a, I: Iterator<Item = Option<&'a str>>>(&mut self, iter: I) {
+        let value_builder = self.builder.values();
+        for opt_v in iter {
+            match opt_v {
+                Some(v) => value_builder.append_value(v),
+                None => value_builder.append_null(),
+            };
+        }
+        self.builder.append(true).expect("should not fail");
+    }
+
+    pub fn append_null(&mut self) {
+        self.builder.append(false).expect("should not fail");
+    }
 }
 
 impl ListBuilderTrait for ListUtf8ChunkedBuilder {
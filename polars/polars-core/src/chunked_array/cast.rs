@@ -58,7 +58,7 @@ impl ChunkCast for CategoricalChunked {
 
                 let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.len() * 5);
 
-                let f = |idx: u32| mapping.get(&idx).unwrap();
+                let f = |idx: u32| mapping.get(idx).unwrap();
 
                 if self.null_count() == 0 {
                     self.into_no_null_iter()
@@ -0,0 +1,702 @@
+//! Reverse mappings from categorical codes to their string values, and the ordering of
+//! categories.
+use crate::chunked_array::builder::CategoricalChunkedBuilder;
+use crate::prelude::*;
+use ahash::AHashMap;
+use arrow::array::{
+    Array, ArrayData, ArrayRef, DictionaryArray, LargeStringArray, StringArray, UInt32Array,
+};
+use arrow::compute::cast;
+use arrow::datatypes::{ArrowDictionaryKeyType, ArrowNativeType};
+use std::cmp::Ordering;
+use std::ops::Deref;
+
+/// How the categories of a [`CategoricalChunked`] are ordered when sorting, comparing and
+/// computing the min/max.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CategoricalOrdering {
+    /// Order by the physical codes, i.e. the order in which the categories were first seen.
+    Physical,
+    /// Order by the string values of the categories.
+    Lexical,
+    /// Order by a user supplied order of the categories, e.g. `["low", "medium", "high"]`.
+    /// Categories that are added later, e.g. by appending, are ordered after these.
+    User(Vec<String>),
+}
+
+impl Default for CategoricalOrdering {
+    fn default() -> Self {
+        CategoricalOrdering::Physical
+    }
+}
+
+/// Maps the `u32` codes of a [`CategoricalChunked`] to their string values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevMapping {
+    map: AHashMap<u32, String>,
+    /// The generation of the global string cache the codes were assigned by. `None` if the codes
+    /// are only valid for this mapping.
+    cache_generation: Option<u32>,
+    ordering: CategoricalOrdering,
+}
+
+impl RevMapping {
+    /// A mapping of codes that were assigned by the global string cache of the given generation.
+    pub fn global(map: AHashMap<u32, String>, generation: u32) -> Self {
+        RevMapping {
+            map,
+            cache_generation: Some(generation),
+            ordering: CategoricalOrdering::Physical,
+        }
+    }
+
+    /// A mapping of codes that are only valid for this mapping.
+    pub fn local(map: AHashMap<u32, String>) -> Self {
+        RevMapping {
+            map,
+            cache_generation: None,
+            ordering: CategoricalOrdering::Physical,
+        }
+    }
+
+    /// Number of categories in this mapping.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Get the string value of a categorical code.
+    pub fn get(&self, idx: u32) -> Option<&str> {
+        self.map.get(&idx).map(|s| s.as_str())
+    }
+
+    /// Find the code of a category.
+    pub fn find(&self, value: &str) -> Option<u32> {
+        self.map
+            .iter()
+            .find(|(_, s)| s.as_str() == value)
+            .map(|(idx, _)| *idx)
+    }
+
+    /// The generation of the global string cache these codes refer to, if any.
+    pub fn cache_generation(&self) -> Option<u32> {
+        self.cache_generation
+    }
+
+    pub fn ordering(&self) -> &CategoricalOrdering {
+        &self.ordering
+    }
+
+    /// The rank of every code under the ordering of this mapping. `None` if the codes are
+    /// ordered physically.
+    pub(crate) fn ranks(&self) -> Option<AHashMap<u32, u32>> {
+        let mut codes: Vec<(&u32, &String)> = match &self.ordering {
+            CategoricalOrdering::Physical => return None,
+            CategoricalOrdering::Lexical => {
+                let mut codes: Vec<_> = self.map.iter().collect();
+                codes.sort_unstable_by(|a, b| a.1.cmp(b.1));
+                codes
+            }
+            CategoricalOrdering::User(order) => {
+                let positions = user_positions(order);
+                let mut codes: Vec<_> = self.map.iter().collect();
+                codes.sort_unstable_by_key(|(_, s)| {
+                    (
+                        positions.get(s.as_str()).copied().unwrap_or(order.len()),
+                        s.as_str(),
+                    )
+                });
+                codes
+            }
+        };
+        Some(
+            codes
+                .drain(..)
+                .enumerate()
+                .map(|(rank, (idx, _))| (*idx, rank as u32))
+                .collect(),
+        )
+    }
+
+    /// Compare a category with a value that does not have to be part of this mapping. `None` if
+    /// the value cannot be placed in the ordering.
+    fn cmp_value(&self, idx: u32, value: &str) -> Option<Ordering> {
+        let category = self.get(idx)?;
+        if category == value {
+            return Some(Ordering::Equal);
+        }
+        match &self.ordering {
+            CategoricalOrdering::Physical => self.find(value).map(|other| idx.cmp(&other)),
+            CategoricalOrdering::Lexical => Some(category.cmp(value)),
+            CategoricalOrdering::User(order) => {
+                let position = |s: &str| order.iter().position(|o| o == s);
+                match (position(category), position(value)) {
+                    (Some(l), Some(r)) => Some(l.cmp(&r)),
+                    (None, Some(_)) => Some(Ordering::Greater),
+                    (_, None) => None,
+                }
+            }
+        }
+    }
+}
+
+fn user_positions(order: &[String]) -> AHashMap<&str, usize> {
+    order
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.as_str(), i))
+        .collect()
+}
+
+/// Create a categorical array from arrow `Dictionary` arrays with string values. Only the values
+/// of the dictionaries are hashed, not the value of every row.
+pub(crate) fn from_dictionary_arrays(
+    name: &str,
+    chunks: &[ArrayRef],
+) -> Result<CategoricalChunked> {
+    let mut out: Option<CategoricalChunked> = None;
+    for arr in chunks {
+        let ca = match arr.data_type() {
+            ArrowDataType::Dictionary(key_type, _) => match **key_type {
+                ArrowDataType::Int8 => from_dictionary::<Int8Type>(name, arr)?,
+                ArrowDataType::Int16 => from_dictionary::<Int16Type>(name, arr)?,
+                ArrowDataType::Int32 => from_dictionary::<Int32Type>(name, arr)?,
+                ArrowDataType::Int64 => from_dictionary::<Int64Type>(name, arr)?,
+                ArrowDataType::UInt8 => from_dictionary::<UInt8Type>(name, arr)?,
+                ArrowDataType::UInt16 => from_dictionary::<UInt16Type>(name, arr)?,
+                ArrowDataType::UInt32 => from_dictionary::<UInt32Type>(name, arr)?,
+                ArrowDataType::UInt64 => from_dictionary::<UInt64Type>(name, arr)?,
+                ref dt => {
+                    return Err(PolarsError::DataTypeMisMatch(
+                        format!("dictionary keys of type {:?} are not supported", dt).into(),
+                    ))
+                }
+            },
+            dt => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("expected a dictionary array, got {:?}", dt).into(),
+                ))
+            }
+        };
+        match &mut out {
+            Some(out) => out.append(&ca),
+            None => out = Some(ca),
+        }
+    }
+    out.ok_or_else(|| PolarsError::NoData("Expected at least on ArrayRef".into()))
+}
+
+fn from_dictionary<K: ArrowDictionaryKeyType>(
+    name: &str,
+    arr: &ArrayRef,
+) -> Result<CategoricalChunked> {
+    let dict = arr.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = cast(&dict.values(), &ArrowDataType::LargeUtf8)?;
+    let values = values.as_any().downcast_ref::<LargeStringArray>().unwrap();
+
+    // register the values of the dictionary, so that we get the code of every dictionary key
+    let mut builder = CategoricalChunkedBuilder::new(name, values.len());
+    builder.append_values((0..values.len()).map(|i| match values.is_valid(i) {
+        true => Some(values.value(i)),
+        false => None,
+    }));
+    let dict_ca = builder.finish();
+    let dict_codes: Vec<Option<u32>> = dict_ca.deref().into_iter().collect();
+
+    let codes: UInt32Chunked = dict
+        .keys()
+        .iter()
+        .map(|opt_key| opt_key.and_then(|key| dict_codes[key.to_usize().unwrap()]))
+        .collect();
+    let mut ca: CategoricalChunked = codes.cast()?;
+    ca.rename(name);
+    Ok(ca.set_state(&dict_ca))
+}
+
+/// Check if two reverse mappings can be combined. Local mappings can always be re-encoded, but
+/// codes from different generations of the global string cache cannot.
+pub(crate) fn check_categorical_src(left: &Arc<RevMapping>, right: &Arc<RevMapping>) -> Result<()> {
+    match (left.cache_generation(), right.cache_generation()) {
+        (Some(l), Some(r)) if l != r => Err(PolarsError::InvalidOperation(
+            "cannot combine categoricals that were built against different generations of the string cache"
+                .into(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The categories of two categorical arrays merged into one reverse mapping.
+pub(crate) struct MergedCategories {
+    pub(crate) rev_map: Arc<RevMapping>,
+    /// Re-encoded chunks of the left array, `None` if its codes are unchanged.
+    pub(crate) left: Option<Vec<ArrayRef>>,
+    /// Re-encoded chunks of the right array, `None` if its codes are unchanged.
+    pub(crate) right: Option<Vec<ArrayRef>>,
+}
+
+fn remap_chunks(chunks: &[ArrayRef], translation: &AHashMap<u32, u32>) -> Vec<ArrayRef> {
+    let ca = UInt32Chunked::new_from_chunks("", chunks.to_vec());
+    // null slots may hold any value, so don't index the translation table blindly
+    ca.apply(|idx| translation.get(&idx).copied().unwrap_or(0))
+        .chunks
+}
+
+/// Merge the categories of two categorical arrays, given by their chunks and reverse mapping.
+///
+/// Codes that refer to the same generation of the global string cache are kept as is. Otherwise
+/// both arrays get a shared local mapping: the codes of a local left array are kept and the
+/// categories of the right array are appended to it.
+///
+/// The merged mapping has the ordering of the left mapping, unless that one is physical.
+pub(crate) fn merge_categories(
+    left_chunks: &[ArrayRef],
+    left_map: &Arc<RevMapping>,
+    right_chunks: &[ArrayRef],
+    right_map: &Arc<RevMapping>,
+) -> Result<MergedCategories> {
+    if Arc::ptr_eq(left_map, right_map) {
+        return Ok(MergedCategories {
+            rev_map: left_map.clone(),
+            left: None,
+            right: None,
+        });
+    }
+    check_categorical_src(left_map, right_map)?;
+    let ordering = match left_map.ordering() {
+        CategoricalOrdering::Physical => right_map.ordering().clone(),
+        ordering => ordering.clone(),
+    };
+
+    if left_map.cache_generation.is_some() && right_map.cache_generation.is_some() {
+        let mut map = left_map.map.clone();
+        for (idx, s) in &right_map.map {
+            map.entry(*idx).or_insert_with(|| s.clone());
+        }
+        return Ok(MergedCategories {
+            rev_map: Arc::new(RevMapping {
+                map,
+                cache_generation: left_map.cache_generation,
+                ordering,
+            }),
+            left: None,
+            right: None,
+        });
+    }
+
+    let mut rev_map = AHashMap::with_capacity(left_map.len() + right_map.len());
+    let mut lookup = AHashMap::with_capacity(left_map.len() + right_map.len());
+
+    let left = if left_map.cache_generation.is_none() {
+        for (idx, s) in &left_map.map {
+            rev_map.insert(*idx, s.clone());
+            lookup.insert(s.clone(), *idx);
+        }
+        None
+    } else {
+        let mut translation = AHashMap::with_capacity(left_map.len());
+        for (idx, s) in &left_map.map {
+            let new_idx = rev_map.len() as u32;
+            rev_map.insert(new_idx, s.clone());
+            lookup.insert(s.clone(), new_idx);
+            translation.insert(*idx, new_idx);
+        }
+        Some(remap_chunks(left_chunks, &translation))
+    };
+
+    let mut translation = AHashMap::with_capacity(right_map.len());
+    for (idx, s) in &right_map.map {
+        let new_idx = match lookup.get(s) {
+            Some(new_idx) => *new_idx,
+            None => {
+                let new_idx = rev_map.len() as u32;
+                rev_map.insert(new_idx, s.clone());
+                lookup.insert(s.clone(), new_idx);
+                new_idx
+            }
+        };
+        translation.insert(*idx, new_idx);
+    }
+    let right = if translation.iter().all(|(idx, new_idx)| idx == new_idx) {
+        None
+    } else {
+        Some(remap_chunks(right_chunks, &translation))
+    };
+
+    Ok(MergedCategories {
+        rev_map: Arc::new(RevMapping {
+            map: rev_map,
+            cache_generation: None,
+            ordering,
+        }),
+        left,
+        right,
+    })
+}
+
+impl CategoricalChunked {
+    /// Check if this array can be joined or appended to `other`. Categoricals with a local
+    /// mapping are re-encoded, but codes of different string cache generations cannot be mixed.
+    pub fn check_same_src(&self, other: &CategoricalChunked) -> Result<()> {
+        match (&self.categorical_map, &other.categorical_map) {
+            (Some(l), Some(r)) => check_categorical_src(l, r),
+            _ => Ok(()),
+        }
+    }
+
+    /// The ordering of the categories. Categoricals are ordered physically by default.
+    pub fn ordering(&self) -> &CategoricalOrdering {
+        self.categorical_map
+            .as_ref()
+            .map(|rev_map| rev_map.ordering())
+            .unwrap_or(&CategoricalOrdering::Physical)
+    }
+
+    /// Set the ordering of the categories that is used by sorting, comparisons and min/max.
+    ///
+    /// A user supplied order should contain every category of this array exactly once, but may
+    /// contain categories that don't occur (yet).
+    pub fn set_ordering(&mut self, ordering: CategoricalOrdering) -> Result<()> {
+        if let CategoricalOrdering::User(order) = &ordering {
+            let positions = user_positions(order);
+            if positions.len() != order.len() {
+                return Err(PolarsError::ValueError(
+                    "the categories of a user supplied ordering should be unique".into(),
+                ));
+            }
+            if let Some(rev_map) = &self.categorical_map {
+                if let Some(missing) = rev_map
+                    .map
+                    .values()
+                    .find(|s| !positions.contains_key(s.as_str()))
+                {
+                    return Err(PolarsError::ValueError(
+                        format!(
+                            "category '{}' of column '{}' is missing in the user supplied ordering",
+                            missing,
+                            self.name()
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+        let mut rev_map = match &self.categorical_map {
+            Some(rev_map) => (**rev_map).clone(),
+            None => RevMapping::local(AHashMap::new()),
+        };
+        rev_map.ordering = ordering;
+        self.categorical_map = Some(Arc::new(rev_map));
+        Ok(())
+    }
+
+    /// The codes replaced by their rank under the ordering of the categories. These can be
+    /// sorted and compared like any other integers.
+    pub(crate) fn ordered_codes(&self) -> UInt32Chunked {
+        let codes = self.deref().clone();
+        match self.categorical_map.as_ref().and_then(|m| m.ranks()) {
+            Some(ranks) => {
+                // null slots may hold any value, so don't index the ranks blindly
+                let mut ca = codes.apply(|idx| ranks.get(&idx).copied().unwrap_or(0));
+                ca.rename(self.name());
+                ca
+            }
+            None => codes,
+        }
+    }
+
+    /// Compare the categories with a value that does not have to be one of the categories.
+    /// Values that cannot be placed in the ordering produce nulls, except for (in)equality.
+    pub(crate) fn compare_str<F>(&self, value: &str, eq: bool, f: F) -> BooleanChunked
+    where
+        F: Fn(Ordering) -> bool,
+    {
+        let rev_map = match &self.categorical_map {
+            Some(rev_map) => rev_map,
+            None => return BooleanChunked::full_null(self.name(), self.len()),
+        };
+        let results: AHashMap<u32, Option<bool>> = rev_map
+            .map
+            .keys()
+            .map(|idx| {
+                let res = match rev_map.cmp_value(*idx, value) {
+                    Some(ord) => Some(f(ord)),
+                    // the value is not a category, so it is unequal to all of them
+                    None if eq => Some(f(Ordering::Less)),
+                    None => None,
+                };
+                (*idx, res)
+            })
+            .collect();
+        let mut ca: BooleanChunked = self
+            .deref()
+            .into_iter()
+            .map(|opt_idx| opt_idx.and_then(|idx| results.get(&idx).copied().flatten()))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Convert a chunk to an arrow `Dictionary(UInt32, Utf8)` array, with the categories as
+    /// dictionary.
+    pub(crate) fn to_dictionary_array(&self, chunk_idx: usize) -> ArrayRef {
+        let rev_map = self
+            .categorical_map
+            .as_ref()
+            .expect("categorical type has categorical_map");
+        let mut codes: Vec<u32> = rev_map.map.keys().copied().collect();
+        codes.sort_unstable();
+        let values: StringArray = codes
+            .iter()
+            .map(|idx| Some(rev_map.map[idx].as_str()))
+            .collect();
+
+        // codes of the global string cache are not contiguous, so they are re-encoded
+        let contiguous = codes.iter().enumerate().all(|(i, idx)| i as u32 == *idx);
+        let positions: AHashMap<u32, u32> = if contiguous {
+            AHashMap::new()
+        } else {
+            codes
+                .iter()
+                .enumerate()
+                .map(|(i, idx)| (*idx, i as u32))
+                .collect()
+        };
+        let chunk = self.chunks[chunk_idx]
+            .as_any()
+            .downcast_ref::<UInt32Array>()
+            .unwrap();
+        let keys: UInt32Array = chunk
+            .iter()
+            .map(|opt_idx| {
+                opt_idx.map(|idx| match contiguous {
+                    true => idx,
+                    false => positions[&idx],
+                })
+            })
+            .collect();
+
+        let dtype = ArrowDataType::Dictionary(
+            Box::new(ArrowDataType::UInt32),
+            Box::new(ArrowDataType::Utf8),
+        );
+        let mut builder = ArrayData::builder(dtype)
+            .len(keys.len())
+            .add_buffer(keys.data().buffers()[0].clone())
+            .add_child_data(values.data());
+        if let Some(bitmap) = keys.data().null_bitmap() {
+            builder = builder.null_bit_buffer(bitmap.clone().into_buffer());
+        }
+        Arc::new(DictionaryArray::<UInt32Type>::from(builder.build()))
+    }
+
+    /// Re-encode `self` and `other` such that their codes refer to the same categories.
+    pub fn merge_categories(
+        &self,
+        other: &CategoricalChunked,
+    ) -> Result<(CategoricalChunked, CategoricalChunked)> {
+        match (&self.categorical_map, &other.categorical_map) {
+            (Some(l), Some(r)) => {
+                let merged = merge_categories(&self.chunks, l, &other.chunks, r)?;
+                let finish = |ca: &CategoricalChunked, chunks: Option<Vec<ArrayRef>>| {
+                    let mut ca = match chunks {
+                        Some(chunks) => ca.copy_with_chunks(chunks),
+                        None => ca.clone(),
+                    };
+                    ca.categorical_map = Some(merged.rev_map.clone());
+                    ca
+                };
+                Ok((finish(self, merged.left), finish(other, merged.right)))
+            }
+            _ => Ok((self.clone(), other.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::{toggle_string_cache, StringCacheHolder, SINGLE_LOCK};
+
+    #[test]
+    fn test_string_cache_generations() -> Result<()> {
+        let _lock = SINGLE_LOCK.lock();
+        let s = Series::new("a", &["a", "b", "a"]);
+
+        let a = {
+            let _holder = StringCacheHolder::new();
+            let a = s.cast_with_datatype(&DataType::Categorical)?;
+            let b = {
+                // nested holders share the same generation
+                let _holder = StringCacheHolder::new();
+                s.cast_with_datatype(&DataType::Categorical)?
+            };
+            let mut appended = a.clone();
+            appended.append(&b)?;
+            assert_eq!(appended.len(), 6);
+            a
+        };
+
+        let _holder = StringCacheHolder::new();
+        let b = s.cast_with_datatype(&DataType::Categorical)?;
+        let mut appended = a.clone();
+        assert!(appended.append(&b).is_err());
+
+        let df_a = DataFrame::new(vec![a])?;
+        let df_b = DataFrame::new(vec![b])?;
+        assert!(df_a.inner_join(&df_b, "a", "a").is_err());
+        assert!(df_a.vstack(&df_b).is_err());
+        Ok(())
+    }
+
+    fn assert_utf8(s: &Series, expected: &[Option<&str>]) {
+        let s = s.cast_with_datatype(&DataType::Utf8).unwrap();
+        assert_eq!(Vec::from(s.utf8().unwrap()), expected);
+    }
+
+    #[test]
+    fn test_merge_local_categoricals() -> Result<()> {
+        let a = Series::new("a", &["x", "y", "x"]).cast_with_datatype(&DataType::Categorical)?;
+        let b = Series::new("a", &[Some("z"), None, Some("x")])
+            .cast_with_datatype(&DataType::Categorical)?;
+
+        let mut appended = a.clone();
+        appended.append(&b)?;
+        assert_utf8(
+            &appended,
+            &[Some("x"), Some("y"), Some("x"), Some("z"), None, Some("x")],
+        );
+
+        let mask = BooleanChunked::new_from_slice("", &[true, false, false]);
+        let zipped = a.zip_with(&mask, &b)?;
+        assert_utf8(&zipped, &[Some("x"), None, Some("x")]);
+
+        let df_a = DataFrame::new(vec![a, Series::new("left", &[1, 2, 3])])?;
+        let df_b = DataFrame::new(vec![b, Series::new("right", &[1, 2, 3])])?;
+        let out = df_a.inner_join(&df_b, "a", "a")?;
+        assert_eq!(out.height(), 2);
+        assert_eq!(Vec::from(out.column("right")?.i32()?), &[Some(3), Some(3)]);
+
+        let out = df_a.outer_join(&df_b, "a", "a")?;
+        assert_eq!(out.height(), 5);
+
+        let stacked = df_a.vstack(&df_b)?;
+        assert_utf8(
+            stacked.column("a")?,
+            &[Some("x"), Some("y"), Some("x"), Some("z"), None, Some("x")],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_categorical_ordering() -> Result<()> {
+        let s = Series::new("level", &["medium", "low", "high", "low"])
+            .cast_with_datatype(&DataType::Categorical)?;
+        let mut ca = s.categorical()?.clone();
+        let order = vec!["low".to_string(), "medium".to_string()];
+        assert!(ca
+            .set_ordering(CategoricalOrdering::User(order.clone()))
+            .is_err());
+        let order = vec!["low".to_string(), "medium".to_string(), "high".to_string()];
+        ca.set_ordering(CategoricalOrdering::User(order))?;
+        let s = ca.clone().into_series();
+
+        assert_utf8(
+            &s.sort(false),
+            &[Some("low"), Some("low"), Some("medium"), Some("high")],
+        );
+        assert_eq!(
+            Vec::from(&s.lt("medium")),
+            &[Some(false), Some(true), Some(false), Some(true)]
+        );
+        let other = Series::new("other", &["high", "high", "low", "medium"]);
+        assert_eq!(
+            Vec::from(&s.gt(&other)),
+            &[Some(false), Some(false), Some(true), Some(false)]
+        );
+        assert_utf8(&s.max_as_series(), &[Some("high")]);
+        assert_utf8(&s.min_as_series(), &[Some("low")]);
+
+        ca.set_ordering(CategoricalOrdering::Lexical)?;
+        let s = ca.into_series();
+        assert_utf8(
+            &s.sort(true),
+            &[Some("medium"), Some("low"), Some("low"), Some("high")],
+        );
+        assert_utf8(&s.min_as_series(), &[Some("high")]);
+
+        let df = DataFrame::new(vec![
+            Series::new("key", &["a", "a", "b", "b"]),
+            s,
+            Series::new("val", &[1, 2, 3, 4]),
+        ])?;
+        let out = df.groupby("key")?.pivot("level", "val").sum()?;
+        let names: Vec<_> = out.get_column_names();
+        assert_eq!(names, &["key", "high", "low", "medium"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_toggle_string_cache() {
+        let _lock = SINGLE_LOCK.lock();
+        toggle_string_cache(true);
+        toggle_string_cache(true);
+        let holder = StringCacheHolder::new();
+        toggle_string_cache(false);
+        assert!(crate::use_string_cache());
+        drop(holder);
+        assert!(!crate::use_string_cache());
+    }
+
+    #[test]
+    fn test_toggle_string_cache_concurrent() {
+        let _lock = SINGLE_LOCK.lock();
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        toggle_string_cache(i % 2 == 0);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        // whatever the last toggle was, switching off releases the cache
+        toggle_string_cache(false);
+        assert!(!crate::use_string_cache());
+    }
+
+    #[test]
+    fn test_dictionary_round_trip() -> Result<()> {
+        use arrow::array::{Array, ArrayRef, DictionaryArray};
+        use std::convert::TryFrom;
+
+        let _lock = SINGLE_LOCK.lock();
+        let arr: DictionaryArray<Int32Type> = vec![Some("b"), None, Some("a"), Some("b")]
+            .into_iter()
+            .collect();
+        let s = Series::try_from(("a", Arc::new(arr) as ArrayRef))?;
+        assert_eq!(s.dtype(), &DataType::Categorical);
+        assert_utf8(&s, &[Some("b"), None, Some("a"), Some("b")]);
+
+        // global codes are not contiguous, so the dictionary has to be compacted
+        let _holder = StringCacheHolder::new();
+        let _ = Series::new("a", &["x", "y"]).cast_with_datatype(&DataType::Categorical)?;
+        let s = Series::new("a", &[Some("y"), Some("z"), None])
+            .cast_with_datatype(&DataType::Categorical)?;
+        let arr = s.to_arrow(0);
+        assert_eq!(arr.data_type(), &DataType::Categorical.to_arrow());
+        assert_eq!(arr.null_count(), 1);
+        let out = Series::try_from(("a", arr))?;
+        assert_utf8(&out, &[Some("y"), Some("z"), None]);
+        // the global cache is used on read, so the codes are unchanged
+        assert!(out
+            .cast::<UInt32Type>()?
+            .series_equal_missing(&s.cast::<UInt32Type>()?));
+        Ok(())
+    }
+}
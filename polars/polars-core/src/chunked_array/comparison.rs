@@ -6,7 +6,8 @@ use arrow::{
     compute::kernels::comparison,
 };
 use num::{Num, NumCast, ToPrimitive};
-use std::ops::{BitAnd, BitOr, Not};
+use std::cmp::Ordering;
+use std::ops::{BitAnd, BitOr, Deref, Not};
 use std::sync::Arc;
 
 impl<T> ChunkedArray<T>
@@ -416,6 +417,179 @@ impl ChunkCompare<&Utf8Chunked> for Utf8Chunked {
     }
 }
 
+impl BinaryChunked {
+    fn broadcast_cmp(
+        &self,
+        rhs: &BinaryChunked,
+        f: impl Fn(&[u8], &[u8]) -> bool,
+    ) -> BooleanChunked {
+        match rhs.get(0) {
+            Some(value) => self
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| f(v, value)))
+                .collect(),
+            None => BooleanChunked::full("", false, self.len()),
+        }
+    }
+}
+
+impl ChunkCompare<&BinaryChunked> for BinaryChunked {
+    fn eq_missing(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_eq_missing!(self, rhs)
+    }
+
+    fn eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        // broadcast
+        if rhs.len() == 1 {
+            self.broadcast_cmp(rhs, |a, b| a == b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(self, rhs, ==)
+        }
+    }
+
+    fn neq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        // broadcast
+        if rhs.len() == 1 {
+            self.broadcast_cmp(rhs, |a, b| a != b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(self, rhs, !=)
+        }
+    }
+
+    fn gt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        // broadcast
+        if rhs.len() == 1 {
+            self.broadcast_cmp(rhs, |a, b| a > b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(self, rhs, >)
+        }
+    }
+
+    fn gt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        // broadcast
+        if rhs.len() == 1 {
+            self.broadcast_cmp(rhs, |a, b| a >= b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(self, rhs, >=)
+        }
+    }
+
+    fn lt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        // broadcast
+        if rhs.len() == 1 {
+            self.broadcast_cmp(rhs, |a, b| a < b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(self, rhs, <)
+        }
+    }
+
+    fn lt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        // broadcast
+        if rhs.len() == 1 {
+            self.broadcast_cmp(rhs, |a, b| a <= b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(self, rhs, <=)
+        }
+    }
+}
+
+impl DecimalChunked {
+    /// Rescale both sides to the largest scale, so that the integers can be compared.
+    fn align_scale(&self, rhs: &DecimalChunked) -> (DecimalChunked, DecimalChunked) {
+        let scale = std::cmp::max(self.scale(), rhs.scale());
+        let rescale = |ca: &DecimalChunked| {
+            if ca.scale() == scale {
+                ca.clone()
+            } else {
+                ca.rescale(scale)
+                    .expect("cannot align the scales of the decimals")
+            }
+        };
+        (rescale(self), rescale(rhs))
+    }
+
+    fn broadcast_cmp(
+        &self,
+        rhs: &DecimalChunked,
+        f: impl Fn(i128, i128) -> bool,
+    ) -> BooleanChunked {
+        match rhs.get(0) {
+            Some(value) => self
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| f(v, value)))
+                .collect(),
+            None => BooleanChunked::full("", false, self.len()),
+        }
+    }
+}
+
+impl ChunkCompare<&DecimalChunked> for DecimalChunked {
+    fn eq_missing(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self.align_scale(rhs);
+        impl_eq_missing!(lhs, rhs)
+    }
+
+    fn eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self.align_scale(rhs);
+        // broadcast
+        if rhs.len() == 1 {
+            lhs.broadcast_cmp(&rhs, |a, b| a == b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(lhs, rhs, ==)
+        }
+    }
+
+    fn neq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self.align_scale(rhs);
+        // broadcast
+        if rhs.len() == 1 {
+            lhs.broadcast_cmp(&rhs, |a, b| a != b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(lhs, rhs, !=)
+        }
+    }
+
+    fn gt(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self.align_scale(rhs);
+        // broadcast
+        if rhs.len() == 1 {
+            lhs.broadcast_cmp(&rhs, |a, b| a > b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(lhs, rhs, >)
+        }
+    }
+
+    fn gt_eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self.align_scale(rhs);
+        // broadcast
+        if rhs.len() == 1 {
+            lhs.broadcast_cmp(&rhs, |a, b| a >= b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(lhs, rhs, >=)
+        }
+    }
+
+    fn lt(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self.align_scale(rhs);
+        // broadcast
+        if rhs.len() == 1 {
+            lhs.broadcast_cmp(&rhs, |a, b| a < b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(lhs, rhs, <)
+        }
+    }
+
+    fn lt_eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self.align_scale(rhs);
+        // broadcast
+        if rhs.len() == 1 {
+            lhs.broadcast_cmp(&rhs, |a, b| a <= b)
+        } else {
+            apply_operand_on_chunkedarray_by_iter!(lhs, rhs, <=)
+        }
+    }
+}
+
 pub trait NumComp: Num + NumCast + PartialOrd {}
 
 impl NumComp for f32 {}
@@ -499,6 +673,89 @@ impl ChunkCompare<&str> for Utf8Chunked {
     }
 }
 
+macro_rules! impl_cmp_categorical {
+    ($self:ident, $rhs:ident, $method:ident) => {{
+        if $rhs.len() == 1 {
+            match $rhs
+                .get_categorical_map()
+                .and_then(|m| m.get($rhs.deref().get(0)?))
+            {
+                Some(value) => $self.$method(value),
+                None => BooleanChunked::full_null($self.name(), $self.len()),
+            }
+        } else {
+            let (lhs, rhs) = $self
+                .merge_categories($rhs)
+                .expect("cannot compare categoricals of different string cache generations");
+            lhs.ordered_codes().$method(&rhs.ordered_codes())
+        }
+    }};
+}
+
+/// Categoricals are compared by the ordering of their categories, see [`CategoricalOrdering`].
+impl ChunkCompare<&CategoricalChunked> for CategoricalChunked {
+    fn eq_missing(&self, rhs: &CategoricalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self
+            .merge_categories(rhs)
+            .expect("cannot compare categoricals of different string cache generations");
+        lhs.deref().eq_missing(rhs.deref())
+    }
+
+    fn eq(&self, rhs: &CategoricalChunked) -> BooleanChunked {
+        impl_cmp_categorical!(self, rhs, eq)
+    }
+
+    fn neq(&self, rhs: &CategoricalChunked) -> BooleanChunked {
+        impl_cmp_categorical!(self, rhs, neq)
+    }
+
+    fn gt(&self, rhs: &CategoricalChunked) -> BooleanChunked {
+        impl_cmp_categorical!(self, rhs, gt)
+    }
+
+    fn gt_eq(&self, rhs: &CategoricalChunked) -> BooleanChunked {
+        impl_cmp_categorical!(self, rhs, gt_eq)
+    }
+
+    fn lt(&self, rhs: &CategoricalChunked) -> BooleanChunked {
+        impl_cmp_categorical!(self, rhs, lt)
+    }
+
+    fn lt_eq(&self, rhs: &CategoricalChunked) -> BooleanChunked {
+        impl_cmp_categorical!(self, rhs, lt_eq)
+    }
+}
+
+impl ChunkCompare<&str> for CategoricalChunked {
+    fn eq_missing(&self, rhs: &str) -> BooleanChunked {
+        self.eq(rhs)
+    }
+
+    fn eq(&self, rhs: &str) -> BooleanChunked {
+        self.compare_str(rhs, true, |ord| ord == Ordering::Equal)
+    }
+
+    fn neq(&self, rhs: &str) -> BooleanChunked {
+        self.compare_str(rhs, true, |ord| ord != Ordering::Equal)
+    }
+
+    fn gt(&self, rhs: &str) -> BooleanChunked {
+        self.compare_str(rhs, false, |ord| ord == Ordering::Greater)
+    }
+
+    fn gt_eq(&self, rhs: &str) -> BooleanChunked {
+        self.compare_str(rhs, false, |ord| ord != Ordering::Less)
+    }
+
+    fn lt(&self, rhs: &str) -> BooleanChunked {
+        self.compare_str(rhs, false, |ord| ord == Ordering::Less)
+    }
+
+    fn lt_eq(&self, rhs: &str) -> BooleanChunked {
+        self.compare_str(rhs, false, |ord| ord != Ordering::Greater)
+    }
+}
+
 macro_rules! impl_cmp_list {
     ($self:ident, $rhs:ident, $cmp_method:ident) => {{
         match ($self.null_count(), $rhs.null_count()) {
@@ -0,0 +1,636 @@
+//! Fixed point numbers for values that must be exact, e.g. prices.
+//!
+//! A decimal is stored as an i128 that holds the value multiplied by `10^scale`. The `precision`
+//! is the maximum number of digits, so the absolute value of the integer is always smaller
+//! than `10^precision`.
+use crate::prelude::*;
+use std::cmp::{max, min};
+
+/// Maximum number of digits that fit in an i128.
+pub const MAX_PRECISION: usize = 38;
+
+#[inline]
+fn pow10(exp: usize) -> Option<i128> {
+    10i128.checked_pow(exp as u32)
+}
+
+/// Divide and round half away from zero.
+#[inline]
+fn div_round(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    let r = (a % b).abs();
+    if r >= b.abs() - r {
+        if (a < 0) == (b < 0) {
+            q + 1
+        } else {
+            q - 1
+        }
+    } else {
+        q
+    }
+}
+
+/// Change the number of digits after the decimal point of a single value.
+fn rescale_value(v: i128, from: usize, to: usize) -> Option<i128> {
+    if to >= from {
+        v.checked_mul(pow10(to - from)?)
+    } else {
+        Some(div_round(v, pow10(from - to)?))
+    }
+}
+
+/// Check if the integer of a decimal value has at most `precision` digits.
+#[inline]
+pub fn fits_precision(v: i128, precision: usize) -> bool {
+    match pow10(precision) {
+        Some(bound) => v.abs() < bound,
+        None => true,
+    }
+}
+
+/// What to do with results that don't fit in the precision of the output.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum OnOverflow {
+    Error,
+    Null,
+}
+
+fn overflow_err(op: &str, precision: usize, scale: usize) -> PolarsError {
+    PolarsError::ValueError(
+        format!(
+            "decimal overflow in {}: result does not fit in {:?}",
+            op,
+            DataType::Decimal(precision, scale)
+        )
+        .into(),
+    )
+}
+
+/// Parse a string like `-123.45` to an integer with `scale` digits after the decimal point.
+/// Superfluous fractional digits are rounded half away from zero.
+pub fn parse_decimal(s: &str, scale: usize) -> Option<i128> {
+    let s = s.trim();
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int_part, frac_part) = match s.find('.') {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => (s, ""),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    let mut v = 0i128;
+    for c in int_part.bytes().chain(frac_part.bytes()) {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        v = v.checked_mul(10)?.checked_add((c - b'0') as i128)?;
+    }
+    let v = rescale_value(v, frac_part.len(), scale)?;
+    Some(if negative { -v } else { v })
+}
+
+/// Format an integer with `scale` digits after the decimal point, e.g. `12345` with scale 2 as
+/// `123.45`.
+pub fn format_decimal(v: i128, scale: usize) -> String {
+    let digits = v.abs().to_string();
+    let sign = if v < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = if digits.len() <= scale {
+        format!("{}{}", "0".repeat(scale + 1 - digits.len()), digits)
+    } else {
+        digits
+    };
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, int_part, frac_part)
+}
+
+/// Number of digits needed to represent every value of an integer type.
+pub(crate) fn integer_precision(dtype: &DataType) -> Option<usize> {
+    match dtype {
+        DataType::Int8 | DataType::UInt8 => Some(3),
+        DataType::Int16 | DataType::UInt16 => Some(5),
+        DataType::Int32 | DataType::UInt32 => Some(10),
+        DataType::Int64 => Some(19),
+        DataType::UInt64 => Some(20),
+        _ => None,
+    }
+}
+
+/// Cast a Series of integers, floats, strings or decimals to a decimal. Values that cannot be
+/// represented are null.
+pub(crate) fn cast_to_decimal(
+    s: &Series,
+    precision: usize,
+    scale: usize,
+) -> Result<DecimalChunked> {
+    let to_decimal = |opt_v: Option<i128>| {
+        opt_v
+            .and_then(|v| rescale_value(v, 0, scale))
+            .filter(|v| fits_precision(*v, precision))
+    };
+    let mut ca = match s.dtype() {
+        DataType::Decimal(_, _) => {
+            let ca = s.decimal()?;
+            DecimalChunked::new_from_opt_iter(
+                s.name(),
+                precision,
+                scale,
+                ca.into_iter().map(|opt_v| {
+                    opt_v
+                        .and_then(|v| rescale_value(v, ca.scale(), scale))
+                        .filter(|v| fits_precision(*v, precision))
+                }),
+            )
+        }
+        DataType::UInt64 => DecimalChunked::new_from_opt_iter(
+            s.name(),
+            precision,
+            scale,
+            s.u64()?
+                .into_iter()
+                .map(|opt_v| to_decimal(opt_v.map(|v| v as i128))),
+        ),
+        dt if integer_precision(dt).is_some() => {
+            let s = s.cast::<Int64Type>()?;
+            DecimalChunked::new_from_opt_iter(
+                s.name(),
+                precision,
+                scale,
+                s.i64()?
+                    .into_iter()
+                    .map(|opt_v| to_decimal(opt_v.map(|v| v as i128))),
+            )
+        }
+        DataType::Float32 | DataType::Float64 => {
+            let s = s.cast::<Float64Type>()?;
+            let multiplier = 10f64.powi(scale as i32);
+            let bound = 10f64.powi(precision as i32);
+            DecimalChunked::new_from_opt_iter(
+                s.name(),
+                precision,
+                scale,
+                s.f64()?.into_iter().map(|opt_v| {
+                    opt_v
+                        .map(|v| (v * multiplier).round())
+                        .filter(|v| v.is_finite() && v.abs() < bound)
+                        .map(|v| v as i128)
+                }),
+            )
+        }
+        DataType::Utf8 => s.utf8()?.parse_decimal(precision, scale, false)?,
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "cannot cast {:?} to {:?}",
+                    dt,
+                    DataType::Decimal(precision, scale)
+                )
+                .into(),
+            ))
+        }
+    };
+    ca.rename(s.name());
+    Ok(ca)
+}
+
+impl DecimalChunked {
+    /// Create a new DecimalChunked from an iterator of integers that are already multiplied by
+    /// `10^scale`.
+    pub fn new_from_opt_iter(
+        name: &str,
+        precision: usize,
+        scale: usize,
+        iter: impl Iterator<Item = Option<i128>>,
+    ) -> Self {
+        let mut builder = DecimalChunkedBuilder::new(name, iter.size_hint().0, precision, scale);
+        for opt_v in iter {
+            builder.append_option(opt_v);
+        }
+        builder.finish()
+    }
+
+    /// Create a new DecimalChunked with the name, precision and scale of `self`.
+    pub(crate) fn new_with_same_type(&self, iter: impl Iterator<Item = Option<i128>>) -> Self {
+        Self::new_from_opt_iter(self.name(), self.precision(), self.scale(), iter)
+    }
+
+    /// Maximum number of digits of the values.
+    pub fn precision(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Number of digits after the decimal point.
+    pub fn scale(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(_, scale) => *scale,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Change the number of digits after the decimal point. Reducing the scale rounds half away
+    /// from zero and adds a digit to the precision for values that round up, e.g. `9.99` to
+    /// `10.0`. Increasing it errors if the values no longer fit in 38 digits.
+    pub fn rescale(&self, scale: usize) -> Result<DecimalChunked> {
+        let from = self.scale();
+        let precision = if scale < from {
+            min(MAX_PRECISION, self.precision() - (from - scale) + 1)
+        } else {
+            min(MAX_PRECISION, self.precision() + scale - from)
+        };
+        let values = self
+            .into_iter()
+            .map(|opt_v| match opt_v {
+                Some(v) => match rescale_value(v, from, scale) {
+                    Some(v) if fits_precision(v, precision) => Ok(Some(v)),
+                    _ => Err(overflow_err("rescale", precision, scale)),
+                },
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DecimalChunked::new_from_opt_iter(
+            self.name(),
+            precision,
+            scale,
+            values.into_iter(),
+        ))
+    }
+
+    fn binary_op<F>(
+        &self,
+        rhs: &DecimalChunked,
+        op: &str,
+        precision: usize,
+        scale: usize,
+        on_overflow: OnOverflow,
+        f: F,
+    ) -> Result<DecimalChunked>
+    where
+        F: Fn(i128, i128) -> Option<Option<i128>>,
+    {
+        let overflow = || match on_overflow {
+            OnOverflow::Error => Err(overflow_err(op, precision, scale)),
+            OnOverflow::Null => Ok(None),
+        };
+        let apply = |(l, r): (Option<i128>, Option<i128>)| match (l, r) {
+            (Some(l), Some(r)) => match f(l, r) {
+                Some(Some(v)) if !fits_precision(v, precision) => overflow(),
+                Some(out) => Ok(out),
+                None => overflow(),
+            },
+            _ => Ok(None),
+        };
+        let values = match (self.len(), rhs.len()) {
+            (_, 1) => {
+                let r = rhs.get(0);
+                self.into_iter()
+                    .map(|l| apply((l, r)))
+                    .collect::<Result<Vec<_>>>()?
+            }
+            (1, _) => {
+                let l = self.get(0);
+                rhs.into_iter()
+                    .map(|r| apply((l, r)))
+                    .collect::<Result<Vec<_>>>()?
+            }
+            (l_len, r_len) if l_len == r_len => self
+                .into_iter()
+                .zip(rhs.into_iter())
+                .map(apply)
+                .collect::<Result<Vec<_>>>()?,
+            _ => {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!("cannot apply {} on decimals of different lengths", op).into(),
+                ))
+            }
+        };
+        Ok(DecimalChunked::new_from_opt_iter(
+            self.name(),
+            precision,
+            scale,
+            values.into_iter(),
+        ))
+    }
+
+    /// Precision and scale of the result of adding or subtracting `rhs`.
+    fn additive_type(&self, rhs: &DecimalChunked) -> (usize, usize) {
+        let scale = max(self.scale(), rhs.scale());
+        let int_digits = max(
+            self.precision().saturating_sub(self.scale()),
+            rhs.precision().saturating_sub(rhs.scale()),
+        );
+        (min(MAX_PRECISION, int_digits + scale + 1), scale)
+    }
+
+    /// Add two decimals exactly. The result has the larger scale of both sides. Errors if a
+    /// result does not fit in the precision of the output.
+    pub fn try_add(&self, rhs: &DecimalChunked) -> Result<DecimalChunked> {
+        self.add_with(rhs, OnOverflow::Error)
+    }
+
+    pub(crate) fn add_with(
+        &self,
+        rhs: &DecimalChunked,
+        on_overflow: OnOverflow,
+    ) -> Result<DecimalChunked> {
+        let (precision, scale) = self.additive_type(rhs);
+        let (l_scale, r_scale) = (self.scale(), rhs.scale());
+        self.binary_op(rhs, "addition", precision, scale, on_overflow, |l, r| {
+            let l = rescale_value(l, l_scale, scale)?;
+            let r = rescale_value(r, r_scale, scale)?;
+            l.checked_add(r).map(Some)
+        })
+    }
+
+    /// Subtract two decimals exactly. The result has the larger scale of both sides. Errors if a
+    /// result does not fit in the precision of the output.
+    pub fn try_sub(&self, rhs: &DecimalChunked) -> Result<DecimalChunked> {
+        self.sub_with(rhs, OnOverflow::Error)
+    }
+
+    pub(crate) fn sub_with(
+        &self,
+        rhs: &DecimalChunked,
+        on_overflow: OnOverflow,
+    ) -> Result<DecimalChunked> {
+        let (precision, scale) = self.additive_type(rhs);
+        let (l_scale, r_scale) = (self.scale(), rhs.scale());
+        self.binary_op(rhs, "subtraction", precision, scale, on_overflow, |l, r| {
+            let l = rescale_value(l, l_scale, scale)?;
+            let r = rescale_value(r, r_scale, scale)?;
+            l.checked_sub(r).map(Some)
+        })
+    }
+
+    /// Multiply two decimals exactly. The scale of the result is the sum of both scales. Errors if
+    /// a result does not fit in the precision of the output.
+    pub fn try_mul(&self, rhs: &DecimalChunked) -> Result<DecimalChunked> {
+        self.mul_with(rhs, OnOverflow::Error)
+    }
+
+    /// Errors if the scale of the output is larger than 38, regardless of `on_overflow`.
+    pub(crate) fn mul_with(
+        &self,
+        rhs: &DecimalChunked,
+        on_overflow: OnOverflow,
+    ) -> Result<DecimalChunked> {
+        let precision = min(MAX_PRECISION, self.precision() + rhs.precision());
+        let scale = self.scale() + rhs.scale();
+        if scale > MAX_PRECISION {
+            return Err(overflow_err("multiplication", precision, scale));
+        }
+        self.binary_op(
+            rhs,
+            "multiplication",
+            precision,
+            scale,
+            on_overflow,
+            |l, r| l.checked_mul(r).map(Some),
+        )
+    }
+
+    /// Divide two decimals. The result has the larger scale of both sides and is rounded half
+    /// away from zero. Division by zero results in null. Errors if a result does not fit in the
+    /// precision of the output.
+    pub fn try_div(&self, rhs: &DecimalChunked) -> Result<DecimalChunked> {
+        self.div_with(rhs, OnOverflow::Error)
+    }
+
+    pub(crate) fn div_with(
+        &self,
+        rhs: &DecimalChunked,
+        on_overflow: OnOverflow,
+    ) -> Result<DecimalChunked> {
+        let scale = max(self.scale(), rhs.scale());
+        let precision = min(
+            MAX_PRECISION,
+            self.precision().saturating_sub(self.scale()) + rhs.scale() + scale,
+        );
+        // l / 10^s1 / (r / 10^s2) * 10^s = l * 10^(s - s1 + s2) / r
+        let shift = scale - self.scale() + rhs.scale();
+        self.binary_op(rhs, "division", precision, scale, on_overflow, |l, r| {
+            if r == 0 {
+                return Some(None);
+            }
+            let l = l.checked_mul(pow10(shift)?)?;
+            Some(Some(div_round(l, r)))
+        })
+    }
+
+    /// Sum the values exactly. Errors if the sum does not fit in 38 digits. The sum of a Series
+    /// and of the groups of a groupby are null instead.
+    pub fn try_sum(&self) -> Result<Option<i128>> {
+        if self.null_count() == self.len() {
+            return Ok(None);
+        }
+        self.into_iter()
+            .flatten()
+            .try_fold(0i128, |acc, v| acc.checked_add(v))
+            .filter(|sum| fits_precision(*sum, MAX_PRECISION))
+            .map(Some)
+            .ok_or_else(|| overflow_err("sum", MAX_PRECISION, self.scale()))
+    }
+
+    /// Mean of the values with the same scale, rounded half away from zero. Errors if the sum of
+    /// the values does not fit in 38 digits. The mean of a Series and of the groups of a groupby
+    /// are null instead.
+    pub fn try_mean(&self) -> Result<Option<i128>> {
+        let count = (self.len() - self.null_count()) as i128;
+        Ok(self.try_sum()?.map(|sum| div_round(sum, count)))
+    }
+
+    /// Convert to floats. This may lose precision.
+    pub fn to_f64(&self) -> Float64Chunked {
+        let divisor = 10f64.powi(self.scale() as i32);
+        let mut ca: Float64Chunked = self
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| v as f64 / divisor))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+}
+
+impl Utf8Chunked {
+    /// Parse the strings to decimals with `scale` digits after the decimal point. Superfluous
+    /// fractional digits are rounded half away from zero.
+    ///
+    /// If `strict`, values that cannot be parsed or that have more than `precision` digits
+    /// return an error, otherwise they are null.
+    pub fn parse_decimal(
+        &self,
+        precision: usize,
+        scale: usize,
+        strict: bool,
+    ) -> Result<DecimalChunked> {
+        let values = self
+            .into_iter()
+            .enumerate()
+            .map(|(idx, opt_s)| match opt_s {
+                Some(s) => {
+                    match parse_decimal(s, scale).filter(|v| fits_precision(*v, precision)) {
+                        Some(v) => Ok(Some(v)),
+                        None if strict => Err(PolarsError::ValueError(
+                            format!(
+                                "could not parse '{}' at row {} of column '{}' as {:?}",
+                                s,
+                                idx,
+                                self.name(),
+                                DataType::Decimal(precision, scale)
+                            )
+                            .into(),
+                        )),
+                        None => Ok(None),
+                    }
+                }
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DecimalChunked::new_from_opt_iter(
+            self.name(),
+            precision,
+            scale,
+            values.into_iter(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn decimals(values: &[Option<&str>], precision: usize, scale: usize) -> DecimalChunked {
+        let ca: Utf8Chunked = values.iter().copied().collect();
+        ca.parse_decimal(precision, scale, true).unwrap()
+    }
+
+    fn fmt(ca: &DecimalChunked) -> Vec<Option<String>> {
+        ca.into_iter()
+            .map(|opt_v| opt_v.map(|v| format_decimal(v, ca.scale())))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_format_decimal() {
+        assert_eq!(parse_decimal("12.345", 2), Some(1235));
+        assert_eq!(parse_decimal("-12.345", 2), Some(-1235));
+        assert_eq!(parse_decimal("7", 3), Some(7000));
+        assert_eq!(parse_decimal(".5", 1), Some(5));
+        assert_eq!(parse_decimal("1.2.3", 1), None);
+        assert_eq!(parse_decimal("-", 1), None);
+        assert_eq!(format_decimal(-5, 3), "-0.005");
+        assert_eq!(format_decimal(12345, 2), "123.45");
+        assert_eq!(format_decimal(42, 0), "42");
+
+        let ca = Utf8Chunked::new_from_slice("a", &["1.5", "abc", "123456"]);
+        assert!(ca.parse_decimal(5, 2, true).is_err());
+        let out = ca.parse_decimal(5, 2, false).unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(5, 2));
+        assert_eq!(Vec::from(&out.to_f64()), &[Some(1.5), None, None]);
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() {
+        let a = decimals(&[Some("0.10"), Some("1.25"), None], 10, 2);
+        let b = decimals(&[Some("0.200"), Some("-2.5"), Some("1")], 10, 3);
+
+        let out = a.try_add(&b).unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(12, 3));
+        assert_eq!(
+            fmt(&out),
+            &[Some("0.300".into()), Some("-1.250".into()), None]
+        );
+        let out = a.try_sub(&b).unwrap();
+        assert_eq!(
+            fmt(&out),
+            &[Some("-0.100".into()), Some("3.750".into()), None]
+        );
+        let out = a.try_mul(&b).unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(20, 5));
+        assert_eq!(
+            fmt(&out),
+            &[Some("0.02000".into()), Some("-3.12500".into()), None]
+        );
+        let out = a.try_div(&b).unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(14, 3));
+        assert_eq!(
+            fmt(&out),
+            &[Some("0.500".into()), Some("-0.500".into()), None]
+        );
+
+        let one_third = decimals(&[Some("1")], 5, 0)
+            .try_div(&decimals(&[Some("3.0000")], 5, 4))
+            .unwrap();
+        assert_eq!(fmt(&one_third), &[Some("0.3333".into())]);
+        let zero = decimals(&[Some("0")], 5, 0);
+        assert_eq!(a.try_div(&zero).unwrap().null_count(), 3);
+
+        let big = decimals(&[Some("9".repeat(38).as_str())], 38, 0);
+        assert!(big.try_add(&big).is_err());
+        assert!(big.try_mul(&big).is_err());
+    }
+
+    #[test]
+    fn test_decimal_series() {
+        let a = decimals(&[Some("0.1"), Some("0.2"), Some("0.3")], 5, 1).into_series();
+        let b = Series::new("b", &[1, 2, 3]);
+        let out = &a + &b;
+        assert_eq!(out.dtype(), &DataType::Decimal(12, 1));
+        assert_eq!(
+            fmt(out.decimal().unwrap()),
+            &[Some("1.1".into()), Some("2.2".into()), Some("3.3".into())]
+        );
+        let out = &a * &b;
+        assert_eq!(format!("{}", out.get(2)), "0.9");
+
+        assert_eq!(a.sum_as_series().get(0), AnyValue::Decimal(6, 1));
+        assert_eq!(a.mean_as_series().get(0), AnyValue::Decimal(2, 1));
+        assert_eq!(a.sum::<f64>(), Some(0.6));
+
+        // the operators return null instead of an error on overflow
+        let big = decimals(&[Some("9".repeat(38).as_str()), Some("1")], 38, 0).into_series();
+        let out = &big + &big;
+        assert_eq!(out.get(0), AnyValue::Null);
+        assert_eq!(out.get(1), AnyValue::Decimal(2, 0));
+        assert_eq!((&big * &big).null_count(), 1);
+
+        // the sum of a Series and of a group are null on overflow, try_sum errors
+        assert!(big.decimal().unwrap().try_sum().is_err());
+        assert_eq!(big.sum_as_series().get(0), AnyValue::Null);
+        let mut big = big;
+        big.rename("big");
+        let df = DataFrame::new(vec![Series::new("g", &[1, 1]), big]).unwrap();
+        let out = df.groupby("g").unwrap().sum().unwrap();
+        assert_eq!(out.column("big_sum").unwrap().get(0), AnyValue::Null);
+
+        let f = Series::new("f", &[0.5f64, 0.5, 0.5]);
+        assert_eq!((&a + &f).dtype(), &DataType::Float64);
+
+        // reducing the scale can round up to an extra integer digit
+        let out = decimals(&[Some("9.99"), Some("-9.95"), Some("0.04")], 3, 2)
+            .rescale(1)
+            .unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(3, 1));
+        assert_eq!(
+            fmt(&out),
+            &[
+                Some("10.0".into()),
+                Some("-10.0".into()),
+                Some("0.0".into())
+            ]
+        );
+
+        let s = a.cast_with_datatype(&DataType::Decimal(5, 3)).unwrap();
+        assert_eq!(s.get(0), AnyValue::Decimal(100, 3));
+        let s = s.cast_with_datatype(&DataType::Utf8).unwrap();
+        assert_eq!(Vec::from(s.utf8().unwrap())[0], Some("0.100"));
+    }
+}
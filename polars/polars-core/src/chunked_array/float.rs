@@ -103,3 +103,16 @@ where
         self.apply_kernel_cast(is_infinite)
     }
 }
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    /// Replace the NaN values with `value`. Null values are not replaced.
+    pub fn fill_nan(&self, value: T::Native) -> Self {
+        let mut ca = self.apply(|v| if v.is_nan() { value } else { v });
+        ca.rename(self.name());
+        ca
+    }
+}
@@ -1,9 +1,11 @@
 use crate::datatypes::CategoricalChunked;
 use crate::prelude::{
-    BooleanChunked, ChunkedArray, Downcast, ListChunked, PolarsNumericType, Series, Utf8Chunked,
+    BinaryChunked, BooleanChunked, ChunkedArray, DecimalChunked, Downcast, ListChunked,
+    PolarsNumericType, Series, Utf8Chunked,
 };
 use arrow::array::{
-    Array, ArrayDataRef, ArrayRef, BooleanArray, LargeListArray, LargeStringArray, PrimitiveArray,
+    Array, ArrayDataRef, ArrayRef, BooleanArray, DecimalArray, LargeBinaryArray, LargeListArray,
+    LargeStringArray, PrimitiveArray,
 };
 use std::convert::TryFrom;
 use std::iter::Copied;
@@ -1413,6 +1415,24 @@ impl_all_iterators!(
     Utf8IterManyChunkNullCheck,
     &'a str
 );
+impl_all_iterators!(
+    BinaryChunked,
+    LargeBinaryArray,
+    BinaryIterSingleChunk,
+    BinaryIterSingleChunkNullCheck,
+    BinaryIterManyChunk,
+    BinaryIterManyChunkNullCheck,
+    &'a [u8]
+);
+impl_all_iterators!(
+    DecimalChunked,
+    DecimalArray,
+    DecimalIterSingleChunk,
+    DecimalIterSingleChunkNullCheck,
+    DecimalIterManyChunk,
+    DecimalIterManyChunkNullCheck,
+    i128
+);
 impl_all_iterators!(
     BooleanChunked,
     BooleanArray,
@@ -1,5 +1,7 @@
 pub(crate) mod cast;
 pub mod set;
+pub(crate) mod sketch;
+pub(crate) mod skiplist;
 pub(crate) mod take;
 #[cfg(feature = "temporal")]
 #[doc(cfg(feature = "temporal"))]
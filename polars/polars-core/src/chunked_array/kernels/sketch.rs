@@ -0,0 +1,285 @@
+//! Mergeable sketches for approximate aggregations. The state of a sketch can be serialized to
+//! bytes, such that partial results of partitions or batches can be stored in a `Binary` column
+//! and merged later on.
+use crate::prelude::*;
+use std::convert::TryInto;
+
+/// The number of bits of the hash that select a register.
+const HLL_PRECISION: u32 = 12;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// A HyperLogLog sketch to estimate the number of distinct values. The relative error is
+/// about `1.04 / sqrt(4096) = 1.6%`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    pub(crate) fn new() -> Self {
+        HyperLogLog {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+
+    pub(crate) fn add_hash(&mut self, hash: u64) {
+        let idx = (hash >> (64 - HLL_PRECISION)) as usize;
+        // the position of the first set bit in the remaining bits, a sentinel bit limits the rank
+        let rest = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if rank > self.registers[idx] {
+            self.registers[idx] = rank
+        }
+    }
+
+    pub(crate) fn merge(&mut self, other: &HyperLogLog) {
+        self.registers
+            .iter_mut()
+            .zip(&other.registers)
+            .for_each(|(a, b)| *a = std::cmp::max(*a, *b))
+    }
+
+    pub(crate) fn estimate(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum = self
+            .registers
+            .iter()
+            .map(|&rank| 2f64.powi(-(rank as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+        let n_zeros = self.registers.iter().filter(|&&rank| rank == 0).count();
+        // small range correction with linear counting
+        if estimate <= 2.5 * m && n_zeros > 0 {
+            (m * (m / n_zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        self.registers.clone()
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != HLL_REGISTERS {
+            return Err(PolarsError::ValueError(
+                "bytes are not a serialized HyperLogLog sketch".into(),
+            ));
+        }
+        Ok(HyperLogLog {
+            registers: bytes.to_vec(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging t-digest to estimate quantiles. The values are summarized by centroids, that are
+/// small near the tails and larger near the median.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct TDigest {
+    centroids: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+/// Controls the number of centroids, and with that the trade off between size and accuracy.
+const COMPRESSION: f64 = 100.0;
+
+/// The k1 scale function maps a quantile to the index of a centroid.
+fn k_scale(q: f64) -> f64 {
+    COMPRESSION / (2.0 * std::f64::consts::PI) * (2.0 * q - 1.0).asin()
+}
+
+fn k_scale_inv(k: f64) -> f64 {
+    if k >= COMPRESSION / 4.0 {
+        1.0
+    } else {
+        ((2.0 * std::f64::consts::PI * k / COMPRESSION).sin() + 1.0) / 2.0
+    }
+}
+
+impl TDigest {
+    /// Create a t-digest from values. NaN values are ignored.
+    pub(crate) fn new(values: impl Iterator<Item = f64>) -> Self {
+        let centroids = values
+            .filter(|v| !v.is_nan())
+            .map(|mean| Centroid { mean, weight: 1.0 })
+            .collect();
+        let mut digest = TDigest {
+            centroids,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        };
+        digest.compress();
+        digest
+    }
+
+    pub(crate) fn merge(&mut self, other: &TDigest) {
+        self.centroids.extend_from_slice(&other.centroids);
+        // the extremes of `other` can be inside its centroids
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.compress();
+    }
+
+    fn compress(&mut self) {
+        if self.centroids.is_empty() {
+            return;
+        }
+        self.centroids
+            .sort_unstable_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap());
+        self.min = self.min.min(self.centroids[0].mean);
+        self.max = self.max.max(self.centroids[self.centroids.len() - 1].mean);
+
+        let total = self.centroids.iter().map(|c| c.weight).sum::<f64>();
+        let mut compressed = Vec::with_capacity(COMPRESSION as usize);
+        let mut current = self.centroids[0];
+        let mut weight_so_far = 0.0;
+        let mut q_limit = k_scale_inv(k_scale(0.0) + 1.0);
+        for c in &self.centroids[1..] {
+            let q = (weight_so_far + current.weight + c.weight) / total;
+            if q <= q_limit {
+                let weight = current.weight + c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                compressed.push(current);
+                q_limit = k_scale_inv(k_scale(weight_so_far / total) + 1.0);
+                current = *c;
+            }
+        }
+        compressed.push(current);
+        self.centroids = compressed;
+    }
+
+    /// Estimate a quantile by interpolating between the centers of the centroids.
+    pub(crate) fn quantile(&self, quantile: f64) -> Option<f64> {
+        let first = self.centroids.first()?;
+        let last = self.centroids[self.centroids.len() - 1];
+        let total = self.centroids.iter().map(|c| c.weight).sum::<f64>();
+        let index = quantile * total;
+
+        if index <= first.weight / 2.0 {
+            let fraction = index / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * fraction);
+        }
+        if index >= total - last.weight / 2.0 {
+            let fraction = (total - index) / (last.weight / 2.0);
+            return Some(self.max - (self.max - last.mean) * fraction);
+        }
+        let mut center = first.weight / 2.0;
+        for window in self.centroids.windows(2) {
+            let next_center = center + (window[0].weight + window[1].weight) / 2.0;
+            if index <= next_center {
+                let fraction = (index - center) / (next_center - center);
+                return Some(window[0].mean + (window[1].mean - window[0].mean) * fraction);
+            }
+            center = next_center;
+        }
+        Some(last.mean)
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity((2 + 2 * self.centroids.len()) * 8);
+        bytes.extend_from_slice(&self.min.to_le_bytes());
+        bytes.extend_from_slice(&self.max.to_le_bytes());
+        for c in &self.centroids {
+            bytes.extend_from_slice(&c.mean.to_le_bytes());
+            bytes.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 16 || bytes.len() % 16 != 0 {
+            return Err(PolarsError::ValueError(
+                "bytes are not a serialized t-digest".into(),
+            ));
+        }
+        let mut floats = bytes
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()));
+        let min = floats.next().unwrap();
+        let max = floats.next().unwrap();
+        let mut centroids = Vec::with_capacity(bytes.len() / 16 - 1);
+        while let (Some(mean), Some(weight)) = (floats.next(), floats.next()) {
+            centroids.push(Centroid { mean, weight })
+        }
+        Ok(TDigest {
+            centroids,
+            min,
+            max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hyperloglog() {
+        let mut hll = HyperLogLog::new();
+        // a simple mixing function as hash
+        let hash = |v: u64| {
+            let mut x = v.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            x ^= x >> 31;
+            x.wrapping_mul(0xBF58_476D_1CE4_E5B9)
+        };
+        (0..10_000).for_each(|v| hll.add_hash(hash(v)));
+        let estimate = hll.estimate() as f64;
+        assert!((estimate - 10_000.0).abs() / 10_000.0 < 0.05);
+
+        // merging with overlapping values
+        let mut other = HyperLogLog::new();
+        (5_000..20_000).for_each(|v| other.add_hash(hash(v)));
+        hll.merge(&other);
+        let hll = HyperLogLog::from_bytes(&hll.to_bytes()).unwrap();
+        let estimate = hll.estimate() as f64;
+        assert!((estimate - 20_000.0).abs() / 20_000.0 < 0.05);
+
+        let mut small = HyperLogLog::new();
+        (0..10).for_each(|v| small.add_hash(hash(v)));
+        assert_eq!(small.estimate(), 10);
+    }
+
+    #[test]
+    fn test_tdigest() {
+        let digest = TDigest::new([1.0, 2.0, 3.0, 4.0].iter().copied());
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(2.5));
+        assert_eq!(digest.quantile(1.0), Some(4.0));
+        assert_eq!(TDigest::new(std::iter::empty()).quantile(0.5), None);
+
+        let mut digest = TDigest::new((0..5_000).map(|v| v as f64));
+        let other = TDigest::new((5_000..10_001).map(|v| v as f64));
+        digest.merge(&other);
+        let digest = TDigest::from_bytes(&digest.to_bytes()).unwrap();
+        assert!(digest.centroids.len() < 200);
+        for &q in &[0.01, 0.25, 0.5, 0.9, 0.999] {
+            let estimate = digest.quantile(q).unwrap();
+            assert!((estimate - q * 10_000.0).abs() < 10_000.0 * 0.005);
+        }
+
+        // the extremes are kept when they are not the mean of a centroid
+        let other = TDigest {
+            centroids: vec![Centroid {
+                mean: 5.0,
+                weight: 10.0,
+            }],
+            min: -100.0,
+            max: 100.0,
+        };
+        let mut digest = TDigest::new(std::iter::once(1.0));
+        digest.merge(&other);
+        assert_eq!(digest.quantile(0.0), Some(-100.0));
+        assert_eq!(digest.quantile(1.0), Some(100.0));
+    }
+}
@@ -0,0 +1,197 @@
+//! An indexable skip list that keeps its values sorted and supports insertion, removal and
+//! access by rank in `O(log n)`. Used for the rolling median and quantiles.
+use std::cmp::Ordering;
+
+const NIL: usize = usize::MAX;
+
+/// Order floats and put NaN after all other values.
+fn total_cmp(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+struct Node {
+    value: f64,
+    // the next node at every level of this node
+    next: Vec<usize>,
+    // the number of values the link at every level skips over
+    width: Vec<usize>,
+}
+
+pub(crate) struct IndexableSkipList {
+    // the head is the first node, the other nodes are stored in insertion order
+    nodes: Vec<Node>,
+    // slots of removed nodes that can be reused
+    free: Vec<usize>,
+    max_levels: usize,
+    len: usize,
+    rng_state: u64,
+}
+
+impl IndexableSkipList {
+    /// Create a skip list that is efficient for up to `capacity` values.
+    pub(crate) fn new(capacity: usize) -> Self {
+        let max_levels = std::mem::size_of::<usize>() * 8 - capacity.leading_zeros() as usize + 1;
+        let head = Node {
+            value: f64::NAN,
+            next: vec![NIL; max_levels],
+            width: vec![1; max_levels],
+        };
+        let mut nodes = Vec::with_capacity(capacity + 1);
+        nodes.push(head);
+        IndexableSkipList {
+            nodes,
+            free: vec![],
+            max_levels,
+            len: 0,
+            rng_state: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Draw a level from a geometric distribution with p = 0.5 (xorshift).
+    fn random_levels(&mut self) -> usize {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        std::cmp::min(x.trailing_ones() as usize + 1, self.max_levels)
+    }
+
+    /// Find the last node at every level that is ordered before `value`. If `include_equal`
+    /// nodes equal to `value` are passed as well. Also returns the steps taken at every level.
+    fn find_chain(&self, value: f64, include_equal: bool) -> (Vec<usize>, Vec<usize>) {
+        let mut chain = vec![0; self.max_levels];
+        let mut steps_at_level = vec![0; self.max_levels];
+        let mut node = 0;
+        for level in (0..self.max_levels).rev() {
+            loop {
+                let next = self.nodes[node].next[level];
+                if next == NIL {
+                    break;
+                }
+                match total_cmp(self.nodes[next].value, value) {
+                    Ordering::Less => {}
+                    Ordering::Equal if include_equal => {}
+                    _ => break,
+                }
+                steps_at_level[level] += self.nodes[node].width[level];
+                node = next;
+            }
+            chain[level] = node;
+        }
+        (chain, steps_at_level)
+    }
+
+    pub(crate) fn insert(&mut self, value: f64) {
+        let (chain, steps_at_level) = self.find_chain(value, true);
+        let levels = self.random_levels();
+
+        let new = match self.free.pop() {
+            Some(idx) => {
+                let node = &mut self.nodes[idx];
+                node.value = value;
+                node.next.clear();
+                node.next.resize(levels, NIL);
+                node.width.clear();
+                node.width.resize(levels, 0);
+                idx
+            }
+            None => {
+                self.nodes.push(Node {
+                    value,
+                    next: vec![NIL; levels],
+                    width: vec![0; levels],
+                });
+                self.nodes.len() - 1
+            }
+        };
+
+        let mut steps = 0;
+        for (level, &prev) in chain.iter().enumerate().take(levels) {
+            self.nodes[new].next[level] = self.nodes[prev].next[level];
+            self.nodes[prev].next[level] = new;
+            self.nodes[new].width[level] = self.nodes[prev].width[level] - steps;
+            self.nodes[prev].width[level] = steps + 1;
+            steps += steps_at_level[level];
+        }
+        for (level, &prev) in chain.iter().enumerate().skip(levels) {
+            self.nodes[prev].width[level] += 1;
+        }
+        self.len += 1;
+    }
+
+    /// Remove a single occurrence of `value`. Returns `false` if the value is not in the list.
+    pub(crate) fn remove(&mut self, value: f64) -> bool {
+        let (chain, _) = self.find_chain(value, false);
+        let found = self.nodes[chain[0]].next[0];
+        if found == NIL || total_cmp(self.nodes[found].value, value) != Ordering::Equal {
+            return false;
+        }
+
+        let levels = self.nodes[found].next.len();
+        for (level, &prev) in chain.iter().enumerate().take(levels) {
+            self.nodes[prev].width[level] += self.nodes[found].width[level] - 1;
+            self.nodes[prev].next[level] = self.nodes[found].next[level];
+        }
+        for (level, &prev) in chain.iter().enumerate().skip(levels) {
+            self.nodes[prev].width[level] -= 1;
+        }
+        self.free.push(found);
+        self.len -= 1;
+        true
+    }
+
+    /// Get the value with rank `idx`, i.e. the `idx`th value in sorted order.
+    pub(crate) fn get(&self, idx: usize) -> Option<f64> {
+        if idx >= self.len {
+            return None;
+        }
+        let mut remaining = idx + 1;
+        let mut node = 0;
+        for level in (0..self.max_levels).rev() {
+            while self.nodes[node].next[level] != NIL && self.nodes[node].width[level] <= remaining
+            {
+                remaining -= self.nodes[node].width[level];
+                node = self.nodes[node].next[level];
+            }
+        }
+        Some(self.nodes[node].value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_skiplist() {
+        let mut list = IndexableSkipList::new(8);
+        for v in &[5.0, 1.0, 3.0, 3.0, f64::NAN, -2.0] {
+            list.insert(*v)
+        }
+        let sorted = (0..list.len())
+            .map(|i| list.get(i).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(&sorted[..5], &[-2.0, 1.0, 3.0, 3.0, 5.0]);
+        assert!(sorted[5].is_nan());
+
+        assert!(list.remove(3.0));
+        assert!(list.remove(f64::NAN));
+        assert!(!list.remove(4.0));
+        list.insert(0.0);
+        let sorted = (0..list.len())
+            .map(|i| list.get(i).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(sorted, &[-2.0, 0.0, 1.0, 3.0, 5.0]);
+        assert_eq!(list.get(5), None);
+    }
+}
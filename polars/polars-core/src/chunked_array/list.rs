@@ -0,0 +1,539 @@
+//! Operations on the individual lists of a [ListChunked].
+use crate::chunked_array::builder::get_list_builder;
+use crate::prelude::*;
+use arrow::array::{Array, LargeListArray};
+use num::{NumCast, ToPrimitive};
+use std::convert::TryFrom;
+use std::ops::Add;
+
+/// The location of every list in the flat values of a [ListChunked]: the start and the length,
+/// or `None` for a null list.
+type ListSlices = [Option<(usize, usize)>];
+
+/// Dispatch a kernel over the flat values of numeric lists. Returns `None` for other dtypes.
+macro_rules! apply_numeric_list_kernel {
+    ($values:expr, $kernel:ident $(, $args:expr)*) => {{
+        match $values.dtype() {
+            DataType::UInt8 => Some($kernel($values.u8().unwrap() $(, $args)*)),
+            DataType::UInt16 => Some($kernel($values.u16().unwrap() $(, $args)*)),
+            DataType::UInt32 => Some($kernel($values.u32().unwrap() $(, $args)*)),
+            DataType::UInt64 => Some($kernel($values.u64().unwrap() $(, $args)*)),
+            DataType::Int8 => Some($kernel($values.i8().unwrap() $(, $args)*)),
+            DataType::Int16 => Some($kernel($values.i16().unwrap() $(, $args)*)),
+            DataType::Int32 => Some($kernel($values.i32().unwrap() $(, $args)*)),
+            DataType::Int64 => Some($kernel($values.i64().unwrap() $(, $args)*)),
+            DataType::Float32 => Some($kernel($values.f32().unwrap() $(, $args)*)),
+            DataType::Float64 => Some($kernel($values.f64().unwrap() $(, $args)*)),
+            _ => None,
+        }
+    }};
+}
+
+/// Fold the non-null values of every list with `f` and count them. Null lists and lists
+/// without non-null values result in `None`.
+fn fold_lists_with_count<'a, T, F>(
+    values: &'a ChunkedArray<T>,
+    slices: &'a ListSlices,
+    f: F,
+) -> impl Iterator<Item = Option<(T::Native, usize)>> + 'a
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native + 'a,
+{
+    // the values come from a single arrow array
+    let arr = values.downcast_chunks()[0];
+    slices.iter().map(move |opt_slice| {
+        opt_slice.and_then(|(start, len)| {
+            (start..start + len)
+                .filter(|&i| arr.is_valid(i))
+                .map(|i| arr.value(i))
+                .fold(None, |acc, v| match acc {
+                    Some((acc, count)) => Some((f(acc, v), count + 1)),
+                    None => Some((v, 1)),
+                })
+        })
+    })
+}
+
+/// Fold the non-null values of every list with `f`. Null lists and lists without non-null
+/// values result in null values.
+fn fold_lists<T, F>(values: &ChunkedArray<T>, slices: &ListSlices, f: F) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    fold_lists_with_count(values, slices, f)
+        .map(|opt| opt.map(|(v, _)| v))
+        .collect()
+}
+
+fn sum_lists<T>(values: &ChunkedArray<T>, slices: &ListSlices) -> Series
+where
+    T: PolarsNumericType,
+    T::Native: Add<Output = T::Native>,
+    ChunkedArray<T>: IntoSeries,
+{
+    fold_lists(values, slices, |acc, v| acc + v).into_series()
+}
+
+fn mean_lists<T>(values: &ChunkedArray<T>, slices: &ListSlices) -> Series
+where
+    T: PolarsNumericType,
+    T::Native: Add<Output = T::Native> + NumCast,
+{
+    // like the mean of a Series, null values are not counted
+    let ca: Float64Chunked = fold_lists_with_count(values, slices, |acc, v| acc + v)
+        .map(|opt| opt.map(|(sum, count)| sum.to_f64().unwrap() / count as f64))
+        .collect();
+    ca.into_series()
+}
+
+fn min_lists<T>(values: &ChunkedArray<T>, slices: &ListSlices) -> Series
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+    ChunkedArray<T>: IntoSeries,
+{
+    fold_lists(values, slices, |acc, v| if v < acc { v } else { acc }).into_series()
+}
+
+fn max_lists<T>(values: &ChunkedArray<T>, slices: &ListSlices) -> Series
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+    ChunkedArray<T>: IntoSeries,
+{
+    fold_lists(values, slices, |acc, v| if v > acc { v } else { acc }).into_series()
+}
+
+/// Get the index of the first minimal or maximal value of every list, compared in the native
+/// type of the values.
+fn arg_agg_lists<T>(values: &ChunkedArray<T>, slices: &ListSlices, max: bool) -> UInt32Chunked
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+{
+    let arr = values.downcast_chunks()[0];
+    slices
+        .iter()
+        .map(|opt_slice| {
+            opt_slice.and_then(|(start, len)| {
+                (start..start + len)
+                    .filter(|&i| arr.is_valid(i))
+                    .map(|i| (i - start, arr.value(i)))
+                    .fold(
+                        None,
+                        |acc: Option<(usize, T::Native)>, (idx, v)| match acc {
+                            Some((_, best)) if (max && v <= best) || (!max && v >= best) => acc,
+                            _ => Some((idx, v)),
+                        },
+                    )
+                    .map(|(idx, _)| idx as u32)
+            })
+        })
+        .collect()
+}
+
+/// Resolve a possibly negative index in a list of length `len`.
+fn list_index(idx: i64, len: usize) -> Option<usize> {
+    let idx = if idx < 0 { len as i64 + idx } else { idx };
+    if idx >= 0 && (idx as usize) < len {
+        Some(idx as usize)
+    } else {
+        None
+    }
+}
+
+impl ListChunked {
+    /// Get the dtype of the values in the lists.
+    pub fn inner_dtype(&self) -> DataType {
+        DataType::from(self.get_inner_dtype())
+    }
+
+    /// An empty Series with the dtype of the values in the lists.
+    fn empty_inner(&self) -> Result<Series> {
+        let values = self.explode()?;
+        values.slice(0, 0)
+    }
+
+    /// Get the values of all lists in a single Series, and the location of every list in
+    /// these values.
+    fn lst_flat_values(&self) -> Result<(Series, Vec<Option<(usize, usize)>>)> {
+        let ca = self.rechunk()?;
+        let arr: &LargeListArray = ca.downcast_chunks()[0];
+        let values = Series::try_from((self.name(), arr.values()))?;
+        let slices = (0..arr.len())
+            .map(|i| {
+                if arr.is_valid(i) {
+                    Some((arr.value_offset(i) as usize, arr.value_length(i) as usize))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Ok((values, slices))
+    }
+
+    /// Apply `f` to every list and concatenate the outputs, which should have a length of 1.
+    /// Null lists result in null values. This creates a Series for every list, so it is only
+    /// used for dtypes that don't have a kernel over the flat values.
+    fn lst_apply_single<F>(&self, f: F) -> Result<Series>
+    where
+        F: Fn(&Series) -> Result<Series>,
+    {
+        // the output on an empty list determines the dtype of the nulls
+        let null = f(&self.empty_inner()?)?.take_opt_iter(&mut std::iter::once(None), Some(1));
+        let mut out = null.slice(0, 0)?;
+        for opt_s in self {
+            match opt_s {
+                Some(s) => out.append(&f(&s)?)?,
+                None => out.append(&null)?,
+            };
+        }
+        let mut out = out.rechunk()?;
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Apply `f` to every list and collect the outputs in a new ListChunked with the same
+    /// inner dtype. Null lists stay null.
+    fn lst_apply_list<F>(&self, f: F) -> Result<ListChunked>
+    where
+        F: Fn(&Series) -> Result<Series>,
+    {
+        let mut builder =
+            get_list_builder(&self.inner_dtype(), self.len() * 5, self.len(), self.name());
+        for opt_s in self {
+            match opt_s {
+                Some(s) => builder.append_series(&f(&s)?),
+                None => builder.append_opt_series(None),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Get the number of values in every list.
+    pub fn lst_lengths(&self) -> UInt32Chunked {
+        let mut ca: UInt32Chunked = self
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.len() as u32))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Get the value at index `idx` of every list. Negative indexes count from the end of the
+    /// list. Out of bounds indexes result in null values.
+    pub fn lst_get(&self, idx: i64) -> Result<Series> {
+        let (values, slices) = self.lst_flat_values()?;
+        let mut iter = slices.iter().map(|opt_slice| {
+            opt_slice.and_then(|(start, len)| list_index(idx, len).map(|i| start + i))
+        });
+        let mut out = values.take_opt_iter(&mut iter, Some(slices.len()));
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Get the first value of every list.
+    pub fn lst_first(&self) -> Result<Series> {
+        self.lst_get(0)
+    }
+
+    /// Get the last value of every list.
+    pub fn lst_last(&self) -> Result<Series> {
+        self.lst_get(-1)
+    }
+
+    /// Get the sum of every list. Lists of 8 and 16 bit integers are summed as Int64.
+    pub fn lst_sum(&self) -> Result<Series> {
+        let (values, slices) = self.lst_flat_values()?;
+        // small integers are summed in Int64, so that the sums don't overflow
+        let values = match values.dtype() {
+            DataType::Int8 | DataType::Int16 | DataType::UInt8 | DataType::UInt16 => {
+                values.cast::<Int64Type>()?
+            }
+            _ => values,
+        };
+        match apply_numeric_list_kernel!(values, sum_lists, &slices) {
+            Some(mut out) => {
+                out.rename(self.name());
+                Ok(out)
+            }
+            None => self.lst_apply_single(|s| Ok(s.sum_as_series())),
+        }
+    }
+
+    /// Get the mean of every list.
+    pub fn lst_mean(&self) -> Result<Series> {
+        let (values, slices) = self.lst_flat_values()?;
+        match apply_numeric_list_kernel!(values, mean_lists, &slices) {
+            Some(mut out) => {
+                out.rename(self.name());
+                Ok(out)
+            }
+            None => self.lst_apply_single(|s| Ok(s.mean_as_series())),
+        }
+    }
+
+    /// Get the minimum of every list.
+    pub fn lst_min(&self) -> Result<Series> {
+        let (values, slices) = self.lst_flat_values()?;
+        match apply_numeric_list_kernel!(values, min_lists, &slices) {
+            Some(mut out) => {
+                out.rename(self.name());
+                Ok(out)
+            }
+            None => self.lst_apply_single(|s| Ok(s.min_as_series())),
+        }
+    }
+
+    /// Get the maximum of every list.
+    pub fn lst_max(&self) -> Result<Series> {
+        let (values, slices) = self.lst_flat_values()?;
+        match apply_numeric_list_kernel!(values, max_lists, &slices) {
+            Some(mut out) => {
+                out.rename(self.name());
+                Ok(out)
+            }
+            None => self.lst_apply_single(|s| Ok(s.max_as_series())),
+        }
+    }
+
+    fn lst_arg_agg(&self, max: bool) -> Result<UInt32Chunked> {
+        let (values, slices) = self.lst_flat_values()?;
+        let values = match values.dtype() {
+            DataType::Boolean => values.cast::<UInt8Type>()?,
+            _ => values,
+        };
+        let mut ca =
+            apply_numeric_list_kernel!(values, arg_agg_lists, &slices, max).ok_or_else(|| {
+                PolarsError::InvalidOperation(
+                    format!(
+                        "cannot get the arg_min/arg_max of lists of dtype {:?}",
+                        self.inner_dtype()
+                    )
+                    .into(),
+                )
+            })?;
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Get the index of the minimal value of every numeric or boolean list. The first index is
+    /// returned if the minimum occurs more than once.
+    pub fn lst_arg_min(&self) -> Result<UInt32Chunked> {
+        self.lst_arg_agg(false)
+    }
+
+    /// Get the index of the maximal value of every numeric or boolean list. The first index is
+    /// returned if the maximum occurs more than once.
+    pub fn lst_arg_max(&self) -> Result<UInt32Chunked> {
+        self.lst_arg_agg(true)
+    }
+
+    /// Sort the values of every list.
+    pub fn lst_sort(&self, reverse: bool) -> Result<ListChunked> {
+        self.lst_apply_list(|s| Ok(s.sort(reverse)))
+    }
+
+    /// Get the unique values of every list.
+    pub fn lst_unique(&self) -> Result<ListChunked> {
+        self.lst_apply_list(|s| s.unique())
+    }
+
+    /// Take a slice of every list. A negative `offset` counts from the end of the list.
+    pub fn lst_slice(&self, offset: i64, length: usize) -> Result<ListChunked> {
+        self.lst_apply_list(|s| {
+            let len = s.len() as i64;
+            let start = if offset < 0 {
+                (len + offset).max(0)
+            } else {
+                offset.min(len)
+            };
+            let length = length.min((len - start) as usize);
+            s.slice(start as usize, length)
+        })
+    }
+
+    /// Check if the lists contain `value`, a Series of length 1.
+    pub fn lst_contains(&self, value: &Series) -> Result<BooleanChunked> {
+        if value.len() != 1 {
+            return Err(PolarsError::ShapeMisMatch(
+                "the value to search in the lists should have length 1".into(),
+            ));
+        }
+        let mut ca: BooleanChunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    ChunkCompare::<&Series>::eq(&s, value)
+                        .into_iter()
+                        .any(|v| v == Some(true))
+                })
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Join the values of every list of strings with a separator. Null values are ignored.
+    pub fn lst_join(&self, separator: &str) -> Result<Utf8Chunked> {
+        if self.inner_dtype() != DataType::Utf8 {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!("cannot join lists of dtype {:?}", self.inner_dtype()).into(),
+            ));
+        }
+        let mut ca: Utf8Chunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    let ca = s.utf8().unwrap();
+                    let mut out = String::new();
+                    for (i, v) in ca.into_iter().flatten().enumerate() {
+                        if i > 0 {
+                            out.push_str(separator);
+                        }
+                        out.push_str(v);
+                    }
+                    out
+                })
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Concatenate the lists with the lists of `other`. If one of the lists is null, the
+    /// result is null.
+    pub fn lst_concat(&self, other: &ListChunked) -> Result<ListChunked> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the list columns to concatenate should have the same length".into(),
+            ));
+        }
+        if self.inner_dtype() != other.inner_dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot concatenate lists of dtype {:?} and {:?}",
+                    self.inner_dtype(),
+                    other.inner_dtype()
+                )
+                .into(),
+            ));
+        }
+        let mut builder = get_list_builder(
+            &self.inner_dtype(),
+            self.len() * 10,
+            self.len(),
+            self.name(),
+        );
+        for (opt_l, opt_r) in self.into_iter().zip(other.into_iter()) {
+            match (opt_l, opt_r) {
+                (Some(mut l), Some(r)) => {
+                    l.append(&r)?;
+                    builder.append_series(&l)
+                }
+                _ => builder.append_opt_series(None),
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::chunked_array::builder::get_list_builder;
+    use crate::prelude::*;
+
+    fn get_list() -> ListChunked {
+        let mut builder = get_list_builder(&DataType::Int32, 10, 3, "a");
+        builder.append_series(&Series::new("", &[3, 1, 2]));
+        builder.append_opt_series(None);
+        builder.append_series(&Series::new("", &[5, 5]));
+        builder.finish()
+    }
+
+    #[test]
+    fn test_list_namespace() {
+        let ca = get_list();
+        assert_eq!(Vec::from(&ca.lst_lengths()), &[Some(3), None, Some(2)]);
+        assert_eq!(
+            Vec::from(ca.lst_get(-1).unwrap().i32().unwrap()),
+            &[Some(2), None, Some(5)]
+        );
+        assert_eq!(
+            Vec::from(ca.lst_max().unwrap().i32().unwrap()),
+            &[Some(3), None, Some(5)]
+        );
+        assert_eq!(
+            Vec::from(&ca.lst_arg_min().unwrap()),
+            &[Some(1), None, Some(0)]
+        );
+        assert_eq!(
+            Vec::from(&ca.lst_contains(&Series::new("", &[5])).unwrap()),
+            &[Some(false), None, Some(true)]
+        );
+
+        let sorted = ca.lst_sort(false).unwrap();
+        assert_eq!(
+            Vec::from(sorted.lst_first().unwrap().i32().unwrap()),
+            &[Some(1), None, Some(5)]
+        );
+        let concat = ca.lst_concat(&ca.lst_slice(-1, 1).unwrap()).unwrap();
+        assert_eq!(Vec::from(&concat.lst_lengths()), &[Some(4), None, Some(3)]);
+    }
+
+    #[test]
+    fn test_list_kernels() {
+        let mut builder = get_list_builder(&DataType::Int64, 10, 4, "a");
+        builder.append_series(&Series::new("", &[Some(1i64), None, Some(4)]));
+        builder.append_series(&Series::new(
+            "",
+            &[9_007_199_254_740_992i64, 9_007_199_254_740_993],
+        ));
+        builder.append_opt_series(None);
+        builder.append_series(&Series::new("", &[None::<i64>]));
+        let ca = builder.finish();
+
+        assert_eq!(
+            Vec::from(ca.lst_sum().unwrap().i64().unwrap()),
+            &[Some(5), Some(18_014_398_509_481_985), None, None]
+        );
+        assert_eq!(
+            Vec::from(ca.lst_min().unwrap().i64().unwrap()),
+            &[Some(1), Some(9_007_199_254_740_992), None, None]
+        );
+        // null values are not counted in the mean
+        assert_eq!(
+            Vec::from(ca.lst_mean().unwrap().f64().unwrap()),
+            &[Some(2.5), Some(9_007_199_254_740_992.0), None, None]
+        );
+        // these values are equal as f64
+        assert_eq!(
+            Vec::from(&ca.lst_arg_max().unwrap()),
+            &[Some(2), Some(1), None, None]
+        );
+
+        // the lists of a slice start at an offset in the values
+        let sliced = ca.slice(1, 3).unwrap();
+        assert_eq!(sliced.name(), "a");
+        assert_eq!(
+            Vec::from(sliced.lst_last().unwrap().i64().unwrap()),
+            &[Some(9_007_199_254_740_993), None, None]
+        );
+        assert_eq!(
+            Vec::from(sliced.lst_max().unwrap().i64().unwrap()),
+            &[Some(9_007_199_254_740_993), None, None]
+        );
+
+        // small integers don't overflow in the sum
+        let mut builder = get_list_builder(&DataType::UInt8, 10, 2, "a");
+        builder.append_series(&Series::new("", &[200u8, 100]));
+        builder.append_series(&Series::new("", &[1u8]));
+        let ca = builder.finish();
+        assert_eq!(
+            Vec::from(ca.lst_sum().unwrap().i64().unwrap()),
+            &[Some(300), Some(1)]
+        );
+    }
+}
@@ -5,9 +5,9 @@ use crate::chunked_array::builder::{
 use crate::prelude::*;
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, Date64Array, Float32Array, Float64Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, LargeStringArray, PrimitiveArray, Time64NanosecondArray,
-        UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+        ArrayRef, BooleanArray, Date64Array, DecimalArray, Float32Array, Float64Array, Int16Array,
+        Int32Array, Int64Array, Int8Array, LargeBinaryArray, LargeStringArray, PrimitiveArray,
+        Time64NanosecondArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
     },
     buffer::Buffer,
     datatypes::TimeUnit,
@@ -21,13 +21,17 @@ use std::sync::Arc;
 pub mod ops;
 #[macro_use]
 pub mod arithmetic;
+pub mod binary;
 pub mod boolean;
 pub mod builder;
 pub mod cast;
+pub mod categorical;
 pub mod comparison;
+pub mod decimal;
 pub mod float;
 pub mod iterator;
 pub mod kernels;
+pub mod list;
 #[cfg(feature = "ndarray")]
 #[doc(cfg(feature = "ndarray"))]
 mod ndarray;
@@ -41,6 +45,7 @@ mod random;
 #[cfg(feature = "strings")]
 #[doc(cfg(feature = "strings"))]
 pub mod strings;
+pub mod struct_;
 #[cfg(feature = "temporal")]
 #[doc(cfg(feature = "temporal"))]
 pub mod temporal;
@@ -49,11 +54,12 @@ pub mod upstream_traits;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::ObjectArray;
 use arrow::array::{
-    Array, ArrayDataRef, BooleanBuilder, Date32Array, DurationMillisecondArray,
-    DurationNanosecondArray, LargeListArray,
+    make_array, Array, ArrayData, ArrayDataRef, BooleanBuilder, Date32Array,
+    DurationMillisecondArray, DurationNanosecondArray, LargeListArray, StructArray,
+    TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
 };
 
-use ahash::AHashMap;
+use crate::chunked_array::categorical::merge_categories;
 use arrow::util::bit_util::{get_bit, round_upto_power_of_2};
 use polars_arrow::array::ValueSize;
 use std::mem;
@@ -164,7 +170,7 @@ pub struct ChunkedArray<T> {
     chunk_id: Vec<usize>,
     phantom: PhantomData<T>,
     /// maps categorical u32 indexes to String values
-    pub(crate) categorical_map: Option<Arc<AHashMap<u32, String>>>,
+    pub(crate) categorical_map: Option<Arc<RevMapping>>,
 }
 
 impl<T> ChunkedArray<T> {
@@ -174,7 +180,7 @@ impl<T> ChunkedArray<T> {
     }
 
     /// Get a reference to the mapping of categorical types to the string values.
-    pub fn get_categorical_map(&self) -> Option<&Arc<AHashMap<u32, String>>> {
+    pub fn get_categorical_map(&self) -> Option<&Arc<RevMapping>> {
         self.categorical_map.as_ref()
     }
 
@@ -391,6 +397,55 @@ impl<T> ChunkedArray<T> {
         self.field.data_type()
     }
 
+    /// Get the time zone of a timestamp array.
+    ///
+    /// The time zone is kept by operations that select or move values, e.g. filter, take, sort,
+    /// shift and the first and last aggregations, and by arithmetic with numbers or durations.
+    /// Aggregations that compute new values, e.g. min, max, mean and quantiles, don't keep
+    /// the time zone.
+    pub fn time_zone(&self) -> Option<&str> {
+        match self.dtype() {
+            DataType::Timestamp(_, Some(tz)) => Some(tz),
+            _ => None,
+        }
+    }
+
+    /// Restore the parts of the data type that are not known by `T`, e.g. the time zone of a
+    /// timestamp. These get lost in operations that create a new array from values.
+    pub(crate) fn restore_logical_dtype(mut self, other: &Self) -> Self {
+        if matches!(other.dtype(), DataType::Timestamp(_, Some(_))) && self.dtype() != other.dtype()
+        {
+            self.set_logical_dtype(other.dtype().clone());
+        }
+        self
+    }
+
+    /// Set a data type with the same physical representation as the current one, e.g. a
+    /// timestamp with another time zone. The arrow arrays are updated as well, so that the
+    /// data type is kept when writing to IPC or Parquet.
+    pub(crate) fn set_logical_dtype(&mut self, dtype: DataType) {
+        let arrow_dtype = dtype.to_arrow();
+        self.chunks = self
+            .chunks
+            .iter()
+            .map(|arr| {
+                let data = arr.data();
+                make_array(Arc::new(ArrayData::new(
+                    arrow_dtype.clone(),
+                    arr.len(),
+                    Some(arr.null_count()),
+                    data.null_bitmap()
+                        .clone()
+                        .map(|bitmap| bitmap.into_buffer()),
+                    data.offset(),
+                    data.buffers().to_vec(),
+                    data.child_data().to_vec(),
+                )))
+            })
+            .collect();
+        self.field = Arc::new(Field::new(self.name(), dtype));
+    }
+
     /// Get the index of the chunk and the index of the value in that chunk
     #[inline]
     pub(crate) fn index_to_chunked_index(&self, index: usize) -> (usize, usize) {
@@ -436,17 +491,30 @@ impl<T> ChunkedArray<T> {
         Self: std::marker::Sized,
     {
         if matches!(self.dtype(), DataType::Categorical) {
-            assert!(Arc::ptr_eq(
+            let merged = merge_categories(
+                &self.chunks,
                 self.categorical_map.as_ref().unwrap(),
-                other.categorical_map.as_ref().unwrap()
-            ));
+                &other.chunks,
+                other.categorical_map.as_ref().unwrap(),
+            )
+            .expect("cannot append categoricals of different string cache generations");
+            self.categorical_map = Some(merged.rev_map);
+            if let Some(chunks) = merged.left {
+                self.chunks = chunks;
+            }
+            let other_chunks = merged.right.unwrap_or_else(|| other.chunks.clone());
+            self.append_chunks(other_chunks);
+        } else {
+            self.append_chunks(other.chunks.clone());
         }
+    }
 
+    fn append_chunks(&mut self, chunks: Vec<ArrayRef>) {
         // replace an empty array
         if self.chunks.len() == 1 && self.is_empty() {
-            self.chunks = other.chunks.clone();
+            self.chunks = chunks;
         } else {
-            self.chunks.extend(other.chunks.clone())
+            self.chunks.extend(chunks)
         }
         self.chunk_id = create_chunk_id(&self.chunks);
     }
@@ -473,8 +541,15 @@ where
 {
     /// Create a new ChunkedArray from existing chunks.
     pub fn new_from_chunks(name: &str, chunks: Vec<ArrayRef>) -> Self {
-        // prevent List<Null> if the inner list type is known.
-        let datatype = if matches!(T::get_dtype(), DataType::List(_)) {
+        // prevent List<Null> if the inner list type is known and keep the time zone of
+        // timestamps and the precision and scale of decimals.
+        let datatype = if matches!(
+            T::get_dtype(),
+            DataType::List(_)
+                | DataType::Timestamp(_, _)
+                | DataType::Struct(_)
+                | DataType::Decimal(_, _)
+        ) {
             if let Some(arr) = chunks.get(0) {
                 arr.data_type().into()
             } else {
@@ -515,6 +590,7 @@ where
         // TODO: insert types
         match T::get_dtype() {
             DataType::Utf8 => downcast_and_pack!(LargeStringArray, Utf8),
+            DataType::Binary => downcast_and_pack!(LargeBinaryArray, Binary),
             DataType::Boolean => downcast_and_pack!(BooleanArray, Boolean),
             DataType::UInt8 => downcast_and_pack!(UInt8Array, UInt8),
             DataType::UInt16 => downcast_and_pack!(UInt16Array, UInt16),
@@ -526,6 +602,13 @@ where
             DataType::Int64 => downcast_and_pack!(Int64Array, Int64),
             DataType::Float32 => downcast_and_pack!(Float32Array, Float32),
             DataType::Float64 => downcast_and_pack!(Float64Array, Float64),
+            DataType::Decimal(_, _) => {
+                let v = downcast!(DecimalArray);
+                match self.dtype() {
+                    DataType::Decimal(_, scale) => AnyValue::Decimal(v, *scale),
+                    _ => unreachable!(),
+                }
+            }
             DataType::Date32 => downcast_and_pack!(Date32Array, Date32),
             DataType::Date64 => downcast_and_pack!(Date64Array, Date64),
             DataType::Time64(TimeUnit::Nanosecond) => {
@@ -540,11 +623,35 @@ where
                 let v = downcast!(DurationMillisecondArray);
                 AnyValue::Duration(v, TimeUnit::Millisecond)
             }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                let v = downcast!(TimestampNanosecondArray);
+                AnyValue::Timestamp(v, TimeUnit::Nanosecond, self.time_zone())
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                let v = downcast!(TimestampMicrosecondArray);
+                AnyValue::Timestamp(v, TimeUnit::Microsecond, self.time_zone())
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                let v = downcast!(TimestampMillisecondArray);
+                AnyValue::Timestamp(v, TimeUnit::Millisecond, self.time_zone())
+            }
             DataType::List(_) => {
                 let v = downcast!(LargeListArray);
                 let s = Series::try_from(("", v));
                 AnyValue::List(s.unwrap())
             }
+            DataType::Struct(fields) => {
+                let arr = unsafe { &*(arr as *const dyn Array as *const StructArray) };
+                let values = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let values = struct_::struct_column(arr, i).slice(idx, 1);
+                        Series::try_from((field.name().as_str(), values)).unwrap()
+                    })
+                    .collect();
+                AnyValue::Struct(values)
+            }
             #[cfg(feature = "object")]
             DataType::Object => AnyValue::Object(&"object"),
             DataType::Categorical => {
@@ -554,7 +661,7 @@ where
                         .categorical_map
                         .as_ref()
                         .expect("should be set")
-                        .get(&v)
+                        .get(v)
                         .unwrap(),
                 )
             }
@@ -846,6 +953,30 @@ impl Downcast<LargeStringArray> for Utf8Chunked {
     }
 }
 
+impl Downcast<LargeBinaryArray> for BinaryChunked {
+    fn downcast_chunks(&self) -> Vec<&LargeBinaryArray> {
+        self.chunks
+            .iter()
+            .map(|arr| {
+                let arr = &**arr;
+                unsafe { &*(arr as *const dyn Array as *const LargeBinaryArray) }
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+impl Downcast<DecimalArray> for DecimalChunked {
+    fn downcast_chunks(&self) -> Vec<&DecimalArray> {
+        self.chunks
+            .iter()
+            .map(|arr| {
+                let arr = &**arr;
+                unsafe { &*(arr as *const dyn Array as *const DecimalArray) }
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
 impl Downcast<LargeListArray> for ListChunked {
     fn downcast_chunks(&self) -> Vec<&LargeListArray> {
         self.chunks
@@ -858,6 +989,18 @@ impl Downcast<LargeListArray> for ListChunked {
     }
 }
 
+impl Downcast<StructArray> for StructChunked {
+    fn downcast_chunks(&self) -> Vec<&StructArray> {
+        self.chunks
+            .iter()
+            .map(|arr| {
+                let arr = &**arr;
+                unsafe { &*(arr as *const dyn Array as *const StructArray) }
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
 #[cfg(feature = "object")]
 impl<T> Downcast<ObjectArray<T>> for ObjectChunked<T>
 where
@@ -907,7 +1050,7 @@ impl From<UInt32Chunked> for CategoricalChunked {
 }
 
 impl CategoricalChunked {
-    fn set_state<T>(mut self, other: &ChunkedArray<T>) -> Self {
+    pub(crate) fn set_state<T>(mut self, other: &ChunkedArray<T>) -> Self {
         self.categorical_map = other.categorical_map.clone();
         self
     }
@@ -929,6 +1072,14 @@ impl ValueSize for Utf8Chunked {
     }
 }
 
+impl ValueSize for BinaryChunked {
+    fn get_values_size(&self) -> usize {
+        self.chunks
+            .iter()
+            .fold(0usize, |acc, arr| acc + arr.get_values_size())
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use crate::prelude::*;
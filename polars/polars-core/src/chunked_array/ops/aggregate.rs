@@ -30,7 +30,11 @@ pub trait ChunkAggSeries {
         unimplemented!()
     }
     /// Get the quantile of the ChunkedArray as a new Series of length 1.
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         unimplemented!()
     }
 }
@@ -75,20 +79,72 @@ macro_rules! agg_float_with_nans {
     }};
 }
 
-macro_rules! impl_quantile {
-    ($self:expr, $quantile:expr) => {{
-        let null_count = $self.null_count();
-        let opt = ChunkSort::sort($self, false)
-            .slice(
-                ((($self.len() - null_count) as f64) * $quantile + null_count as f64) as usize,
-                1,
-            )
-            .unwrap()
-            .into_iter()
-            .next()
-            .unwrap();
-        opt
-    }};
+/// Sort the values and put NaN after all other values.
+pub(crate) fn sort_nan_last<T: PartialOrd>(values: &mut [T]) {
+    // NaN is the only value that is not comparable to itself
+    let is_nan = |v: &T| v.partial_cmp(v).is_none();
+    values.sort_unstable_by(|a, b| match a.partial_cmp(b) {
+        Some(ord) => ord,
+        None => is_nan(a).cmp(&is_nan(b)),
+    })
+}
+
+/// Get the sorted values of a ChunkedArray without the nulls.
+pub(crate) fn sorted_values<T>(ca: &ChunkedArray<T>) -> Vec<T::Native>
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+{
+    let mut values = ca.into_iter().flatten().collect::<Vec<_>>();
+    sort_nan_last(&mut values);
+    values
+}
+
+pub(crate) fn check_quantile(quantile: f64) -> Result<()> {
+    if (0.0..=1.0).contains(&quantile) {
+        Ok(())
+    } else {
+        Err(PolarsError::ValueError(
+            "quantile should be between 0.0 and 1.0".into(),
+        ))
+    }
+}
+
+/// Get a quantile of values that are sorted and contain no nulls.
+pub(crate) fn quantile_sorted<T>(
+    sorted: &[T],
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+) -> Option<T>
+where
+    T: NumCast + Copy,
+{
+    if sorted.is_empty() {
+        return None;
+    }
+    let position = quantile * (sorted.len() - 1) as f64;
+    let lower = sorted[position.floor() as usize];
+    let higher = sorted[position.ceil() as usize];
+    let interpolate = |fraction: f64| {
+        let lower = lower.to_f64()?;
+        let higher = higher.to_f64()?;
+        NumCast::from(lower + (higher - lower) * fraction)
+    };
+    match interpol {
+        QuantileInterpolOptions::Nearest => {
+            let nearest = if position.fract() == 0.5 {
+                // round half to even
+                2.0 * (position / 2.0).round()
+            } else {
+                position.round()
+            };
+            Some(sorted[nearest as usize])
+        }
+        QuantileInterpolOptions::Lower => Some(lower),
+        QuantileInterpolOptions::Higher => Some(higher),
+        QuantileInterpolOptions::Midpoint => interpolate(0.5),
+        QuantileInterpolOptions::Linear => interpolate(position.fract()),
+    }
 }
 
 impl<T> ChunkAgg<T::Native> for ChunkedArray<T>
@@ -140,18 +196,33 @@ where
     }
 
     fn median(&self) -> Option<T::Native> {
-        self.quantile(0.5).unwrap()
-    }
-
-    fn quantile(&self, quantile: f64) -> Result<Option<T::Native>> {
-        if !(0.0..=1.0).contains(&quantile) {
-            Err(PolarsError::ValueError(
-                "quantile should be between 0.0 and 1.0".into(),
-            ))
-        } else {
-            let opt = impl_quantile!(self, quantile);
-            Ok(opt)
+        // the lower middle value, like the median of a groupby
+        self.quantile(0.5, QuantileInterpolOptions::Lower).unwrap()
+    }
+
+    fn quantile(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Option<T::Native>> {
+        check_quantile(quantile)?;
+        let sorted = sorted_values(self);
+        Ok(quantile_sorted(&sorted, quantile, interpol))
+    }
+
+    fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Vec<Option<T::Native>>> {
+        for quantile in quantiles {
+            check_quantile(*quantile)?;
         }
+        let sorted = sorted_values(self);
+        Ok(quantiles
+            .iter()
+            .map(|quantile| quantile_sorted(&sorted, *quantile, interpol))
+            .collect())
     }
 }
 
@@ -259,18 +330,34 @@ impl ChunkAgg<u32> for BooleanChunked {
     }
 
     fn median(&self) -> Option<u32> {
-        self.quantile(0.5).unwrap()
+        // the lower middle value, like the median of a groupby
+        self.quantile(0.5, QuantileInterpolOptions::Lower).unwrap()
     }
 
-    fn quantile(&self, quantile: f64) -> Result<Option<u32>> {
-        if !(0.0..=1.0).contains(&quantile) {
-            Err(PolarsError::ValueError(
-                "quantile should be between 0.0 and 1.0".into(),
-            ))
-        } else {
-            let opt = impl_quantile!(self, quantile);
-            Ok(opt.map(|v| v as u32))
+    fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<Option<u32>> {
+        Ok(self.quantiles(&[quantile], interpol)?[0])
+    }
+
+    fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Vec<Option<u32>>> {
+        for quantile in quantiles {
+            check_quantile(*quantile)?;
         }
+        // false sorts before true
+        let n_false = self
+            .into_iter()
+            .filter(|opt_v| *opt_v == Some(false))
+            .count();
+        let n_true = self.sum().unwrap_or(0) as usize;
+        let mut sorted = vec![0u32; n_false];
+        sorted.resize(n_false + n_true, 1);
+        Ok(quantiles
+            .iter()
+            .map(|quantile| quantile_sorted(&sorted, *quantile, interpol))
+            .collect())
     }
 }
 
@@ -311,8 +398,17 @@ where
         ca.rename(self.name());
         ca.into_series()
     }
-    fn quantile_as_series(&self, quantile: f64) -> Result<Series> {
-        let v = self.quantile(quantile)?;
+    fn quantile_as_series(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
+        if interpol.quantile_dtype(&T::get_dtype()) != T::get_dtype() {
+            return self
+                .cast::<Float64Type>()?
+                .quantile_as_series(quantile, interpol);
+        }
+        let v = self.quantile(quantile, interpol)?;
         let mut ca: ChunkedArray<T> = [v].iter().copied().collect();
         ca.rename(self.name());
         Ok(ca.into_series())
@@ -400,8 +496,12 @@ impl ChunkAggSeries for BooleanChunked {
         ca.rename(self.name());
         ca.into_series()
     }
-    fn quantile_as_series(&self, quantile: f64) -> Result<Series> {
-        let v = ChunkAgg::quantile(self, quantile)?;
+    fn quantile_as_series(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
+        let v = ChunkAgg::quantile(self, quantile, interpol)?;
         let mut ca: UInt32Chunked = [v].iter().copied().collect();
         ca.rename(self.name());
         Ok(ca.into_series())
@@ -432,12 +532,42 @@ impl ChunkAggSeries for Utf8Chunked {
     fn median_as_series(&self) -> Series {
         one_null_utf8!(self)
     }
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         Ok(one_null_utf8!(self))
     }
 }
 
-impl ChunkAggSeries for CategoricalChunked {}
+/// The min and max of categoricals follow the ordering of their categories.
+impl ChunkAggSeries for CategoricalChunked {
+    fn max_as_series(&self) -> Series {
+        let ordered = self.ordered_codes();
+        categorical_extreme(self, &ordered, ordered.max())
+    }
+    fn min_as_series(&self) -> Series {
+        let ordered = self.ordered_codes();
+        categorical_extreme(self, &ordered, ordered.min())
+    }
+}
+
+fn categorical_extreme(
+    ca: &CategoricalChunked,
+    ordered: &UInt32Chunked,
+    extreme: Option<u32>,
+) -> Series {
+    let idx =
+        extreme.and_then(|extreme| ordered.into_iter().position(|opt_v| opt_v == Some(extreme)));
+    match idx {
+        Some(idx) => ca.take(std::iter::once(idx), Some(1)).into_series(),
+        None => {
+            let out: CategoricalChunked = UInt32Chunked::full_null(ca.name(), 1).cast().unwrap();
+            out.set_state(ca).into_series()
+        }
+    }
+}
 
 macro_rules! one_null_list {
     ($self:ident) => {{
@@ -463,7 +593,11 @@ impl ChunkAggSeries for ListChunked {
     fn median_as_series(&self) -> Series {
         one_null_list!(self)
     }
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         Ok(one_null_list!(self))
     }
 }
@@ -510,4 +644,35 @@ mod test {
         );
         assert_eq!(ca.median(), Some(4));
     }
+
+    #[test]
+    fn test_quantile_interpol() {
+        use QuantileInterpolOptions::*;
+        let ca = Float64Chunked::new_from_opt_slice(
+            "a",
+            &[Some(4.0), None, Some(1.0), Some(3.0), Some(2.0)],
+        );
+        // numpy: np.quantile([1, 2, 3, 4], 0.5, interpolation=...)
+        assert_eq!(ca.quantile(0.5, Nearest).unwrap(), Some(3.0));
+        assert_eq!(ca.quantile(0.5, Lower).unwrap(), Some(2.0));
+        assert_eq!(ca.quantile(0.5, Higher).unwrap(), Some(3.0));
+        assert_eq!(ca.quantile(0.5, Midpoint).unwrap(), Some(2.5));
+        assert_eq!(ca.quantile(0.4, Linear).unwrap(), Some(2.2));
+        assert_eq!(ca.quantile(0.4, Nearest).unwrap(), Some(2.0));
+        assert!(ca.quantile(1.1, Linear).is_err());
+
+        let quantiles = ca.quantiles(&[0.0, 0.25, 1.0], Linear).unwrap();
+        assert_eq!(quantiles, &[Some(1.0), Some(1.75), Some(4.0)]);
+
+        // interpolated quantiles of integers are not truncated
+        let ca = Int32Chunked::new_from_slice("a", &[4, 1, 3, 2]);
+        let s = ca.quantile_as_series(0.5, Midpoint).unwrap();
+        assert_eq!(Vec::from(s.f64().unwrap()), &[Some(2.5)]);
+        let s = ca.quantile_as_series(0.5, Lower).unwrap();
+        assert_eq!(Vec::from(s.i32().unwrap()), &[Some(2)]);
+
+        let ca = BooleanChunked::new_from_slice("a", &[true, false, true, true]);
+        assert_eq!(ca.quantile(0.25, Lower).unwrap(), Some(0));
+        assert_eq!(ca.quantile(0.5, Lower).unwrap(), Some(1));
+    }
 }
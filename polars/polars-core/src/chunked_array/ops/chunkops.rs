@@ -61,6 +61,29 @@ impl ChunkOps for Utf8Chunked {
     }
 }
 
+impl ChunkOps for BinaryChunked {
+    fn rechunk(&self) -> Result<Self> {
+        if self.chunks().len() == 1 {
+            Ok(self.clone())
+        } else {
+            let chunks = vec![concat(
+                &self.chunks.iter().map(|a| &**a).collect_vec().as_slice(),
+            )?];
+            Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        }
+    }
+}
+
+impl ChunkOps for DecimalChunked {
+    fn rechunk(&self) -> Result<Self> {
+        if self.chunks().len() == 1 {
+            Ok(self.clone())
+        } else {
+            Ok(self.new_with_same_type(self.into_iter()))
+        }
+    }
+}
+
 impl ChunkOps for CategoricalChunked {
     fn rechunk(&self) -> Result<Self>
     where
@@ -1,12 +1,11 @@
 use crate::prelude::*;
 use itertools::__std_iter::FromIterator;
-use num::Bounded;
-use std::ops::AddAssign;
+use num::{Bounded, Num};
 
 impl<T> ChunkCumAgg<T> for ChunkedArray<T>
 where
     T: PolarsNumericType,
-    T::Native: Bounded + PartialOrd + AddAssign,
+    T::Native: Bounded + PartialOrd + Num,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     fn cum_max(&self, reverse: bool) -> ChunkedArray<T> {
@@ -63,9 +62,31 @@ where
             true => Box::new(self.into_iter().rev()),
         };
         let mut ca: Self = iter
-            .scan(Bounded::max_value(), |state, v| match v {
+            .scan(T::Native::zero(), |state, v| match v {
                 Some(v) => {
-                    *state += v;
+                    *state = *state + v;
+                    Some(Some(*state))
+                }
+                None => Some(None),
+            })
+            .collect();
+        ca.rename(self.name());
+        if reverse {
+            ca.reverse()
+        } else {
+            ca
+        }
+    }
+
+    fn cum_prod(&self, reverse: bool) -> ChunkedArray<T> {
+        let iter: Box<dyn Iterator<Item = Option<T::Native>>> = match reverse {
+            false => Box::new(self.into_iter()),
+            true => Box::new(self.into_iter().rev()),
+        };
+        let mut ca: Self = iter
+            .scan(T::Native::one(), |state, v| match v {
+                Some(v) => {
+                    *state = *state * v;
                     Some(Some(*state))
                 }
                 None => Some(None),
@@ -147,4 +168,18 @@ mod test {
         let out = ca.cum_max(false);
         assert_eq!(Vec::from(&out), &[None, Some(1), Some(3), None, Some(3)]);
     }
+
+    #[test]
+    fn test_cum_sum_prod() {
+        let ca = Int32Chunked::new_from_opt_slice("foo", &[Some(2), None, Some(3), Some(-1)]);
+        let out = ca.cum_sum(false);
+        assert_eq!(Vec::from(&out), &[Some(2), None, Some(5), Some(4)]);
+        let out = ca.cum_sum(true);
+        assert_eq!(Vec::from(&out), &[Some(4), None, Some(2), Some(-1)]);
+        let out = ca.cum_prod(false);
+        assert_eq!(Vec::from(&out), &[Some(2), None, Some(6), Some(-6)]);
+        let out = ca.cum_prod(true);
+        assert_eq!(Vec::from(&out), &[Some(-6), None, Some(-3), Some(-1)]);
+        assert_eq!(out.name(), "foo");
+    }
 }
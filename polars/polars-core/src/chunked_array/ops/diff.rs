@@ -0,0 +1,192 @@
+//! Differences and percentage changes between values and the values `n` positions before them.
+use crate::prelude::*;
+use num::{NumCast, ToPrimitive};
+use std::ops::Sub;
+
+/// What to do with the first `n` values of [ChunkDiff::diff], that have no value to subtract.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NullBehavior {
+    /// The first values are null.
+    Ignore,
+    /// The first values are removed, the output is shorter than the input.
+    Drop,
+}
+
+impl Default for NullBehavior {
+    fn default() -> Self {
+        NullBehavior::Ignore
+    }
+}
+
+pub trait ChunkDiff {
+    /// Compute the difference with the value `n` positions before. Unsigned integers result in
+    /// `Int64`, dates and times result in a `Duration` if there is one with the same unit.
+    /// Differences that don't fit in the output type are null.
+    fn diff(&self, _n: usize, _null_behavior: NullBehavior) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "diff not supported for this datatype".into(),
+        ))
+    }
+
+    /// Compute the change as a fraction between a value and the value `n` positions before.
+    /// Null values are first filled with the previous value.
+    fn pct_change(&self, _n: usize) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "pct_change not supported for this datatype".into(),
+        ))
+    }
+}
+
+/// Subtract two integers without overflowing. Returns `None` if the difference doesn't fit
+/// in an `i64`.
+fn checked_diff_i64<N>(a: N, b: N) -> Option<i64>
+where
+    N: Sub<Output = N> + PartialOrd + ToPrimitive,
+{
+    match (a.to_i64(), b.to_i64()) {
+        (Some(a), Some(b)) => a.checked_sub(b),
+        // unsigned values larger than i64::MAX
+        _ if a >= b => (a - b).to_i64(),
+        _ => (b - a).to_i64().map(|d| -d),
+    }
+}
+
+fn diff_with<T, N, F>(
+    ca: &ChunkedArray<T>,
+    n: usize,
+    null_behavior: NullBehavior,
+    f: F,
+) -> ChunkedArray<N>
+where
+    T: PolarsNumericType,
+    N: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> Option<N::Native>,
+{
+    let values = Vec::from(ca);
+    let iter = values.iter().enumerate().map(|(i, opt_v)| {
+        if i < n {
+            return None;
+        }
+        match (opt_v, values[i - n]) {
+            (Some(v), Some(prev)) => f(*v, prev),
+            _ => None,
+        }
+    });
+    let mut out: ChunkedArray<N> = match null_behavior {
+        NullBehavior::Ignore => iter.collect(),
+        NullBehavior::Drop => iter.skip(n).collect(),
+    };
+    out.rename(ca.name());
+    out
+}
+
+impl<T> ChunkDiff for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Sub<Output = T::Native> + PartialOrd + NumCast,
+    ChunkedArray<T>: IntoSeries,
+{
+    fn diff(&self, n: usize, null_behavior: NullBehavior) -> Result<Series> {
+        let s = match T::get_dtype() {
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                diff_with::<_, Int64Type, _>(self, n, null_behavior, checked_diff_i64).into_series()
+            }
+            DataType::Date32 => diff_with::<_, Int32Type, _>(self, n, null_behavior, |a, b| {
+                checked_diff_i64(a, b).and_then(|d| d.to_i32())
+            })
+            .into_series(),
+            DataType::Date64 | DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                diff_with::<_, DurationMillisecondType, _>(self, n, null_behavior, checked_diff_i64)
+                    .into_series()
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) | DataType::Time64(_) => {
+                diff_with::<_, DurationNanosecondType, _>(self, n, null_behavior, checked_diff_i64)
+                    .into_series()
+            }
+            DataType::Timestamp(_, _) => {
+                diff_with::<_, Int64Type, _>(self, n, null_behavior, checked_diff_i64).into_series()
+            }
+            DataType::Float32 | DataType::Float64 => {
+                diff_with::<_, T, _>(self, n, null_behavior, |a, b| Some(a - b)).into_series()
+            }
+            // signed integers keep their type, differences out of its range are null
+            _ => diff_with::<_, T, _>(self, n, null_behavior, |a, b| {
+                checked_diff_i64(a, b).and_then(NumCast::from)
+            })
+            .into_series(),
+        };
+        Ok(s)
+    }
+
+    fn pct_change(&self, n: usize) -> Result<Series> {
+        if !T::get_dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("pct_change not supported for dtype {:?}", T::get_dtype()).into(),
+            ));
+        }
+        let filled = self
+            .into_iter()
+            .scan(None, |last, opt_v| {
+                if opt_v.is_some() {
+                    *last = opt_v.and_then(|v| v.to_f64());
+                }
+                Some(*last)
+            })
+            .collect::<Float64Chunked>();
+        let mut ca = diff_with::<_, Float64Type, _>(&filled, n, NullBehavior::Ignore, |a, b| {
+            Some((a - b) / b)
+        });
+        ca.rename(self.name());
+        match T::get_dtype() {
+            DataType::Float32 => ca.cast::<Float32Type>().map(|ca| ca.into_series()),
+            _ => Ok(ca.into_series()),
+        }
+    }
+}
+
+impl ChunkDiff for Utf8Chunked {}
+impl ChunkDiff for ListChunked {}
+impl ChunkDiff for BooleanChunked {}
+impl ChunkDiff for CategoricalChunked {}
+#[cfg(feature = "object")]
+impl<T> ChunkDiff for ObjectChunked<T> {}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_diff() {
+        let ca =
+            UInt32Chunked::new_from_opt_slice("a", &[Some(1), Some(4), None, Some(2), Some(8)]);
+        let s = ca.diff(1, NullBehavior::Ignore).unwrap();
+        assert_eq!(s.name(), "a");
+        assert_eq!(
+            Vec::from(s.i64().unwrap()),
+            &[None, Some(3), None, None, Some(6)]
+        );
+        let s = ca.diff(2, NullBehavior::Drop).unwrap();
+        assert_eq!(Vec::from(s.i64().unwrap()), &[None, Some(-2), None]);
+        assert_eq!(ca.diff(10, NullBehavior::Drop).unwrap().len(), 0);
+
+        let ca = UInt64Chunked::new_from_slice("a", &[u64::MAX, u64::MAX - 1, 0]);
+        let s = ca.diff(1, NullBehavior::Ignore).unwrap();
+        assert_eq!(Vec::from(s.i64().unwrap()), &[None, Some(-1), None]);
+        let ca = Int8Chunked::new_from_slice("a", &[-100, 100, 90]);
+        let s = ca.diff(1, NullBehavior::Ignore).unwrap();
+        assert_eq!(Vec::from(s.i8().unwrap()), &[None, None, Some(-10)]);
+
+        let ca = Date64Chunked::new_from_slice("a", &[1000, 3000]);
+        let s = ca.diff(1, NullBehavior::Ignore).unwrap();
+        assert_eq!(s.dtype(), &DataType::Duration(TimeUnit::Millisecond));
+
+        // pandas: pd.Series([1, None, 3, 6]).pct_change()
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(3), Some(6)]);
+        let s = ca.pct_change(1).unwrap();
+        assert_eq!(
+            Vec::from(s.f64().unwrap()),
+            &[None, Some(0.0), Some(2.0), Some(1.0)]
+        );
+        assert!(ca.cast::<Date32Type>().unwrap().pct_change(1).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+//! Exponentially weighted moving statistics, computed like pandas does.
+use crate::prelude::*;
+use num::ToPrimitive;
+
+/// Options for the exponentially weighted moving statistics of [ChunkEwm].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EWMOptions {
+    /// The smoothing factor, `0 < alpha <= 1`.
+    pub alpha: f64,
+    /// Divide by a decaying adjustment factor to account for the imbalance of the weights of
+    /// the first values. If `false` the statistics are computed recursively.
+    pub adjust: bool,
+    /// Compute a biased variance and standard deviation.
+    pub bias: bool,
+    /// The minimal number of values needed to compute a result. Before that the result is Null.
+    pub min_periods: usize,
+    /// Ignore the null values when computing the weights. If `false` the weights are based on
+    /// the absolute positions of the values.
+    pub ignore_nulls: bool,
+}
+
+impl Default for EWMOptions {
+    fn default() -> Self {
+        EWMOptions {
+            alpha: 0.5,
+            adjust: true,
+            bias: false,
+            min_periods: 1,
+            ignore_nulls: true,
+        }
+    }
+}
+
+impl EWMOptions {
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Set the decay in terms of a center of mass: `alpha = 1 / (1 + com)` with `com >= 0`.
+    pub fn with_com(mut self, com: f64) -> Self {
+        self.alpha = 1.0 / (1.0 + com);
+        self
+    }
+
+    /// Set the decay in terms of a span: `alpha = 2 / (span + 1)` with `span >= 1`.
+    pub fn with_span(mut self, span: f64) -> Self {
+        self.alpha = 2.0 / (span + 1.0);
+        self
+    }
+
+    /// Set the decay in terms of a half life: `alpha = 1 - exp(-ln(2) / half_life)` with
+    /// `half_life > 0`.
+    pub fn with_half_life(mut self, half_life: f64) -> Self {
+        self.alpha = 1.0 - (-std::f64::consts::LN_2 / half_life).exp();
+        self
+    }
+
+    pub fn with_adjust(mut self, adjust: bool) -> Self {
+        self.adjust = adjust;
+        self
+    }
+
+    pub fn with_bias(mut self, bias: bool) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    pub fn with_min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    pub fn with_ignore_nulls(mut self, ignore_nulls: bool) -> Self {
+        self.ignore_nulls = ignore_nulls;
+        self
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.alpha > 0.0 && self.alpha <= 1.0 {
+            Ok(())
+        } else {
+            Err(PolarsError::ValueError(
+                format!(
+                    "alpha should be in the range (0, 1], got {}. Check the com, span or half life.",
+                    self.alpha
+                )
+                .into(),
+            ))
+        }
+    }
+}
+
+/// The exponentially weighted mean of every value. A Null value leads to a Null.
+fn ewm_mean<I>(iter: I, options: &EWMOptions) -> Vec<Option<f64>>
+where
+    I: Iterator<Item = Option<f64>>,
+{
+    let old_wt_factor = 1.0 - options.alpha;
+    let new_wt = if options.adjust { 1.0 } else { options.alpha };
+    let mut weighted_avg: Option<f64> = None;
+    let mut old_wt = 1.0;
+    let mut nobs = 0;
+
+    iter.map(|opt_v| {
+        if let Some(avg) = weighted_avg {
+            if opt_v.is_some() || !options.ignore_nulls {
+                old_wt *= old_wt_factor;
+                if let Some(v) = opt_v {
+                    if avg != v {
+                        weighted_avg = Some((old_wt * avg + new_wt * v) / (old_wt + new_wt));
+                    }
+                    if options.adjust {
+                        old_wt += new_wt;
+                    } else {
+                        old_wt = 1.0;
+                    }
+                }
+            }
+        } else {
+            weighted_avg = opt_v;
+        }
+        opt_v?;
+        nobs += 1;
+        if nobs >= options.min_periods {
+            weighted_avg
+        } else {
+            None
+        }
+    })
+    .collect()
+}
+
+/// The exponentially weighted variance of every value. A Null value leads to a Null.
+fn ewm_var<I>(iter: I, options: &EWMOptions) -> Vec<Option<f64>>
+where
+    I: Iterator<Item = Option<f64>>,
+{
+    let old_wt_factor = 1.0 - options.alpha;
+    let new_wt = if options.adjust { 1.0 } else { options.alpha };
+    let mut mean: Option<f64> = None;
+    let mut var = 0.0;
+    let mut sum_wt = 1.0;
+    let mut sum_wt2 = 1.0;
+    let mut old_wt = 1.0;
+    let mut nobs = 0;
+
+    iter.map(|opt_v| {
+        if let Some(old_mean) = mean {
+            if opt_v.is_some() || !options.ignore_nulls {
+                sum_wt *= old_wt_factor;
+                sum_wt2 *= old_wt_factor * old_wt_factor;
+                old_wt *= old_wt_factor;
+                if let Some(v) = opt_v {
+                    let new_mean = if old_mean != v {
+                        (old_wt * old_mean + new_wt * v) / (old_wt + new_wt)
+                    } else {
+                        old_mean
+                    };
+                    var = (old_wt * (var + (old_mean - new_mean).powi(2))
+                        + new_wt * (v - new_mean).powi(2))
+                        / (old_wt + new_wt);
+                    mean = Some(new_mean);
+                    sum_wt += new_wt;
+                    sum_wt2 += new_wt * new_wt;
+                    old_wt += new_wt;
+                    if !options.adjust {
+                        sum_wt /= old_wt;
+                        sum_wt2 /= old_wt * old_wt;
+                        old_wt = 1.0;
+                    }
+                }
+            }
+        } else {
+            mean = opt_v;
+        }
+        opt_v?;
+        nobs += 1;
+        if nobs < options.min_periods {
+            None
+        } else if options.bias {
+            Some(var)
+        } else {
+            // correct for the bias with the effective number of observations
+            let numerator = sum_wt * sum_wt;
+            let denominator = numerator - sum_wt2;
+            if denominator > 0.0 {
+                Some(numerator / denominator * var)
+            } else {
+                None
+            }
+        }
+    })
+    .collect()
+}
+
+/// Floats keep their precision, other types are computed as f64.
+fn finish_ewm<T: PolarsNumericType>(name: &str, values: Vec<Option<f64>>) -> Result<Series> {
+    let mut ca: Float64Chunked = values.into_iter().collect();
+    ca.rename(name);
+    match T::get_dtype() {
+        DataType::Float32 => ca.cast::<Float32Type>().map(|ca| ca.into_series()),
+        _ => Ok(ca.into_series()),
+    }
+}
+
+impl<T> ChunkEwm for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    fn ewm_mean(&self, options: &EWMOptions) -> Result<Series> {
+        options.check()?;
+        let iter = self.into_iter().map(|opt_v| opt_v.and_then(|v| v.to_f64()));
+        finish_ewm::<T>(self.name(), ewm_mean(iter, options))
+    }
+
+    fn ewm_var(&self, options: &EWMOptions) -> Result<Series> {
+        options.check()?;
+        let iter = self.into_iter().map(|opt_v| opt_v.and_then(|v| v.to_f64()));
+        finish_ewm::<T>(self.name(), ewm_var(iter, options))
+    }
+
+    fn ewm_std(&self, options: &EWMOptions) -> Result<Series> {
+        options.check()?;
+        let iter = self.into_iter().map(|opt_v| opt_v.and_then(|v| v.to_f64()));
+        let values = ewm_var(iter, options)
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| v.sqrt()))
+            .collect();
+        finish_ewm::<T>(self.name(), values)
+    }
+}
+
+impl ChunkEwm for Utf8Chunked {}
+impl ChunkEwm for ListChunked {}
+impl ChunkEwm for BooleanChunked {}
+impl ChunkEwm for CategoricalChunked {}
+#[cfg(feature = "object")]
+impl<T> ChunkEwm for ObjectChunked<T> {}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn assert_close(s: &Series, expected: &[Option<f64>]) {
+        let ca = s.f64().unwrap();
+        assert_eq!(ca.len(), expected.len());
+        for (a, b) in ca.into_iter().zip(expected) {
+            match (a, b) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 1e-6, "{} != {}", a, b),
+                (a, b) => assert_eq!(a, *b),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ewm() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), Some(2), None, Some(4)]);
+        let options = EWMOptions::default();
+
+        // pandas: pd.Series([1, 2, None, 4]).ewm(alpha=0.5).mean()
+        let s = ca.ewm_mean(&options).unwrap();
+        assert_close(&s, &[Some(1.0), Some(1.666667), None, Some(3.0)]);
+        let s = ca.ewm_mean(&options.with_ignore_nulls(false)).unwrap();
+        assert_close(&s, &[Some(1.0), Some(1.666667), None, Some(3.363636)]);
+        let s = ca.ewm_mean(&options.with_adjust(false)).unwrap();
+        assert_close(&s, &[Some(1.0), Some(1.5), None, Some(2.75)]);
+
+        // pandas: pd.Series([1, 2, None, 4]).ewm(com=1).var()
+        let s = ca.ewm_var(&options.with_com(1.0)).unwrap();
+        assert_close(&s, &[None, Some(0.5), None, Some(2.5)]);
+        let s = ca.ewm_std(&options.with_bias(true)).unwrap();
+        assert_close(&s, &[Some(0.0), Some(0.471405), None, Some(1.195229)]);
+
+        let ca = Float32Chunked::new_from_slice("a", &[1.0, 2.0]);
+        let s = ca.ewm_mean(&options.with_span(3.0)).unwrap();
+        assert_eq!(s.dtype(), &DataType::Float32);
+        assert!(ca.ewm_mean(&options.with_half_life(-1.0)).is_err());
+    }
+}
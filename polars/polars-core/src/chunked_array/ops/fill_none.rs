@@ -1,80 +1,95 @@
 use crate::prelude::*;
-use num::{Num, NumCast};
+use num::{Num, NumCast, ToPrimitive};
 use std::ops::{Add, Div};
 
-fn fill_forward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+/// Replace every null with the last valid value before it, but fill at most `limit`
+/// consecutive nulls.
+fn fill_forward<T, I>(iter: I, limit: Option<u32>) -> impl Iterator<Item = Option<T>>
 where
-    T: PolarsNumericType,
+    I: Iterator<Item = Option<T>>,
+    T: Clone,
 {
-    ca.into_iter()
-        .scan(None, |previous, opt_v| {
-            let val = match opt_v {
-                Some(_) => Some(opt_v),
-                None => Some(*previous),
-            };
-            *previous = opt_v;
-            val
-        })
-        .collect()
-}
-
-macro_rules! impl_fill_forward {
-    ($ca:ident) => {{
-        let ca = $ca
-            .into_iter()
-            .scan(None, |previous, opt_v| {
-                let val = match opt_v {
-                    Some(_) => Some(opt_v),
-                    None => Some(*previous),
-                };
-                *previous = opt_v;
-                val
-            })
-            .collect();
-        Ok(ca)
-    }};
+    let limit = limit.unwrap_or(u32::MAX);
+    iter.scan(
+        (None, 0u32),
+        move |(previous, n_filled), opt_v| match opt_v {
+            Some(v) => {
+                *previous = Some(v.clone());
+                *n_filled = 0;
+                Some(Some(v))
+            }
+            None if *n_filled < limit => {
+                *n_filled += 1;
+                Some(previous.clone())
+            }
+            None => Some(None),
+        },
+    )
 }
 
-fn fill_backward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+/// Replace every null with the first valid value after it, but fill at most `limit`
+/// consecutive nulls.
+fn fill_backward<T, I>(iter: I, limit: Option<u32>) -> Vec<Option<T>>
 where
-    T: PolarsNumericType,
+    I: Iterator<Item = Option<T>>,
+    T: Clone,
 {
-    let mut iter = ca.into_iter().peekable();
-
-    let mut builder = PrimitiveChunkedBuilder::<T>::new(ca.name(), ca.len());
-    while let Some(opt_v) = iter.next() {
-        match opt_v {
-            Some(v) => builder.append_value(v),
-            None => {
-                match iter.peek() {
-                    // end of iterator
-                    None => builder.append_null(),
-                    Some(opt_v) => builder.append_option(*opt_v),
+    let values = iter.collect::<Vec<_>>();
+    let mut filled = fill_forward(values.into_iter().rev(), limit).collect::<Vec<_>>();
+    filled.reverse();
+    filled
+}
+
+/// Linearly interpolate the nulls between two valid values. The `x` coordinates of the values are
+/// given by `by`, or by the position in the array if `by` is `None`. Nulls before the first and
+/// after the last valid value are not filled.
+fn interpolate(values: &mut [Option<f64>], by: Option<&[Option<f64>]>) {
+    let x = |idx: usize| match by {
+        Some(by) => by[idx],
+        None => Some(idx as f64),
+    };
+    let mut low: Option<(usize, f64, f64)> = None;
+    for idx in 0..values.len() {
+        let (y1, x1) = match (values[idx], x(idx)) {
+            (Some(y1), Some(x1)) => (y1, x1),
+            _ => continue,
+        };
+        if let Some((low_idx, x0, y0)) = low {
+            let gap = values.iter_mut().enumerate().take(idx).skip(low_idx + 1);
+            for (fill_idx, value) in gap {
+                if value.is_none() {
+                    *value = x(fill_idx).map(|xi| {
+                        if x1 == x0 {
+                            y0
+                        } else {
+                            y0 + (y1 - y0) * (xi - x0) / (x1 - x0)
+                        }
+                    })
                 }
             }
         }
+        low = Some((idx, x1, y1));
     }
-    builder.finish()
-}
-
-macro_rules! impl_fill_backward {
-    ($ca:ident, $builder:ident) => {{
-        let mut iter = $ca.into_iter().peekable();
-
-        while let Some(opt_v) = iter.next() {
-            match opt_v {
-                Some(v) => $builder.append_value(v),
-                None => {
-                    match iter.peek() {
-                        // end of iterator
-                        None => $builder.append_null(),
-                        Some(opt_v) => $builder.append_option(*opt_v),
-                    }
-                }
-            }
-        }
-        Ok($builder.finish())
-    }};
+}
+
+fn interpolate_numeric<T>(ca: &ChunkedArray<T>, by: Option<&[Option<f64>]>) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast,
+{
+    let mut values = ca
+        .into_iter()
+        .map(|opt_v| opt_v.and_then(|v| v.to_f64()))
+        .collect::<Vec<_>>();
+    interpolate(&mut values, by);
+    let is_float = matches!(T::get_dtype(), DataType::Float32 | DataType::Float64);
+    values
+        .into_iter()
+        .map(|opt_v| {
+            // integers are rounded to the nearest value
+            opt_v.and_then(|v| NumCast::from(if is_float { v } else { v.round() }))
+        })
+        .collect()
 }
 
 macro_rules! impl_fill_value {
@@ -98,13 +113,37 @@ where
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
-        let ca = match strategy {
-            FillNoneStrategy::Forward => fill_forward(self),
-            FillNoneStrategy::Backward => fill_backward(self),
+        let mut ca: Self = match strategy {
+            FillNoneStrategy::Forward(limit) => fill_forward(self.into_iter(), limit).collect(),
+            FillNoneStrategy::Backward(limit) => {
+                fill_backward(self.into_iter(), limit).into_iter().collect()
+            }
             FillNoneStrategy::Min => impl_fill_value!(self, self.min()),
             FillNoneStrategy::Max => impl_fill_value!(self, self.max()),
             FillNoneStrategy::Mean => impl_fill_value!(self, self.mean()),
+            FillNoneStrategy::Zero => impl_fill_value!(self, Some(T::Native::zero())),
+            FillNoneStrategy::One => impl_fill_value!(self, Some(T::Native::one())),
+            FillNoneStrategy::Linear => interpolate_numeric(self, None),
         };
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    fn interpolate_by(&self, by: &Date64Chunked) -> Result<Self> {
+        if self.len() != by.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the index to interpolate by should have the same length as the array".into(),
+            ));
+        }
+        if self.null_count() == 0 {
+            return Ok(self.clone());
+        }
+        let by = by
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| v as f64))
+            .collect::<Vec<_>>();
+        let mut ca = interpolate_numeric(self, Some(&by));
+        ca.rename(self.name());
         Ok(ca)
     }
 }
@@ -125,14 +164,24 @@ impl ChunkFillNone for BooleanChunked {
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
-        let mut builder = BooleanChunkedBuilder::new(self.name(), self.len());
-        match strategy {
-            FillNoneStrategy::Forward => impl_fill_forward!(self),
-            FillNoneStrategy::Backward => impl_fill_backward!(self, builder),
-            FillNoneStrategy::Min => Ok(impl_fill_value!(self, self.min().map(|v| v != 0))),
-            FillNoneStrategy::Max => Ok(impl_fill_value!(self, self.max().map(|v| v != 0))),
-            FillNoneStrategy::Mean => Ok(impl_fill_value!(self, self.mean().map(|v| v != 0))),
-        }
+        let mut ca: Self = match strategy {
+            FillNoneStrategy::Forward(limit) => fill_forward(self.into_iter(), limit).collect(),
+            FillNoneStrategy::Backward(limit) => {
+                fill_backward(self.into_iter(), limit).into_iter().collect()
+            }
+            FillNoneStrategy::Min => impl_fill_value!(self, self.min().map(|v| v != 0)),
+            FillNoneStrategy::Max => impl_fill_value!(self, self.max().map(|v| v != 0)),
+            FillNoneStrategy::Mean => impl_fill_value!(self, self.mean().map(|v| v != 0)),
+            FillNoneStrategy::Zero => impl_fill_value!(self, Some(false)),
+            FillNoneStrategy::One => impl_fill_value!(self, Some(true)),
+            strat => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("Strategy {:?} not supported for Boolean type", strat).into(),
+                ))
+            }
+        };
+        ca.rename(self.name());
+        Ok(ca)
     }
 }
 
@@ -148,16 +197,19 @@ impl ChunkFillNone for Utf8Chunked {
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
-        let factor = self.len() as f32 / (self.len() - self.null_count()) as f32;
-        let value_cap = (self.get_values_size() as f32 * 1.25 * factor) as usize;
-        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), value_cap);
-        match strategy {
-            FillNoneStrategy::Forward => impl_fill_forward!(self),
-            FillNoneStrategy::Backward => impl_fill_backward!(self, builder),
-            strat => Err(PolarsError::InvalidOperation(
-                format!("Strategy {:?} not supported", strat).into(),
-            )),
-        }
+        let mut ca: Self = match strategy {
+            FillNoneStrategy::Forward(limit) => fill_forward(self.into_iter(), limit).collect(),
+            FillNoneStrategy::Backward(limit) => {
+                fill_backward(self.into_iter(), limit).into_iter().collect()
+            }
+            strat => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("Strategy {:?} not supported for Utf8 type", strat).into(),
+                ))
+            }
+        };
+        ca.rename(self.name());
+        Ok(ca)
     }
 }
 
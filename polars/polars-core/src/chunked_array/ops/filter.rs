@@ -200,6 +200,67 @@ impl ChunkFilter<Utf8Type> for Utf8Chunked {
     }
 }
 
+impl ChunkFilter<BinaryType> for BinaryChunked {
+    fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<BinaryType>> {
+        // broadcast
+        if filter.len() == 1 {
+            return match filter.get(0) {
+                Some(true) => Ok(self.clone()),
+                _ => Ok(BinaryChunked::new_from_chunks(self.name(), vec![])),
+            };
+        }
+        check_filter_len!(self, filter);
+        if self.chunk_id == filter.chunk_id {
+            let chunks = self
+                .downcast_chunks()
+                .iter()
+                .zip(filter.downcast_chunks())
+                .map(|(&left, mask)| filter_fn(left, mask).unwrap())
+                .collect();
+            return Ok(ChunkedArray::new_from_chunks(self.name(), chunks));
+        }
+        let out: Result<BinaryChunked> = match (self.null_count(), filter.null_count()) {
+            (0, 0) => {
+                let ca = impl_filter_no_nulls!(self, filter);
+                Ok(ca)
+            }
+            (0, _) => {
+                let ca = impl_filter_no_nulls_in_self!(self, filter);
+                Ok(ca)
+            }
+            (_, 0) => impl_filter_no_nulls_in_mask!(self, filter),
+            (_, _) => impl_filter_with_nulls_in_both!(self, filter),
+        };
+
+        out.map(|mut ca| {
+            ca.rename(self.name());
+            ca
+        })
+    }
+}
+
+impl ChunkFilter<DecimalType> for DecimalChunked {
+    fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<DecimalType>> {
+        // broadcast
+        if filter.len() == 1 {
+            return match filter.get(0) {
+                Some(true) => Ok(self.clone()),
+                _ => Ok(self.new_with_same_type(std::iter::empty())),
+            };
+        }
+        check_filter_len!(self, filter);
+        Ok(
+            self.new_with_same_type(self.into_iter().zip(filter).filter_map(|(opt_v, mask)| {
+                if mask == Some(true) {
+                    Some(opt_v)
+                } else {
+                    None
+                }
+            })),
+        )
+    }
+}
+
 impl ChunkFilter<CategoricalType> for CategoricalChunked {
     fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<CategoricalType>>
     where
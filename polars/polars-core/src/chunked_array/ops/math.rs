@@ -0,0 +1,310 @@
+//! Elementwise math functions on numeric arrays.
+use crate::prelude::*;
+use num::{Bounded, Float, Num, NumCast, PrimInt, ToPrimitive};
+
+/// Elementwise math functions on float arrays. Null values remain null.
+pub trait ChunkFloatMath {
+    fn sqrt(&self) -> Self;
+    fn exp(&self) -> Self;
+    /// The natural logarithm.
+    fn log(&self) -> Self;
+    fn log10(&self) -> Self;
+    /// `ln(1 + x)`, more accurate than [log](#tymethod.log) for small values.
+    fn log1p(&self) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self;
+    fn arcsin(&self) -> Self;
+    fn arccos(&self) -> Self;
+    fn arctan(&self) -> Self;
+    fn sinh(&self) -> Self;
+    fn cosh(&self) -> Self;
+    fn tanh(&self) -> Self;
+    fn floor(&self) -> Self;
+    fn ceil(&self) -> Self;
+    /// Round to a number of decimals. Halfway values are rounded away from zero. Values that
+    /// can't be scaled by `10^decimals` in the float type are returned unchanged.
+    fn round(&self, decimals: u32) -> Self;
+}
+
+fn apply_named<T, F>(ca: &ChunkedArray<T>, f: F) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native) -> T::Native + Copy,
+{
+    let mut out = ca.apply(f);
+    out.rename(ca.name());
+    out
+}
+
+macro_rules! impl_float_fn {
+    ($method:ident, $float_method:ident) => {
+        fn $method(&self) -> Self {
+            apply_named(self, |v| v.$float_method())
+        }
+    };
+}
+
+impl<T> ChunkFloatMath for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Float,
+{
+    impl_float_fn!(sqrt, sqrt);
+    impl_float_fn!(exp, exp);
+    impl_float_fn!(log, ln);
+    impl_float_fn!(log10, log10);
+    impl_float_fn!(log1p, ln_1p);
+    impl_float_fn!(sin, sin);
+    impl_float_fn!(cos, cos);
+    impl_float_fn!(tan, tan);
+    impl_float_fn!(arcsin, asin);
+    impl_float_fn!(arccos, acos);
+    impl_float_fn!(arctan, atan);
+    impl_float_fn!(sinh, sinh);
+    impl_float_fn!(cosh, cosh);
+    impl_float_fn!(tanh, tanh);
+    impl_float_fn!(floor, floor);
+    impl_float_fn!(ceil, ceil);
+
+    fn round(&self, decimals: u32) -> Self {
+        // more decimals than the float type can represent leave the values unchanged
+        let multiplier: T::Native = match NumCast::from(10f64.powi(decimals as i32)) {
+            Some(multiplier) if Float::is_finite(multiplier) => multiplier,
+            _ => return apply_named(self, |v| v),
+        };
+        apply_named(self, |v| {
+            let scaled = v * multiplier;
+            if scaled.is_finite() {
+                scaled.round() / multiplier
+            } else {
+                v
+            }
+        })
+    }
+}
+
+/// Elementwise math functions on integer and float arrays. Null values remain null.
+pub trait ChunkNumMath: Sized {
+    /// The absolute value. The minimum value of a signed integer has no absolute value in its
+    /// type and becomes null.
+    fn abs(&self) -> Self;
+
+    /// `-1` for negative values, `1` for positive values and `0` for zero. NaN remains NaN.
+    fn sign(&self) -> Self;
+
+    /// Limit the values to the range `[min, max]`. Bounds that are out of the range of the
+    /// data type are saturated.
+    fn clip(&self, min: f64, max: f64) -> Result<Self>;
+}
+
+/// Convert a bound to the closest value of the native type.
+fn clip_bound<N: NumCast + Bounded + ToPrimitive>(bound: f64) -> N {
+    if bound <= N::min_value().to_f64().unwrap() {
+        N::min_value()
+    } else if bound >= N::max_value().to_f64().unwrap() {
+        N::max_value()
+    } else {
+        NumCast::from(bound).unwrap()
+    }
+}
+
+impl<T> ChunkNumMath for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Num + NumCast + Bounded + PartialOrd,
+{
+    fn abs(&self) -> Self {
+        let zero = T::Native::zero();
+        let min = T::Native::min_value();
+        // signed integers have one more negative value than positive values
+        let min_overflows = min + T::Native::max_value() < zero;
+        let mut ca: Self = self
+            .into_iter()
+            .map(|opt_v| {
+                let v = opt_v?;
+                if v >= zero {
+                    Some(v)
+                } else if min_overflows && v == min {
+                    None
+                } else {
+                    Some(zero - v)
+                }
+            })
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    fn sign(&self) -> Self {
+        let zero = T::Native::zero();
+        let one = T::Native::one();
+        apply_named(self, |v| {
+            if v > zero {
+                one
+            } else if v < zero {
+                zero - one
+            } else {
+                v
+            }
+        })
+    }
+
+    fn clip(&self, min: f64, max: f64) -> Result<Self> {
+        if min.is_nan() || max.is_nan() || min > max {
+            return Err(PolarsError::ValueError(
+                format!("invalid clip bounds [{}, {}]", min, max).into(),
+            ));
+        }
+        // integers are clipped to the integers within the bounds
+        let (min, max) = if T::get_dtype().is_float() {
+            (min, max)
+        } else {
+            (min.ceil(), max.floor())
+        };
+        let min: T::Native = clip_bound(min);
+        let max: T::Native = clip_bound(max);
+        Ok(apply_named(self, |v| {
+            if v < min {
+                min
+            } else if v > max {
+                max
+            } else {
+                v
+            }
+        }))
+    }
+}
+
+/// Elementwise bitwise operations on integer arrays.
+pub trait ChunkBitwise: Sized {
+    /// Bitwise and with an array of the same length, or of length 1.
+    fn bit_and(&self, rhs: &Self) -> Result<Self>;
+
+    /// Bitwise or with an array of the same length, or of length 1.
+    fn bit_or(&self, rhs: &Self) -> Result<Self>;
+
+    /// Bitwise exclusive or with an array of the same length, or of length 1.
+    fn xor(&self, rhs: &Self) -> Result<Self>;
+
+    /// Shift the bits to the left, shifted out bits are dropped.
+    fn shl(&self, n: u32) -> Self;
+
+    /// Shift the bits to the right. Signed integers keep their sign.
+    fn shr(&self, n: u32) -> Self;
+}
+
+fn bitwise_with<T, F>(lhs: &ChunkedArray<T>, rhs: &ChunkedArray<T>, f: F) -> Result<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    let mut ca: ChunkedArray<T> = match (lhs.len(), rhs.len()) {
+        (_, 1) => {
+            let opt_r = rhs.get(0);
+            lhs.into_iter()
+                .map(|opt_l| match (opt_l, opt_r) {
+                    (Some(l), Some(r)) => Some(f(l, r)),
+                    _ => None,
+                })
+                .collect()
+        }
+        (l, r) if l == r => lhs
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(opt_l, opt_r)| match (opt_l, opt_r) {
+                (Some(l), Some(r)) => Some(f(l, r)),
+                _ => None,
+            })
+            .collect(),
+        _ => {
+            return Err(PolarsError::ShapeMisMatch(
+                "bitwise operations need arrays of the same length".into(),
+            ))
+        }
+    };
+    ca.rename(lhs.name());
+    Ok(ca)
+}
+
+impl<T> ChunkBitwise for ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: PrimInt,
+{
+    fn bit_and(&self, rhs: &Self) -> Result<Self> {
+        bitwise_with(self, rhs, |l, r| l & r)
+    }
+
+    fn bit_or(&self, rhs: &Self) -> Result<Self> {
+        bitwise_with(self, rhs, |l, r| l | r)
+    }
+
+    fn xor(&self, rhs: &Self) -> Result<Self> {
+        bitwise_with(self, rhs, |l, r| l ^ r)
+    }
+
+    fn shl(&self, n: u32) -> Self {
+        let bits = T::Native::zero().count_zeros();
+        if n >= bits {
+            return apply_named(self, |_| T::Native::zero());
+        }
+        apply_named(self, |v| v << n as usize)
+    }
+
+    fn shr(&self, n: u32) -> Self {
+        let bits = T::Native::zero().count_zeros();
+        // shifting by the number of bits or more leaves the sign, if any
+        if n >= bits {
+            return apply_named(self, |v| (v >> (bits as usize - 1)) >> 1);
+        }
+        apply_named(self, |v| v >> n as usize)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_math() {
+        let ca = Float64Chunked::new_from_opt_slice("a", &[Some(-1.5551), None, Some(4.0)]);
+        assert_eq!(Vec::from(&ca.round(2)), &[Some(-1.56), None, Some(4.0)]);
+        assert_eq!(Vec::from(&ca.round(400)), Vec::from(&ca));
+        let ca32 = Float32Chunked::new_from_slice("a", &[1.5, f32::MAX]);
+        assert_eq!(Vec::from(&ca32.round(39)), &[Some(1.5), Some(f32::MAX)]);
+        assert_eq!(Vec::from(&ca32.round(1)), &[Some(1.5), Some(f32::MAX)]);
+        assert_eq!(
+            Vec::from(&ca.abs().sqrt()),
+            &[Some(1.5551f64.sqrt()), None, Some(2.0)]
+        );
+        assert_eq!(Vec::from(&ca.sign()), &[Some(-1.0), None, Some(1.0)]);
+        assert_eq!(ca.floor().name(), "a");
+
+        let ca = Int8Chunked::new_from_slice("a", &[-100, 0, 100]);
+        assert_eq!(Vec::from(&ca.abs()), &[Some(100), Some(0), Some(100)]);
+        let min = Int8Chunked::new_from_slice("a", &[i8::MIN, -1]);
+        assert_eq!(Vec::from(&min.abs()), &[None, Some(1)]);
+        let clipped = ca.clip(-1000.0, 10.5).unwrap();
+        assert_eq!(Vec::from(&clipped), &[Some(-100), Some(0), Some(10)]);
+        assert!(ca.clip(1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_bitwise() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(0b1100), None, Some(-8)]);
+        let rhs = Int32Chunked::new_from_slice("b", &[0b1010]);
+        assert_eq!(
+            Vec::from(&ca.bit_and(&rhs).unwrap()),
+            &[Some(0b1000), None, Some(8)]
+        );
+        assert_eq!(
+            Vec::from(&ca.xor(&rhs).unwrap()),
+            &[Some(0b0110), None, Some(-14)]
+        );
+        assert_eq!(Vec::from(&ca.shl(1)), &[Some(0b11000), None, Some(-16)]);
+        assert_eq!(Vec::from(&ca.shr(40)), &[Some(0), None, Some(-1)]);
+        let rhs = Int32Chunked::new_from_slice("b", &[1, 2]);
+        assert!(ca.bit_or(&rhs).is_err());
+    }
+}
@@ -11,9 +11,14 @@ pub(crate) mod aggregate;
 pub(crate) mod apply;
 pub(crate) mod chunkops;
 pub(crate) mod cum_agg;
+pub(crate) mod diff;
+pub(crate) mod ewm;
 pub(crate) mod explode;
 pub(crate) mod fill_none;
 pub(crate) mod filter;
+pub(crate) mod math;
+pub(crate) mod moment;
+pub(crate) mod rank;
 pub(crate) mod set;
 pub(crate) mod shift;
 pub(crate) mod sort;
@@ -35,6 +40,32 @@ pub trait ChunkCumAgg<T> {
     fn cum_sum(&self, _reverse: bool) -> ChunkedArray<T> {
         panic!("operation cum_sum not supported for this dtype")
     }
+    /// Get an array with the cumulative product computed at every element
+    fn cum_prod(&self, _reverse: bool) -> ChunkedArray<T> {
+        panic!("operation cum_prod not supported for this dtype")
+    }
+}
+
+/// Exponentially weighted moving statistics. See [EWMOptions].
+pub trait ChunkEwm {
+    /// Get the exponentially weighted moving mean.
+    fn ewm_mean(&self, _options: &EWMOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "ewm_mean not supported for this datatype".into(),
+        ))
+    }
+    /// Get the exponentially weighted moving standard deviation.
+    fn ewm_std(&self, _options: &EWMOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "ewm_std not supported for this datatype".into(),
+        ))
+    }
+    /// Get the exponentially weighted moving variance.
+    fn ewm_var(&self, _options: &EWMOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "ewm_var not supported for this datatype".into(),
+        ))
+    }
 }
 
 pub trait ChunkTakeEvery<T> {
@@ -52,26 +83,55 @@ pub trait ChunkBytes {
     fn to_byte_slices(&self) -> Vec<&[u8]>;
 }
 
+/// Options for the rolling window functions of [ChunkWindow].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingOptions {
+    /// The length of the window.
+    pub window_size: usize,
+    /// The minimal number of non null values in a window to compute a result. Windows with
+    /// less values lead to a Null.
+    pub min_periods: usize,
+    /// Set the window at the center of a value instead of letting it end at the value.
+    pub center: bool,
+    /// An optional slice with the same length of the window that will be multiplied
+    /// elementwise with the values in the window. Only used by the sum, mean, min and max.
+    pub weight: Option<Vec<f64>>,
+}
+
+impl RollingOptions {
+    /// Options for a trailing window that requires `window_size` non null values.
+    pub fn new(window_size: usize) -> Self {
+        RollingOptions {
+            window_size,
+            min_periods: window_size,
+            center: false,
+            weight: None,
+        }
+    }
+
+    pub fn with_min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    pub fn with_center(mut self, center: bool) -> Self {
+        self.center = center;
+        self
+    }
+
+    pub fn with_weight(mut self, weight: Option<Vec<f64>>) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// Rolling (moving) aggregations. A window of length `window_size` traverses the array and the
+/// values that fill the window are aggregated. Null values are ignored, but a window with less
+/// than `min_periods` non null values leads to a Null. See [RollingOptions].
 pub trait ChunkWindow {
-    /// apply a rolling sum (moving sum) over the values in this array.
-    /// a window of length `window_size` will traverse the array. the values that fill this window
-    /// will (optionally) be multiplied with the weights given by the `weight` vector. the resulting
-    /// values will be aggregated to their sum.
-    ///
-    /// # Arguments
-    ///
-    /// * `window_size` - The length of the window.
-    /// * `weight` - An optional slice with the same length of the window that will be multiplied
-    ///              elementwise with the values in the window.
-    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
-    ///                     `true` -> Null values will be ignored.
-    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
-    fn rolling_sum(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Self>
+    /// Apply a rolling sum (moving sum) over the values in this array. The values in the window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector.
+    fn rolling_sum(&self, _options: &RollingOptions) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
@@ -79,25 +139,10 @@ pub trait ChunkWindow {
             "rolling sum not supported for this datatype".into(),
         ))
     }
-    /// Apply a rolling mean (moving mean) over the values in this array.
-    /// A window of length `window_size` will traverse the array. The values that fill this window
-    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
-    /// values will be aggregated to their mean.
-    ///
-    /// # Arguments
-    ///
-    /// * `window_size` - The length of the window.
-    /// * `weight` - An optional slice with the same length of the window that will be multiplied
-    ///              elementwise with the values in the window.
-    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
-    ///                     `true` -> Null values will be ignored.
-    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
-    fn rolling_mean(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Self>
+
+    /// Apply a rolling mean (moving mean) over the values in this array. The values in the window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector.
+    fn rolling_mean(&self, _options: &RollingOptions) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
@@ -106,57 +151,69 @@ pub trait ChunkWindow {
         ))
     }
 
-    /// Apply a rolling min (moving min) over the values in this array.
-    /// A window of length `window_size` will traverse the array. The values that fill this window
-    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
-    /// values will be aggregated to their min.
-    ///
-    /// # Arguments
-    ///
-    /// * `window_size` - The length of the window.
-    /// * `weight` - An optional slice with the same length of the window that will be multiplied
-    ///              elementwise with the values in the window.
-    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
-    ///                     `true` -> Null values will be ignored.
-    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
-    fn rolling_min(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Self>
+    /// Apply a rolling min (moving min) over the values in this array. The values in the window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector.
+    fn rolling_min(&self, _options: &RollingOptions) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
         Err(PolarsError::InvalidOperation(
-            "rolling mean not supported for this datatype".into(),
+            "rolling min not supported for this datatype".into(),
         ))
     }
 
-    /// Apply a rolling max (moving max) over the values in this array.
-    /// A window of length `window_size` will traverse the array. The values that fill this window
-    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
-    /// values will be aggregated to their max.
-    ///
-    /// # Arguments
-    ///
-    /// * `window_size` - The length of the window.
-    /// * `weight` - An optional slice with the same length of the window that will be multiplied
-    ///              elementwise with the values in the window.
-    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
-    ///                     `true` -> Null values will be ignored.
-    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
-    fn rolling_max(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Self>
+    /// Apply a rolling max (moving max) over the values in this array. The values in the window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector.
+    fn rolling_max(&self, _options: &RollingOptions) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
         Err(PolarsError::InvalidOperation(
-            "rolling mean not supported for this datatype".into(),
+            "rolling max not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling variance over the values in this array. The result is a float Series.
+    fn rolling_var(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "rolling var not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling standard deviation over the values in this array. The result is a float
+    /// Series.
+    fn rolling_std(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "rolling std not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling median over the values in this array. The result is a float Series.
+    fn rolling_median(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "rolling median not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling quantile over the values in this array. Quantiles that fall between two
+    /// values are linearly interpolated. The result is a float Series.
+    fn rolling_quantile(&self, _quantile: f64, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "rolling quantile not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling skew over the values in this array. The result is a float Series.
+    fn rolling_skew(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "rolling skew not supported for this datatype".into(),
+        ))
+    }
+
+    /// Count the non null values in a rolling window.
+    fn rolling_count(&self, _options: &RollingOptions) -> Result<UInt32Chunked> {
+        Err(PolarsError::InvalidOperation(
+            "rolling count not supported for this datatype".into(),
         ))
     }
 }
@@ -388,6 +445,43 @@ pub trait ChunkApply<'a, A, B> {
         F: Fn((usize, Option<A>)) -> Option<B> + Copy;
 }
 
+/// The method to pick a quantile that falls between two values `i < j` of the sorted data, with
+/// the quantile at position `quantile * (n - 1)`. These are the methods of numpy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QuantileInterpolOptions {
+    /// `i` or `j`, whichever is nearest. Halfway positions are rounded to the even position.
+    Nearest,
+    /// `i`
+    Lower,
+    /// `j`
+    Higher,
+    /// `(i + j) / 2`
+    Midpoint,
+    /// `i + (j - i) * fraction`, where `fraction` is the fractional part of the position.
+    Linear,
+}
+
+impl Default for QuantileInterpolOptions {
+    fn default() -> Self {
+        QuantileInterpolOptions::Nearest
+    }
+}
+
+impl QuantileInterpolOptions {
+    /// The data type of a quantile of values of type `dtype`. Interpolated quantiles of integers
+    /// are `Float64`.
+    pub fn quantile_dtype(&self, dtype: &DataType) -> DataType {
+        match self {
+            QuantileInterpolOptions::Midpoint | QuantileInterpolOptions::Linear
+                if dtype.is_numeric() && !dtype.is_float() =>
+            {
+                DataType::Float64
+            }
+            _ => dtype.clone(),
+        }
+    }
+}
+
 /// Aggregation operations
 pub trait ChunkAgg<T> {
     /// Aggregate the sum of the ChunkedArray.
@@ -403,13 +497,25 @@ pub trait ChunkAgg<T> {
     /// Returns `None` if the array is empty or only contains null values.
     fn mean(&self) -> Option<T>;
 
-    /// Returns the mean value in the array.
+    /// Returns the median value in the array, the lower of the two middle values if the
+    /// number of values is even.
     /// Returns `None` if the array is empty or only contains null values.
     fn median(&self) -> Option<T>;
 
     /// Aggregate a given quantile of the ChunkedArray.
     /// Returns `None` if the array is empty or only contains null values.
-    fn quantile(&self, quantile: f64) -> Result<Option<T>>;
+    ///
+    /// Interpolated quantiles of integers are truncated to the integer type, use
+    /// [quantile_as_series](crate::prelude::ChunkAggSeries::quantile_as_series) to get them as
+    /// `Float64`.
+    fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<Option<T>>;
+
+    /// Aggregate multiple quantiles of the ChunkedArray. The values are only sorted once.
+    fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Vec<Option<T>>>;
 }
 
 /// Variance and standard deviation aggregation.
@@ -521,13 +627,20 @@ pub trait ChunkSort<T> {
     fn argsort(&self, reverse: bool) -> Vec<usize>;
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum FillNoneStrategy {
-    Backward,
-    Forward,
+    /// Replace None with the next valid value, filling at most `limit` consecutive Nones.
+    Backward(Option<u32>),
+    /// Replace None with the previous valid value, filling at most `limit` consecutive Nones.
+    Forward(Option<u32>),
     Mean,
     Min,
     Max,
+    Zero,
+    One,
+    /// Linearly interpolate between the valid neighbours. Nones before the first and after the
+    /// last valid value are not filled. Integers are rounded to the nearest value.
+    Linear,
 }
 
 /// Replace None values with various strategies
@@ -538,9 +651,23 @@ pub trait ChunkFillNone {
     /// * Mean fill (replace None with the mean of the whole array)
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
+    /// * Zero fill (replace None with zero)
+    /// * One fill (replace None with one)
+    /// * Linear fill (interpolate linearly between the previous and the next value)
     fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Self>
     where
         Self: Sized;
+
+    /// Linearly interpolate the None values, weighted by the time that passed between the
+    /// values in `by`.
+    fn interpolate_by(&self, _by: &Date64Chunked) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        Err(PolarsError::InvalidOperation(
+            "interpolate_by not supported for this dtype".into(),
+        ))
+    }
 }
 /// Replace None values with a value
 pub trait ChunkFillNoneValue<T> {
@@ -641,6 +768,28 @@ impl ChunkFullNull for Utf8Chunked {
     }
 }
 
+impl<'a> ChunkFull<&'a [u8]> for BinaryChunked {
+    fn full(name: &str, value: &'a [u8], length: usize) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, length, length * value.len());
+
+        for _ in 0..length {
+            builder.append_value(value);
+        }
+        builder.finish()
+    }
+}
+
+impl ChunkFullNull for BinaryChunked {
+    fn full_null(name: &str, length: usize) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, length, 0);
+
+        for _ in 0..length {
+            builder.append_null()
+        }
+        builder.finish()
+    }
+}
+
 impl ChunkFull<&Series> for ListChunked {
     fn full(name: &str, value: &Series, length: usize) -> ListChunked {
         let mut builder = get_list_builder(value.dtype(), value.len() * length, length, name);
@@ -702,6 +851,8 @@ macro_rules! impl_reverse {
 
 impl_reverse!(BooleanType, BooleanChunked);
 impl_reverse!(Utf8Type, Utf8Chunked);
+impl_reverse!(BinaryType, BinaryChunked);
+impl_reverse!(DecimalType, DecimalChunked);
 impl_reverse!(ListType, ListChunked);
 #[cfg(feature = "object")]
 impl<T> ChunkReverse<ObjectType<T>> for ObjectChunked<T> {
@@ -765,6 +916,19 @@ impl ChunkExpandAtIndex<Utf8Type> for Utf8Chunked {
     }
 }
 
+impl ChunkExpandAtIndex<BinaryType> for BinaryChunked {
+    fn expand_at_index(&self, index: usize, length: usize) -> BinaryChunked {
+        impl_chunk_expand!(self, length, index)
+    }
+}
+
+impl ChunkExpandAtIndex<DecimalType> for DecimalChunked {
+    fn expand_at_index(&self, index: usize, length: usize) -> DecimalChunked {
+        let opt_val = self.get(index);
+        self.new_with_same_type((0..length).map(|_| opt_val))
+    }
+}
+
 impl ChunkExpandAtIndex<CategoricalType> for CategoricalChunked {
     fn expand_at_index(&self, index: usize, length: usize) -> CategoricalChunked {
         self.cast::<UInt32Type>()
@@ -830,12 +994,12 @@ mod test {
     fn test_fill_none() {
         let ca =
             Int32Chunked::new_from_opt_slice("", &[None, Some(2), Some(3), None, Some(4), None]);
-        let filled = ca.fill_none(FillNoneStrategy::Forward).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Forward(None)).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[None, Some(2), Some(3), Some(3), Some(4), Some(4)]
         );
-        let filled = ca.fill_none(FillNoneStrategy::Backward).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Backward(None)).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[Some(2), Some(2), Some(3), Some(4), Some(4), None]
@@ -857,4 +1021,58 @@ mod test {
         );
         println!("{:?}", filled);
     }
+
+    #[test]
+    fn test_fill_none_limit_and_interpolate() {
+        let ca = Int32Chunked::new_from_opt_slice(
+            "a",
+            &[None, Some(1), None, None, None, Some(5), None],
+        );
+        let filled = ca.fill_none(FillNoneStrategy::Forward(Some(2))).unwrap();
+        assert_eq!(filled.name(), "a");
+        assert_eq!(
+            Vec::from(&filled),
+            &[None, Some(1), Some(1), Some(1), None, Some(5), Some(5)]
+        );
+        let filled = ca.fill_none(FillNoneStrategy::Backward(Some(1))).unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(1), Some(1), None, None, Some(5), Some(5), None]
+        );
+        let filled = ca.fill_none(FillNoneStrategy::Linear).unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[None, Some(1), Some(2), Some(3), Some(4), Some(5), None]
+        );
+        let filled = ca.fill_none(FillNoneStrategy::Zero).unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[
+                Some(0),
+                Some(1),
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(5),
+                Some(0)
+            ]
+        );
+
+        let ca = Float64Chunked::new_from_opt_slice("a", &[Some(1.0), None, Some(4.0)]);
+        let by = Date64Chunked::new_from_slice("t", &[0, 2000, 3000]);
+        let filled = ca.interpolate_by(&by).unwrap();
+        assert_eq!(Vec::from(&filled), &[Some(1.0), Some(3.0), Some(4.0)]);
+
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("a"), None, None]);
+        let filled = ca.fill_none(FillNoneStrategy::Forward(None)).unwrap();
+        assert_eq!(Vec::from(&filled), &[Some("a"), Some("a"), Some("a")]);
+        assert!(ca.fill_none(FillNoneStrategy::Linear).is_err());
+    }
+
+    #[test]
+    fn test_fill_nan() {
+        let ca = Float64Chunked::new_from_opt_slice("a", &[Some(1.0), Some(f64::NAN), None]);
+        let filled = ca.fill_nan(0.0);
+        assert_eq!(Vec::from(&filled), &[Some(1.0), Some(0.0), None]);
+    }
 }
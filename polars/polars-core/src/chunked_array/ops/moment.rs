@@ -0,0 +1,211 @@
+//! Aggregations that describe the shape of a distribution.
+use crate::chunked_array::ops::rank::cmp_nan_last;
+use crate::prelude::*;
+use num::ToPrimitive;
+use std::cmp::Ordering;
+
+/// Skewness and kurtosis aggregation. Null values are ignored.
+pub trait ChunkMoment {
+    /// Compute the sample skewness. With `bias = false` the statistic is corrected for the
+    /// sample size, like pandas does. Returns `None` if there are too few values and `0.0` if
+    /// all values are equal.
+    fn skew(&self, _bias: bool) -> Option<f64> {
+        None
+    }
+
+    /// Compute the sample kurtosis. With `fisher = true` the kurtosis of a normal distribution
+    /// is 0.0, otherwise 3.0. With `bias = false` the statistic is corrected for the sample
+    /// size, like pandas does. Returns `None` if there are too few values. If all values are
+    /// equal the excess kurtosis is `0.0`, like in pandas.
+    fn kurtosis(&self, _fisher: bool, _bias: bool) -> Option<f64> {
+        None
+    }
+}
+
+/// The number of values and the second, third and fourth central moments.
+fn central_moments(values: impl Iterator<Item = f64> + Clone) -> (f64, f64, f64, f64) {
+    let (n, sum) = values
+        .clone()
+        .fold((0.0, 0.0), |(n, sum), v| (n + 1.0, sum + v));
+    let mean = sum / n;
+    let (m2, m3, m4) = values.fold((0.0, 0.0, 0.0), |(m2, m3, m4), v| {
+        let d = v - mean;
+        let d2 = d * d;
+        (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+    });
+    (n, m2 / n, m3 / n, m4 / n)
+}
+
+/// Values that are equal can have a tiny variance because of rounding errors in the mean.
+/// Like pandas, we consider these values constant.
+fn is_constant(m2: f64) -> bool {
+    m2.abs() < 1e-14
+}
+
+pub(crate) fn skew(values: impl Iterator<Item = f64> + Clone, bias: bool) -> Option<f64> {
+    let (n, m2, m3, _) = central_moments(values);
+    if n == 0.0 || (!bias && n < 3.0) {
+        return None;
+    }
+    if is_constant(m2) {
+        return Some(0.0);
+    }
+    let g1 = m3 / m2.powf(1.5);
+    if bias {
+        Some(g1)
+    } else {
+        Some(g1 * (n * (n - 1.0)).sqrt() / (n - 2.0))
+    }
+}
+
+pub(crate) fn kurtosis(
+    values: impl Iterator<Item = f64> + Clone,
+    fisher: bool,
+    bias: bool,
+) -> Option<f64> {
+    let (n, m2, _, m4) = central_moments(values);
+    if n == 0.0 || (!bias && n < 4.0) {
+        return None;
+    }
+    let g2 = m4 / (m2 * m2) - 3.0;
+    let excess = if is_constant(m2) {
+        0.0
+    } else if bias {
+        g2
+    } else {
+        ((n + 1.0) * g2 + 6.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0))
+    };
+    if fisher {
+        Some(excess)
+    } else {
+        Some(excess + 3.0)
+    }
+}
+
+impl<T> ChunkMoment for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    fn skew(&self, bias: bool) -> Option<f64> {
+        let values = self.into_iter().flatten().map(|v| v.to_f64().unwrap());
+        skew(values, bias)
+    }
+
+    fn kurtosis(&self, fisher: bool, bias: bool) -> Option<f64> {
+        let values = self.into_iter().flatten().map(|v| v.to_f64().unwrap());
+        kurtosis(values, fisher, bias)
+    }
+}
+
+impl ChunkMoment for Utf8Chunked {}
+impl ChunkMoment for ListChunked {}
+impl ChunkMoment for BooleanChunked {}
+impl ChunkMoment for CategoricalChunked {}
+#[cfg(feature = "object")]
+impl<T> ChunkMoment for ObjectChunked<T> {}
+
+/// Mode aggregation.
+pub trait ChunkMode<T> {
+    /// Get the most frequent value. If multiple values are the most frequent, the smallest is
+    /// returned. Returns `None` if the array is empty or only contains null values.
+    fn mode(&self) -> Option<T>;
+}
+
+/// Get the index of the first occurrence of the most frequent value. Ties are resolved in
+/// favor of the smallest value.
+pub(crate) fn mode_idx<T: PartialOrd>(mut values: Vec<(usize, T)>) -> Option<usize> {
+    // a stable sort keeps the first occurrence first in its run
+    values.sort_by(|a, b| cmp_nan_last(&a.1, &b.1));
+    let mut best: Option<(usize, usize)> = None;
+    let mut start = 0;
+    while start < values.len() {
+        let mut end = start + 1;
+        while end < values.len()
+            && cmp_nan_last(&values[start].1, &values[end].1) == Ordering::Equal
+        {
+            end += 1;
+        }
+        match best {
+            Some((_, count)) if count >= end - start => {}
+            _ => best = Some((values[start].0, end - start)),
+        }
+        start = end;
+    }
+    best.map(|(idx, _)| idx)
+}
+
+impl<T> ChunkMode<T::Native> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    fn mode(&self) -> Option<T::Native> {
+        let values = self
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)))
+            .collect();
+        mode_idx(values).and_then(|idx| self.get(idx))
+    }
+}
+
+impl ChunkMode<String> for Utf8Chunked {
+    fn mode(&self) -> Option<String> {
+        let values = self
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)))
+            .collect::<Vec<_>>();
+        let idx = mode_idx(values.clone())?;
+        values
+            .into_iter()
+            .find(|(i, _)| *i == idx)
+            .map(|(_, v)| v.to_string())
+    }
+}
+
+impl ChunkMode<bool> for BooleanChunked {
+    fn mode(&self) -> Option<bool> {
+        let values = self
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)))
+            .collect();
+        mode_idx(values).and_then(|idx| self.get(idx))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_moments() {
+        // pandas: pd.Series([1, 2, 3, 10]).skew(), .kurt()
+        let ca =
+            Int32Chunked::new_from_opt_slice("a", &[Some(1), Some(2), None, Some(3), Some(10)]);
+        assert!((ca.skew(false).unwrap() - 1.763_632_6).abs() < 1e-6);
+        assert!((ca.kurtosis(true, false).unwrap() - 3.228).abs() < 1e-6);
+        // scipy: stats.skew([1, 2, 3, 10]), stats.kurtosis([1, 2, 3, 10], fisher=False)
+        assert!((ca.skew(true).unwrap() - 1.018_233_8).abs() < 1e-6);
+        assert!((ca.kurtosis(false, true).unwrap() - 2.2304).abs() < 1e-6);
+        assert_eq!(ca.slice(0, 2).unwrap().skew(false), None);
+
+        // pandas: pd.Series([0.1] * 5).skew(), .kurt()
+        let ca = Float64Chunked::new_from_slice("a", &[0.1; 5]);
+        assert_eq!(ca.skew(false), Some(0.0));
+        assert_eq!(ca.skew(true), Some(0.0));
+        assert_eq!(ca.kurtosis(true, false), Some(0.0));
+        assert_eq!(ca.kurtosis(false, true), Some(3.0));
+    }
+
+    #[test]
+    fn test_mode() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(3), None, Some(1), Some(3), Some(1)]);
+        assert_eq!(ca.mode(), Some(1));
+        let ca = Utf8Chunked::new_from_slice("a", &["b", "a", "b"]);
+        assert_eq!(ca.mode(), Some("b".to_string()));
+        let ca = Float64Chunked::new_from_opt_slice("a", &[None, None]);
+        assert_eq!(ca.mode(), None);
+    }
+}
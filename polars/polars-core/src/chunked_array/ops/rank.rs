@@ -0,0 +1,227 @@
+//! Rank the values of an array, like pandas and SQL do.
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// How equal values are ranked.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RankMethod {
+    /// The average of the ranks that equal values would get.
+    Average,
+    /// The lowest rank that equal values would get.
+    Min,
+    /// The highest rank that equal values would get.
+    Max,
+    /// Like `Min`, but the rank only increases by one between groups of equal values.
+    Dense,
+    /// Every value gets a distinct rank, equal values are ranked in the order they appear.
+    Ordinal,
+}
+
+impl Default for RankMethod {
+    fn default() -> Self {
+        RankMethod::Average
+    }
+}
+
+/// Options for [ChunkRank::rank].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RankOptions {
+    pub method: RankMethod,
+    /// Give the largest value rank 1.
+    pub descending: bool,
+}
+
+impl RankOptions {
+    pub fn with_method(mut self, method: RankMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+}
+
+/// NaN is ordered after all other values.
+#[allow(clippy::eq_op)]
+pub(crate) fn cmp_nan_last<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    match a.partial_cmp(b) {
+        Some(ord) => ord,
+        None => match (a != a, b != b) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            _ => Ordering::Less,
+        },
+    }
+}
+
+/// Compute the ranks of the non null values. The ranks start at 1.
+fn rank_values<T: PartialOrd>(values: &[(usize, T)], options: RankOptions) -> Vec<(usize, f64)> {
+    let mut sorted = values.iter().collect::<Vec<_>>();
+    // a stable sort keeps the order of appearance for ordinal ranks
+    if options.descending {
+        sorted.sort_by(|a, b| cmp_nan_last(&b.1, &a.1));
+    } else {
+        sorted.sort_by(|a, b| cmp_nan_last(&a.1, &b.1));
+    }
+
+    let mut ranks = Vec::with_capacity(sorted.len());
+    let mut start = 0;
+    let mut dense_rank = 0;
+    while start < sorted.len() {
+        let mut end = start + 1;
+        while end < sorted.len()
+            && cmp_nan_last(&sorted[start].1, &sorted[end].1) == Ordering::Equal
+        {
+            end += 1;
+        }
+        dense_rank += 1;
+        for (i, (idx, _)) in sorted[start..end].iter().enumerate() {
+            let rank = match options.method {
+                RankMethod::Average => (start + end + 1) as f64 / 2.0,
+                RankMethod::Min => (start + 1) as f64,
+                RankMethod::Max => end as f64,
+                RankMethod::Dense => dense_rank as f64,
+                RankMethod::Ordinal => (start + i + 1) as f64,
+            };
+            ranks.push((*idx, rank))
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Rank the values of an array. Null values remain null.
+pub trait ChunkRank {
+    /// Rank the values. The ranks start at 1 and are `Float64` for [RankMethod::Average] and
+    /// `UInt32` for the other methods.
+    fn rank(&self, _options: RankOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "rank not supported for this datatype".into(),
+        ))
+    }
+}
+
+fn finish_rank(name: &str, len: usize, ranks: Vec<(usize, f64)>, method: RankMethod) -> Series {
+    let mut out = vec![None; len];
+    for (idx, rank) in ranks {
+        out[idx] = Some(rank)
+    }
+    let mut s = match method {
+        RankMethod::Average => {
+            let ca: Float64Chunked = out.into_iter().collect();
+            ca.into_series()
+        }
+        _ => {
+            let ca: UInt32Chunked = out
+                .into_iter()
+                .map(|opt_rank| opt_rank.map(|rank| rank as u32))
+                .collect();
+            ca.into_series()
+        }
+    };
+    s.rename(name);
+    s
+}
+
+impl<T> ChunkRank for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    fn rank(&self, options: RankOptions) -> Result<Series> {
+        let values = self
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)))
+            .collect::<Vec<_>>();
+        let ranks = rank_values(&values, options);
+        Ok(finish_rank(self.name(), self.len(), ranks, options.method))
+    }
+}
+
+impl ChunkRank for Utf8Chunked {
+    fn rank(&self, options: RankOptions) -> Result<Series> {
+        let values = self
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)))
+            .collect::<Vec<_>>();
+        let ranks = rank_values(&values, options);
+        Ok(finish_rank(self.name(), self.len(), ranks, options.method))
+    }
+}
+
+impl ChunkRank for BooleanChunked {
+    fn rank(&self, options: RankOptions) -> Result<Series> {
+        let values = self
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)))
+            .collect::<Vec<_>>();
+        let ranks = rank_values(&values, options);
+        Ok(finish_rank(self.name(), self.len(), ranks, options.method))
+    }
+}
+
+impl ChunkRank for ListChunked {}
+impl ChunkRank for CategoricalChunked {}
+#[cfg(feature = "object")]
+impl<T> ChunkRank for ObjectChunked<T> {}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rank() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(3), Some(1), None, Some(3), Some(2)]);
+        let options = RankOptions::default();
+
+        // pandas: pd.Series([3, 1, None, 3, 2]).rank()
+        let s = ca.rank(options).unwrap();
+        assert_eq!(s.name(), "a");
+        assert_eq!(
+            Vec::from(s.f64().unwrap()),
+            &[Some(3.5), Some(1.0), None, Some(3.5), Some(2.0)]
+        );
+        let s = ca.rank(options.with_method(RankMethod::Min)).unwrap();
+        assert_eq!(
+            Vec::from(s.u32().unwrap()),
+            &[Some(3), Some(1), None, Some(3), Some(2)]
+        );
+        let s = ca.rank(options.with_method(RankMethod::Max)).unwrap();
+        assert_eq!(
+            Vec::from(s.u32().unwrap()),
+            &[Some(4), Some(1), None, Some(4), Some(2)]
+        );
+        let s = ca
+            .rank(options.with_method(RankMethod::Dense).with_descending(true))
+            .unwrap();
+        assert_eq!(
+            Vec::from(s.u32().unwrap()),
+            &[Some(1), Some(3), None, Some(1), Some(2)]
+        );
+        let s = ca
+            .rank(
+                options
+                    .with_method(RankMethod::Ordinal)
+                    .with_descending(true),
+            )
+            .unwrap();
+        assert_eq!(
+            Vec::from(s.u32().unwrap()),
+            &[Some(1), Some(4), None, Some(2), Some(3)]
+        );
+
+        let ca = Float64Chunked::new_from_slice("a", &[f64::NAN, 1.0, 0.5]);
+        let s = ca.rank(options).unwrap();
+        assert_eq!(
+            Vec::from(s.f64().unwrap()),
+            &[Some(3.0), Some(2.0), Some(1.0)]
+        );
+        let ca = Utf8Chunked::new_from_slice("a", &["b", "a", "b"]);
+        let s = ca.rank(options.with_method(RankMethod::Dense)).unwrap();
+        assert_eq!(Vec::from(s.u32().unwrap()), &[Some(2), Some(1), Some(2)]);
+    }
+}
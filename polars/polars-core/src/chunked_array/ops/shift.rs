@@ -66,6 +66,39 @@ impl ChunkShift<Utf8Type> for Utf8Chunked {
     }
 }
 
+impl ChunkShiftFill<BinaryType, Option<&[u8]>> for BinaryChunked {
+    fn shift_and_fill(&self, periods: i64, fill_value: Option<&[u8]>) -> BinaryChunked {
+        impl_shift_fill!(self, periods, fill_value)
+    }
+}
+
+impl ChunkShift<BinaryType> for BinaryChunked {
+    fn shift(&self, periods: i64) -> Self {
+        self.shift_and_fill(periods, None)
+    }
+}
+
+impl ChunkShiftFill<DecimalType, Option<i128>> for DecimalChunked {
+    fn shift_and_fill(&self, periods: i64, fill_value: Option<i128>) -> DecimalChunked {
+        let slice_offset = clamp(-periods, 0, self.len() as i64) as usize;
+        let length = self.len() - abs(periods) as usize;
+        let slice = self.slice(slice_offset, length).unwrap();
+        let fill = (0..abs(periods)).map(|_| fill_value);
+
+        if periods < 0 {
+            self.new_with_same_type(slice.into_iter().chain(fill))
+        } else {
+            self.new_with_same_type(fill.chain(slice.into_iter()))
+        }
+    }
+}
+
+impl ChunkShift<DecimalType> for DecimalChunked {
+    fn shift(&self, periods: i64) -> Self {
+        self.shift_and_fill(periods, None)
+    }
+}
+
 impl ChunkShiftFill<ListType, Option<&Series>> for ListChunked {
     fn shift_and_fill(&self, periods: i64, fill_value: Option<&Series>) -> ListChunked {
         impl_shift_fill!(self, periods, fill_value)
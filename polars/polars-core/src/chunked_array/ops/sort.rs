@@ -3,7 +3,6 @@ use crate::utils::NoNull;
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::cmp::Ordering;
-use std::ops::{Deref, DerefMut};
 
 fn sort_partial<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Ordering {
     match (a, b) {
@@ -170,17 +169,27 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
     }
 }
 
+/// Categoricals are sorted by the ordering of their categories, see [`CategoricalOrdering`].
 impl ChunkSort<CategoricalType> for CategoricalChunked {
     fn sort(&self, reverse: bool) -> Self {
-        self.as_ref().sort(reverse).cast().unwrap()
+        match self.ordering() {
+            CategoricalOrdering::Physical => {
+                let ca: CategoricalChunked = self.as_ref().sort(reverse).cast().unwrap();
+                ca.set_state(self)
+            }
+            _ => {
+                let idx = self.argsort(reverse);
+                self.take(idx.into_iter(), Some(self.len()))
+            }
+        }
     }
 
     fn sort_in_place(&mut self, reverse: bool) {
-        self.deref_mut().sort_in_place(reverse)
+        *self = self.sort(reverse)
     }
 
     fn argsort(&self, reverse: bool) -> Vec<usize> {
-        self.deref().argsort(reverse)
+        self.ordered_codes().argsort(reverse)
     }
 }
 
@@ -10,7 +10,8 @@ use crate::chunked_array::kernels::take::{take_no_null_primitive, take_utf8};
 use crate::prelude::*;
 use crate::utils::NoNull;
 use arrow::array::{
-    Array, ArrayRef, BooleanArray, LargeListArray, LargeStringArray, PrimitiveArray,
+    Array, ArrayRef, BooleanArray, DecimalArray, LargeBinaryArray, LargeListArray,
+    LargeStringArray, PrimitiveArray,
 };
 use arrow::compute::kernels::take::take;
 use polars_arrow::prelude::*;
@@ -124,6 +125,30 @@ impl<'a> TakeRandomUtf8 for &'a Utf8Chunked {
     }
 }
 
+impl<'a> TakeRandom for &'a BinaryChunked {
+    type Item = &'a [u8];
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        impl_take_random_get!(self, index, LargeBinaryArray)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        impl_take_random_get_unchecked!(self, index, LargeBinaryArray)
+    }
+}
+
+impl<'a> TakeRandom for &'a DecimalChunked {
+    type Item = i128;
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        impl_take_random_get!(self, index, DecimalArray)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        impl_take_random_get_unchecked!(self, index, DecimalArray)
+    }
+}
+
 impl TakeRandom for ListChunked {
     type Item = Series;
 
@@ -535,6 +560,156 @@ impl ChunkTake for Utf8Chunked {
     }
 }
 
+impl ChunkTake for BinaryChunked {
+    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Self
+    where
+        Self: std::marker::Sized,
+    {
+        self.take_opt(indices.map(Some), capacity)
+    }
+
+    unsafe fn take_unchecked(
+        &self,
+        indices: impl Iterator<Item = usize>,
+        capacity: Option<usize>,
+    ) -> Self {
+        self.take_opt_unchecked(indices.map(Some), capacity)
+    }
+
+    fn take_opt(
+        &self,
+        indices: impl Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Self
+    where
+        Self: std::marker::Sized,
+    {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let capacity = capacity.unwrap_or(indices.size_hint().0);
+        let fact = capacity as f32 / self.len() as f32 * 1.2;
+        let values_cap = (self.get_values_size() as f32 * fact) as usize;
+
+        let mut builder = BinaryChunkedBuilder::new(self.name(), capacity, values_cap);
+        let taker = self.take_rand();
+
+        for opt_idx in indices {
+            builder.append_option(opt_idx.and_then(|idx| taker.get(idx)));
+        }
+        builder.finish()
+    }
+
+    unsafe fn take_opt_unchecked(
+        &self,
+        indices: impl Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let capacity = capacity.unwrap_or(indices.size_hint().0);
+        let fact = capacity as f32 / self.len() as f32 * 1.2;
+        let values_cap = (self.get_values_size() as f32 * fact) as usize;
+
+        let mut builder = BinaryChunkedBuilder::new(self.name(), capacity, values_cap);
+        let taker = self.take_rand();
+
+        for opt_idx in indices {
+            match opt_idx {
+                Some(idx) => builder.append_value(taker.get_unchecked(idx)),
+                None => builder.append_null(),
+            };
+        }
+        builder.finish()
+    }
+
+    fn take_from_single_chunked(&self, idx: &UInt32Chunked) -> Result<Self> {
+        if self.chunks.len() == 1 && idx.chunks.len() == 1 {
+            Ok(self.take_opt(
+                idx.into_iter()
+                    .map(|opt_idx| opt_idx.map(|idx| idx as usize)),
+                Some(idx.len()),
+            ))
+        } else {
+            Err(PolarsError::NoSlice)
+        }
+    }
+}
+
+impl ChunkTake for DecimalChunked {
+    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Self
+    where
+        Self: std::marker::Sized,
+    {
+        self.take_opt(indices.map(Some), capacity)
+    }
+
+    unsafe fn take_unchecked(
+        &self,
+        indices: impl Iterator<Item = usize>,
+        capacity: Option<usize>,
+    ) -> Self {
+        self.take_opt_unchecked(indices.map(Some), capacity)
+    }
+
+    fn take_opt(
+        &self,
+        indices: impl Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Self
+    where
+        Self: std::marker::Sized,
+    {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let capacity = capacity.unwrap_or(indices.size_hint().0);
+        let mut builder =
+            DecimalChunkedBuilder::new(self.name(), capacity, self.precision(), self.scale());
+        let taker = self.take_rand();
+
+        for opt_idx in indices {
+            builder.append_option(opt_idx.and_then(|idx| taker.get(idx)));
+        }
+        builder.finish()
+    }
+
+    unsafe fn take_opt_unchecked(
+        &self,
+        indices: impl Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let capacity = capacity.unwrap_or(indices.size_hint().0);
+        let mut builder =
+            DecimalChunkedBuilder::new(self.name(), capacity, self.precision(), self.scale());
+        let taker = self.take_rand();
+
+        for opt_idx in indices {
+            // the index may point to a null value, so we cannot use `get_unchecked`
+            builder.append_option(opt_idx.and_then(|idx| taker.get(idx)));
+        }
+        builder.finish()
+    }
+
+    fn take_from_single_chunked(&self, idx: &UInt32Chunked) -> Result<Self> {
+        if self.chunks.len() == 1 && idx.chunks.len() == 1 {
+            Ok(self.take_opt(
+                idx.into_iter()
+                    .map(|opt_idx| opt_idx.map(|idx| idx as usize)),
+                Some(idx.len()),
+            ))
+        } else {
+            Err(PolarsError::NoSlice)
+        }
+    }
+}
+
 impl ChunkTake for ListChunked {
     fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Self {
         if self.is_empty() {
@@ -819,6 +994,24 @@ impl<'a> IntoTakeRandom<'a> for &'a Utf8Chunked {
     }
 }
 
+impl<'a> IntoTakeRandom<'a> for &'a BinaryChunked {
+    type Item = &'a [u8];
+    type TakeRandom = Box<dyn TakeRandom<Item = Self::Item> + 'a>;
+
+    fn take_rand(&self) -> Self::TakeRandom {
+        many_or_single!(self, BinaryTakeRandomSingleChunk, BinaryTakeRandom)
+    }
+}
+
+impl<'a> IntoTakeRandom<'a> for &'a DecimalChunked {
+    type Item = i128;
+    type TakeRandom = Box<dyn TakeRandom<Item = Self::Item> + 'a>;
+
+    fn take_rand(&self) -> Self::TakeRandom {
+        many_or_single!(self, DecimalTakeRandomSingleChunk, DecimalTakeRandom)
+    }
+}
+
 impl<'a> IntoTakeRandom<'a> for &'a BooleanChunked {
     type Item = bool;
     type TakeRandom = Box<dyn TakeRandom<Item = Self::Item> + 'a>;
@@ -976,6 +1169,72 @@ impl<'a> TakeRandom for Utf8TakeRandomSingleChunk<'a> {
     }
 }
 
+pub struct BinaryTakeRandom<'a> {
+    ca: &'a BinaryChunked,
+    chunks: Vec<&'a LargeBinaryArray>,
+}
+
+impl<'a> TakeRandom for BinaryTakeRandom<'a> {
+    type Item = &'a [u8];
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        take_random_get!(self, index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        take_random_get_unchecked!(self, index)
+    }
+}
+
+pub struct BinaryTakeRandomSingleChunk<'a> {
+    arr: &'a LargeBinaryArray,
+}
+
+impl<'a> TakeRandom for BinaryTakeRandomSingleChunk<'a> {
+    type Item = &'a [u8];
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        take_random_get_single!(self, index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        self.arr.value(index)
+    }
+}
+
+pub struct DecimalTakeRandom<'a> {
+    ca: &'a DecimalChunked,
+    chunks: Vec<&'a DecimalArray>,
+}
+
+impl<'a> TakeRandom for DecimalTakeRandom<'a> {
+    type Item = i128;
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        take_random_get!(self, index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        take_random_get_unchecked!(self, index)
+    }
+}
+
+pub struct DecimalTakeRandomSingleChunk<'a> {
+    arr: &'a DecimalArray,
+}
+
+impl<'a> TakeRandom for DecimalTakeRandomSingleChunk<'a> {
+    type Item = i128;
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        take_random_get_single!(self, index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        self.arr.value(index)
+    }
+}
+
 pub struct BoolTakeRandom<'a> {
     ca: &'a BooleanChunked,
     chunks: Vec<&'a BooleanArray>,
@@ -1087,6 +1346,24 @@ impl ChunkTakeEvery<Utf8Type> for Utf8Chunked {
     }
 }
 
+impl ChunkTakeEvery<BinaryType> for BinaryChunked {
+    fn take_every(&self, n: usize) -> BinaryChunked {
+        let mut ca: BinaryChunked = if self.null_count() == 0 {
+            self.into_no_null_iter().step_by(n).collect()
+        } else {
+            self.into_iter().step_by(n).collect()
+        };
+        ca.rename(self.name());
+        ca
+    }
+}
+
+impl ChunkTakeEvery<DecimalType> for DecimalChunked {
+    fn take_every(&self, n: usize) -> DecimalChunked {
+        self.new_with_same_type(self.into_iter().step_by(n))
+    }
+}
+
 impl ChunkTakeEvery<ListType> for ListChunked {
     fn take_every(&self, n: usize) -> ListChunked {
         if self.null_count() == 0 {
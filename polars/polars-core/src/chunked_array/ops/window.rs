@@ -1,20 +1,9 @@
+use crate::chunked_array::kernels::skiplist::IndexableSkipList;
 use crate::prelude::*;
-use num::{Bounded, NumCast, Zero};
-use std::ops::{Add, Div, Mul};
-
-/// a fold function to compute the sum. Returns a Null if there is a single null in the window
-fn sum_fold<T>(acc: Option<T>, opt_v: Option<T>) -> Option<T>
-where
-    T: Add<Output = T> + Copy,
-{
-    match acc {
-        None => None,
-        Some(acc) => match opt_v {
-            None => None,
-            Some(v) => Some(acc + v),
-        },
-    }
-}
+use num::{Bounded, NumCast, ToPrimitive, Zero};
+use std::collections::VecDeque;
+use std::iter::FromIterator;
+use std::ops::{Add, Div, Mul, Sub};
 
 /// a fold function to compute the sum. The null values are ignored.
 fn sum_fold_ignore_null<T>(acc: Option<T>, opt_v: Option<T>) -> Option<T>
@@ -30,20 +19,6 @@ where
     }
 }
 
-/// a fold function to compute the minimum. Returns a Null if there is a single null in the window
-fn min_fold<T>(acc: Option<T>, opt_v: Option<T>) -> Option<T>
-where
-    T: PartialOrd,
-{
-    match acc {
-        None => None,
-        Some(acc) => match opt_v {
-            None => None,
-            Some(v) => Some(if acc < v { acc } else { v }),
-        },
-    }
-}
-
 /// a fold function to compute the min. The null values are ignored.
 fn min_fold_ignore_null<T>(acc: Option<T>, opt_v: Option<T>) -> Option<T>
 where
@@ -57,19 +32,6 @@ where
         },
     }
 }
-/// a fold function to compute the maximum. Returns a Null if there is a single null in the window
-fn max_fold<T>(acc: Option<T>, opt_v: Option<T>) -> Option<T>
-where
-    T: PartialOrd,
-{
-    match acc {
-        None => None,
-        Some(acc) => match opt_v {
-            None => None,
-            Some(v) => Some(if acc > v { acc } else { v }),
-        },
-    }
-}
 
 /// a fold function to compute the max. The null values are ignored.
 fn max_fold_ignore_null<T>(acc: Option<T>, opt_v: Option<T>) -> Option<T>
@@ -192,88 +154,576 @@ pub enum InitFold {
     Min,
 }
 
+/// The number of values a window ends after its value. A centered window ends halfway.
+fn window_offset(options: &RollingOptions) -> usize {
+    if options.center {
+        (options.window_size - 1) / 2
+    } else {
+        0
+    }
+}
+
+/// Get the bounds `[start, end)` of the window of every value.
+fn window_bounds(len: usize, options: &RollingOptions) -> impl Iterator<Item = (usize, usize)> {
+    let window_size = options.window_size;
+    let offset = window_offset(options);
+    (0..len).map(move |i| {
+        let end = i + offset + 1;
+        (end.saturating_sub(window_size), std::cmp::min(end, len))
+    })
+}
+
+fn check_window_size(options: &RollingOptions) -> Result<()> {
+    if options.window_size == 0 {
+        Err(PolarsError::ValueError(
+            "the window size should be larger than 0".into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// The state of an aggregation over a window that slides over the values. Values enter at the
+/// end of the window and leave at the start, so that the aggregation does not have to be
+/// recomputed for every window.
+trait RollingAgg<T> {
+    type Output;
+
+    fn push(&mut self, idx: usize, v: T);
+
+    /// Remove the value at `idx`. This is always the oldest value in the window.
+    fn pop(&mut self, idx: usize, v: T);
+
+    fn get(&self) -> Option<Self::Output>;
+}
+
+/// Slide a window over the values of `ca` and get the aggregation of every window.
+fn rolling_agg<T, A, O>(
+    ca: &ChunkedArray<T>,
+    options: &RollingOptions,
+    mut agg: A,
+) -> Result<ChunkedArray<O>>
+where
+    T: PolarsNumericType,
+    A: RollingAgg<T::Native>,
+    ChunkedArray<O>: FromIterator<Option<A::Output>>,
+{
+    check_window_size(options)?;
+    let min_periods = std::cmp::max(options.min_periods, 1);
+    // both ends of the window only move forward
+    let mut lead = ca.into_iter().enumerate();
+    let mut trail = ca.into_iter().enumerate();
+    let mut start = 0;
+    let mut end = 0;
+    let mut count = 0;
+
+    let mut out: ChunkedArray<O> = window_bounds(ca.len(), options)
+        .map(|(window_start, window_end)| {
+            while end < window_end {
+                if let (idx, Some(v)) = lead.next().unwrap() {
+                    agg.push(idx, v);
+                    count += 1;
+                }
+                end += 1;
+            }
+            while start < window_start {
+                if let (idx, Some(v)) = trail.next().unwrap() {
+                    agg.pop(idx, v);
+                    count -= 1;
+                }
+                start += 1;
+            }
+            if count >= min_periods {
+                agg.get()
+            } else {
+                None
+            }
+        })
+        .collect();
+    out.rename(ca.name());
+    Ok(out)
+}
+
+/// Multiply the values in the window of every value with the weights and aggregate with a
+/// `fold_fn`. The weights are aligned with the end of the window, also for windows that are cut
+/// off at the start or the end of the array.
+fn rolling_weighted<T, F, G>(
+    ca: &ChunkedArray<T>,
+    options: &RollingOptions,
+    weight: &[f64],
+    fold_fn: F,
+    finish_fn: G,
+) -> Result<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast + Mul<Output = T::Native> + Copy,
+    F: Fn(Option<T::Native>, Option<T::Native>) -> Option<T::Native>,
+    G: Fn(T::Native, usize) -> T::Native,
+{
+    check_window_size(options)?;
+    let window_size = options.window_size;
+    if weight.len() != window_size {
+        return Err(PolarsError::ValueError(
+            "the weight should have the same length as the window".into(),
+        ));
+    }
+    let weight: Vec<T::Native> = weight_to_native(weight);
+    let min_periods = std::cmp::max(options.min_periods, 1);
+    let values: Vec<Option<T::Native>> = ca.into_iter().collect();
+    let offset = window_offset(options);
+
+    let mut out: ChunkedArray<T> = window_bounds(ca.len(), options)
+        .enumerate()
+        .map(|(idx, (start, end))| {
+            // the end of the window before it is cut off at the end of the array
+            let nominal_end = idx + offset + 1;
+            let mut count = 0;
+            let mut acc = None;
+            for (i, opt_v) in values.iter().enumerate().take(end).skip(start) {
+                if let Some(v) = opt_v {
+                    acc = fold_fn(acc, Some(*v * weight[i + window_size - nominal_end]));
+                    count += 1;
+                }
+            }
+            if count >= min_periods {
+                acc.map(|acc| finish_fn(acc, count))
+            } else {
+                None
+            }
+        })
+        .collect();
+    out.rename(ca.name());
+    Ok(out)
+}
+
+/// Only NaN is not equal to itself.
+#[allow(clippy::eq_op)]
+fn is_nan<T: PartialEq>(v: T) -> bool {
+    v != v
+}
+
+/// Infinite values lead to NaN when subtracted from themselves.
+fn is_infinite<T: Sub<Output = T> + PartialEq + Copy>(v: T) -> bool {
+    !is_nan(v) && is_nan(v - v)
+}
+
+/// A sum of which values can be removed again. Non finite float values are counted separately,
+/// as they cannot be subtracted from the sum.
+struct SumAgg<T> {
+    sum: T,
+    count: usize,
+    mean: bool,
+    n_nan: usize,
+    nan: Option<T>,
+    n_pos_inf: usize,
+    pos_inf: Option<T>,
+    n_neg_inf: usize,
+    neg_inf: Option<T>,
+}
+
+impl<T: Zero> SumAgg<T> {
+    fn new(mean: bool) -> Self {
+        SumAgg {
+            sum: T::zero(),
+            count: 0,
+            mean,
+            n_nan: 0,
+            nan: None,
+            n_pos_inf: 0,
+            pos_inf: None,
+            n_neg_inf: 0,
+            neg_inf: None,
+        }
+    }
+}
+
+impl<T> RollingAgg<T> for SumAgg<T>
+where
+    T: Zero + NumCast + Add<Output = T> + Sub<Output = T> + Div<Output = T> + PartialOrd + Copy,
+{
+    type Output = T;
+
+    fn push(&mut self, _idx: usize, v: T) {
+        self.count += 1;
+        if is_nan(v) {
+            self.n_nan += 1;
+            self.nan = Some(v);
+        } else if is_infinite(v) {
+            if v > T::zero() {
+                self.n_pos_inf += 1;
+                self.pos_inf = Some(v);
+            } else {
+                self.n_neg_inf += 1;
+                self.neg_inf = Some(v);
+            }
+        } else {
+            self.sum = self.sum + v;
+        }
+    }
+
+    fn pop(&mut self, _idx: usize, v: T) {
+        self.count -= 1;
+        if is_nan(v) {
+            self.n_nan -= 1;
+        } else if is_infinite(v) {
+            if v > T::zero() {
+                self.n_pos_inf -= 1;
+            } else {
+                self.n_neg_inf -= 1;
+            }
+        } else {
+            self.sum = self.sum - v;
+        }
+    }
+
+    fn get(&self) -> Option<T> {
+        let sum = match (self.n_nan, self.n_pos_inf, self.n_neg_inf) {
+            (0, 0, 0) => self.sum,
+            (0, 0, _) => self.neg_inf.unwrap(),
+            (0, _, 0) => self.pos_inf.unwrap(),
+            (0, _, _) => self.pos_inf.unwrap() + self.neg_inf.unwrap(),
+            _ => self.nan.unwrap(),
+        };
+        if self.mean {
+            Some(sum / NumCast::from(self.count).unwrap())
+        } else {
+            Some(sum)
+        }
+    }
+}
+
+/// A monotonic deque that holds the values that can still become the min (or max) of the
+/// window, with the current min (or max) at the front.
+struct MinMaxAgg<T> {
+    deque: VecDeque<(usize, T)>,
+    min: bool,
+}
+
+impl<T> MinMaxAgg<T> {
+    fn new(window_size: usize, min: bool) -> Self {
+        MinMaxAgg {
+            deque: VecDeque::with_capacity(window_size),
+            min,
+        }
+    }
+}
+
+impl<T: PartialOrd + Copy> RollingAgg<T> for MinMaxAgg<T> {
+    type Output = T;
+
+    fn push(&mut self, idx: usize, v: T) {
+        while let Some((_, last)) = self.deque.back() {
+            if (self.min && *last >= v) || (!self.min && *last <= v) {
+                self.deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.deque.push_back((idx, v))
+    }
+
+    fn pop(&mut self, idx: usize, _v: T) {
+        if matches!(self.deque.front(), Some((front, _)) if *front == idx) {
+            self.deque.pop_front();
+        }
+    }
+
+    fn get(&self) -> Option<T> {
+        self.deque.front().map(|(_, v)| *v)
+    }
+}
+
+/// Welford's online algorithm for the variance, extended with the removal of values.
+#[derive(Default)]
+struct VarAgg {
+    n: usize,
+    mean: f64,
+    m2: f64,
+    n_non_finite: usize,
+    std: bool,
+}
+
+impl<T: ToPrimitive> RollingAgg<T> for VarAgg {
+    type Output = f64;
+
+    fn push(&mut self, _idx: usize, v: T) {
+        let v = v.to_f64().unwrap();
+        if !v.is_finite() {
+            self.n_non_finite += 1;
+            return;
+        }
+        self.n += 1;
+        let delta = v - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (v - self.mean);
+    }
+
+    fn pop(&mut self, _idx: usize, v: T) {
+        let v = v.to_f64().unwrap();
+        if !v.is_finite() {
+            self.n_non_finite -= 1;
+            return;
+        }
+        self.n -= 1;
+        if self.n == 0 {
+            self.mean = 0.0;
+            self.m2 = 0.0;
+            return;
+        }
+        let delta = v - self.mean;
+        self.mean -= delta / self.n as f64;
+        self.m2 -= delta * (v - self.mean);
+    }
+
+    fn get(&self) -> Option<f64> {
+        if self.n_non_finite > 0 {
+            return Some(f64::NAN);
+        }
+        if self.n < 2 {
+            return None;
+        }
+        // the removal of values may lead to small negative rounding errors
+        let var = f64::max(self.m2, 0.0) / (self.n - 1) as f64;
+        if self.std {
+            Some(var.sqrt())
+        } else {
+            Some(var)
+        }
+    }
+}
+
+/// The skew computed from the sums of the values and their powers.
+#[derive(Default)]
+struct SkewAgg {
+    n: usize,
+    sum: f64,
+    sum2: f64,
+    sum3: f64,
+    n_non_finite: usize,
+}
+
+impl SkewAgg {
+    fn update(&mut self, v: f64, sign: f64) {
+        self.sum += sign * v;
+        self.sum2 += sign * v * v;
+        self.sum3 += sign * v * v * v;
+    }
+}
+
+impl<T: ToPrimitive> RollingAgg<T> for SkewAgg {
+    type Output = f64;
+
+    fn push(&mut self, _idx: usize, v: T) {
+        let v = v.to_f64().unwrap();
+        if !v.is_finite() {
+            self.n_non_finite += 1;
+        } else {
+            self.n += 1;
+            self.update(v, 1.0)
+        }
+    }
+
+    fn pop(&mut self, _idx: usize, v: T) {
+        let v = v.to_f64().unwrap();
+        if !v.is_finite() {
+            self.n_non_finite -= 1;
+        } else {
+            self.n -= 1;
+            if self.n == 0 {
+                // reset to prevent the accumulation of rounding errors
+                self.sum = 0.0;
+                self.sum2 = 0.0;
+                self.sum3 = 0.0;
+            } else {
+                self.update(v, -1.0)
+            }
+        }
+    }
+
+    fn get(&self) -> Option<f64> {
+        if self.n_non_finite > 0 {
+            return Some(f64::NAN);
+        }
+        if self.n < 3 {
+            return None;
+        }
+        let n = self.n as f64;
+        let a = self.sum / n;
+        let b = self.sum2 / n - a * a;
+        let c = self.sum3 / n - a * a * a - 3.0 * a * b;
+        if b <= 1e-14 {
+            return Some(f64::NAN);
+        }
+        let r = b.sqrt();
+        // the adjusted Fisher-Pearson coefficient, like pandas
+        Some((n * (n - 1.0)).sqrt() * c / ((n - 2.0) * r * r * r))
+    }
+}
+
+/// The values of the window kept sorted in an indexable skip list.
+struct QuantileAgg {
+    values: IndexableSkipList,
+    quantile: f64,
+}
+
+impl<T: ToPrimitive> RollingAgg<T> for QuantileAgg {
+    type Output = f64;
+
+    fn push(&mut self, _idx: usize, v: T) {
+        self.values.insert(v.to_f64().unwrap())
+    }
+
+    fn pop(&mut self, _idx: usize, v: T) {
+        self.values.remove(v.to_f64().unwrap());
+    }
+
+    fn get(&self) -> Option<f64> {
+        let len = self.values.len();
+        if len == 0 {
+            return None;
+        }
+        let pos = self.quantile * (len - 1) as f64;
+        let lower = pos.floor() as usize;
+        let lower_value = self.values.get(lower)?;
+        if pos == lower as f64 {
+            Some(lower_value)
+        } else {
+            let upper_value = self.values.get(lower + 1)?;
+            Some(lower_value + (upper_value - lower_value) * (pos - lower as f64))
+        }
+    }
+}
+
+struct CountAgg(u32);
+
+impl<T> RollingAgg<T> for CountAgg {
+    type Output = u32;
+
+    fn push(&mut self, _idx: usize, _v: T) {
+        self.0 += 1
+    }
+
+    fn pop(&mut self, _idx: usize, _v: T) {
+        self.0 -= 1
+    }
+
+    fn get(&self) -> Option<u32> {
+        Some(self.0)
+    }
+}
+
+/// Floats keep their precision, other types are aggregated to f64.
+fn finish_float<T: PolarsNumericType>(ca: Float64Chunked) -> Result<Series> {
+    match T::get_dtype() {
+        DataType::Float32 => ca.cast::<Float32Type>().map(|ca| ca.into_series()),
+        _ => Ok(ca.into_series()),
+    }
+}
+
 impl<T> ChunkWindow for ChunkedArray<T>
 where
     T: PolarsNumericType,
     T::Native: Zero
         + Bounded
         + NumCast
+        + Add<Output = T::Native>
+        + Sub<Output = T::Native>
         + Div<Output = T::Native>
         + Mul<Output = T::Native>
         + PartialOrd
         + Copy,
 {
-    fn rolling_sum(
-        &self,
-        window_size: usize,
-        weight: Option<&[f64]>,
-        ignore_null: bool,
-    ) -> Result<Self> {
-        let fold_fn = if ignore_null {
-            sum_fold_ignore_null::<T::Native>
-        } else {
-            sum_fold::<T::Native>
-        };
+    fn rolling_sum(&self, options: &RollingOptions) -> Result<Self> {
+        match &options.weight {
+            Some(weight) => rolling_weighted(
+                self,
+                options,
+                weight,
+                sum_fold_ignore_null::<T::Native>,
+                |sum, _| sum,
+            ),
+            None => rolling_agg(self, options, SumAgg::new(false)),
+        }
+    }
 
-        Ok(finish_rolling_method(
-            self,
-            fold_fn,
-            window_size,
-            weight,
-            InitFold::Zero,
-        ))
+    fn rolling_mean(&self, options: &RollingOptions) -> Result<Self> {
+        match &options.weight {
+            Some(weight) => rolling_weighted(
+                self,
+                options,
+                weight,
+                sum_fold_ignore_null::<T::Native>,
+                |sum, count| sum / NumCast::from(count).unwrap(),
+            ),
+            None => rolling_agg(self, options, SumAgg::new(true)),
+        }
     }
 
-    fn rolling_mean(
-        &self,
-        window_size: usize,
-        weight: Option<&[f64]>,
-        ignore_null: bool,
-    ) -> Result<Self> {
-        let ca = self.rolling_sum(window_size, weight, ignore_null)?;
-        Ok(&ca / window_size)
+    fn rolling_min(&self, options: &RollingOptions) -> Result<Self> {
+        match &options.weight {
+            Some(weight) => rolling_weighted(
+                self,
+                options,
+                weight,
+                min_fold_ignore_null::<T::Native>,
+                |min, _| min,
+            ),
+            None => rolling_agg(self, options, MinMaxAgg::new(options.window_size, true)),
+        }
     }
 
-    fn rolling_min(
-        &self,
-        window_size: usize,
-        weight: Option<&[f64]>,
-        ignore_null: bool,
-    ) -> Result<Self> {
-        let fold_fn = if ignore_null {
-            min_fold_ignore_null::<T::Native>
-        } else {
-            min_fold::<T::Native>
+    fn rolling_max(&self, options: &RollingOptions) -> Result<Self> {
+        match &options.weight {
+            Some(weight) => rolling_weighted(
+                self,
+                options,
+                weight,
+                max_fold_ignore_null::<T::Native>,
+                |max, _| max,
+            ),
+            None => rolling_agg(self, options, MinMaxAgg::new(options.window_size, false)),
+        }
+    }
+
+    fn rolling_var(&self, options: &RollingOptions) -> Result<Series> {
+        let ca: Float64Chunked = rolling_agg(self, options, VarAgg::default())?;
+        finish_float::<T>(ca)
+    }
+
+    fn rolling_std(&self, options: &RollingOptions) -> Result<Series> {
+        let agg = VarAgg {
+            std: true,
+            ..Default::default()
         };
+        let ca: Float64Chunked = rolling_agg(self, options, agg)?;
+        finish_float::<T>(ca)
+    }
 
-        Ok(finish_rolling_method(
-            self,
-            fold_fn,
-            window_size,
-            weight,
-            InitFold::Max,
-        ))
+    fn rolling_median(&self, options: &RollingOptions) -> Result<Series> {
+        self.rolling_quantile(0.5, options)
     }
 
-    fn rolling_max(
-        &self,
-        window_size: usize,
-        weight: Option<&[f64]>,
-        ignore_null: bool,
-    ) -> Result<Self> {
-        let fold_fn = if ignore_null {
-            max_fold_ignore_null::<T::Native>
-        } else {
-            max_fold::<T::Native>
+    fn rolling_quantile(&self, quantile: f64, options: &RollingOptions) -> Result<Series> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsError::ValueError(
+                "quantile should be between 0.0 and 1.0".into(),
+            ));
+        }
+        let agg = QuantileAgg {
+            values: IndexableSkipList::new(options.window_size),
+            quantile,
         };
+        let ca: Float64Chunked = rolling_agg(self, options, agg)?;
+        finish_float::<T>(ca)
+    }
 
-        Ok(finish_rolling_method(
-            self,
-            fold_fn,
-            window_size,
-            weight,
-            InitFold::Min,
-        ))
+    fn rolling_skew(&self, options: &RollingOptions) -> Result<Series> {
+        let ca: Float64Chunked = rolling_agg(self, options, SkewAgg::default())?;
+        finish_float::<T>(ca)
+    }
+
+    fn rolling_count(&self, options: &RollingOptions) -> Result<UInt32Chunked> {
+        rolling_agg(self, options, CountAgg(0))
     }
 }
 
@@ -322,7 +772,8 @@ mod test {
     #[test]
     fn test_rolling() {
         let ca = Int32Chunked::new_from_slice("foo", &[1, 2, 3, 2, 1]);
-        let a = ca.rolling_sum(2, None, true).unwrap();
+        let options = RollingOptions::new(2).with_min_periods(1);
+        let a = ca.rolling_sum(&options).unwrap();
         assert_eq!(
             Vec::from(&a),
             [1, 3, 5, 5, 3]
@@ -331,7 +782,7 @@ mod test {
                 .map(Some)
                 .collect::<Vec<_>>()
         );
-        let a = ca.rolling_min(2, None, true).unwrap();
+        let a = ca.rolling_min(&options).unwrap();
         assert_eq!(
             Vec::from(&a),
             [1, 1, 2, 2, 1]
@@ -341,7 +792,7 @@ mod test {
                 .collect::<Vec<_>>()
         );
         let a = ca
-            .rolling_max(2, Some(&[1., 1., 1., 1., 1.]), true)
+            .rolling_max(&options.clone().with_weight(Some(vec![1., 1.])))
             .unwrap();
         assert_eq!(
             Vec::from(&a),
@@ -352,4 +803,95 @@ mod test {
                 .collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_rolling_min_periods_center() {
+        let ca = Float64Chunked::new_from_opt_slice(
+            "foo",
+            &[Some(1.0), Some(3.0), None, Some(2.0), Some(6.0)],
+        );
+        let options = RollingOptions::new(3);
+        let a = ca.rolling_sum(&options).unwrap();
+        assert_eq!(Vec::from(&a), &[None, None, None, None, None]);
+
+        let options = options.with_min_periods(2);
+        let a = ca.rolling_mean(&options).unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[None, Some(2.0), Some(2.0), Some(2.5), Some(4.0)]
+        );
+        let a = ca.rolling_max(&options).unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[None, Some(3.0), Some(3.0), Some(3.0), Some(6.0)]
+        );
+        let a = ca
+            .rolling_count(&options.clone().with_min_periods(0))
+            .unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[Some(1), Some(2), Some(2), Some(2), Some(2)]
+        );
+
+        let options = options.with_center(true);
+        let a = ca.rolling_min(&options).unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[Some(1.0), Some(1.0), Some(2.0), Some(2.0), Some(2.0)]
+        );
+        let a = ca
+            .rolling_sum(&options.with_weight(Some(vec![1.0, 2.0, 3.0])))
+            .unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[Some(11.0), Some(7.0), Some(9.0), Some(22.0), Some(14.0)]
+        );
+    }
+
+    #[test]
+    fn test_rolling_stats() {
+        let ca = Int32Chunked::new_from_slice("foo", &[1, 4, 2, 8, 5, 7]);
+        let options = RollingOptions::new(3);
+
+        let s = ca.rolling_var(&options).unwrap();
+        assert_eq!(s.dtype(), &DataType::Float64);
+        let expected = [
+            None,
+            None,
+            Some(7.0 / 3.0),
+            Some(28.0 / 3.0),
+            Some(9.0),
+            Some(7.0 / 3.0),
+        ];
+        for (a, b) in s.f64().unwrap().into_iter().zip(expected.iter()) {
+            match (a, b) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 1e-10),
+                (a, b) => assert_eq!(a, *b),
+            }
+        }
+        let s = ca.rolling_std(&options).unwrap();
+        assert!((s.f64().unwrap().get(4).unwrap() - 3.0).abs() < 1e-10);
+
+        let s = ca.rolling_median(&options).unwrap();
+        assert_eq!(
+            Vec::from(s.f64().unwrap()),
+            &[None, None, Some(2.0), Some(4.0), Some(5.0), Some(7.0)]
+        );
+        let s = ca.rolling_quantile(0.25, &RollingOptions::new(4)).unwrap();
+        assert_eq!(
+            Vec::from(s.f64().unwrap()),
+            &[None, None, None, Some(1.75), Some(3.5), Some(4.25)]
+        );
+
+        let s = ca.rolling_skew(&options).unwrap();
+        let skew = s.f64().unwrap();
+        assert_eq!(skew.get(1), None);
+        // [2, 8, 5] is symmetric and [8, 5, 7] has a long left tail
+        assert_eq!(skew.get(4), Some(0.0));
+        assert!(skew.get(5).unwrap() < 0.0);
+        let ca = Float32Chunked::new_from_slice("foo", &[1.0, 2.0, 3.0, 4.0]);
+        let s = ca.rolling_skew(&options).unwrap();
+        assert_eq!(s.dtype(), &DataType::Float32);
+        assert!(s.f32().unwrap().get(3).unwrap().abs() < 1e-6);
+    }
 }
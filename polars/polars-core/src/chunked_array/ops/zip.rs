@@ -200,10 +200,13 @@ impl ChunkZip<CategoricalType> for CategoricalChunked {
         mask: &BooleanChunked,
         other: &ChunkedArray<CategoricalType>,
     ) -> Result<ChunkedArray<CategoricalType>> {
-        self.cast::<UInt32Type>()
+        let (left, right) = self.merge_categories(other)?;
+        let ca: CategoricalChunked = left
+            .cast::<UInt32Type>()
             .unwrap()
-            .zip_with(mask, &other.cast().unwrap())?
-            .cast()
+            .zip_with(mask, &right.cast().unwrap())?
+            .cast()?;
+        Ok(ca.set_state(&left))
     }
 
     fn zip_with_series(
@@ -1,6 +1,9 @@
 use crate::prelude::*;
 use crate::utils::NoNull;
+use polars_arrow::prelude::LargeStringBuilder;
 use regex::Regex;
+use std::borrow::Cow;
+use std::iter::FromIterator;
 
 macro_rules! apply_closure_to_primitive {
     ($self:expr, $f:expr) => {{
@@ -73,4 +76,426 @@ impl Utf8Chunked {
     pub fn concat(&self, other: &Utf8Chunked) -> Self {
         self + other
     }
+
+    /// Modify the strings to title case, i.e. the first character of every word is uppercase
+    /// and the other characters are lowercase.
+    pub fn to_titlecase(&self) -> Utf8Chunked {
+        self.apply(to_titlecase)
+    }
+
+    /// Get the number of unicode characters of the string values.
+    pub fn n_chars(&self) -> UInt32Chunked {
+        let f = |s: &str| s.chars().count() as u32;
+        apply_closure_to_primitive!(self, f)
+    }
+
+    /// Check if the strings start with a (non regex) prefix.
+    pub fn starts_with(&self, prefix: &str) -> BooleanChunked {
+        let f = |s: &str| s.starts_with(prefix);
+        apply_closure!(self, f)
+    }
+
+    /// Check if the strings end with a (non regex) suffix.
+    pub fn ends_with(&self, suffix: &str) -> BooleanChunked {
+        let f = |s: &str| s.ends_with(suffix);
+        apply_closure!(self, f)
+    }
+
+    /// Split the strings by a (non regex) separator into a list of substrings.
+    pub fn split(&self, by: &str) -> ListChunked {
+        let mut builder = self.list_builder();
+        for opt_s in self {
+            match opt_s {
+                Some(s) => builder.append_values_iter(s.split(by).map(Some)),
+                None => builder.append_null(),
+            }
+        }
+        builder.finish()
+    }
+
+    /// Split the strings by a (non regex) separator into lists of exactly `n + 1` substrings.
+    /// The remainder of the string is kept in the last substring and missing substrings are
+    /// null.
+    pub fn split_exact(&self, by: &str, n: usize) -> ListChunked {
+        let mut builder = self.list_builder();
+        for opt_s in self {
+            match opt_s {
+                Some(s) => {
+                    let mut parts = s.splitn(n + 1, by);
+                    builder.append_values_iter((0..n + 1).map(|_| parts.next()))
+                }
+                None => builder.append_null(),
+            }
+        }
+        builder.finish()
+    }
+
+    /// Remove leading and trailing characters. If `matches` is `None`, whitespace is removed,
+    /// otherwise all the characters in `matches` are removed.
+    pub fn strip(&self, matches: Option<&str>) -> Utf8Chunked {
+        match matches {
+            Some(pat) => {
+                let f = |s| str::trim_matches(s, |c: char| pat.contains(c));
+                apply_closure!(self, f)
+            }
+            None => apply_closure!(self, str::trim),
+        }
+    }
+
+    /// Remove leading characters. See [strip](Utf8Chunked::strip).
+    pub fn lstrip(&self, matches: Option<&str>) -> Utf8Chunked {
+        match matches {
+            Some(pat) => {
+                let f = |s| str::trim_start_matches(s, |c: char| pat.contains(c));
+                apply_closure!(self, f)
+            }
+            None => apply_closure!(self, str::trim_start),
+        }
+    }
+
+    /// Remove trailing characters. See [strip](Utf8Chunked::strip).
+    pub fn rstrip(&self, matches: Option<&str>) -> Utf8Chunked {
+        match matches {
+            Some(pat) => {
+                let f = |s| str::trim_end_matches(s, |c: char| pat.contains(c));
+                apply_closure!(self, f)
+            }
+            None => apply_closure!(self, str::trim_end),
+        }
+    }
+
+    /// Take a substring of `length` unicode characters from `start`. A negative `start` counts
+    /// from the end of the string and a `length` of `None` takes the rest of the string.
+    pub fn str_slice(&self, start: i64, length: Option<u64>) -> Utf8Chunked {
+        let f = |s| slice_chars(s, start, length);
+        apply_closure!(self, f)
+    }
+
+    /// Pad the start of the strings with `fill_char` until they are `width` characters long.
+    pub fn pad_start(&self, width: usize, fill_char: char) -> Utf8Chunked {
+        let f = |s| pad(s, width, fill_char, true);
+        apply_closure!(self, f)
+    }
+
+    /// Pad the end of the strings with `fill_char` until they are `width` characters long.
+    pub fn pad_end(&self, width: usize, fill_char: char) -> Utf8Chunked {
+        let f = |s| pad(s, width, fill_char, false);
+        apply_closure!(self, f)
+    }
+
+    /// Extract the capture group `group_index` of the first match of a regex pattern. The
+    /// value is null if the pattern doesn't match. Group 0 is the whole match.
+    pub fn extract(&self, pat: &str, group_index: usize) -> Result<Utf8Chunked> {
+        let reg = Regex::new(pat)?;
+        Ok(self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.and_then(|s| {
+                    reg.captures(s)
+                        .and_then(|caps| caps.get(group_index))
+                        .map(|m| m.as_str())
+                })
+            })
+            .collect())
+    }
+
+    /// Extract all the matches of a regex pattern into a list of substrings.
+    pub fn extract_all(&self, pat: &str) -> Result<ListChunked> {
+        let reg = Regex::new(pat)?;
+        let mut builder = self.list_builder();
+        for opt_s in self {
+            match opt_s {
+                Some(s) => builder.append_values_iter(reg.find_iter(s).map(|m| Some(m.as_str()))),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Count the (non overlapping) matches of a regex pattern.
+    pub fn count_matches(&self, pat: &str) -> Result<UInt32Chunked> {
+        let reg = Regex::new(pat)?;
+        let f = |s| reg.find_iter(s).count() as u32;
+        Ok(apply_closure_to_primitive!(self, f))
+    }
+
+    /// Parse the strings to numbers or booleans. Numbers may contain `,` as thousands
+    /// separator, see [parse_with_format](Utf8Chunked::parse_with_format).
+    ///
+    /// If `strict`, values that cannot be parsed return an error, otherwise they are null.
+    pub fn parse<T>(&self, strict: bool) -> Result<ChunkedArray<T>>
+    where
+        T: Utf8Parse,
+        ChunkedArray<T>: FromIterator<Option<T::Output>>,
+    {
+        self.parse_with_format(strict, &NumberFormat::default())
+    }
+
+    /// Parse the strings to numbers or booleans that are formatted with `format`, e.g. with a
+    /// decimal comma.
+    pub fn parse_with_format<T>(
+        &self,
+        strict: bool,
+        format: &NumberFormat,
+    ) -> Result<ChunkedArray<T>>
+    where
+        T: Utf8Parse,
+        ChunkedArray<T>: FromIterator<Option<T::Output>>,
+    {
+        let mut ca: ChunkedArray<T> = self
+            .into_iter()
+            .enumerate()
+            .map(|(idx, opt_s)| match opt_s {
+                Some(s) => match T::parse_str(s, format) {
+                    Some(v) => Ok(Some(v)),
+                    None if strict => Err(PolarsError::ValueError(
+                        format!(
+                            "could not parse '{}' at row {} of column '{}' as {:?}",
+                            s,
+                            idx,
+                            self.name(),
+                            T::get_dtype()
+                        )
+                        .into(),
+                    )),
+                    None => Ok(None),
+                },
+                None => Ok(None),
+            })
+            .collect::<Result<_>>()?;
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    fn list_builder(&self) -> ListUtf8ChunkedBuilder {
+        let values_builder = LargeStringBuilder::with_capacity(self.len() * 5, self.len());
+        ListUtf8ChunkedBuilder::new(self.name(), values_builder, self.len())
+    }
+}
+
+/// The characters used to format numbers in strings, see [Utf8Chunked::parse_with_format].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NumberFormat {
+    /// Separates the integer part from the fractional part.
+    pub decimal: char,
+    /// Separates groups of three digits in the integer part, e.g. `1,000,000`.
+    pub thousands: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            decimal: '.',
+            thousands: Some(','),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Numbers formatted like `1.234.567,89`.
+    pub fn decimal_comma() -> Self {
+        NumberFormat {
+            decimal: ',',
+            thousands: Some('.'),
+        }
+    }
+}
+
+/// Types that can be parsed from strings with [Utf8Chunked::parse].
+pub trait Utf8Parse: PolarsDataType {
+    type Output;
+
+    fn parse_str(s: &str, format: &NumberFormat) -> Option<Self::Output>;
+}
+
+macro_rules! impl_parse_number {
+    ($polars_type:ty, $native:ty) => {
+        impl Utf8Parse for $polars_type {
+            type Output = $native;
+
+            fn parse_str(s: &str, format: &NumberFormat) -> Option<$native> {
+                normalize_number(s, format)?.parse().ok()
+            }
+        }
+    };
+}
+
+impl_parse_number!(UInt8Type, u8);
+impl_parse_number!(UInt16Type, u16);
+impl_parse_number!(UInt32Type, u32);
+impl_parse_number!(UInt64Type, u64);
+impl_parse_number!(Int8Type, i8);
+impl_parse_number!(Int16Type, i16);
+impl_parse_number!(Int32Type, i32);
+impl_parse_number!(Int64Type, i64);
+impl_parse_number!(Float32Type, f32);
+impl_parse_number!(Float64Type, f64);
+
+impl Utf8Parse for BooleanType {
+    type Output = bool;
+
+    fn parse_str(s: &str, _format: &NumberFormat) -> Option<bool> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("true") || s == "1" {
+            Some(true)
+        } else if s.eq_ignore_ascii_case("false") || s == "0" {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// Rewrite a formatted number to a string that can be parsed by `str::parse`. Returns `None`
+/// if the thousands separators don't separate groups of three digits.
+fn normalize_number<'a>(s: &'a str, format: &NumberFormat) -> Option<Cow<'a, str>> {
+    let s = s.trim();
+    let has_thousands = format.thousands.map_or(false, |t| s.contains(t));
+    if format.decimal == '.' && !has_thousands {
+        return Some(Cow::Borrowed(s));
+    }
+    let (int_part, frac_part) = match s.find(format.decimal) {
+        Some(i) => (&s[..i], Some(&s[i + format.decimal.len_utf8()..])),
+        None => (s, None),
+    };
+
+    let mut out = String::with_capacity(s.len());
+    match format.thousands {
+        Some(thousands) if has_thousands => {
+            let mut groups = int_part.split(thousands);
+            let first = groups.next()?;
+            let n_digits = first.trim_start_matches(|c| c == '-' || c == '+').len();
+            if n_digits == 0 || n_digits > 3 {
+                return None;
+            }
+            out.push_str(first);
+            for group in groups {
+                if group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                out.push_str(group);
+            }
+        }
+        _ => out.push_str(int_part),
+    }
+    if let Some(frac_part) = frac_part {
+        out.push('.');
+        out.push_str(frac_part);
+    }
+    Some(Cow::Owned(out))
+}
+
+fn to_titlecase(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut start_of_word = true;
+    for c in s.chars() {
+        if start_of_word {
+            out.extend(c.to_uppercase())
+        } else {
+            out.extend(c.to_lowercase())
+        }
+        start_of_word = c.is_whitespace();
+    }
+    out
+}
+
+fn slice_chars(s: &str, start: i64, length: Option<u64>) -> &str {
+    let start = if start < 0 {
+        (s.chars().count() as i64 + start).max(0) as usize
+    } else {
+        start as usize
+    };
+    // byte offset of the `n`th character of `s`
+    let byte_offset = |s: &str, n: usize| s.char_indices().nth(n).map_or(s.len(), |(i, _)| i);
+    let s = &s[byte_offset(s, start)..];
+    match length {
+        Some(length) => &s[..byte_offset(s, length as usize)],
+        None => s,
+    }
+}
+
+fn pad(s: &str, width: usize, fill_char: char, start: bool) -> Cow<'_, str> {
+    let n_chars = s.chars().count();
+    if n_chars >= width {
+        return Cow::Borrowed(s);
+    }
+    let fill = std::iter::repeat(fill_char).take(width - n_chars);
+    let out = if start {
+        fill.chain(s.chars()).collect()
+    } else {
+        s.chars().chain(fill).collect()
+    };
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_string_namespace() {
+        let ca =
+            Utf8Chunked::new_from_opt_slice("s", &[Some("  hello world "), None, Some("éa-b")]);
+        let stripped = ca.strip(None);
+        assert_eq!(
+            Vec::from(&stripped),
+            &[Some("hello world"), None, Some("éa-b")]
+        );
+        assert_eq!(
+            Vec::from(&stripped.to_titlecase()),
+            &[Some("Hello World"), None, Some("Éa-b")]
+        );
+        assert_eq!(Vec::from(&ca.n_chars()), &[Some(14), None, Some(4)]);
+        assert_eq!(
+            Vec::from(&stripped.str_slice(-3, Some(2))),
+            &[Some("rl"), None, Some("a-")]
+        );
+        assert_eq!(
+            Vec::from(&stripped.pad_start(6, '*')),
+            &[Some("hello world"), None, Some("**éa-b")]
+        );
+        assert_eq!(
+            Vec::from(&ca.extract(r"(\w)-(\w)", 2).unwrap()),
+            &[None, None, Some("b")]
+        );
+        assert_eq!(
+            Vec::from(&ca.count_matches("o").unwrap()),
+            &[Some(2), None, Some(0)]
+        );
+
+        let split = stripped.split_exact(" ", 1);
+        let first = split.get(0).unwrap();
+        assert_eq!(
+            Vec::from(first.utf8().unwrap()),
+            &[Some("hello"), Some("world")]
+        );
+        let last = split.get(2).unwrap();
+        assert_eq!(Vec::from(last.utf8().unwrap()), &[Some("éa-b"), None]);
+        assert!(split.get(1).is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "s",
+            &[Some("1,234"), None, Some(" -12 "), Some("1,5")],
+        );
+        let out = ca.parse::<Int64Type>(false).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(1234), None, Some(-12), None]);
+        let err = ca.parse::<Int64Type>(true).unwrap_err();
+        assert!(format!("{}", err).contains("'1,5' at row 3"));
+
+        let ca = Utf8Chunked::new_from_slice("s", &["1.234,5", "-0,25", "7"]);
+        let out = ca
+            .parse_with_format::<Float64Type>(true, &NumberFormat::decimal_comma())
+            .unwrap();
+        assert_eq!(Vec::from(&out), &[Some(1234.5), Some(-0.25), Some(7.0)]);
+
+        let ca = Utf8Chunked::new_from_slice("s", &["true", "False", "0", "maybe"]);
+        let out = ca.parse::<BooleanType>(false).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(false), None]
+        );
+    }
 }
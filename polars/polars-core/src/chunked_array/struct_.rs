@@ -0,0 +1,252 @@
+//! Struct columns group multiple named fields of equal length, e.g. the records of a JSON or
+//! Parquet file. The values are stored as arrow `StructArray`s.
+use crate::fmt::FmtList;
+use crate::prelude::*;
+use arrow::array::{Array, ArrayRef, StructArray};
+use itertools::Itertools;
+use std::convert::TryFrom;
+
+/// Get the values of field `i`, taking the offset of a sliced `StructArray` into account.
+pub(crate) fn struct_column(arr: &StructArray, i: usize) -> ArrayRef {
+    let col = arr.column(i);
+    if col.len() != arr.len() {
+        col.slice(arr.offset(), arr.len())
+    } else {
+        col.clone()
+    }
+}
+
+impl StructChunked {
+    /// Create a struct column from fields of equal length. The names of the fields must be
+    /// unique. Categorical fields are stored as Utf8.
+    pub fn new(name: &str, fields: &[Series]) -> Result<Self> {
+        let len = match fields.first() {
+            Some(s) => s.len(),
+            None => {
+                return Err(PolarsError::NoData(
+                    "a struct should have at least one field".into(),
+                ))
+            }
+        };
+        let mut arrow_fields = Vec::with_capacity(fields.len());
+        for (i, s) in fields.iter().enumerate() {
+            if s.len() != len {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "field {} has length {} while the struct has length {}",
+                        s.name(),
+                        s.len(),
+                        len
+                    )
+                    .into(),
+                ));
+            }
+            if fields[..i].iter().any(|other| other.name() == s.name()) {
+                return Err(PolarsError::InvalidOperation(
+                    format!("duplicate field {} in struct", s.name()).into(),
+                ));
+            }
+            let s = match s.dtype() {
+                DataType::Categorical => s.cast::<Utf8Type>()?,
+                _ => s.clone(),
+            }
+            .rechunk()?;
+            let arr = s.chunks()[0].clone();
+            arrow_fields.push((
+                ArrowField::new(s.name(), arr.data_type().clone(), true),
+                arr,
+            ));
+        }
+        let arr: ArrayRef = Arc::new(StructArray::from(arrow_fields));
+        Ok(StructChunked::new_from_chunks(name, vec![arr]))
+    }
+
+    /// Get the schema of the fields.
+    pub fn struct_fields(&self) -> &[Field] {
+        match self.dtype() {
+            DataType::Struct(fields) => fields,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the fields as Series.
+    pub fn fields(&self) -> Vec<Series> {
+        let chunks = self.downcast_chunks();
+        self.struct_fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let arrays = chunks.iter().map(|arr| struct_column(arr, i)).collect_vec();
+                Series::try_from((field.name().as_str(), arrays)).unwrap()
+            })
+            .collect()
+    }
+
+    /// Get a field by name.
+    pub fn field_by_name(&self, name: &str) -> Result<Series> {
+        let idx = self
+            .struct_fields()
+            .iter()
+            .position(|field| field.name() == name)
+            .ok_or_else(|| PolarsError::NotFound(name.into()))?;
+        Ok(self.fields().swap_remove(idx))
+    }
+
+    /// Apply `f` to every field and create a new struct from the outputs.
+    pub fn apply_fields<F>(&self, f: F) -> Result<Self>
+    where
+        F: Fn(&Series) -> Result<Series>,
+    {
+        let fields = self.fields().iter().map(f).collect::<Result<Vec<_>>>()?;
+        StructChunked::new(self.name(), &fields)
+    }
+
+    fn apply_fields_infallible<F>(&self, f: F) -> Self
+    where
+        F: Fn(&Series) -> Series,
+    {
+        self.apply_fields(|s| Ok(f(s)))
+            .expect("operation should keep the fields of equal length")
+    }
+}
+
+impl ChunkOps for StructChunked {
+    fn rechunk(&self) -> Result<Self> {
+        if self.chunks().len() == 1 {
+            Ok(self.clone())
+        } else {
+            self.apply_fields(|s| s.rechunk())
+        }
+    }
+}
+
+impl ChunkTake for StructChunked {
+    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Self {
+        let indices = indices.collect_vec();
+        self.apply_fields_infallible(|s| s.take_iter(&mut indices.iter().copied(), capacity))
+    }
+
+    unsafe fn take_unchecked(
+        &self,
+        indices: impl Iterator<Item = usize>,
+        capacity: Option<usize>,
+    ) -> Self {
+        let indices = indices.collect_vec();
+        self.apply_fields_infallible(|s| {
+            s.take_iter_unchecked(&mut indices.iter().copied(), capacity)
+        })
+    }
+
+    fn take_opt(
+        &self,
+        indices: impl Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Self {
+        let indices = indices.collect_vec();
+        self.apply_fields_infallible(|s| s.take_opt_iter(&mut indices.iter().copied(), capacity))
+    }
+
+    unsafe fn take_opt_unchecked(
+        &self,
+        indices: impl Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Self {
+        let indices = indices.collect_vec();
+        self.apply_fields_infallible(|s| {
+            s.take_opt_iter_unchecked(&mut indices.iter().copied(), capacity)
+        })
+    }
+
+    fn take_from_single_chunked(&self, idx: &UInt32Chunked) -> Result<Self> {
+        self.apply_fields(|s| unsafe { s.take_from_single_chunked(idx) })
+    }
+}
+
+impl ChunkTakeEvery<StructType> for StructChunked {
+    fn take_every(&self, n: usize) -> StructChunked {
+        self.apply_fields_infallible(|s| s.take_every(n))
+    }
+}
+
+impl ChunkFilter<StructType> for StructChunked {
+    fn filter(&self, filter: &BooleanChunked) -> Result<StructChunked> {
+        self.apply_fields(|s| s.filter(filter))
+    }
+}
+
+impl ChunkReverse<StructType> for StructChunked {
+    fn reverse(&self) -> StructChunked {
+        self.apply_fields_infallible(|s| s.reverse())
+    }
+}
+
+impl ChunkShift<StructType> for StructChunked {
+    fn shift(&self, periods: i64) -> StructChunked {
+        self.apply_fields_infallible(|s| s.shift(periods))
+    }
+}
+
+impl ChunkExpandAtIndex<StructType> for StructChunked {
+    fn expand_at_index(&self, index: usize, length: usize) -> StructChunked {
+        self.apply_fields_infallible(|s| s.expand_at_index(index, length))
+    }
+}
+
+impl FmtList for StructChunked {
+    fn fmt_list(&self) -> String {
+        let values = (0..std::cmp::min(self.len(), 3))
+            .map(|i| format!("{}", self.get_any_value(i)))
+            .join(", ");
+        if self.len() > 3 {
+            format!("[{}, ...]", values)
+        } else {
+            format!("[{}]", values)
+        }
+    }
+}
+
+impl DataFrame {
+    /// Expand the fields of the struct column `name` into separate columns at its position.
+    pub fn unnest(&self, name: &str) -> Result<DataFrame> {
+        let idx = self
+            .find_idx_by_name(name)
+            .ok_or_else(|| PolarsError::NotFound(name.into()))?;
+        let ca = self.columns[idx].struct_()?;
+        let mut columns = Vec::with_capacity(self.width() + ca.struct_fields().len());
+        columns.extend_from_slice(&self.columns[..idx]);
+        columns.extend(ca.fields());
+        columns.extend_from_slice(&self.columns[idx + 1..]);
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_struct_unnest() {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &["x", "y", "z"]);
+        let ca = StructChunked::new("s", &[a.clone(), b.clone()]).unwrap();
+        assert_eq!(
+            ca.dtype(),
+            &DataType::Struct(vec![
+                Field::new("a", DataType::Int32),
+                Field::new("b", DataType::Utf8)
+            ])
+        );
+        assert!(ca.field_by_name("b").unwrap().series_equal(&b));
+        assert!(StructChunked::new("s", &[a.clone(), a.slice(0, 2).unwrap()]).is_err());
+
+        let s = ca.into_series();
+        let taken = s.take_iter(&mut vec![2, 0].into_iter(), None);
+        let fields = taken.struct_().unwrap().fields();
+        assert_eq!(Vec::from(fields[0].i32().unwrap()), &[Some(3), Some(1)]);
+
+        let df = DataFrame::new(vec![Series::new("id", &[0, 1, 2]), s]).unwrap();
+        let out = df.unnest("s").unwrap();
+        assert_eq!(out.get_column_names(), &["id", "a", "b"]);
+        assert!(out.column("a").unwrap().series_equal(&a));
+    }
+}
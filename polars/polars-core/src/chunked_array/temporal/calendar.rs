@@ -0,0 +1,231 @@
+//! Calendar operations on dates, e.g. truncation to a period and calendar offsets.
+use super::conversions_utils::date64_as_datetime;
+use super::interval::{days_in_month, is_leap_year};
+use crate::prelude::*;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+/// Parse an interval that can be used to truncate timestamps.
+fn parse_truncate_interval(every: &str) -> Result<Interval> {
+    let every = Interval::parse(every)?;
+    if every.months() < 0 || every.milliseconds() < 0 {
+        return Err(PolarsError::ValueError(
+            "cannot truncate or round by a negative interval".into(),
+        ));
+    }
+    // errors on zero intervals and intervals that mix calendar and fixed units
+    every.truncate_ms(0)?;
+    Ok(every)
+}
+
+fn round_ms(every: &Interval, t: i64) -> i64 {
+    let lower = every.truncate_ms(t).unwrap();
+    let upper = every.add_ms(lower);
+    // halfway values are rounded up
+    if t - lower >= upper - t {
+        upper
+    } else {
+        lower
+    }
+}
+
+/// Move a timestamp to the first or last day of its month, keeping the time of the day.
+fn month_boundary_ms(t: i64, end: bool) -> i64 {
+    let dt = date64_as_datetime(t);
+    let day = if end {
+        days_in_month(dt.year(), dt.month())
+    } else {
+        1
+    };
+    NaiveDate::from_ymd(dt.year(), dt.month(), day)
+        .and_time(dt.time())
+        .timestamp_millis()
+}
+
+fn date32_to_ms(v: i32) -> i64 {
+    v as i64 * MILLISECONDS_IN_DAY
+}
+
+fn ms_to_date32(t: i64) -> i32 {
+    t.div_euclid(MILLISECONDS_IN_DAY) as i32
+}
+
+macro_rules! impl_calendar_components {
+    ($ca:ty) => {
+        impl $ca {
+            fn apply_naive_datetime<F, N>(&self, f: F) -> ChunkedArray<N>
+            where
+                F: Fn(NaiveDateTime) -> N::Native,
+                N: PolarsPrimitiveType,
+            {
+                let mut ca: ChunkedArray<N> = self
+                    .as_naive_datetime_iter()
+                    .map(|opt_dt| opt_dt.map(&f))
+                    .collect();
+                ca.rename(self.name());
+                ca
+            }
+
+            /// Returns the ISO day of the week, starting at 0 for Monday.
+            ///
+            /// The return value ranges from 0 to 6.
+            pub fn weekday(&self) -> UInt32Chunked {
+                self.apply_naive_datetime(|dt| dt.weekday().num_days_from_monday())
+            }
+
+            /// Returns the ISO week number.
+            ///
+            /// The return value ranges from 1 to 53. (The last week of year differs by years.)
+            pub fn week(&self) -> UInt32Chunked {
+                self.apply_naive_datetime(|dt| dt.iso_week().week())
+            }
+
+            /// Returns the quarter of the year.
+            ///
+            /// The return value ranges from 1 to 4.
+            pub fn quarter(&self) -> UInt32Chunked {
+                self.apply_naive_datetime(|dt| dt.month0() / 3 + 1)
+            }
+
+            /// Check if the year of the date is a leap year.
+            pub fn is_leap_year(&self) -> BooleanChunked {
+                let mut ca: BooleanChunked = self
+                    .as_naive_datetime_iter()
+                    .map(|opt_dt| opt_dt.map(|dt| is_leap_year(dt.year())))
+                    .collect();
+                ca.rename(self.name());
+                ca
+            }
+        }
+    };
+}
+
+impl_calendar_components!(Date32Chunked);
+impl_calendar_components!(Date64Chunked);
+
+impl Date64Chunked {
+    /// Truncate the timestamps to a multiple of the interval `every`, e.g. `"1h"` or `"1mo"`.
+    ///
+    /// Fixed intervals are aligned on the unix epoch, calendar intervals on the first day of
+    /// the month. See [Interval::parse](crate::chunked_array::temporal::Interval::parse).
+    pub fn truncate(&self, every: &str) -> Result<Date64Chunked> {
+        let every = parse_truncate_interval(every)?;
+        Ok(self.apply(|t| every.truncate_ms(t).unwrap()))
+    }
+
+    /// Round the timestamps to the nearest multiple of the interval `every`. Values halfway
+    /// between two multiples are rounded up.
+    pub fn round(&self, every: &str) -> Result<Date64Chunked> {
+        let every = parse_truncate_interval(every)?;
+        Ok(self.apply(|t| round_ms(&every, t)))
+    }
+
+    /// Add a calendar aware interval like `"1mo"` or `"-2d"` to the timestamps.
+    ///
+    /// If adding months results in a day that doesn't exist, e.g. the 31st of April, the
+    /// day is clamped to the last day of the month.
+    pub fn offset_by(&self, by: &str) -> Result<Date64Chunked> {
+        let by = Interval::parse(by)?;
+        Ok(self.apply(|t| by.add_ms(t)))
+    }
+
+    /// Move the timestamps to the first day of their month, keeping the time of the day.
+    pub fn month_start(&self) -> Date64Chunked {
+        self.apply(|t| month_boundary_ms(t, false))
+    }
+
+    /// Move the timestamps to the last day of their month, keeping the time of the day.
+    pub fn month_end(&self) -> Date64Chunked {
+        self.apply(|t| month_boundary_ms(t, true))
+    }
+}
+
+impl Date32Chunked {
+    /// Truncate the dates to a multiple of the interval `every`, e.g. `"1w"` or `"1q"`.
+    ///
+    /// See [Date64Chunked::truncate](crate::datatypes::Date64Chunked::truncate).
+    pub fn truncate(&self, every: &str) -> Result<Date32Chunked> {
+        let every = parse_truncate_interval(every)?;
+        Ok(self.apply(|v| ms_to_date32(every.truncate_ms(date32_to_ms(v)).unwrap())))
+    }
+
+    /// Round the dates to the nearest multiple of the interval `every`. Values halfway
+    /// between two multiples are rounded up.
+    pub fn round(&self, every: &str) -> Result<Date32Chunked> {
+        let every = parse_truncate_interval(every)?;
+        Ok(self.apply(|v| ms_to_date32(round_ms(&every, date32_to_ms(v)))))
+    }
+
+    /// Add a calendar aware interval like `"1mo"` or `"-2d"` to the dates. Intervals that are
+    /// not a whole number of days are truncated to the date.
+    pub fn offset_by(&self, by: &str) -> Result<Date32Chunked> {
+        let by = Interval::parse(by)?;
+        Ok(self.apply(|v| ms_to_date32(by.add_ms(date32_to_ms(v)))))
+    }
+
+    /// Move the dates to the first day of their month.
+    pub fn month_start(&self) -> Date32Chunked {
+        self.apply(|v| ms_to_date32(month_boundary_ms(date32_to_ms(v), false)))
+    }
+
+    /// Move the dates to the last day of their month.
+    pub fn month_end(&self) -> Date32Chunked {
+        self.apply(|v| ms_to_date32(month_boundary_ms(date32_to_ms(v), true)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_truncate_round_offset() {
+        // 2021-01-31 10:40:00 and 2020-02-15 23:59:00
+        let ca = Date64Chunked::new_from_slice("t", &[1612089600000, 1581811140000]);
+        assert_eq!(
+            ca.truncate("1h").unwrap().cont_slice().unwrap(),
+            &[1612087200000, 1581807600000]
+        );
+        assert_eq!(
+            ca.round("1h").unwrap().cont_slice().unwrap(),
+            &[1612090800000, 1581811200000]
+        );
+        // 2021-01-01 00:00:00 and 2020-01-01 00:00:00
+        assert_eq!(
+            ca.truncate("1q").unwrap().cont_slice().unwrap(),
+            &[1609459200000, 1577836800000]
+        );
+        // 2021-02-28 10:40:00 and 2020-03-15 23:59:00
+        assert_eq!(
+            ca.offset_by("1mo").unwrap().cont_slice().unwrap(),
+            &[1614508800000, 1584316740000]
+        );
+        assert!(ca.truncate("1mo1d").is_err());
+        assert!(ca.round("0h").is_err());
+        assert!(ca.round("-1h").is_err());
+        assert!(ca.truncate("-1mo").is_err());
+    }
+
+    #[test]
+    fn test_calendar_components() {
+        // 2020-02-15, 2021-01-31 and 2021-03-01
+        let ca = Date32Chunked::new_from_slice("d", &[18307, 18658, 18687]);
+        assert_eq!(Vec::from(&ca.weekday()), &[Some(5), Some(6), Some(0)]);
+        assert_eq!(Vec::from(&ca.week()), &[Some(7), Some(4), Some(9)]);
+        assert_eq!(Vec::from(&ca.quarter()), &[Some(1), Some(1), Some(1)]);
+        assert_eq!(
+            Vec::from(&ca.is_leap_year()),
+            &[Some(true), Some(false), Some(false)]
+        );
+        assert_eq!(
+            ca.month_start().cont_slice().unwrap(),
+            &[18293, 18628, 18687]
+        );
+        assert_eq!(ca.month_end().cont_slice().unwrap(), &[18321, 18658, 18717]);
+        assert_eq!(
+            ca.offset_by("-1mo").unwrap().cont_slice().unwrap(),
+            &[18276, 18627, 18659]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Create ranges of dates with a fixed or calendar aware interval.
+use super::conversion::naive_date_to_date32;
+use super::conversions_utils::naive_datetime_to_date64;
+use crate::prelude::*;
+use chrono::{NaiveDate, NaiveDateTime};
+
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+/// Get the timestamps in milliseconds between `start` and `stop` with steps of `every`.
+fn date_range_ms(start: i64, stop: i64, every: &str, closed: ClosedWindow) -> Result<Vec<i64>> {
+    let every = Interval::parse(every)?;
+    if every.add_ms(start) <= start {
+        return Err(PolarsError::ValueError(
+            "the interval of a date range should be positive".into(),
+        ));
+    }
+    let mut out = vec![];
+    let mut i = 0;
+    loop {
+        // step from the start every time, so that the day of the month doesn't drift when
+        // it is clamped to the end of a shorter month
+        let t = every.multiply(i).add_ms(start);
+        if t > stop {
+            break;
+        }
+        let include = match closed {
+            ClosedWindow::Both => true,
+            ClosedWindow::Left => t < stop,
+            ClosedWindow::Right => t > start,
+            ClosedWindow::None => t > start && t < stop,
+        };
+        if include {
+            out.push(t)
+        }
+        i += 1;
+    }
+    Ok(out)
+}
+
+impl Date64Chunked {
+    /// Create a range of timestamps from `start` to `stop` with steps of `every`, e.g.
+    /// `"1h"` or `"1mo"`. `closed` determines if `start` and `stop` are part of the range.
+    ///
+    /// See [Interval::parse](crate::chunked_array::temporal::Interval::parse).
+    pub fn date_range(
+        name: &str,
+        start: NaiveDateTime,
+        stop: NaiveDateTime,
+        every: &str,
+        closed: ClosedWindow,
+    ) -> Result<Self> {
+        let values = date_range_ms(
+            naive_datetime_to_date64(&start),
+            naive_datetime_to_date64(&stop),
+            every,
+            closed,
+        )?;
+        Ok(Date64Chunked::new_from_slice(name, &values))
+    }
+}
+
+impl Date32Chunked {
+    /// Create a range of dates from `start` to `stop` with steps of `every`, e.g. `"1d"` or
+    /// `"1q"`. The interval should be a whole number of days.
+    pub fn date_range(
+        name: &str,
+        start: NaiveDate,
+        stop: NaiveDate,
+        every: &str,
+        closed: ClosedWindow,
+    ) -> Result<Self> {
+        if Interval::parse(every)?.milliseconds() % MILLISECONDS_IN_DAY != 0 {
+            return Err(PolarsError::ValueError(
+                format!(
+                    "the interval '{}' of a date32 range should be a whole number of days",
+                    every
+                )
+                .into(),
+            ));
+        }
+        let values: Vec<_> = date_range_ms(
+            naive_date_to_date32(start) as i64 * MILLISECONDS_IN_DAY,
+            naive_date_to_date32(stop) as i64 * MILLISECONDS_IN_DAY,
+            every,
+            closed,
+        )?
+        .into_iter()
+        .map(|t| t.div_euclid(MILLISECONDS_IN_DAY) as i32)
+        .collect();
+        Ok(Date32Chunked::new_from_slice(name, &values))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_date_range() {
+        let start = NaiveDate::from_ymd(2021, 1, 31);
+        let stop = NaiveDate::from_ymd(2021, 4, 30);
+        let ca = Date32Chunked::date_range("d", start, stop, "1mo", ClosedWindow::Both).unwrap();
+        assert_eq!(
+            ca.as_naive_date_iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>(),
+            &[
+                NaiveDate::from_ymd(2021, 1, 31),
+                NaiveDate::from_ymd(2021, 2, 28),
+                NaiveDate::from_ymd(2021, 3, 31),
+                NaiveDate::from_ymd(2021, 4, 30),
+            ]
+        );
+        assert!(Date32Chunked::date_range("d", start, stop, "1h", ClosedWindow::Both).is_err());
+
+        let ca = Date64Chunked::date_range(
+            "t",
+            start.and_hms(0, 0, 0),
+            start.and_hms(3, 0, 0),
+            "1h",
+            ClosedWindow::Left,
+        )
+        .unwrap();
+        assert_eq!(ca.len(), 3);
+        assert_eq!(Vec::from(&ca.hour()), &[Some(0), Some(1), Some(2)]);
+        assert!(Date64Chunked::date_range(
+            "t",
+            start.and_hms(0, 0, 0),
+            start.and_hms(3, 0, 0),
+            "-1h",
+            ClosedWindow::Both
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_duration_arithmetic() {
+        let start = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+        let stop = NaiveDate::from_ymd(2021, 1, 1).and_hms(2, 0, 0);
+        let a = Date64Chunked::date_range("a", start, stop, "1h", ClosedWindow::Both)
+            .unwrap()
+            .into_series();
+        let b = Date64Chunked::new_from_naive_datetime("b", &[start]).into_series();
+
+        let diff = &a - &b;
+        assert_eq!(diff.dtype(), &DataType::Duration(TimeUnit::Millisecond));
+        assert_eq!(
+            diff.duration_millisecond().unwrap().cont_slice().unwrap(),
+            &[0, 3_600_000, 7_200_000]
+        );
+
+        let shifted = &a + &diff;
+        assert_eq!(shifted.dtype(), &DataType::Date64);
+        assert_eq!(
+            Vec::from(shifted.hour().unwrap().u32().unwrap()),
+            &[Some(0), Some(2), Some(4)]
+        );
+
+        // a date plus a duration gives a timestamp with the time of the day
+        let dates = a.cast::<Date32Type>().unwrap();
+        let out = &dates + &diff;
+        assert_eq!(out.dtype(), &DataType::Date64);
+        assert_eq!(
+            Vec::from(out.hour().unwrap().u32().unwrap()),
+            &[Some(0), Some(1), Some(2)]
+        );
+    }
+}
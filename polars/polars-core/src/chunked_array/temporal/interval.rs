@@ -0,0 +1,246 @@
+//! Calendar aware time intervals, e.g. `"1h"`, `"7d"` or `"1mo"`.
+use crate::prelude::*;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+const MILLISECONDS_IN_SECOND: i64 = 1_000;
+const MILLISECONDS_IN_MINUTE: i64 = 60 * MILLISECONDS_IN_SECOND;
+const MILLISECONDS_IN_HOUR: i64 = 60 * MILLISECONDS_IN_MINUTE;
+const MILLISECONDS_IN_DAY: i64 = 24 * MILLISECONDS_IN_HOUR;
+const MILLISECONDS_IN_WEEK: i64 = 7 * MILLISECONDS_IN_DAY;
+
+/// A time interval with a calendar part (months) and a fixed part (milliseconds).
+///
+/// Months, quarters and years don't have a fixed length, so they are kept separately and
+/// applied on the calendar date. Everything else is converted to milliseconds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Interval {
+    months: i64,
+    milliseconds: i64,
+}
+
+impl Interval {
+    pub fn from_months(months: i64) -> Self {
+        Interval {
+            months,
+            milliseconds: 0,
+        }
+    }
+
+    pub fn from_milliseconds(milliseconds: i64) -> Self {
+        Interval {
+            months: 0,
+            milliseconds,
+        }
+    }
+
+    /// Parse an interval from a string like `"1h30m"`, `"7d"` or `"-1mo"`.
+    ///
+    /// Supported units:
+    /// * ms: millisecond
+    /// * s: second
+    /// * m: minute
+    /// * h: hour
+    /// * d: day
+    /// * w: week
+    /// * mo: calendar month
+    /// * q: calendar quarter
+    /// * y: calendar year
+    pub fn parse(interval: &str) -> Result<Self> {
+        let err =
+            || PolarsError::ValueError(format!("could not parse interval '{}'", interval).into());
+        let (negative, s) = match interval.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, interval),
+        };
+        if s.is_empty() {
+            return Err(err());
+        }
+
+        let mut out = Interval::default();
+        let mut chars = s.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if !c.is_ascii_digit() {
+                return Err(err());
+            }
+            let mut unit_start = s.len();
+            while let Some((i, c)) = chars.peek() {
+                if c.is_ascii_digit() {
+                    chars.next();
+                } else {
+                    unit_start = *i;
+                    break;
+                }
+            }
+            let mut unit_end = s.len();
+            while let Some((i, c)) = chars.peek() {
+                if c.is_ascii_alphabetic() {
+                    chars.next();
+                } else {
+                    unit_end = *i;
+                    break;
+                }
+            }
+            let n: i64 = s[start..unit_start].parse().map_err(|_| err())?;
+            match &s[unit_start..unit_end] {
+                "ms" => out.milliseconds += n,
+                "s" => out.milliseconds += n * MILLISECONDS_IN_SECOND,
+                "m" => out.milliseconds += n * MILLISECONDS_IN_MINUTE,
+                "h" => out.milliseconds += n * MILLISECONDS_IN_HOUR,
+                "d" => out.milliseconds += n * MILLISECONDS_IN_DAY,
+                "w" => out.milliseconds += n * MILLISECONDS_IN_WEEK,
+                "mo" => out.months += n,
+                "q" => out.months += 3 * n,
+                "y" => out.months += 12 * n,
+                _ => return Err(err()),
+            }
+        }
+        if negative {
+            out = out.negate();
+        }
+        Ok(out)
+    }
+
+    /// Number of calendar months in this interval.
+    pub fn months(&self) -> i64 {
+        self.months
+    }
+
+    /// Fixed part of this interval in milliseconds.
+    pub fn milliseconds(&self) -> i64 {
+        self.milliseconds
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.months == 0 && self.milliseconds == 0
+    }
+
+    pub fn negate(&self) -> Self {
+        Interval {
+            months: -self.months,
+            milliseconds: -self.milliseconds,
+        }
+    }
+
+    /// Multiply both the calendar and the fixed part of this interval by `n`.
+    pub fn multiply(&self, n: i64) -> Self {
+        Interval {
+            months: self.months * n,
+            milliseconds: self.milliseconds * n,
+        }
+    }
+
+    /// Add this interval to a timestamp in milliseconds since the unix epoch.
+    ///
+    /// Months are added on the calendar date. If the resulting month is shorter than the day
+    /// of the month of `t`, the day is clamped to the last day of that month.
+    pub fn add_ms(&self, t: i64) -> i64 {
+        let t = if self.months != 0 {
+            let (date, ms_in_day) = split_ms(t);
+            let total = date.year() as i64 * 12 + date.month0() as i64 + self.months;
+            let year = total.div_euclid(12) as i32;
+            let month = total.rem_euclid(12) as u32 + 1;
+            let day = std::cmp::min(date.day(), days_in_month(year, month));
+            date_to_ms(NaiveDate::from_ymd(year, month, day)) + ms_in_day
+        } else {
+            t
+        };
+        t + self.milliseconds
+    }
+
+    /// Truncate a timestamp in milliseconds since the unix epoch to a multiple of this interval.
+    ///
+    /// Fixed intervals are aligned on the unix epoch, calendar intervals on the first day of
+    /// the month, counted from January 1970.
+    pub fn truncate_ms(&self, t: i64) -> Result<i64> {
+        match (self.months, self.milliseconds) {
+            (0, 0) => Err(PolarsError::ValueError(
+                "cannot truncate by a zero interval".into(),
+            )),
+            (0, every) => Ok(t - t.rem_euclid(every.abs())),
+            (every, 0) => {
+                let (date, _) = split_ms(t);
+                let total = (date.year() as i64 - 1970) * 12 + date.month0() as i64;
+                let total = total - total.rem_euclid(every.abs());
+                let year = (1970 + total.div_euclid(12)) as i32;
+                let month = total.rem_euclid(12) as u32 + 1;
+                Ok(date_to_ms(NaiveDate::from_ymd(year, month, 1)))
+            }
+            _ => Err(PolarsError::ValueError(
+                "cannot truncate by an interval that mixes calendar and fixed units".into(),
+            )),
+        }
+    }
+}
+
+/// Split a timestamp in milliseconds in its calendar date and the milliseconds in that day.
+fn split_ms(t: i64) -> (NaiveDate, i64) {
+    let days = t.div_euclid(MILLISECONDS_IN_DAY);
+    let date = NaiveDateTime::from_timestamp(days * 86_400, 0).date();
+    (date, t.rem_euclid(MILLISECONDS_IN_DAY))
+}
+
+fn date_to_ms(date: NaiveDate) -> i64 {
+    date.and_hms(0, 0, 0).timestamp_millis()
+}
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(
+            Interval::parse("1h30m").unwrap(),
+            Interval::from_milliseconds(90 * 60 * 1000)
+        );
+        assert_eq!(Interval::parse("1y2q").unwrap(), Interval::from_months(18));
+        assert_eq!(
+            Interval::parse("-7d").unwrap(),
+            Interval::from_milliseconds(-7 * MILLISECONDS_IN_DAY)
+        );
+        assert!(Interval::parse("1x").is_err());
+        assert!(Interval::parse("h").is_err());
+        assert!(Interval::parse("").is_err());
+    }
+
+    #[test]
+    fn test_interval_calendar() {
+        // 2020-01-31 12:00:00
+        let t = date_to_ms(NaiveDate::from_ymd(2020, 1, 31)) + 12 * MILLISECONDS_IN_HOUR;
+        let one_month = Interval::from_months(1);
+        assert_eq!(
+            one_month.add_ms(t),
+            date_to_ms(NaiveDate::from_ymd(2020, 2, 29)) + 12 * MILLISECONDS_IN_HOUR
+        );
+        assert_eq!(
+            one_month.truncate_ms(t).unwrap(),
+            date_to_ms(NaiveDate::from_ymd(2020, 1, 1))
+        );
+        assert_eq!(
+            Interval::from_months(3).truncate_ms(t).unwrap(),
+            date_to_ms(NaiveDate::from_ymd(2020, 1, 1))
+        );
+        assert_eq!(
+            Interval::parse("1d").unwrap().truncate_ms(t).unwrap(),
+            date_to_ms(NaiveDate::from_ymd(2020, 1, 31))
+        );
+    }
+}
@@ -1,11 +1,17 @@
 //! Traits and utilities for temporal data.
+mod calendar;
 pub mod conversion;
 pub(crate) mod conversions_utils;
+mod date_range;
+mod interval;
+mod timestamp;
 pub use self::conversion::{
     AsDuration, AsNaiveDate, AsNaiveDateTime, AsNaiveTime, FromNaiveDate, FromNaiveDateTime,
     FromNaiveTime,
 };
 pub(crate) use self::conversions_utils::*;
+pub use self::interval::Interval;
+pub(crate) use self::timestamp::fmt_timestamp;
 use chrono::NaiveDateTime;
 
 pub fn unix_time() -> NaiveDateTime {
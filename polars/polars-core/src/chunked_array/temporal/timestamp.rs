@@ -0,0 +1,298 @@
+//! Timestamps in UTC with an optional time zone.
+//!
+//! The values of a timestamp array are always the elapsed time since the unix epoch in UTC.
+//! The time zone is only metadata that is used to compute the local time, e.g. when
+//! extracting the hour or when formatting the values.
+use crate::prelude::*;
+use chrono::offset::LocalResult;
+use chrono::{Datelike, NaiveDateTime, TimeZone, Timelike};
+use chrono_tz::Tz;
+
+const MILLISECONDS_IN_SECOND: i64 = 1_000;
+const MICROSECONDS_IN_SECOND: i64 = 1_000_000;
+const NANOSECONDS_IN_SECOND: i64 = 1_000_000_000;
+
+/// Parse a time zone from the IANA time zone database, e.g. `"Europe/Amsterdam"`.
+pub(crate) fn parse_time_zone(tz: &str) -> Result<Tz> {
+    tz.parse::<Tz>()
+        .map_err(|_| PolarsError::ValueError(format!("unknown time zone '{}'", tz).into()))
+}
+
+pub(crate) fn timestamp_to_naive_datetime(v: i64, tu: &TimeUnit) -> NaiveDateTime {
+    let (per_second, nanos_per_unit) = match tu {
+        TimeUnit::Nanosecond => (NANOSECONDS_IN_SECOND, 1),
+        TimeUnit::Microsecond => (MICROSECONDS_IN_SECOND, 1_000),
+        TimeUnit::Millisecond => (MILLISECONDS_IN_SECOND, 1_000_000),
+        TimeUnit::Second => (1, 0),
+    };
+    NaiveDateTime::from_timestamp(
+        v.div_euclid(per_second),
+        (v.rem_euclid(per_second) * nanos_per_unit) as u32,
+    )
+}
+
+pub(crate) fn naive_datetime_to_timestamp(dt: &NaiveDateTime, tu: &TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanosecond => dt.timestamp_nanos(),
+        TimeUnit::Microsecond => {
+            dt.timestamp() * MICROSECONDS_IN_SECOND + dt.timestamp_subsec_micros() as i64
+        }
+        TimeUnit::Millisecond => dt.timestamp_millis(),
+        TimeUnit::Second => dt.timestamp(),
+    }
+}
+
+/// Get the wall time of a UTC timestamp in the given time zone.
+fn timestamp_to_local_datetime(v: i64, tu: &TimeUnit, tz: Option<&Tz>) -> NaiveDateTime {
+    let utc = timestamp_to_naive_datetime(v, tu);
+    match tz {
+        Some(tz) => tz.from_utc_datetime(&utc).naive_local(),
+        None => utc,
+    }
+}
+
+/// Format a timestamp as local time, followed by the time zone if there is one.
+pub(crate) fn fmt_timestamp(v: i64, tu: &TimeUnit, tz: Option<&str>) -> String {
+    match tz {
+        Some(tz_name) => {
+            let local = timestamp_to_local_datetime(v, tu, parse_time_zone(tz_name).ok().as_ref());
+            format!("{} {}", local, tz_name)
+        }
+        None => format!("{}", timestamp_to_naive_datetime(v, tu)),
+    }
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsTimestampType,
+{
+    fn parsed_time_zone(&self) -> Result<Option<Tz>> {
+        match self.time_zone() {
+            Some(tz) => parse_time_zone(tz).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn as_local_datetime_iter(&self) -> Result<impl Iterator<Item = Option<NaiveDateTime>> + '_> {
+        let tz = self.parsed_time_zone()?;
+        let tu = T::time_unit();
+        Ok(self
+            .into_iter()
+            .map(move |opt_v| opt_v.map(|v| timestamp_to_local_datetime(v, &tu, tz.as_ref()))))
+    }
+
+    fn apply_local_datetime<F, N>(&self, f: F) -> Result<ChunkedArray<N>>
+    where
+        F: Fn(NaiveDateTime) -> N::Native,
+        N: PolarsPrimitiveType,
+    {
+        let mut ca: ChunkedArray<N> = self
+            .as_local_datetime_iter()?
+            .map(|opt_dt| opt_dt.map(&f))
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Set the time zone in which the timestamps are displayed. The underlying UTC values
+    /// don't change, so the local time is converted to the new time zone.
+    pub fn convert_time_zone(&self, tz: &str) -> Result<Self> {
+        parse_time_zone(tz)?;
+        let mut ca = self.clone();
+        ca.set_logical_dtype(DataType::Timestamp(T::time_unit(), Some(tz.to_string())));
+        Ok(ca)
+    }
+
+    /// Keep the local time, but interpret it in another time zone. If `tz` is `None`, the
+    /// local time is interpreted as UTC and the time zone is removed.
+    ///
+    /// Local times that are ambiguous in the new time zone resolve to the earliest instant.
+    /// Local times that don't exist in the new time zone, e.g. because of a daylight saving
+    /// time transition, become null.
+    pub fn replace_time_zone(&self, tz: Option<&str>) -> Result<Self> {
+        let new_tz = match tz {
+            Some(tz) => Some(parse_time_zone(tz)?),
+            None => None,
+        };
+        let tu = T::time_unit();
+        let mut ca: Self = self
+            .as_local_datetime_iter()?
+            .map(|opt_dt| {
+                opt_dt.and_then(|local| match &new_tz {
+                    Some(tz) => match tz.from_local_datetime(&local) {
+                        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => {
+                            Some(naive_datetime_to_timestamp(&dt.naive_utc(), &tu))
+                        }
+                        LocalResult::None => None,
+                    },
+                    None => Some(naive_datetime_to_timestamp(&local, &tu)),
+                })
+            })
+            .collect();
+        ca.rename(self.name());
+        ca.set_logical_dtype(DataType::Timestamp(tu, tz.map(|tz| tz.to_string())));
+        Ok(ca)
+    }
+
+    /// Extract the year of the local time.
+    pub fn year(&self) -> Result<Int32Chunked> {
+        self.apply_local_datetime(|dt| dt.year())
+    }
+
+    /// Extract the month of the local time. The return value ranges from 1 to 12.
+    pub fn month(&self) -> Result<UInt32Chunked> {
+        self.apply_local_datetime(|dt| dt.month())
+    }
+
+    /// Extract the day of the month of the local time. The return value ranges from 1 to 31.
+    pub fn day(&self) -> Result<UInt32Chunked> {
+        self.apply_local_datetime(|dt| dt.day())
+    }
+
+    /// Extract the hour of the local time. The return value ranges from 0 to 23.
+    pub fn hour(&self) -> Result<UInt32Chunked> {
+        self.apply_local_datetime(|dt| dt.hour())
+    }
+
+    /// Extract the minute of the local time. The return value ranges from 0 to 59.
+    pub fn minute(&self) -> Result<UInt32Chunked> {
+        self.apply_local_datetime(|dt| dt.minute())
+    }
+
+    /// Extract the second of the local time. The return value ranges from 0 to 59.
+    pub fn second(&self) -> Result<UInt32Chunked> {
+        self.apply_local_datetime(|dt| dt.second())
+    }
+
+    /// Returns the number of nanoseconds since the whole non-leap second.
+    pub fn nanosecond(&self) -> Result<UInt32Chunked> {
+        self.apply_local_datetime(|dt| dt.nanosecond())
+    }
+
+    /// Returns the day of year of the local time. The return value ranges from 1 to 366.
+    pub fn ordinal(&self) -> Result<UInt32Chunked> {
+        self.apply_local_datetime(|dt| dt.ordinal())
+    }
+
+    /// Format the local time with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    pub fn str_fmt(&self, fmt: &str) -> Result<Utf8Chunked> {
+        let mut ca: Utf8Chunked = self
+            .as_local_datetime_iter()?
+            .map(|opt_dt| opt_dt.map(|dt| format!("{}", dt.format(fmt))))
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_timestamp_time_zones() {
+        // 2021-03-28 00:30:00 and 01:30:00 UTC, around the DST transition in Europe/Amsterdam
+        let ca = TimestampMillisecondChunked::new_from_slice("t", &[1616891400000, 1616895000000]);
+        let ca = ca.convert_time_zone("Europe/Amsterdam").unwrap();
+        assert_eq!(
+            ca.dtype(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("Europe/Amsterdam".into()))
+        );
+        assert_eq!(ca.cont_slice().unwrap(), &[1616891400000, 1616895000000]);
+        assert_eq!(Vec::from(&ca.hour().unwrap()), &[Some(1), Some(3)]);
+
+        // keep the wall time 01:30 and 03:30, but in UTC
+        let utc = ca.replace_time_zone(None).unwrap();
+        assert_eq!(utc.time_zone(), None);
+        assert_eq!(Vec::from(&utc.hour().unwrap()), &[Some(1), Some(3)]);
+
+        // 02:30 doesn't exist in Amsterdam on that day
+        let s = TimestampMillisecondChunked::new_from_slice("t", &[1616898600000]);
+        let s = s.replace_time_zone(Some("Europe/Amsterdam")).unwrap();
+        assert_eq!(s.null_count(), 1);
+
+        assert!(ca.convert_time_zone("Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_series() {
+        let s = TimestampMillisecondChunked::new_from_slice("t", &[1616891400000])
+            .into_series()
+            .convert_time_zone("Asia/Kolkata")
+            .unwrap();
+        assert_eq!(
+            s.datetime_str_fmt("%Y-%m-%d %H:%M")
+                .unwrap()
+                .utf8()
+                .unwrap()
+                .get(0),
+            Some("2021-03-28 06:00")
+        );
+        // the time zone survives operations that build a new array
+        let taken = s.take(&[0usize, 0]);
+        assert_eq!(taken.dtype(), s.dtype());
+        assert_eq!(format!("{}", s.get(0)), "2021-03-28 06:00:00 Asia/Kolkata");
+    }
+
+    #[test]
+    fn test_timestamp_time_zone_kept() {
+        let s = TimestampMillisecondChunked::new_from_slice(
+            "t",
+            &[1616895000000, 1616891400000, 1616898600000],
+        )
+        .into_series()
+        .convert_time_zone("Europe/Amsterdam")
+        .unwrap();
+        let dtype = s.dtype().clone();
+
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true]);
+        assert_eq!(s.filter(&mask).unwrap().dtype(), &dtype);
+        assert_eq!(s.take(&[2usize, 0]).dtype(), &dtype);
+        let sorted = s.sort(false);
+        assert_eq!(sorted.dtype(), &dtype);
+        assert_eq!(
+            sorted
+                .timestamp_millisecond()
+                .unwrap()
+                .cont_slice()
+                .unwrap(),
+            &[1616891400000, 1616895000000, 1616898600000]
+        );
+
+        let mut concat = s.clone();
+        concat.append(&sorted).unwrap();
+        assert_eq!(concat.dtype(), &dtype);
+        assert_eq!(concat.len(), 6);
+        // the arrow arrays keep the time zone as well, so that it is written to files
+        let concat = concat.rechunk().unwrap();
+        assert_eq!(concat.chunks()[0].data_type(), &dtype.to_arrow());
+
+        let mut df = DataFrame::new(vec![s.clone()]).unwrap();
+        df.vstack_mut(&DataFrame::new(vec![sorted]).unwrap())
+            .unwrap();
+        assert_eq!(df.column("t").unwrap().dtype(), &dtype);
+
+        // arithmetic keeps the time zone
+        let hour = DurationMillisecondChunked::new_from_slice("d", &[3_600_000; 3]).into_series();
+        let shifted = &s + &hour;
+        assert_eq!(shifted.dtype(), &dtype);
+        assert_eq!(
+            shifted
+                .timestamp_millisecond()
+                .unwrap()
+                .cont_slice()
+                .unwrap(),
+            &[1616898600000, 1616895000000, 1616902200000]
+        );
+        assert_eq!((&s - &hour).dtype(), &dtype);
+        assert_eq!((&s + 1).dtype(), &dtype);
+
+        let df = DataFrame::new(vec![Series::new("g", &[1, 2, 1]), s.clone()]).unwrap();
+        let out = df.groupby("g").unwrap().select("t").first().unwrap();
+        assert_eq!(out.column("t_first").unwrap().dtype(), &dtype);
+
+        // a different time zone is a different data type
+        let mut other = s.replace_time_zone(None).unwrap();
+        assert!(other.append(&s).is_err());
+    }
+}
@@ -219,6 +219,60 @@ impl FromIterator<Option<String>> for Utf8Chunked {
     }
 }
 
+// FromIterator for BinaryChunked variants.
+
+macro_rules! impl_from_iter_binary {
+    ($iter:ident) => {{
+        let iter = $iter.into_iter();
+        let cap = get_iter_capacity(&iter);
+        let values_cap = cap * 16;
+        let mut builder = BinaryChunkedBuilder::new("", cap, values_cap);
+
+        for opt_val in iter {
+            builder.append_option(opt_val)
+        }
+        builder.finish()
+    }};
+}
+
+impl<'a> FromIterator<Option<&'a [u8]>> for BinaryChunked {
+    fn from_iter<I: IntoIterator<Item = Option<&'a [u8]>>>(iter: I) -> Self {
+        impl_from_iter_binary!(iter)
+    }
+}
+
+impl FromIterator<Option<Vec<u8>>> for BinaryChunked {
+    fn from_iter<I: IntoIterator<Item = Option<Vec<u8>>>>(iter: I) -> Self {
+        impl_from_iter_binary!(iter)
+    }
+}
+
+impl<'a> FromIterator<&'a [u8]> for BinaryChunked {
+    fn from_iter<I: IntoIterator<Item = &'a [u8]>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let cap = get_iter_capacity(&iter);
+        let mut builder = BinaryChunkedBuilder::new("", cap, cap * 16);
+
+        for val in iter {
+            builder.append_value(val);
+        }
+        builder.finish()
+    }
+}
+
+impl FromIterator<Vec<u8>> for BinaryChunked {
+    fn from_iter<I: IntoIterator<Item = Vec<u8>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let cap = get_iter_capacity(&iter);
+        let mut builder = BinaryChunkedBuilder::new("", cap, cap * 16);
+
+        for val in iter {
+            builder.append_value(val);
+        }
+        builder.finish()
+    }
+}
+
 impl<'a> FromIterator<&'a Series> for ListChunked {
     fn from_iter<I: IntoIterator<Item = &'a Series>>(iter: I) -> Self {
         let mut it = iter.into_iter();
@@ -553,6 +607,14 @@ impl From<Utf8Chunked> for Vec<Option<String>> {
     }
 }
 
+impl<'a> From<&'a BinaryChunked> for Vec<Option<&'a [u8]>> {
+    fn from(ca: &'a BinaryChunked) -> Self {
+        let mut vec = Vec::with_capacity(ca.len());
+        ca.into_iter().for_each(|opt| vec.push(opt));
+        vec
+    }
+}
+
 impl<'a> From<&'a BooleanChunked> for Vec<Option<bool>> {
     fn from(ca: &'a BooleanChunked) -> Self {
         let mut vec = Vec::with_capacity(ca.len());
@@ -21,10 +21,16 @@ use std::fmt::{Display, Formatter};
 
 pub struct Utf8Type {}
 
+pub struct BinaryType {}
+
+pub struct DecimalType {}
+
 pub struct ListType {}
 
 pub struct CategoricalType {}
 
+pub struct StructType {}
+
 pub trait PolarsDataType: Send + Sync {
     fn get_dtype() -> DataType;
 }
@@ -71,12 +77,43 @@ impl PolarsDataType for DurationMillisecondType {
     }
 }
 
+impl PolarsDataType for TimestampNanosecondType {
+    fn get_dtype() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    }
+}
+
+impl PolarsDataType for TimestampMicrosecondType {
+    fn get_dtype() -> DataType {
+        DataType::Timestamp(TimeUnit::Microsecond, None)
+    }
+}
+
+impl PolarsDataType for TimestampMillisecondType {
+    fn get_dtype() -> DataType {
+        DataType::Timestamp(TimeUnit::Millisecond, None)
+    }
+}
+
 impl PolarsDataType for Utf8Type {
     fn get_dtype() -> DataType {
         DataType::Utf8
     }
 }
 
+impl PolarsDataType for BinaryType {
+    fn get_dtype() -> DataType {
+        DataType::Binary
+    }
+}
+
+impl PolarsDataType for DecimalType {
+    fn get_dtype() -> DataType {
+        // zero as we cannot know the precision and scale without self.
+        DataType::Decimal(0, 0)
+    }
+}
+
 impl PolarsDataType for ListType {
     fn get_dtype() -> DataType {
         // null as we cannot no anything without self.
@@ -90,6 +127,13 @@ impl PolarsDataType for CategoricalType {
     }
 }
 
+impl PolarsDataType for StructType {
+    fn get_dtype() -> DataType {
+        // empty as we cannot know the fields without self.
+        DataType::Struct(vec![])
+    }
+}
+
 #[cfg(feature = "object")]
 #[doc(cfg(feature = "object"))]
 pub struct ObjectType<T>(T);
@@ -111,6 +155,10 @@ impl<T> PolarsSingleType for T where T: ArrowPrimitiveType + PolarsDataType {}
 
 impl PolarsSingleType for Utf8Type {}
 
+impl PolarsSingleType for BinaryType {}
+
+impl PolarsSingleType for DecimalType {}
+
 pub type ListChunked = ChunkedArray<ListType>;
 pub type BooleanChunked = ChunkedArray<BooleanType>;
 pub type UInt8Chunked = ChunkedArray<UInt8Type>;
@@ -124,12 +172,18 @@ pub type Int64Chunked = ChunkedArray<Int64Type>;
 pub type Float32Chunked = ChunkedArray<Float32Type>;
 pub type Float64Chunked = ChunkedArray<Float64Type>;
 pub type Utf8Chunked = ChunkedArray<Utf8Type>;
+pub type BinaryChunked = ChunkedArray<BinaryType>;
+pub type DecimalChunked = ChunkedArray<DecimalType>;
 pub type Date32Chunked = ChunkedArray<Date32Type>;
 pub type Date64Chunked = ChunkedArray<Date64Type>;
 pub type DurationNanosecondChunked = ChunkedArray<DurationNanosecondType>;
 pub type DurationMillisecondChunked = ChunkedArray<DurationMillisecondType>;
 pub type Time64NanosecondChunked = ChunkedArray<Time64NanosecondType>;
+pub type TimestampNanosecondChunked = ChunkedArray<TimestampNanosecondType>;
+pub type TimestampMicrosecondChunked = ChunkedArray<TimestampMicrosecondType>;
+pub type TimestampMillisecondChunked = ChunkedArray<TimestampMillisecondType>;
 pub type CategoricalChunked = ChunkedArray<CategoricalType>;
+pub type StructChunked = ChunkedArray<StructType>;
 
 pub trait PolarsPrimitiveType: ArrowPrimitiveType + Send + Sync + PolarsDataType {}
 // impl PolarsPrimitiveType for BooleanType {}
@@ -148,6 +202,9 @@ impl PolarsPrimitiveType for Date64Type {}
 impl PolarsPrimitiveType for Time64NanosecondType {}
 impl PolarsPrimitiveType for DurationNanosecondType {}
 impl PolarsPrimitiveType for DurationMillisecondType {}
+impl PolarsPrimitiveType for TimestampNanosecondType {}
+impl PolarsPrimitiveType for TimestampMicrosecondType {}
+impl PolarsPrimitiveType for TimestampMillisecondType {}
 
 pub trait PolarsNumericType: PolarsPrimitiveType + ArrowNumericType {}
 impl PolarsNumericType for UInt8Type {}
@@ -165,6 +222,9 @@ impl PolarsNumericType for Date64Type {}
 impl PolarsNumericType for Time64NanosecondType {}
 impl PolarsNumericType for DurationNanosecondType {}
 impl PolarsNumericType for DurationMillisecondType {}
+impl PolarsNumericType for TimestampNanosecondType {}
+impl PolarsNumericType for TimestampMicrosecondType {}
+impl PolarsNumericType for TimestampMillisecondType {}
 
 pub trait PolarsIntegerType: PolarsNumericType {}
 impl PolarsIntegerType for UInt8Type {}
@@ -180,6 +240,32 @@ impl PolarsIntegerType for Date64Type {}
 impl PolarsIntegerType for Time64NanosecondType {}
 impl PolarsIntegerType for DurationNanosecondType {}
 impl PolarsIntegerType for DurationMillisecondType {}
+impl PolarsIntegerType for TimestampNanosecondType {}
+impl PolarsIntegerType for TimestampMicrosecondType {}
+impl PolarsIntegerType for TimestampMillisecondType {}
+
+/// Timestamp types with a time unit that Polars supports.
+pub trait PolarsTimestampType: PolarsIntegerType<Native = i64> {
+    fn time_unit() -> TimeUnit;
+}
+
+impl PolarsTimestampType for TimestampNanosecondType {
+    fn time_unit() -> TimeUnit {
+        TimeUnit::Nanosecond
+    }
+}
+
+impl PolarsTimestampType for TimestampMicrosecondType {
+    fn time_unit() -> TimeUnit {
+        TimeUnit::Microsecond
+    }
+}
+
+impl PolarsTimestampType for TimestampMillisecondType {
+    fn time_unit() -> TimeUnit {
+        TimeUnit::Millisecond
+    }
+}
 
 pub trait PolarsFloatType: PolarsNumericType {}
 impl PolarsFloatType for Float32Type {}
@@ -192,6 +278,8 @@ pub enum AnyValue<'a> {
     Boolean(bool),
     /// A UTF8 encoded string type.
     Utf8(&'a str),
+    /// Raw bytes.
+    Binary(&'a [u8]),
     /// An unsigned 8-bit integer number.
     UInt8(u8),
     /// An unsigned 16-bit integer number.
@@ -212,6 +300,9 @@ pub enum AnyValue<'a> {
     Float32(f32),
     /// A 64-bit floating point number.
     Float64(f64),
+    /// A 128-bit fixed point number, stored as an integer and the number of digits after the
+    /// decimal point.
+    Decimal(i128, usize),
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     Date32(i32),
@@ -222,9 +313,14 @@ pub enum AnyValue<'a> {
     Time64(i64, TimeUnit),
     /// A 32-bit time representing the elapsed time since midnight in the unit of `TimeUnit`.
     Duration(i64, TimeUnit),
+    /// A 64-bit timestamp representing the elapsed time since UNIX epoch (1970-01-01) in UTC,
+    /// in the unit of `TimeUnit`, with an optional time zone.
+    Timestamp(i64, TimeUnit, Option<&'a str>),
     /// Naive Time elapsed from the Unix epoch, 00:00:00.000 on 1 January 1970, excluding leap seconds, as a 64-bit integer.
     /// Note that UNIX time does not include leap seconds.
     List(Series),
+    /// A row of a struct column, with every field as a Series of length 1.
+    Struct(Vec<Series>),
     #[cfg(feature = "object")]
     /// Use as_any to get a dyn Any
     Object(&'a str),
@@ -246,12 +342,38 @@ impl Display for DataType {
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             DataType::Utf8 => "str",
+            DataType::Binary => "binary",
+            DataType::Decimal(precision, scale) => {
+                return write!(f, "decimal({}, {})", precision, scale)
+            }
             DataType::Date32 => "date32(days)",
             DataType::Date64 => "date64(ms)",
             DataType::Time64(TimeUnit::Nanosecond) => "time64(ns)",
             DataType::Duration(TimeUnit::Nanosecond) => "duration(ns)",
             DataType::Duration(TimeUnit::Millisecond) => "duration(ms)",
+            DataType::Timestamp(tu, tz) => {
+                let unit = match tu {
+                    TimeUnit::Nanosecond => "ns",
+                    TimeUnit::Microsecond => "us",
+                    TimeUnit::Millisecond => "ms",
+                    TimeUnit::Second => "s",
+                };
+                return match tz {
+                    Some(tz) => write!(f, "timestamp({}, {})", unit, tz),
+                    None => write!(f, "timestamp({})", unit),
+                };
+            }
             DataType::List(tp) => return write!(f, "list [{}]", DataType::from(tp)),
+            DataType::Struct(fields) => {
+                f.write_str("struct {")?;
+                for (i, fld) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}: {}", fld.name(), fld.data_type())?;
+                }
+                return f.write_str("}");
+            }
             #[cfg(feature = "object")]
             DataType::Object => "object",
             DataType::Categorical => "cat",
@@ -267,6 +389,7 @@ impl<'a> PartialEq for AnyValue<'a> {
         use AnyValue::*;
         match (self, other) {
             (Utf8(l), Utf8(r)) => l == r,
+            (Binary(l), Binary(r)) => l == r,
             (UInt8(l), UInt8(r)) => l == r,
             (UInt16(l), UInt16(r)) => l == r,
             (UInt32(l), UInt32(r)) => l == r,
@@ -277,12 +400,17 @@ impl<'a> PartialEq for AnyValue<'a> {
             (Int64(l), Int64(r)) => l == r,
             (Float32(l), Float32(r)) => l == r,
             (Float64(l), Float64(r)) => l == r,
+            (Decimal(l, l_scale), Decimal(r, r_scale)) => l == r && l_scale == r_scale,
             (Date32(l), Date32(r)) => l == r,
             (Date64(l), Date64(r)) => l == r,
             (Time64(l, _), Time64(r, _)) => l == r,
             (Duration(l, _), Duration(r, _)) => l == r,
+            (Timestamp(l, _, _), Timestamp(r, _, _)) => l == r,
             (Boolean(l), Boolean(r)) => l == r,
             (List(_), List(_)) => panic!("eq between list series not supported"),
+            (Struct(l), Struct(r)) => {
+                l.len() == r.len() && l.iter().zip(r).all(|(l, r)| l.series_equal_missing(r))
+            }
             #[cfg(feature = "object")]
             (Object(_), Object(_)) => panic!("eq between object not supported"),
             // should it?
@@ -305,19 +433,41 @@ pub enum DataType {
     Int64,
     Float32,
     Float64,
+    /// A fixed point number with a precision (total number of digits) and a scale (number of
+    /// digits after the decimal point), backed by an i128.
+    Decimal(usize, usize),
     Utf8,
+    /// Raw bytes, e.g. serialized messages or hashes.
+    Binary,
     Date32,
     Date64,
     Time64(TimeUnit),
     List(ArrowDataType),
     Duration(TimeUnit),
+    /// A timestamp in UTC with an optional time zone that is used to compute the local time.
+    Timestamp(TimeUnit, Option<String>),
     #[cfg(feature = "object")]
     Object,
     Null,
     Categorical,
+    /// A nested type with named fields, e.g. a record in a JSON or Parquet file.
+    Struct(Vec<Field>),
 }
 
 impl DataType {
+    /// Check if this is an integer or a float type. Temporal types are not numeric.
+    pub fn is_numeric(&self) -> bool {
+        use DataType::*;
+        matches!(
+            self,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
+        )
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, DataType::Float32 | DataType::Float64)
+    }
+
     pub fn to_arrow(&self) -> ArrowDataType {
         use DataType::*;
         match self {
@@ -332,7 +482,9 @@ impl DataType {
             Int64 => ArrowDataType::Int64,
             Float32 => ArrowDataType::Float32,
             Float64 => ArrowDataType::Float64,
+            Decimal(precision, scale) => ArrowDataType::Decimal(*precision, *scale),
             Utf8 => ArrowDataType::LargeUtf8,
+            Binary => ArrowDataType::LargeBinary,
             Date32 => ArrowDataType::Date32,
             Date64 => ArrowDataType::Date64,
             Time64(tu) => ArrowDataType::Time64(tu.clone()),
@@ -340,10 +492,15 @@ impl DataType {
                 ArrowDataType::List(Box::new(arrow::datatypes::Field::new("", dt.clone(), true)))
             }
             Duration(tu) => ArrowDataType::Duration(tu.clone()),
+            Timestamp(tu, tz) => ArrowDataType::Timestamp(tu.clone(), tz.clone()),
             Null => ArrowDataType::Null,
             #[cfg(feature = "object")]
             Object => unimplemented!(),
-            Categorical => ArrowDataType::UInt16,
+            Categorical => ArrowDataType::Dictionary(
+                Box::new(ArrowDataType::UInt32),
+                Box::new(ArrowDataType::Utf8),
+            ),
+            Struct(fields) => ArrowDataType::Struct(fields.iter().map(|f| f.to_arrow()).collect()),
         }
     }
 }
@@ -355,7 +512,7 @@ impl PartialEq<ArrowDataType> for DataType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Field {
     name: String,
     data_type: DataType,
@@ -498,9 +655,11 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Int32 => DataType::Int32,
             ArrowDataType::Int64 => DataType::Int64,
             ArrowDataType::LargeUtf8 => DataType::Utf8,
+            ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::Boolean => DataType::Boolean,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
+            ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
             ArrowDataType::LargeList(f) => DataType::List(f.data_type().clone()),
             ArrowDataType::Date32 => DataType::Date32,
             ArrowDataType::Date64 => DataType::Date64,
@@ -511,6 +670,17 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Duration(TimeUnit::Millisecond) => {
                 DataType::Duration(TimeUnit::Millisecond)
             }
+            ArrowDataType::Timestamp(tu, tz) if !matches!(tu, TimeUnit::Second) => {
+                DataType::Timestamp(tu.clone(), tz.clone())
+            }
+            ArrowDataType::Struct(fields) => {
+                DataType::Struct(fields.iter().map(|f| f.into()).collect())
+            }
+            ArrowDataType::Dictionary(_, values)
+                if matches!(**values, ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) =>
+            {
+                DataType::Categorical
+            }
             dt => panic!(format!("Arrow datatype {:?} not supported by Polars", dt)),
         }
     }
@@ -1,8 +1,9 @@
+use crate::chunked_array::decimal::format_decimal;
 use crate::prelude::*;
 
 #[cfg(feature = "temporal")]
 use crate::chunked_array::temporal::{
-    date32_as_datetime, date64_as_datetime, time64_nanosecond_as_time,
+    date32_as_datetime, date64_as_datetime, fmt_timestamp, time64_nanosecond_as_time,
 };
 use comfy_table::modifiers::UTF8_ROUND_CORNERS;
 use comfy_table::presets::UTF8_FULL;
@@ -180,6 +181,20 @@ impl Debug for Utf8Chunked {
     }
 }
 
+impl Debug for BinaryChunked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = set_limit!(self);
+        format_array!(limit, f, self, "binary", self.name(), "ChunkedArray")
+    }
+}
+
+impl Debug for DecimalChunked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = set_limit!(self);
+        format_array!(limit, f, self, self.dtype(), self.name(), "ChunkedArray")
+    }
+}
+
 impl Debug for ListChunked {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let limit = set_limit!(self);
@@ -222,6 +237,22 @@ impl Debug for Series {
             DataType::Utf8 => {
                 format_utf8_array!(limit, f, self.utf8().unwrap(), self.name(), "Series")
             }
+            DataType::Binary => format_array!(
+                limit,
+                f,
+                self.binary().unwrap(),
+                "binary",
+                self.name(),
+                "Series"
+            ),
+            DataType::Decimal(_, _) => format_array!(
+                limit,
+                f,
+                self.decimal().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
             DataType::UInt8 => {
                 format_array!(limit, f, self.u8().unwrap(), "u8", self.name(), "Series")
             }
@@ -292,6 +323,30 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => format_array!(
+                limit,
+                f,
+                self.timestamp_nanosecond().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => format_array!(
+                limit,
+                f,
+                self.timestamp_microsecond().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => format_array!(
+                limit,
+                f,
+                self.timestamp_millisecond().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
             DataType::List(_) => {
                 format_list_array!(limit, f, self.list().unwrap(), self.name(), "Series")
             }
@@ -305,6 +360,14 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
+            DataType::Struct(_) => format_array!(
+                limit,
+                f,
+                self.struct_().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
             _ => unimplemented!(),
         }
     }
@@ -462,8 +525,17 @@ impl Display for AnyValue<'_> {
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
+            AnyValue::Decimal(v, scale) => write!(f, "{}", format_decimal(*v, *scale)),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
             AnyValue::Utf8(v) => write!(f, "{}", format!("\"{}\"", v)),
+            AnyValue::Binary(v) => {
+                let escaped: String = v
+                    .iter()
+                    .flat_map(|b| std::ascii::escape_default(*b))
+                    .map(char::from)
+                    .collect();
+                write!(f, "b\"{}\"", escaped)
+            }
             AnyValue::Date32(v) => write!(f, "{}", date32_as_datetime(*v).date()),
             #[cfg(feature = "temporal")]
             AnyValue::Date64(v) => write!(f, "{}", date64_as_datetime(*v)),
@@ -472,7 +544,19 @@ impl Display for AnyValue<'_> {
             }
             AnyValue::Duration(v, TimeUnit::Nanosecond) => write!(f, "{}", v),
             AnyValue::Duration(v, TimeUnit::Millisecond) => write!(f, "{}", v),
+            #[cfg(feature = "temporal")]
+            AnyValue::Timestamp(v, tu, tz) => write!(f, "{}", fmt_timestamp(*v, tu, *tz)),
             AnyValue::List(s) => write!(f, "{:?}", s.fmt_list()),
+            AnyValue::Struct(fields) => {
+                write!(f, "{{")?;
+                for (i, s) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", s.name(), s.get(0))?;
+                }
+                write!(f, "}}")
+            }
             #[cfg(feature = "object")]
             AnyValue::Object(_) => write!(f, "object"),
             _ => unimplemented!(),
@@ -540,6 +624,28 @@ impl FmtList for Utf8Chunked {
     }
 }
 
+impl FmtList for BinaryChunked {
+    fn fmt_list(&self) -> String {
+        impl_fmt_list!(self)
+    }
+}
+
+impl FmtList for DecimalChunked {
+    fn fmt_list(&self) -> String {
+        let fmt = |idx: usize| match self.get(idx) {
+            Some(v) => format_decimal(v, self.scale()),
+            None => "null".to_string(),
+        };
+        match self.len() {
+            0 => "[]".to_string(),
+            1 => format!("[{}]", fmt(0)),
+            2 => format!("[{}, {}]", fmt(0), fmt(1)),
+            3 => format!("[{}, {}, {}]", fmt(0), fmt(1), fmt(2)),
+            _ => format!("[{}, {}, ... {}]", fmt(0), fmt(1), fmt(self.len() - 1)),
+        }
+    }
+}
+
 impl FmtList for ListChunked {
     fn fmt_list(&self) -> String {
         impl_fmt_list!(self)
@@ -0,0 +1,159 @@
+//! Summary statistics and correlation matrices of a DataFrame.
+use crate::functions::{cov, drop_null_pairs, pearson_corr, spearman_rank_corr, CorrelationMethod};
+use crate::prelude::*;
+
+const DESCRIBE_STATS: [&str; 9] = [
+    "count",
+    "null_count",
+    "mean",
+    "std",
+    "min",
+    "25%",
+    "50%",
+    "75%",
+    "max",
+];
+
+fn describe_column(s: &Series) -> Result<Series> {
+    let mut stats = vec![
+        Some((s.len() - s.null_count()) as f64),
+        Some(s.null_count() as f64),
+    ];
+    if s.dtype().is_numeric() {
+        let values = s.cast_with_datatype(&DataType::Float64)?.drop_nulls();
+        let ca = values.f64().unwrap();
+        let quartiles = ca.quantiles(&[0.25, 0.5, 0.75], QuantileInterpolOptions::Linear)?;
+        stats.extend_from_slice(&[ca.mean(), ca.std(), ca.min()]);
+        stats.extend(quartiles);
+        stats.push(ca.max());
+    } else {
+        stats.resize(DESCRIBE_STATS.len(), None);
+    }
+    let mut ca: Float64Chunked = stats.into_iter().collect();
+    ca.rename(s.name());
+    Ok(ca.into_series())
+}
+
+impl DataFrame {
+    /// Summarize the columns. The first column, `"describe"`, names the statistics: the count of
+    /// valid values, the null count, the mean, the standard deviation, the minimum, the
+    /// quartiles and the maximum. Only the counts are computed for non numeric columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 3]).unwrap();
+    /// let summary = df.describe().unwrap();
+    /// assert_eq!(summary.shape(), (9, 2));
+    /// ```
+    pub fn describe(&self) -> Result<DataFrame> {
+        let mut columns = Vec::with_capacity(self.width() + 1);
+        columns.push(Series::new("describe", &DESCRIBE_STATS));
+        for s in &self.columns {
+            columns.push(describe_column(s)?);
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Apply `f` to every pair of numeric columns. The result has a `Float64` column for every
+    /// numeric column, the rows are in the same order as the columns.
+    fn pairwise_matrix<F>(&self, f: F) -> Result<DataFrame>
+    where
+        F: Fn(&Float64Chunked, &Float64Chunked) -> Option<f64>,
+    {
+        let columns = self
+            .columns
+            .iter()
+            .filter(|s| s.dtype().is_numeric())
+            .map(|s| s.cast_with_datatype(&DataType::Float64))
+            .collect::<Result<Vec<_>>>()?;
+        let columns = columns.iter().map(|s| s.f64().unwrap()).collect::<Vec<_>>();
+        let matrix = columns
+            .iter()
+            .map(|a| {
+                let mut ca: Float64Chunked = columns.iter().map(|b| f(a, b)).collect();
+                ca.rename(a.name());
+                ca.into_series()
+            })
+            .collect();
+        DataFrame::new(matrix)
+    }
+
+    /// Compute the correlation matrix of the numeric columns. The rows are in the same order as
+    /// the columns. For every pair of columns, rows with a null value are ignored.
+    pub fn corr(&self, method: CorrelationMethod) -> Result<DataFrame> {
+        self.pairwise_matrix(|a, b| match method {
+            CorrelationMethod::Pearson => {
+                let (a, b) = drop_null_pairs(a, b)?;
+                pearson_corr(&a, &b)
+            }
+            CorrelationMethod::Spearman => spearman_rank_corr(a, b),
+        })
+    }
+
+    /// Compute the covariance matrix of the numeric columns. The rows are in the same order as
+    /// the columns. For every pair of columns, rows with a null value are ignored.
+    pub fn cov(&self) -> Result<DataFrame> {
+        self.pairwise_matrix(|a, b| {
+            let (a, b) = drop_null_pairs(a, b)?;
+            cov(&a, &b)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::CorrelationMethod;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_describe() {
+        let df = df!(
+            "a" => &[Some(1.0), Some(2.0), None, Some(3.0), Some(4.0)],
+            "b" => &["a", "b", "c", "d", "e"]
+        )
+        .unwrap();
+        let out = df.describe().unwrap();
+        assert_eq!(out.get_column_names(), &["describe", "a", "b"]);
+        assert_eq!(
+            Vec::from(out.column("a").unwrap().f64().unwrap()),
+            &[
+                Some(4.0),
+                Some(1.0),
+                Some(2.5),
+                Some((5.0f64 / 3.0).sqrt()),
+                Some(1.0),
+                Some(1.75),
+                Some(2.5),
+                Some(3.25),
+                Some(4.0)
+            ]
+        );
+        let b = out.column("b").unwrap().f64().unwrap();
+        assert_eq!(b.get(0), Some(5.0));
+        assert_eq!(b.null_count(), 7);
+    }
+
+    #[test]
+    fn test_corr_cov() {
+        let df = df!(
+            "a" => &[1, 2, 3, 4],
+            "b" => &[2.0, 4.0, 6.0, 8.0],
+            "c" => &[4, 3, 2, 1],
+            "d" => &["a", "b", "c", "d"]
+        )
+        .unwrap();
+        let corr = df.corr(CorrelationMethod::Spearman).unwrap();
+        assert_eq!(corr.shape(), (3, 3));
+        let c = corr.column("c").unwrap().f64().unwrap();
+        assert!((c.get(0).unwrap() + 1.0).abs() < 1e-9);
+        assert!((c.get(2).unwrap() - 1.0).abs() < 1e-9);
+
+        let cov = df.cov().unwrap();
+        let b = cov.column("b").unwrap().f64().unwrap();
+        // var([1, 2, 3, 4]) = 5 / 3 and b = 2 * a
+        assert!((b.get(0).unwrap() - 10.0 / 3.0).abs() < 1e-9);
+        assert!((b.get(1).unwrap() - 20.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,360 @@
+//! Groupby operations on time windows instead of on the values of the keys.
+use crate::frame::group_by::GroupBy;
+use crate::prelude::*;
+
+/// Which sides of a time window `[start, stop]` are included in that window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClosedWindow {
+    Left,
+    Right,
+    Both,
+    None,
+}
+
+/// Describes how the rows of a DataFrame are assigned to time windows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DynamicGroupOptions {
+    /// Windows of length `period` that start at every multiple of `every`, shifted by `offset`.
+    /// The windows may overlap if `period` is larger than `every`.
+    Dynamic {
+        every: String,
+        period: String,
+        offset: String,
+        closed: ClosedWindow,
+    },
+    /// One window per row that covers the trailing `period` up to and including that row.
+    Rolling { period: String },
+}
+
+/// Get the window boundaries and the row indexes of every non empty window.
+fn dynamic_groups(
+    ts: &[i64],
+    every: Interval,
+    period: Interval,
+    offset: Interval,
+    closed: ClosedWindow,
+) -> Result<(Vec<i64>, Vec<(usize, Vec<usize>)>)> {
+    let mut lower_bounds = vec![];
+    let mut groups = vec![];
+    let (first, last) = match (ts.first(), ts.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return Ok((lower_bounds, groups)),
+    };
+    if every.add_ms(first) <= first {
+        return Err(PolarsError::ValueError(
+            "the 'every' interval of a dynamic groupby should be positive".into(),
+        ));
+    }
+
+    let mut start = offset.add_ms(every.truncate_ms(first)?);
+    // a positive offset can move the first window past the first time, start earlier so that
+    // the first rows are not dropped
+    let every_back = every.negate();
+    while start > first {
+        start = every_back.add_ms(start);
+    }
+    // both pointers only move forward, as the windows are sorted
+    let mut lo = 0;
+    let mut hi = 0;
+    while start <= last {
+        let stop = period.add_ms(start);
+        match closed {
+            ClosedWindow::Left | ClosedWindow::Both => {
+                while lo < ts.len() && ts[lo] < start {
+                    lo += 1
+                }
+            }
+            ClosedWindow::Right | ClosedWindow::None => {
+                while lo < ts.len() && ts[lo] <= start {
+                    lo += 1
+                }
+            }
+        }
+        hi = std::cmp::max(hi, lo);
+        match closed {
+            ClosedWindow::Right | ClosedWindow::Both => {
+                while hi < ts.len() && ts[hi] <= stop {
+                    hi += 1
+                }
+            }
+            ClosedWindow::Left | ClosedWindow::None => {
+                while hi < ts.len() && ts[hi] < stop {
+                    hi += 1
+                }
+            }
+        }
+        if hi > lo {
+            lower_bounds.push(start);
+            groups.push((lo, (lo..hi).collect()));
+        }
+        start = every.add_ms(start);
+    }
+    Ok((lower_bounds, groups))
+}
+
+/// Get one group per row that contains all rows in the half open interval `(t - period, t]`.
+fn rolling_groups(ts: &[i64], period: Interval) -> Result<Vec<(usize, Vec<usize>)>> {
+    if let Some(first) = ts.first() {
+        if period.add_ms(*first) <= *first {
+            return Err(PolarsError::ValueError(
+                "the period of a rolling groupby should be positive".into(),
+            ));
+        }
+    }
+    let period = period.negate();
+    let mut lo = 0;
+    let groups = ts
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let start = period.add_ms(*t);
+            while ts[lo] <= start {
+                lo += 1
+            }
+            (lo, (lo..=i).collect())
+        })
+        .collect();
+    Ok(groups)
+}
+
+impl DataFrame {
+    /// Group the DataFrame by time windows of length `period` that start every `every`,
+    /// shifted by `offset`. The windows may overlap, in which case a row is part of multiple
+    /// groups. The key column contains the start of every window. Empty windows are skipped.
+    ///
+    /// The first window is the last one that starts at or before the first time, so e.g. with
+    /// `every = "1d"` and `offset = "6h"` the first time `2021-01-01 03:00` falls in the window
+    /// that starts at `2020-12-31 06:00`.
+    ///
+    /// The intervals are strings like `"1h"`, `"7d"` or `"1mo"`.
+    /// See [Interval::parse](crate::chunked_array::temporal::Interval::parse).
+    ///
+    /// The time column must be sorted in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    ///
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     // a 7 day window that starts every day
+    ///     df.groupby_dynamic("datetime", "1d", "7d", "0d", ClosedWindow::Left)?
+    ///         .select("rain")
+    ///         .mean()
+    /// }
+    /// ```
+    pub fn groupby_dynamic(
+        &self,
+        time_column: &str,
+        every: &str,
+        period: &str,
+        offset: &str,
+        closed: ClosedWindow,
+    ) -> Result<GroupBy> {
+        let options = DynamicGroupOptions::Dynamic {
+            every: every.to_string(),
+            period: period.to_string(),
+            offset: offset.to_string(),
+            closed,
+        };
+        self.groupby_dynamic_with_series(self.column(time_column)?, &options)
+    }
+
+    /// Group the DataFrame by a trailing time window of length `period` for every row. The
+    /// window of a row contains all rows with a time in the interval `(t - period, t]`.
+    /// The key column is the original time column.
+    ///
+    /// The time column must be sorted in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    ///
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     // the sum over the last 2 days for every row
+    ///     df.groupby_rolling("datetime", "2d")?
+    ///         .select("rain")
+    ///         .sum()
+    /// }
+    /// ```
+    pub fn groupby_rolling(&self, time_column: &str, period: &str) -> Result<GroupBy> {
+        let options = DynamicGroupOptions::Rolling {
+            period: period.to_string(),
+        };
+        self.groupby_dynamic_with_series(self.column(time_column)?, &options)
+    }
+
+    /// See [groupby_dynamic](crate::frame::DataFrame::groupby_dynamic) and
+    /// [groupby_rolling](crate::frame::DataFrame::groupby_rolling).
+    pub fn groupby_dynamic_with_series(
+        &self,
+        time: &Series,
+        options: &DynamicGroupOptions,
+    ) -> Result<GroupBy> {
+        if time.len() != self.height() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the time Series should have the same length as the DataFrame".into(),
+            ));
+        }
+        match time.dtype() {
+            DataType::Date32 | DataType::Date64 => {}
+            dt => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("cannot group by time windows on a column of dtype {:?}", dt).into(),
+                ))
+            }
+        }
+        if time.null_count() > 0 {
+            return Err(PolarsError::HasNullValues(
+                "the time column of a dynamic groupby may not contain null values".into(),
+            ));
+        }
+        let ts: Vec<i64> = time
+            .cast::<Date64Type>()?
+            .date64()?
+            .into_no_null_iter()
+            .collect();
+        if ts.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PolarsError::InvalidOperation(
+                "the time column of a dynamic groupby should be sorted in ascending order".into(),
+            ));
+        }
+
+        let (groups, keys) = match options {
+            DynamicGroupOptions::Dynamic {
+                every,
+                period,
+                offset,
+                closed,
+            } => {
+                let (lower_bounds, groups) = dynamic_groups(
+                    &ts,
+                    Interval::parse(every)?,
+                    Interval::parse(period)?,
+                    Interval::parse(offset)?,
+                    *closed,
+                )?;
+                let mut keys = Date64Chunked::new_from_slice(time.name(), &lower_bounds)
+                    .into_series()
+                    .cast_with_datatype(time.dtype())?;
+                keys.rename(time.name());
+                (groups, keys)
+            }
+            DynamicGroupOptions::Rolling { period } => {
+                (rolling_groups(&ts, Interval::parse(period)?)?, time.clone())
+            }
+        };
+
+        Ok(GroupBy::new(
+            self,
+            vec![time.clone()],
+            groups,
+            Some(vec![keys]),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        // 2021-01-01 00:00 until 2021-01-01 05:00, every hour
+        let ts = Date64Chunked::new_from_slice(
+            "t",
+            &[
+                1609459200000,
+                1609462800000,
+                1609466400000,
+                1609470000000,
+                1609473600000,
+                1609477200000,
+            ],
+        )
+        .into_series();
+        let vals = Series::new("vals", [1, 2, 3, 4, 5, 6].as_ref());
+        DataFrame::new(vec![ts, vals]).unwrap()
+    }
+
+    #[test]
+    fn test_groupby_dynamic() {
+        let df = create_df();
+        let out = df
+            .groupby_dynamic("t", "2h", "3h", "0h", ClosedWindow::Left)
+            .unwrap()
+            .select("vals")
+            .sum()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("t").unwrap().date64().unwrap()),
+            &[
+                Some(1609459200000),
+                Some(1609466400000),
+                Some(1609473600000)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("vals_sum").unwrap().i32().unwrap()),
+            &[Some(6), Some(12), Some(11)]
+        );
+
+        let out = df
+            .groupby_dynamic("t", "2h", "2h", "0h", ClosedWindow::Both)
+            .unwrap()
+            .select("vals")
+            .count()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("vals_count").unwrap().u32().unwrap()),
+            &[Some(3), Some(3), Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_groupby_dynamic_offset() {
+        let df = create_df();
+        let out = df
+            .groupby_dynamic("t", "2h", "2h", "30m", ClosedWindow::Left)
+            .unwrap()
+            .select("vals")
+            .sum()
+            .unwrap();
+        // the first window starts at 2020-12-31 22:30, so the row at 00:00 is kept
+        assert_eq!(
+            Vec::from(out.column("t").unwrap().date64().unwrap()),
+            &[
+                Some(1609453800000),
+                Some(1609461000000),
+                Some(1609468200000),
+                Some(1609475400000)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("vals_sum").unwrap().i32().unwrap()),
+            &[Some(1), Some(5), Some(9), Some(6)]
+        );
+    }
+
+    #[test]
+    fn test_groupby_rolling() {
+        let df = create_df();
+        let out = df
+            .groupby_rolling("t", "2h")
+            .unwrap()
+            .select("vals")
+            .sum()
+            .unwrap();
+        assert_eq!(out.height(), 6);
+        assert_eq!(
+            Vec::from(out.column("vals_sum").unwrap().i32().unwrap()),
+            &[Some(1), Some(3), Some(5), Some(7), Some(9), Some(11)]
+        );
+    }
+
+    #[test]
+    fn test_groupby_dynamic_unsorted() {
+        let df = create_df().sort("t", true).unwrap();
+        assert!(df.groupby_rolling("t", "2h").is_err());
+    }
+}
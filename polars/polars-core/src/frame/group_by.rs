@@ -1,4 +1,9 @@
-use crate::chunked_array::{builder::PrimitiveChunkedBuilder, float::IntegerDecode};
+use crate::chunked_array::{
+    builder::PrimitiveChunkedBuilder,
+    decimal::MAX_PRECISION,
+    float::IntegerDecode,
+    ops::aggregate::{quantile_sorted, sorted_values},
+};
 use crate::frame::row::Row;
 use crate::frame::select::Selection;
 use crate::prelude::*;
@@ -82,6 +87,30 @@ impl VecHash for Utf8Chunked {
     }
 }
 
+impl VecHash for BinaryChunked {
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        if self.null_count() == 0 {
+            self.into_no_null_iter()
+                .map(|v| {
+                    let mut hasher = random_state.build_hasher();
+                    v.hash(&mut hasher);
+                    hasher.finish()
+                })
+                .collect::<NoNull<_>>()
+                .into_inner()
+        } else {
+            self.into_iter()
+                .map(|opt_v| {
+                    let mut hasher = random_state.build_hasher();
+                    opt_v.hash(&mut hasher);
+                    hasher.finish()
+                })
+                .collect::<NoNull<_>>()
+                .into_inner()
+        }
+    }
+}
+
 impl VecHash for BooleanChunked {
     fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
         if self.null_count() == 0 {
@@ -410,6 +439,12 @@ impl IntoGroupTuples for Utf8Chunked {
     }
 }
 
+impl IntoGroupTuples for BinaryChunked {
+    fn group_tuples(&self, multithreaded: bool) -> Vec<(usize, Vec<usize>)> {
+        group_tuples!(self, multithreaded)
+    }
+}
+
 impl IntoGroupTuples for CategoricalChunked {
     fn group_tuples(&self, multithreaded: bool) -> Vec<(usize, Vec<usize>)> {
         group_tuples!(self, multithreaded)
@@ -465,6 +500,7 @@ impl<T> IntoGroupTuples for ObjectChunked<T> {}
 pub(crate) enum Groupable<'a> {
     Boolean(bool),
     Utf8(&'a str),
+    Binary(&'a [u8]),
     UInt8(u8),
     UInt16(u16),
     UInt32(u32),
@@ -484,6 +520,7 @@ impl<'a> Debug for Groupable<'a> {
         match self {
             Boolean(v) => write!(f, "{}", v),
             Utf8(v) => write!(f, "{}", v),
+            Binary(v) => write!(f, "{:?}", v),
             UInt8(v) => write!(f, "{}", v),
             UInt16(v) => write!(f, "{}", v),
             UInt32(v) => write!(f, "{}", v),
@@ -558,7 +595,17 @@ impl<'b> (dyn SeriesTrait + 'b) {
             DataType::Duration(TimeUnit::Millisecond) => {
                 as_groupable_iter!(self.duration_millisecond().unwrap(), Int64)
             }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                as_groupable_iter!(self.timestamp_nanosecond().unwrap(), Int64)
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                as_groupable_iter!(self.timestamp_microsecond().unwrap(), Int64)
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                as_groupable_iter!(self.timestamp_millisecond().unwrap(), Int64)
+            }
             DataType::Utf8 => as_groupable_iter!(self.utf8().unwrap(), Utf8),
+            DataType::Binary => as_groupable_iter!(self.binary().unwrap(), Binary),
             DataType::Float32 => Ok(float_to_groupable_iter(self.f32().unwrap())),
             DataType::Float64 => Ok(float_to_groupable_iter(self.f64().unwrap())),
             DataType::Categorical => as_groupable_iter!(self.categorical().unwrap(), UInt32),
@@ -602,12 +649,7 @@ impl DataFrame {
             }
         };
 
-        Ok(GroupBy {
-            df: self,
-            selected_keys: by,
-            groups,
-            selected_agg: None,
-        })
+        Ok(GroupBy::new(self, by, groups, None))
     }
 
     /// Group DataFrame using a Series column.
@@ -693,6 +735,9 @@ pub struct GroupBy<'df, 'selection_str> {
     pub(crate) groups: Vec<(usize, Vec<usize>)>,
     // columns selected for aggregation
     selected_agg: Option<Vec<&'selection_str str>>,
+    // keys that don't map to a row of the DataFrame, e.g. the window boundaries of a dynamic
+    // groupby. If set, these are returned instead of taking the first index of every group.
+    pub(crate) group_keys: Option<Vec<Series>>,
 }
 
 pub(crate) trait NumericAggSync {
@@ -723,6 +768,40 @@ impl NumericAggSync for CategoricalChunked {}
 #[cfg(feature = "object")]
 impl<T> NumericAggSync for ObjectChunked<T> {}
 
+impl NumericAggSync for DecimalChunked {
+    fn agg_mean(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
+        let values: Vec<_> = groups
+            .par_iter()
+            .map(|(_, idx)| {
+                let take = unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
+                // a mean of which the sum does not fit in 38 digits is null
+                take.try_mean().ok().flatten()
+            })
+            .collect();
+        Some(self.new_with_same_type(values.into_iter()).into_series())
+    }
+
+    fn agg_sum(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
+        let values: Vec<_> = groups
+            .par_iter()
+            .map(|(_, idx)| {
+                let take = unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
+                // a sum that does not fit in 38 digits is null, like the sum of a Series
+                take.try_sum().ok().flatten()
+            })
+            .collect();
+        Some(
+            DecimalChunked::new_from_opt_iter(
+                self.name(),
+                MAX_PRECISION,
+                self.scale(),
+                values.into_iter(),
+            )
+            .into_series(),
+        )
+    }
+}
+
 impl<T> NumericAggSync for ChunkedArray<T>
 where
     T: PolarsNumericType + Sync,
@@ -842,7 +921,6 @@ macro_rules! impl_agg_first {
             .iter()
             .map(|(first, _idx)| $self.get(*first))
             .collect::<$ca_type>()
-            .into_series()
     }};
 }
 
@@ -853,24 +931,32 @@ where
 {
     fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
         impl_agg_first!(self, groups, ChunkedArray<T>)
+            .restore_logical_dtype(self)
+            .into_series()
     }
 }
 
 impl AggFirst for BooleanChunked {
     fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
-        impl_agg_first!(self, groups, BooleanChunked)
+        impl_agg_first!(self, groups, BooleanChunked).into_series()
     }
 }
 
 impl AggFirst for Utf8Chunked {
     fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
-        impl_agg_first!(self, groups, Utf8Chunked)
+        impl_agg_first!(self, groups, Utf8Chunked).into_series()
+    }
+}
+
+impl AggFirst for BinaryChunked {
+    fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        impl_agg_first!(self, groups, BinaryChunked).into_series()
     }
 }
 
 impl AggFirst for ListChunked {
     fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
-        impl_agg_first!(self, groups, ListChunked)
+        impl_agg_first!(self, groups, ListChunked).into_series()
     }
 }
 
@@ -901,7 +987,6 @@ macro_rules! impl_agg_last {
             .iter()
             .map(|(_first, idx)| $self.get(idx[idx.len() - 1]))
             .collect::<$ca_type>()
-            .into_series()
     }};
 }
 
@@ -912,18 +997,26 @@ where
 {
     fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
         impl_agg_last!(self, groups, ChunkedArray<T>)
+            .restore_logical_dtype(self)
+            .into_series()
     }
 }
 
 impl AggLast for BooleanChunked {
     fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
-        impl_agg_last!(self, groups, BooleanChunked)
+        impl_agg_last!(self, groups, BooleanChunked).into_series()
     }
 }
 
 impl AggLast for Utf8Chunked {
     fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
-        impl_agg_last!(self, groups, Utf8Chunked)
+        impl_agg_last!(self, groups, Utf8Chunked).into_series()
+    }
+}
+
+impl AggLast for BinaryChunked {
+    fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        impl_agg_last!(self, groups, BinaryChunked).into_series()
     }
 }
 
@@ -939,7 +1032,7 @@ impl AggLast for CategoricalChunked {
 
 impl AggLast for ListChunked {
     fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
-        impl_agg_last!(self, groups, ListChunked)
+        impl_agg_last!(self, groups, ListChunked).into_series()
     }
 }
 
@@ -1086,36 +1179,71 @@ where
 }
 
 pub(crate) trait AggQuantile {
-    fn agg_quantile(&self, _groups: &[(usize, Vec<usize>)], _quantile: f64) -> Option<Series> {
+    fn agg_quantile(
+        &self,
+        groups: &[(usize, Vec<usize>)],
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Option<Series> {
+        self.agg_quantiles(groups, &[quantile], interpol)
+            .and_then(|mut aggs| aggs.pop())
+    }
+
+    /// Aggregate multiple quantiles per group. Every group is only sorted once.
+    fn agg_quantiles(
+        &self,
+        _groups: &[(usize, Vec<usize>)],
+        _quantiles: &[f64],
+        _interpol: QuantileInterpolOptions,
+    ) -> Option<Vec<Series>> {
         None
     }
 
+    /// The lower of the two middle values if a group has an even number of values.
     fn agg_median(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
-        self.agg_quantile(groups, 0.5)
+        self.agg_quantile(groups, 0.5, QuantileInterpolOptions::Lower)
     }
 }
 
 impl<T> AggQuantile for ChunkedArray<T>
 where
     T: PolarsNumericType + Sync,
-    T::Native: PartialEq,
+    T::Native: PartialOrd + NumCast,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_quantile(&self, groups: &[(usize, Vec<usize>)], quantile: f64) -> Option<Series> {
-        Some(
-            groups
-                .into_par_iter()
-                .map(|(_first, idx)| {
-                    let group_vals =
-                        unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
-                    let sorted_idx = group_vals.argsort(false);
-                    let quant_idx = (quantile * (sorted_idx.len() - 1) as f64) as usize;
-                    let value_idx = sorted_idx[quant_idx];
-                    group_vals.get(value_idx)
-                })
-                .collect::<ChunkedArray<T>>()
-                .into_series(),
-        )
+    fn agg_quantiles(
+        &self,
+        groups: &[(usize, Vec<usize>)],
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> Option<Vec<Series>> {
+        if interpol.quantile_dtype(&T::get_dtype()) != T::get_dtype() {
+            return self
+                .cast::<Float64Type>()
+                .ok()?
+                .agg_quantiles(groups, quantiles, interpol);
+        }
+        let aggs = groups
+            .into_par_iter()
+            .map(|(_first, idx)| {
+                let group_vals =
+                    unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
+                let sorted = sorted_values(&group_vals);
+                quantiles
+                    .iter()
+                    .map(|quantile| quantile_sorted(&sorted, *quantile, interpol))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let out = (0..quantiles.len())
+            .map(|i| {
+                aggs.iter()
+                    .map(|group_aggs| group_aggs[i])
+                    .collect::<ChunkedArray<T>>()
+                    .into_series()
+            })
+            .collect();
+        Some(out)
     }
 }
 
@@ -1127,6 +1255,21 @@ impl AggQuantile for CategoricalChunked {}
 impl<T> AggQuantile for ObjectChunked<T> {}
 
 impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
+    pub(crate) fn new(
+        df: &'df DataFrame,
+        by: Vec<Series>,
+        groups: Vec<(usize, Vec<usize>)>,
+        group_keys: Option<Vec<Series>>,
+    ) -> Self {
+        GroupBy {
+            df,
+            selected_keys: by,
+            groups,
+            selected_agg: None,
+            group_keys,
+        }
+    }
+
     /// Select the column(s) that should be aggregated.
     /// You can select a single column or a slice of columns.
     ///
@@ -1149,6 +1292,9 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     }
 
     pub fn keys(&self) -> Vec<Series> {
+        if let Some(keys) = &self.group_keys {
+            return keys.clone();
+        }
         // Keys will later be appended with the aggregation columns, so we already allocate extra space
         let size;
         if let Some(sel) = &self.selected_agg {
@@ -1458,28 +1604,102 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").quantile(0.2)
+    ///     df.groupby("date")?
+    ///         .select("temp")
+    ///         .quantile(0.2, QuantileInterpolOptions::Linear)
+    /// }
+    /// ```
+    pub fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<DataFrame> {
+        self.quantiles(&[quantile], interpol)
+    }
+
+    /// Aggregate grouped `Series` and determine multiple quantiles per group. The values of
+    /// every group are only sorted once. Interpolated quantiles of integers are `Float64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?
+    ///         .select("temp")
+    ///         .quantiles(&[0.25, 0.5, 0.75], QuantileInterpolOptions::Linear)
     /// }
     /// ```
-    pub fn quantile(&self, quantile: f64) -> Result<DataFrame> {
-        if !(0.0..=1.0).contains(&quantile) {
+    pub fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> Result<DataFrame> {
+        if quantiles
+            .iter()
+            .any(|quantile| !(0.0..=1.0).contains(quantile))
+        {
             return Err(PolarsError::Other(
                 "quantile should be within 0.0 and 1.0".into(),
             ));
         }
         let (mut cols, agg_cols) = self.prepare_agg()?;
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Quantile(quantile));
-            let opt_agg = agg_col.agg_quantile(&self.groups, quantile);
-            if let Some(mut agg) = opt_agg {
-                agg.rename(&new_name);
-                cols.push(agg.into_series());
+            let opt_aggs = agg_col.agg_quantiles(&self.groups, quantiles, interpol);
+            if let Some(aggs) = opt_aggs {
+                for (mut agg, quantile) in aggs.into_iter().zip(quantiles) {
+                    let new_name =
+                        fmt_groupby_column(agg_col.name(), GroupByMethod::Quantile(*quantile));
+                    agg.rename(&new_name);
+                    cols.push(agg);
+                }
             }
         }
         DataFrame::new(cols)
     }
 
-    /// Aggregate grouped `Series` and determine the median per group.
+    /// Aggregate grouped `Series` and estimate the number of unique values per group with a
+    /// HyperLogLog sketch. This uses a fixed amount of memory per group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").approx_n_unique()
+    /// }
+    /// ```
+    pub fn approx_n_unique(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::ApproxNUnique);
+            let mut agg = agg_col.agg_approx_n_unique(&self.groups)?;
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and estimate a quantile per group with a t-digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").approx_quantile(0.99)
+    /// }
+    /// ```
+    pub fn approx_quantile(&self, quantile: f64) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name =
+                fmt_groupby_column(agg_col.name(), GroupByMethod::ApproxQuantile(quantile));
+            let mut agg = agg_col.agg_approx_quantile(&self.groups, quantile)?;
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and determine the median per group. Groups with an even
+    /// number of values get the lower of the two middle values.
     ///
     /// # Example
     ///
@@ -1530,6 +1750,56 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
+    /// Aggregate grouped `Series` and determine the skewness per group. With `bias = false` the
+    /// skewness is corrected for the group size.
+    /// See [ChunkMoment::skew](crate::prelude::ChunkMoment::skew).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").skew(false)
+    /// }
+    /// ```
+    pub fn skew(&self, bias: bool) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Skew(bias));
+            let mut agg = agg_col.agg_skew(&self.groups, bias)?;
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and determine the kurtosis per group.
+    /// See [ChunkMoment::kurtosis](crate::prelude::ChunkMoment::kurtosis).
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name =
+                fmt_groupby_column(agg_col.name(), GroupByMethod::Kurtosis(fisher, bias));
+            let mut agg = agg_col.agg_kurtosis(&self.groups, fisher, bias)?;
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and determine the most frequent value per group. If multiple
+    /// values are the most frequent, the smallest is taken.
+    pub fn mode(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Mode);
+            let mut agg = agg_col.agg_mode(&self.groups)?;
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
     /// Aggregate grouped series and compute the number of values per group.
     ///
     /// # Example
@@ -1625,6 +1895,12 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     /// * max
     /// * mean
     /// * median
+    /// * approx_n_unique
+    /// * std
+    /// * var
+    /// * skew (bias corrected)
+    /// * kurtosis (Fisher's definition, bias corrected)
+    /// * mode
     ///
     /// # Example
     ///
@@ -1697,8 +1973,39 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
                             finish_agg_opt!(self, "{}_n_unique", agg_n_unique, agg_col, cols)
                         }
                         "median" => finish_agg_opt!(self, "{}_median", agg_median, agg_col, cols),
+                        "approx_n_unique" => {
+                            let mut agg = agg_col.agg_approx_n_unique(&self.groups)?;
+                            agg.rename(&format!["{}_approx_n_unique", agg_col.name()]);
+                            cols.push(agg);
+                        }
                         "std" => finish_agg_opt!(self, "{}_std", agg_std, agg_col, cols),
                         "var" => finish_agg_opt!(self, "{}_var", agg_var, agg_col, cols),
+                        // like the other aggregations, columns with an unsupported data type
+                        // are skipped
+                        "skew" => {
+                            if let Ok(mut agg) = agg_col.agg_skew(&self.groups, false) {
+                                agg.rename(&fmt_groupby_column(
+                                    agg_col.name(),
+                                    GroupByMethod::Skew(false),
+                                ));
+                                cols.push(agg);
+                            }
+                        }
+                        "kurtosis" => {
+                            if let Ok(mut agg) = agg_col.agg_kurtosis(&self.groups, true, false) {
+                                agg.rename(&fmt_groupby_column(
+                                    agg_col.name(),
+                                    GroupByMethod::Kurtosis(true, false),
+                                ));
+                                cols.push(agg);
+                            }
+                        }
+                        "mode" => {
+                            if let Ok(mut agg) = agg_col.agg_mode(&self.groups) {
+                                agg.rename(&format!["{}_mode", agg_col.name()]);
+                                cols.push(agg);
+                            }
+                        }
                         "count" => {
                             let new_name = format!["{}_count", agg_col.name()];
                             let mut builder = PrimitiveChunkedBuilder::<UInt32Type>::new(
@@ -1885,10 +2192,19 @@ pub enum GroupByMethod {
     Groups,
     NUnique,
     Quantile(f64),
+    /// Multiple quantiles, collected in a list per group.
+    Quantiles,
     Count,
     List,
     Std,
     Var,
+    ApproxNUnique,
+    ApproxQuantile(f64),
+    /// Skewness, with or without bias correction.
+    Skew(bool),
+    /// Kurtosis, Fisher's or Pearson's definition, with or without bias correction.
+    Kurtosis(bool, bool),
+    Mode,
 }
 
 // Formatting functions used in eager and lazy code for renaming grouped columns
@@ -1907,8 +2223,21 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
         Count => format!["{}_count", name],
         List => format!["{}_agg_list", name],
         Quantile(quantile) => format!["{}_quantile_{:.2}", name, quantile],
+        Quantiles => format!["{}_quantiles", name],
         Std => format!["{}_agg_std", name],
         Var => format!["{}_agg_var", name],
+        ApproxNUnique => format!["{}_approx_n_unique", name],
+        ApproxQuantile(quantile) => format!["{}_approx_quantile_{:.2}", name, quantile],
+        // the defaults of the eager `agg` have no suffix, so that the options don't collide
+        Skew(false) => format!["{}_skew", name],
+        Skew(true) => format!["{}_skew_biased", name],
+        Kurtosis(fisher, bias) => format![
+            "{}_kurtosis{}{}",
+            name,
+            if fisher { "" } else { "_pearson" },
+            if bias { "_biased" } else { "" }
+        ],
+        Mode => format!["{}_mode", name],
     }
 }
 
@@ -2234,82 +2563,122 @@ where
 }
 
 impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
+    /// The columns of a pivot on a categorical column are named after the categories and
+    /// ordered like the categories.
+    fn finish_pivot(&self, pivot_series: &Series, df: DataFrame) -> Result<DataFrame> {
+        let ca = match pivot_series.categorical() {
+            Ok(ca) => ca,
+            Err(_) => return Ok(df),
+        };
+        let rev_map = match ca.get_categorical_map() {
+            Some(rev_map) => rev_map,
+            None => return Ok(df),
+        };
+        let ranks = rev_map.ranks();
+        let n_keys = self.gb.selected_keys.len();
+        let mut columns = df.columns;
+        let mut values: Vec<(u32, Series)> = columns
+            .drain(n_keys..)
+            .map(|mut s| {
+                let idx: u32 = s
+                    .name()
+                    .parse()
+                    .expect("pivot column is named after its code");
+                if let Some(name) = rev_map.get(idx) {
+                    s.rename(name);
+                }
+                let rank = ranks.as_ref().map(|r| r[&idx]).unwrap_or(idx);
+                (rank, s)
+            })
+            .collect();
+        values.sort_unstable_by_key(|(rank, _)| *rank);
+        columns.extend(values.into_iter().map(|(_, s)| s));
+        DataFrame::new(columns)
+    }
+
     /// Aggregate the pivot results by taking the count the values.
     pub fn count(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot_count(&**pivot_series, self.gb.keys(), &self.gb.groups)
+        let df = values_series.pivot_count(&**pivot_series, self.gb.keys(), &self.gb.groups)?;
+        self.finish_pivot(pivot_series, df)
     }
 
     /// Aggregate the pivot results by taking the first occurring value.
     pub fn first(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
+        let df = values_series.pivot(
             &**pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::First,
-        )
+        )?;
+        self.finish_pivot(pivot_series, df)
     }
 
     /// Aggregate the pivot results by taking the sum of all duplicates.
     pub fn sum(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
+        let df = values_series.pivot(
             &**pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Sum,
-        )
+        )?;
+        self.finish_pivot(pivot_series, df)
     }
 
     /// Aggregate the pivot results by taking the minimal value of all duplicates.
     pub fn min(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
+        let df = values_series.pivot(
             &**pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Min,
-        )
+        )?;
+        self.finish_pivot(pivot_series, df)
     }
 
     /// Aggregate the pivot results by taking the maximum value of all duplicates.
     pub fn max(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
+        let df = values_series.pivot(
             &**pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Max,
-        )
+        )?;
+        self.finish_pivot(pivot_series, df)
     }
 
     /// Aggregate the pivot results by taking the mean value of all duplicates.
     pub fn mean(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
+        let df = values_series.pivot(
             &**pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Mean,
-        )
+        )?;
+        self.finish_pivot(pivot_series, df)
     }
     /// Aggregate the pivot results by taking the median value of all duplicates.
     pub fn median(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
+        let df = values_series.pivot(
             &**pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Median,
-        )
+        )?;
+        self.finish_pivot(pivot_series, df)
     }
 }
 
@@ -2402,7 +2771,7 @@ mod test {
             df.groupby("date")
                 .unwrap()
                 .select("temp")
-                .quantile(0.2)
+                .quantile(0.2, QuantileInterpolOptions::default())
                 .unwrap()
         );
         println!(
@@ -2568,6 +2937,162 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_groupby_quantiles() {
+        let df = df! {
+            "a" => [1, 1, 1, 1, 2, 2],
+            "b" => [4.0, 1.0, 3.0, 2.0, 5.0, 6.0]
+        }
+        .unwrap();
+        let res = df
+            .groupby("a")
+            .unwrap()
+            .select("b")
+            .quantiles(&[0.5, 0.75], QuantileInterpolOptions::Linear)
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        assert_eq!(
+            res.get_column_names(),
+            &["a", "b_quantile_0.50", "b_quantile_0.75"]
+        );
+        assert_eq!(
+            Vec::from(res.column("b_quantile_0.50").unwrap().f64().unwrap()),
+            &[Some(2.5), Some(5.5)]
+        );
+        assert_eq!(
+            Vec::from(res.column("b_quantile_0.75").unwrap().f64().unwrap()),
+            &[Some(3.25), Some(5.75)]
+        );
+
+        // the median of an even number of values is the lower middle value
+        let res = df
+            .groupby("a")
+            .unwrap()
+            .select("b")
+            .median()
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        assert_eq!(
+            Vec::from(res.column("b_median").unwrap().f64().unwrap()),
+            &[Some(2.0), Some(5.0)]
+        );
+
+        // the groupby median agrees with the median of a Series for an even number of values
+        for n in &[4, 6] {
+            let values: Vec<i32> = (1..=*n).collect();
+            let s = Series::new("v", &values);
+            let df =
+                DataFrame::new(vec![Series::new("g", &vec![0; *n as usize]), s.clone()]).unwrap();
+            let res = df.groupby("g").unwrap().select("v").median().unwrap();
+            let median = s.median_as_series().i32().unwrap().get(0);
+            assert_eq!(median, Some(n / 2));
+            assert_eq!(
+                res.column("v_median").unwrap().i32().unwrap().get(0),
+                median
+            );
+        }
+
+        // interpolated quantiles of integers are Float64
+        let df = df! {
+            "a" => [1, 1, 2],
+            "b" => [1, 2, 3]
+        }
+        .unwrap();
+        let res = df
+            .groupby("a")
+            .unwrap()
+            .select("b")
+            .quantile(0.5, QuantileInterpolOptions::Linear)
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        assert_eq!(
+            Vec::from(res.column("b_quantile_0.50").unwrap().f64().unwrap()),
+            &[Some(1.5), Some(3.0)]
+        );
+    }
+
+    #[test]
+    fn test_groupby_approx() {
+        let df = df! {
+            "a" => [1, 1, 1, 1, 2, 2],
+            "b" => [4.0, 1.0, 3.0, 1.0, 5.0, 6.0]
+        }
+        .unwrap();
+        let gb = df.groupby("a").unwrap();
+        let res = gb
+            .select("b")
+            .approx_n_unique()
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        assert_eq!(
+            Vec::from(res.column("b_approx_n_unique").unwrap().u32().unwrap()),
+            &[Some(3), Some(2)]
+        );
+        let res = gb
+            .select("b")
+            .approx_quantile(0.5)
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        assert_eq!(
+            Vec::from(res.column("b_approx_quantile_0.50").unwrap().f64().unwrap()),
+            &[Some(2.0), Some(5.5)]
+        );
+    }
+
+    #[test]
+    fn test_groupby_moments() {
+        let df = df! {
+            "a" => [1, 1, 1, 1, 2, 2],
+            "b" => [1, 2, 3, 10, 5, 5]
+        }
+        .unwrap();
+        let res = df
+            .groupby("a")
+            .unwrap()
+            .agg(&[("b", &["skew", "mode"])])
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        let skew = res.column("b_skew").unwrap().f64().unwrap();
+        assert!((skew.get(0).unwrap() - 1.763_632_6).abs() < 1e-6);
+        // too few values for the bias correction
+        assert_eq!(skew.get(1), None);
+        assert_eq!(
+            Vec::from(res.column("b_mode").unwrap().i32().unwrap()),
+            &[Some(1), Some(5)]
+        );
+        let res = df.groupby("a").unwrap().select("b").kurtosis(true, false);
+        assert_eq!(res.unwrap().get_column_names(), &["a", "b_kurtosis"]);
+        let res = df.groupby("a").unwrap().select("b").kurtosis(false, true);
+        assert_eq!(
+            res.unwrap().get_column_names(),
+            &["a", "b_kurtosis_pearson_biased"]
+        );
+        assert_eq!(
+            fmt_groupby_column("b", GroupByMethod::Skew(true)),
+            "b_skew_biased"
+        );
+
+        // columns that don't support the aggregation are skipped
+        let df = df! {
+            "a" => [1, 1],
+            "b" => [1, 2],
+            "c" => ["x", "y"]
+        }
+        .unwrap();
+        let res = df
+            .groupby("a")
+            .unwrap()
+            .agg(&[("b", &["skew", "kurtosis"]), ("c", &["skew", "kurtosis"])])
+            .unwrap();
+        assert_eq!(res.get_column_names(), &["a", "b_skew", "b_kurtosis"]);
+    }
+
     #[test]
     fn test_groupby_apply() {
         let df = df! {
@@ -543,73 +543,80 @@ impl HashJoin<BooleanType> for BooleanChunked {
     }
 }
 
-impl HashJoin<Utf8Type> for Utf8Chunked {
-    fn hash_join_inner(&self, other: &Utf8Chunked) -> Vec<(usize, usize)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
+macro_rules! impl_bytes_hash_join {
+    ($type: ty, $ca: ty) => {
+        impl HashJoin<$type> for $ca {
+            fn hash_join_inner(&self, other: &$ca) -> Vec<(usize, usize)> {
+                let (a, b, swap) = det_hash_prone_order!(self, other);
 
-        let n_threads = n_join_threads();
-        let splitted_a = split_ca(a, n_threads).unwrap();
-        let splitted_b = split_ca(b, n_threads).unwrap();
+                let n_threads = n_join_threads();
+                let splitted_a = split_ca(a, n_threads).unwrap();
+                let splitted_b = split_ca(b, n_threads).unwrap();
 
-        match (a.null_count(), b.null_count()) {
-            (0, 0) => {
-                let iters_a = splitted_a
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                let iters_b = splitted_b
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
-            }
-            _ => {
-                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
-                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
-                hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
+                match (a.null_count(), b.null_count()) {
+                    (0, 0) => {
+                        let iters_a = splitted_a
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        let iters_b = splitted_b
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
+                    }
+                    _ => {
+                        let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                        let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                        hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
+                    }
+                }
             }
-        }
-    }
 
-    fn hash_join_left(&self, other: &Utf8Chunked) -> Vec<(usize, Option<usize>)> {
-        let n_threads = n_join_threads();
+            fn hash_join_left(&self, other: &$ca) -> Vec<(usize, Option<usize>)> {
+                let n_threads = n_join_threads();
 
-        let a = self;
-        let b = other;
-        let splitted_a = split_ca(a, n_threads).unwrap();
-        let splitted_b = split_ca(b, n_threads).unwrap();
+                let a = self;
+                let b = other;
+                let splitted_a = split_ca(a, n_threads).unwrap();
+                let splitted_b = split_ca(b, n_threads).unwrap();
 
-        match (a.null_count(), b.null_count()) {
-            (0, 0) => {
-                let iters_a = splitted_a
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                let iters_b = splitted_b
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                hash_join_tuples_left_threaded(iters_a, iters_b)
-            }
-            _ => {
-                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
-                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
-                hash_join_tuples_left_threaded(iters_a, iters_b)
+                match (a.null_count(), b.null_count()) {
+                    (0, 0) => {
+                        let iters_a = splitted_a
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        let iters_b = splitted_b
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        hash_join_tuples_left_threaded(iters_a, iters_b)
+                    }
+                    _ => {
+                        let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                        let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                        hash_join_tuples_left_threaded(iters_a, iters_b)
+                    }
+                }
             }
-        }
-    }
 
-    fn hash_join_outer(&self, other: &Utf8Chunked) -> Vec<(Option<usize>, Option<usize>)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
-        match (a.null_count() == 0, b.null_count() == 0) {
-            (true, true) => {
-                hash_join_tuples_outer(a.into_no_null_iter(), b.into_no_null_iter(), swap)
+            fn hash_join_outer(&self, other: &$ca) -> Vec<(Option<usize>, Option<usize>)> {
+                let (a, b, swap) = det_hash_prone_order!(self, other);
+                match (a.null_count() == 0, b.null_count() == 0) {
+                    (true, true) => {
+                        hash_join_tuples_outer(a.into_no_null_iter(), b.into_no_null_iter(), swap)
+                    }
+                    _ => hash_join_tuples_outer(a.into_iter(), b.into_iter(), swap),
+                }
             }
-            _ => hash_join_tuples_outer(a.into_iter(), b.into_iter(), swap),
         }
-    }
+    };
 }
 
+impl_bytes_hash_join!(Utf8Type, Utf8Chunked);
+impl_bytes_hash_join!(BinaryType, BinaryChunked);
+
 pub trait ZipOuterJoinColumn {
     fn zip_outer_join_column(
         &self,
@@ -656,7 +663,21 @@ where
 impl ZipOuterJoinColumn for Float32Chunked {}
 impl ZipOuterJoinColumn for Float64Chunked {}
 impl ZipOuterJoinColumn for ListChunked {}
-impl ZipOuterJoinColumn for CategoricalChunked {}
+impl ZipOuterJoinColumn for CategoricalChunked {
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<usize>, Option<usize>)],
+    ) -> Series {
+        // the categories are merged before joining, so the codes can be zipped directly
+        let right = right_column.categorical().unwrap().deref().clone();
+        let s = self
+            .deref()
+            .zip_outer_join_column(&right.into_series(), opt_join_tuples);
+        let ca: CategoricalChunked = s.u32().unwrap().cast().unwrap();
+        ca.set_state(self).into_series()
+    }
+}
 #[cfg(feature = "object")]
 impl<T> ZipOuterJoinColumn for ObjectChunked<T> {}
 
@@ -693,6 +714,18 @@ macro_rules! impl_zip_outer_join {
 }
 impl_zip_outer_join!(BooleanChunked);
 impl_zip_outer_join!(Utf8Chunked);
+impl_zip_outer_join!(BinaryChunked);
+
+/// Categorical keys are joined on their codes, so both sides need to share their categories.
+fn merge_categorical_keys(left: &Series, right: &Series) -> Result<(Series, Series)> {
+    match (left.categorical(), right.categorical()) {
+        (Ok(l), Ok(r)) => {
+            let (l, r) = l.merge_categories(r)?;
+            Ok((l.into_series(), r.into_series()))
+        }
+        _ => Ok((left.clone(), right.clone())),
+    }
+}
 
 impl DataFrame {
     /// Utility method to finish a join.
@@ -736,9 +769,14 @@ impl DataFrame {
         right_on: S2,
         how: JoinType,
     ) -> Result<DataFrame> {
-        let selected_left = self.select_series(left_on)?;
-        let selected_right = other.select_series(right_on)?;
+        let mut selected_left = self.select_series(left_on)?;
+        let mut selected_right = other.select_series(right_on)?;
         assert_eq!(selected_right.len(), selected_left.len());
+        for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+            let (merged_l, merged_r) = merge_categorical_keys(l, r)?;
+            *l = merged_l;
+            *r = merged_r;
+        }
 
         if selected_left.len() == 1 {
             return match how {
@@ -960,7 +998,8 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
-        let join_tuples = s_left.hash_join_inner(s_right);
+        let (s_left, s_right) = merge_categorical_keys(s_left, s_right)?;
+        let join_tuples = s_left.hash_join_inner(&s_right);
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&join_tuples),
@@ -998,7 +1037,8 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
-        let opt_join_tuples = s_left.hash_join_left(s_right);
+        let (s_left, s_right) = merge_categorical_keys(s_left, s_right)?;
+        let opt_join_tuples = s_left.hash_join_left(&s_right);
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&opt_join_tuples),
@@ -1040,8 +1080,9 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
+        let (s_left, s_right) = merge_categorical_keys(s_left, s_right)?;
         // Get the indexes of the joined relations
-        let opt_join_tuples = s_left.hash_join_outer(s_right);
+        let opt_join_tuples = s_left.hash_join_outer(&s_right);
 
         // Take the left and right dataframes by join tuples
         let (mut df_left, df_right) = POOL.join(
@@ -1063,7 +1104,7 @@ impl DataFrame {
                     )
             },
         );
-        let mut s = s_left.zip_outer_join_column(s_right, &opt_join_tuples);
+        let mut s = s_left.zip_outer_join_column(&s_right, &opt_join_tuples);
         s.rename(s_left.name());
         df_left.hstack_mut(&[s])?;
         self.finish_join(df_left, df_right)
@@ -1073,7 +1114,7 @@ impl DataFrame {
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
-    use crate::toggle_string_cache;
+    use crate::{StringCacheHolder, SINGLE_LOCK};
 
     fn create_frames() -> (DataFrame, DataFrame) {
         let s0 = Series::new("days", &[0, 1, 2]);
@@ -1264,7 +1305,8 @@ mod test {
 
     #[test]
     fn test_join_categorical() {
-        toggle_string_cache(true);
+        let _lock = SINGLE_LOCK.lock();
+        let _holder = StringCacheHolder::new();
 
         let mut df_a = df! {
             "a" => &[1, 2, 1, 1],
@@ -15,6 +15,10 @@ use std::mem;
 use std::sync::Arc;
 
 mod arithmetic;
+mod describe;
+#[cfg(feature = "temporal")]
+#[doc(cfg(feature = "temporal"))]
+pub mod dynamic_groupby;
 pub mod explode;
 pub mod group_by;
 pub mod hash_join;
@@ -64,6 +68,8 @@ macro_rules! impl_into_series {
 impl_into_series!(Float32Chunked);
 impl_into_series!(Float64Chunked);
 impl_into_series!(Utf8Chunked);
+impl_into_series!(BinaryChunked);
+impl_into_series!(DecimalChunked);
 impl_into_series!(ListChunked);
 impl_into_series!(BooleanChunked);
 impl_into_series!(UInt8Chunked);
@@ -79,7 +85,11 @@ impl_into_series!(DurationMillisecondChunked);
 impl_into_series!(Date32Chunked);
 impl_into_series!(Date64Chunked);
 impl_into_series!(Time64NanosecondChunked);
+impl_into_series!(TimestampNanosecondChunked);
+impl_into_series!(TimestampMicrosecondChunked);
+impl_into_series!(TimestampMillisecondChunked);
 impl_into_series!(CategoricalChunked);
+impl_into_series!(StructChunked);
 
 #[derive(Clone)]
 pub struct DataFrame {
@@ -406,6 +416,12 @@ impl DataFrame {
                 .into(),
             ));
         }
+        // check up front, so that we don't leave a partially appended DataFrame behind
+        for (left, right) in self.columns.iter().zip(df.columns.iter()) {
+            if let (Ok(l), Ok(r)) = (left.categorical(), right.categorical()) {
+                l.check_same_src(r)?;
+            }
+        }
         self.columns
             .iter_mut()
             .zip(df.columns.iter())
@@ -1272,7 +1288,7 @@ impl DataFrame {
             let mut rb_cols = Vec::with_capacity(width);
 
             for col in &self.columns {
-                rb_cols.push(Arc::clone(&col.chunks()[i]))
+                rb_cols.push(col.to_arrow(i))
             }
             let rb = RecordBatch::try_new(Arc::clone(&schema), rb_cols)?;
             record_batches.push(rb)
@@ -1322,6 +1338,9 @@ impl DataFrame {
     /// * Mean fill (replace None with the mean of the whole array)
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
+    /// * Zero fill (replace None with zero)
+    /// * One fill (replace None with one)
+    /// * Linear fill (interpolate linearly between the previous and the next value)
     ///
     /// See the method on [Series](../series/enum.Series.html#method.fill_none) for more info on the `fill_none` operation.
     pub fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Self> {
@@ -1333,6 +1352,30 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(col))
     }
 
+    /// Linearly interpolate the None values of all numeric columns, weighted by the time that
+    /// passed between the values of the `Date64` column `by`. Other columns are not changed.
+    pub fn interpolate_by(&self, by: &str) -> Result<Self> {
+        let by = self.column(by)?;
+        let col = self
+            .columns
+            .par_iter()
+            .map(|s| {
+                if s.name() != by.name() && s.dtype().is_numeric() {
+                    s.interpolate_by(by)
+                } else {
+                    Ok(s.clone())
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataFrame::new_no_checks(col))
+    }
+
+    /// Replace the NaN values of the float columns with `value`.
+    pub fn fill_nan(&self, value: f64) -> Self {
+        let col = self.columns.par_iter().map(|s| s.fill_nan(value)).collect();
+        DataFrame::new_no_checks(col)
+    }
+
     /// Aggregate the columns to their maximum values.
     pub fn max(&self) -> Self {
         let columns = self.columns.par_iter().map(|s| s.max_as_series()).collect();
@@ -1383,11 +1426,11 @@ impl DataFrame {
     }
 
     /// Aggregate the columns to their quantile values.
-    pub fn quantile(&self, quantile: f64) -> Result<Self> {
+    pub fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<Self> {
         let columns = self
             .columns
             .par_iter()
-            .map(|s| s.quantile_as_series(quantile))
+            .map(|s| s.quantile_as_series(quantile, interpol))
             .collect::<Result<Vec<_>>>()?;
         Ok(DataFrame::new_no_checks(columns))
     }
@@ -1570,7 +1613,7 @@ impl<'a> Iterator for RecordBatchIter<'a> {
         // take a slice from all columns and add the the current RecordBatch
         self.columns.iter().for_each(|s| {
             let slice = s.slice(self.idx, length).unwrap();
-            rb_cols.push(Arc::clone(&slice.chunks()[0]))
+            rb_cols.push(slice.to_arrow(0))
         });
         let rb = RecordBatch::try_new(Arc::clone(&self.schema), rb_cols).unwrap();
         self.idx += length;
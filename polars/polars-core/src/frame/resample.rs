@@ -1,11 +1,87 @@
 use crate::frame::group_by::GroupBy;
 use crate::prelude::*;
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum SampleRule {
     Second(u32),
     Minute(u32),
     Day(u32),
     Hour(u32),
+    /// Weeks start on monday.
+    #[cfg(feature = "temporal")]
+    Week(u32),
+    #[cfg(feature = "temporal")]
+    Month(u32),
+    #[cfg(feature = "temporal")]
+    Quarter(u32),
+    #[cfg(feature = "temporal")]
+    Year(u32),
+}
+
+/// How the rows that are inserted by [upsample](crate::frame::DataFrame::upsample_with_fill)
+/// are filled.
+#[cfg(feature = "temporal")]
+#[doc(cfg(feature = "temporal"))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpsampleFill {
+    /// Only timestamps that are in the original DataFrame get values.
+    Null,
+    /// Every timestamp gets the values of the last row at or before that timestamp.
+    Forward,
+}
+
+// 1970-01-05, the first monday after the unix epoch
+#[cfg(feature = "temporal")]
+const FIRST_MONDAY_MS: i64 = 4 * 24 * 3600 * 1000;
+
+impl SampleRule {
+    fn n(&self) -> u32 {
+        use SampleRule::*;
+        match self {
+            Second(n) | Minute(n) | Day(n) | Hour(n) => *n,
+            #[cfg(feature = "temporal")]
+            Week(n) | Month(n) | Quarter(n) | Year(n) => *n,
+        }
+    }
+
+    #[cfg(feature = "temporal")]
+    fn interval(&self) -> Interval {
+        use SampleRule::*;
+        match self {
+            Second(n) => Interval::from_milliseconds(1000 * *n as i64),
+            Minute(n) => Interval::from_milliseconds(60000 * *n as i64),
+            Hour(n) => Interval::from_milliseconds(1000 * 3600 * *n as i64),
+            Day(n) => Interval::from_milliseconds(1000 * 3600 * 24 * *n as i64),
+            Week(n) => Interval::from_milliseconds(1000 * 3600 * 24 * 7 * *n as i64),
+            Month(n) => Interval::from_months(*n as i64),
+            Quarter(n) => Interval::from_months(3 * *n as i64),
+            Year(n) => Interval::from_months(12 * *n as i64),
+        }
+    }
+
+    /// Truncate a timestamp in milliseconds to the start of its bucket.
+    #[cfg(feature = "temporal")]
+    fn truncate_ms(&self, t: i64) -> i64 {
+        let interval = self.interval();
+        match self {
+            SampleRule::Week(_) => {
+                let every = interval.milliseconds();
+                t - (t - FIRST_MONDAY_MS).rem_euclid(every)
+            }
+            // only fails on a zero interval, which is checked in `validate`
+            _ => interval.truncate_ms(t).unwrap(),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.n() == 0 {
+            Err(PolarsError::ValueError(
+                "the frequency of a SampleRule should be larger than 0".into(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl DataFrame {
@@ -68,6 +144,9 @@ impl DataFrame {
     ///  │ 2000-01-01 00:15:00 ┆ 15      │
     ///  ╰─────────────────────┴─────────╯
     /// ```
+    ///
+    /// The calendar rules `Week`, `Month`, `Quarter` and `Year` respect the varying length of
+    /// months and years. Weeks start on monday.
     pub fn downsample(&self, key: &str, rule: SampleRule) -> Result<GroupBy> {
         let s = self.column(key)?;
         self.downsample_with_series(s, rule)
@@ -76,39 +155,145 @@ impl DataFrame {
     /// See [downsample](crate::frame::DataFrame::downsample).
     pub fn downsample_with_series(&self, key: &Series, rule: SampleRule) -> Result<GroupBy> {
         use SampleRule::*;
+        rule.validate()?;
         // todo! implement logic for date32 if we don't want to pay the casting price
         let key = key.cast::<Date64Type>()?;
 
         // first we floor divide so that we get buckets that fit our frequency.
-        let (gb, n, multiply) = match rule {
+        let (gb, multiply) = match rule {
             Second(n) => {
-                let gb = &key / 1000 / n;
-                (gb, n, 1000)
+                let fact = 1000 * n as i64;
+                (&key / fact, fact)
             }
             Minute(n) => {
-                let gb = &key / 60000 / n;
-                (gb, n, 60000)
+                let fact = 60000 * n as i64;
+                (&key / fact, fact)
             }
             Day(n) => {
-                let fact = 1000 * 3600 * 24;
-                let gb = &key / fact / n;
-                (gb, n, fact)
+                let fact = 1000 * 3600 * 24 * n as i64;
+                (&key / fact, fact)
             }
             Hour(n) => {
-                let fact = 1000 * 3600;
-                let gb = &key / fact / n;
-                (gb, n, fact)
+                let fact = 1000 * 3600 * n as i64;
+                (&key / fact, fact)
+            }
+            // calendar buckets don't have a fixed size, so we truncate every timestamp
+            // to the start of its bucket.
+            #[cfg(feature = "temporal")]
+            Week(_) | Month(_) | Quarter(_) | Year(_) => {
+                let gb = key.date64()?.apply(|t| rule.truncate_ms(t)).into_series();
+                (gb, 1)
             }
         };
         let mut gb = self.groupby_with_series(vec![gb], true)?;
-        // we restore the original scale by multiplying with the earlier floor division value
-        gb.selected_keys = gb
-            .selected_keys
-            .into_iter()
-            .map(|s| &s * multiply * n)
-            .collect();
+        if multiply != 1 {
+            // we restore the original scale by multiplying with the earlier floor division value
+            gb.selected_keys = gb
+                .selected_keys
+                .into_iter()
+                .map(|s| &s * multiply)
+                .collect();
+        }
         Ok(gb)
     }
+
+    /// Upsample a DataFrame to a regular grid of timestamps with the frequency of `rule`.
+    /// The grid starts at the bucket of the first timestamp and ends at the last timestamp.
+    /// Timestamps of the grid that are not in the DataFrame are inserted as rows with null
+    /// values. All original rows are kept, also the ones that are not on the grid and rows with
+    /// the same timestamp.
+    ///
+    /// The key column should be sorted in ascending order.
+    /// See [upsample_with_fill](crate::frame::DataFrame::upsample_with_fill) to forward fill the
+    /// values of irregular timestamps onto the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::frame::resample::SampleRule;
+    ///
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.upsample("datetime", SampleRule::Hour(1))
+    /// }
+    /// ```
+    #[cfg(feature = "temporal")]
+    #[doc(cfg(feature = "temporal"))]
+    pub fn upsample(&self, key: &str, rule: SampleRule) -> Result<DataFrame> {
+        self.upsample_with_fill(key, rule, UpsampleFill::Null)
+    }
+
+    /// See [upsample](crate::frame::DataFrame::upsample).
+    #[cfg(feature = "temporal")]
+    #[doc(cfg(feature = "temporal"))]
+    pub fn upsample_with_fill(
+        &self,
+        key: &str,
+        rule: SampleRule,
+        fill: UpsampleFill,
+    ) -> Result<DataFrame> {
+        rule.validate()?;
+        let key_s = self.column(key)?;
+        if key_s.null_count() > 0 {
+            return Err(PolarsError::HasNullValues(
+                "cannot upsample on a key with null values".into(),
+            ));
+        }
+        let key_ca = key_s.cast::<Date64Type>()?;
+        let ts: Vec<i64> = key_ca.date64()?.into_no_null_iter().collect();
+        if ts.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PolarsError::InvalidOperation(
+                "the key of an upsample operation should be sorted in ascending order".into(),
+            ));
+        }
+        let (first, last) = match (ts.first(), ts.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return Ok(self.clone()),
+        };
+
+        let interval = rule.interval();
+        let mut grid = vec![];
+        let mut t = rule.truncate_ms(first);
+        while t <= last {
+            grid.push(t);
+            t = interval.add_ms(t);
+        }
+
+        // merge the grid with the original timestamps; every original row is kept and the
+        // timestamps of the grid that are missing are inserted
+        let mut keys = Vec::with_capacity(grid.len() + ts.len());
+        let mut idx = Vec::with_capacity(grid.len() + ts.len());
+        let mut row = 0;
+        for t in &grid {
+            while row < ts.len() && ts[row] < *t {
+                keys.push(ts[row]);
+                idx.push(Some(row));
+                row += 1;
+            }
+            if row < ts.len() && ts[row] == *t {
+                // the timestamp is in the DataFrame, its rows are added with the next grid point
+                continue;
+            }
+            keys.push(*t);
+            idx.push(match fill {
+                UpsampleFill::Null => None,
+                UpsampleFill::Forward => row.checked_sub(1),
+            });
+        }
+        while row < ts.len() {
+            keys.push(ts[row]);
+            idx.push(Some(row));
+            row += 1;
+        }
+
+        let mut df = self.take_opt_iter(idx.into_iter(), Some(keys.len()));
+        let mut keys = Date64Chunked::new_from_slice(key, &keys)
+            .into_series()
+            .cast_with_datatype(key_s.dtype())?;
+        keys.rename(key);
+        df.replace(key, keys)?;
+        Ok(df)
+    }
 }
 
 #[cfg(test)]
@@ -160,4 +345,105 @@ mod test {
             &[Some(0), Some(5), Some(10), Some(15)]
         );
     }
+
+    #[test]
+    #[cfg(feature = "temporal")]
+    fn test_downsample_calendar() {
+        // 2020-01-31, 2020-02-01, 2020-02-29, 2020-03-01, 2020-04-01
+        let ts = Date32Chunked::new_from_slice("days", &[18292, 18293, 18321, 18322, 18353])
+            .into_series();
+        let idx = UInt8Chunked::new_from_iter("i", 0..5).into_series();
+        let df = DataFrame::new(vec![ts, idx]).unwrap();
+
+        let out = df
+            .downsample("days", SampleRule::Month(1))
+            .unwrap()
+            .count()
+            .unwrap()
+            .sort("days", false)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("i_count").unwrap().u32().unwrap()),
+            &[Some(1), Some(2), Some(1), Some(1)]
+        );
+
+        let out = df
+            .downsample("days", SampleRule::Quarter(1))
+            .unwrap()
+            .count()
+            .unwrap()
+            .sort("days", false)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("i_count").unwrap().u32().unwrap()),
+            &[Some(4), Some(1)]
+        );
+        // 2020-01-27 is a monday
+        assert_eq!(
+            SampleRule::Week(1).truncate_ms(18292 * 86400000),
+            18288 * 86400000
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "temporal")]
+    fn test_upsample() {
+        // 00:00, 00:02:30, 00:03
+        let ts = Date64Chunked::new_from_slice("ms", &[946684800000, 946684950000, 946684980000])
+            .into_series();
+        let idx = UInt8Chunked::new_from_iter("i", 0..3).into_series();
+        let df = DataFrame::new(vec![ts, idx]).unwrap();
+
+        let out = df.upsample("ms", SampleRule::Minute(1)).unwrap();
+        // the row at 00:02:30 is not on the grid, but it is kept
+        assert_eq!(
+            Vec::from(out.column("ms").unwrap().date64().unwrap()),
+            &[
+                Some(946684800000),
+                Some(946684860000),
+                Some(946684920000),
+                Some(946684950000),
+                Some(946684980000)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("i").unwrap().u8().unwrap()),
+            &[Some(0), None, None, Some(1), Some(2)]
+        );
+
+        let out = df
+            .upsample_with_fill("ms", SampleRule::Minute(1), UpsampleFill::Forward)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("i").unwrap().u8().unwrap()),
+            &[Some(0), Some(0), Some(0), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "temporal")]
+    fn test_upsample_duplicate_keys() {
+        // 00:00, 00:02, 00:02
+        let ts = Date64Chunked::new_from_slice("ms", &[946684800000, 946684920000, 946684920000])
+            .into_series();
+        let idx = UInt8Chunked::new_from_iter("i", 0..3).into_series();
+        let df = DataFrame::new(vec![ts, idx]).unwrap();
+
+        let out = df
+            .upsample_with_fill("ms", SampleRule::Minute(1), UpsampleFill::Forward)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("ms").unwrap().date64().unwrap()),
+            &[
+                Some(946684800000),
+                Some(946684860000),
+                Some(946684920000),
+                Some(946684920000)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("i").unwrap().u8().unwrap()),
+            &[Some(0), Some(0), Some(1), Some(2)]
+        );
+    }
 }
@@ -23,6 +23,49 @@ where
     Some(cov(a, b)? / (a.std()? * b.std()?))
 }
 
+/// Only keep the rows where both arrays have a value.
+pub(crate) fn drop_null_pairs<T>(
+    a: &ChunkedArray<T>,
+    b: &ChunkedArray<T>,
+) -> Option<(ChunkedArray<T>, ChunkedArray<T>)>
+where
+    ChunkedArray<T>: ChunkFilter<T>,
+{
+    if a.len() != b.len() {
+        return None;
+    }
+    if a.null_count() == 0 && b.null_count() == 0 {
+        return Some((a.clone(), b.clone()));
+    }
+    let mask = &a.is_not_null() & &b.is_not_null();
+    Some((a.filter(&mask).ok()?, b.filter(&mask).ok()?))
+}
+
+/// Compute the Spearman rank correlation, the Pearson correlation of the ranks of the values.
+/// Equal values get their average rank and rows with a null value in either array are ignored.
+pub fn spearman_rank_corr<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>) -> Option<f64>
+where
+    T: PolarsNumericType,
+{
+    let (a, b) = drop_null_pairs(a, b)?;
+    let a = a.rank(RankOptions::default()).ok()?;
+    let b = b.rank(RankOptions::default()).ok()?;
+    pearson_corr(a.f64().unwrap(), b.f64().unwrap())
+}
+
+/// The correlation coefficient used by [DataFrame::corr](crate::frame::DataFrame::corr).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CorrelationMethod {
+    Pearson,
+    Spearman,
+}
+
+impl Default for CorrelationMethod {
+    fn default() -> Self {
+        CorrelationMethod::Pearson
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -34,4 +77,16 @@ mod test {
         assert!((cov(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 0.5).abs() < 0.001);
         assert!((pearson_corr(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_spearman_rank_corr() {
+        // a monotonic relation has a rank correlation of 1
+        let a = Int32Chunked::new_from_opt_slice("a", &[Some(1), Some(2), None, Some(3), Some(4)]);
+        let b =
+            Int32Chunked::new_from_opt_slice("b", &[Some(1), Some(4), Some(0), Some(9), Some(16)]);
+        assert!((spearman_rank_corr(&a, &b).unwrap() - 1.0).abs() < 1e-9);
+        // scipy: stats.spearmanr([1, 2, 3, 4], [2, 1, 4, 3])
+        let b = Int32Chunked::new_from_opt_slice("b", &[Some(2), Some(1), None, Some(4), Some(3)]);
+        assert!((spearman_rank_corr(&a, &b).unwrap() - 0.6).abs() < 1e-9);
+    }
 }
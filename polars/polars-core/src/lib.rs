@@ -19,7 +19,7 @@ pub(crate) mod vector_hasher;
 use ahash::AHashMap;
 use lazy_static::lazy_static;
 use rayon::{ThreadPool, ThreadPoolBuilder};
-use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Mutex, MutexGuard};
 
 // this is re-exported in utils for polars child crates
@@ -31,41 +31,129 @@ lazy_static! {
 }
 
 /// Used by categorical data that need to share global categories.
-/// In *eager* you need to specifically toggle global string cache to have a global effect.
-/// In *lazy* it is toggled on at the start of a computation run and turned of (deleted) when a
-/// result is produced.
-pub(crate) struct StringCache(pub(crate) Mutex<AHashMap<String, u32>>);
+/// The cache is alive as long as at least one [`StringCacheHolder`] exists (or it is toggled on
+/// with [`toggle_string_cache`]). When the last holder is dropped the cache is cleared, and the
+/// next time it is switched on a new generation starts. Categoricals record the generation they
+/// were built against, so that codes of different generations are never mixed.
+pub(crate) struct StringCache(pub(crate) Mutex<StringCacheInner>);
+
+pub(crate) struct StringCacheInner {
+    pub(crate) map: AHashMap<String, u32>,
+    pub(crate) generation: u32,
+}
 
 impl StringCache {
-    pub(crate) fn lock_map(&self) -> MutexGuard<AHashMap<String, u32>> {
+    pub(crate) fn lock_map(&self) -> MutexGuard<StringCacheInner> {
         self.0.lock().unwrap()
     }
-
-    pub(crate) fn clear(&self) {
-        *self.lock_map() = AHashMap::new();
-    }
 }
 
 impl Default for StringCache {
     fn default() -> Self {
-        StringCache(Mutex::new(AHashMap::new()))
+        StringCache(Mutex::new(StringCacheInner {
+            map: AHashMap::new(),
+            generation: 0,
+        }))
     }
 }
 
-thread_local! {pub(crate) static USE_STRING_CACHE: Cell<bool> = Cell::new(false)}
 lazy_static! {
     static ref L_STRING_CACHE: StringCache = Default::default();
 }
 
 pub(crate) use L_STRING_CACHE as STRING_CACHE;
 
+/// Number of live holders of the string cache. Only modified while the cache is locked.
+static STRING_CACHE_REFCOUNT: AtomicU32 = AtomicU32::new(0);
+/// Whether [`toggle_string_cache`] currently holds a reference.
+static STRING_CACHE_TOGGLED: AtomicBool = AtomicBool::new(false);
+
+fn increment_string_cache_refcount(cache: &mut StringCacheInner) {
+    if STRING_CACHE_REFCOUNT.fetch_add(1, Ordering::AcqRel) == 0 {
+        cache.generation = cache.generation.wrapping_add(1);
+    }
+}
+
+fn decrement_string_cache_refcount(cache: &mut StringCacheInner) {
+    // the refcount is only modified while the cache is locked, so this doesn't race. The
+    // decrement saturates, an unbalanced release should not keep the cache alive forever.
+    let refcount = STRING_CACHE_REFCOUNT.load(Ordering::Acquire);
+    if refcount == 0 {
+        return;
+    }
+    STRING_CACHE_REFCOUNT.store(refcount - 1, Ordering::Release);
+    if refcount == 1 {
+        cache.map = AHashMap::new();
+    }
+}
+
+/// RAII guard that keeps the global string cache alive.
+///
+/// Categoricals created while any holder exists share their categories, so they can be joined and
+/// appended without re-encoding. Holders can be created from multiple threads; the cache is only
+/// cleared once the last one is dropped.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::StringCacheHolder;
+///
+/// fn cast_both(a: &Series, b: &Series) -> Result<(Series, Series)> {
+///     let _holder = StringCacheHolder::new();
+///     Ok((
+///         a.cast_with_datatype(&DataType::Categorical)?,
+///         b.cast_with_datatype(&DataType::Categorical)?,
+///     ))
+/// }
+/// ```
+pub struct StringCacheHolder {
+    // prevent construction without `new`
+    _private: (),
+}
+
+impl StringCacheHolder {
+    pub fn new() -> Self {
+        increment_string_cache_refcount(&mut STRING_CACHE.lock_map());
+        StringCacheHolder { _private: () }
+    }
+}
+
+impl Default for StringCacheHolder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for StringCacheHolder {
+    fn drop(&mut self) {
+        decrement_string_cache_refcount(&mut STRING_CACHE.lock_map())
+    }
+}
+
+/// Toggle a process wide reference to the global string cache.
+///
+/// Toggling on multiple times holds a single reference; toggling off releases it. The cache is
+/// only cleared when no [`StringCacheHolder`] is alive anymore.
 pub fn toggle_string_cache(toggle: bool) {
-    USE_STRING_CACHE.with(|val| val.set(toggle));
-    if !toggle {
-        STRING_CACHE.clear()
+    // the toggle and the refcount change together under the lock of the cache, otherwise a
+    // concurrent toggle could release the reference before it is taken
+    let mut cache = STRING_CACHE.lock_map();
+    if STRING_CACHE_TOGGLED.swap(toggle, Ordering::AcqRel) != toggle {
+        if toggle {
+            increment_string_cache_refcount(&mut cache)
+        } else {
+            decrement_string_cache_refcount(&mut cache)
+        }
     }
 }
 
 pub(crate) fn use_string_cache() -> bool {
-    USE_STRING_CACHE.with(|val| val.get())
+    STRING_CACHE_REFCOUNT.load(Ordering::Acquire) > 0
+}
+
+// tests that toggle the global string cache should not run in parallel
+#[cfg(test)]
+lazy_static! {
+    pub(crate) static ref SINGLE_LOCK: Mutex<()> = Mutex::new(());
 }
@@ -3,14 +3,20 @@ pub use crate::{
     chunked_array::{
         arithmetic::Pow,
         builder::{
-            BooleanChunkedBuilder, ChunkedBuilder, ListBooleanChunkedBuilder, ListBuilderTrait,
-            ListPrimitiveChunkedBuilder, ListUtf8ChunkedBuilder, NewChunkedArray,
-            PrimitiveChunkedBuilder, Utf8ChunkedBuilder,
+            BinaryChunkedBuilder, BooleanChunkedBuilder, ChunkedBuilder, DecimalChunkedBuilder,
+            ListBooleanChunkedBuilder, ListBuilderTrait, ListPrimitiveChunkedBuilder,
+            ListUtf8ChunkedBuilder, NewChunkedArray, PrimitiveChunkedBuilder, Utf8ChunkedBuilder,
         },
+        categorical::{CategoricalOrdering, RevMapping},
         comparison::{CompToSeries, NumComp},
         iterator::{IntoNoNullIterator, PolarsIterator},
         ops::{
             chunkops::ChunkOps,
+            diff::{ChunkDiff, NullBehavior},
+            ewm::EWMOptions,
+            math::{ChunkBitwise, ChunkFloatMath, ChunkNumMath},
+            moment::{ChunkMode, ChunkMoment},
+            rank::{ChunkRank, RankMethod, RankOptions},
             take::{AsTakeIndex, IntoTakeRandom, NumTakeRandomChunked, NumTakeRandomCont},
             window::InitFold,
             *,
@@ -32,8 +38,14 @@ pub(crate) use polars_arrow::array::*;
 pub use polars_arrow::vec::AlignedVec;
 pub use std::sync::Arc;
 
+#[cfg(feature = "strings")]
+pub use crate::chunked_array::strings::{NumberFormat, Utf8Parse};
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
+#[cfg(feature = "temporal")]
+pub use crate::chunked_array::temporal::Interval;
+#[cfg(feature = "temporal")]
+pub use crate::frame::dynamic_groupby::{ClosedWindow, DynamicGroupOptions};
 
 #[cfg(test)]
 pub(crate) fn create_df() -> DataFrame {
@@ -0,0 +1,290 @@
+//! Approximate aggregations backed by mergeable sketches. Partial results are stored as
+//! serialized sketches in a `Binary` Series, such that they can be merged after a partitioned
+//! groupby or a batched scan.
+use crate::chunked_array::kernels::sketch::{HyperLogLog, TDigest};
+use crate::prelude::*;
+use crate::series::private::PrivateSeries;
+use ahash::RandomState;
+use rayon::prelude::*;
+
+impl Series {
+    /// Sketches of different partitions must use the same hasher to be mergeable.
+    fn sketch_hashes(&self) -> Result<Vec<u64>> {
+        let random_state = RandomState::with_seeds(
+            0x243f_6a88_85a3_08d3,
+            0x1319_8a2e_0370_7344,
+            0xa409_3822_299f_31d0,
+            0x082e_fa98_ec4e_6c89,
+        );
+        let hashes = match self.dtype() {
+            // the categories depend on the order of the data, the strings don't
+            DataType::Categorical => self
+                .cast_with_datatype(&DataType::Utf8)?
+                .vec_hash(random_state),
+            DataType::List(_) | DataType::Struct(_) | DataType::Decimal(_, _) | DataType::Null => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("approx_n_unique not supported on dtype {:?}", self.dtype()).into(),
+                ))
+            }
+            #[cfg(feature = "object")]
+            DataType::Object => {
+                return Err(PolarsError::InvalidOperation(
+                    "approx_n_unique not supported on dtype Object".into(),
+                ))
+            }
+            _ => self.vec_hash(random_state),
+        };
+        Ok(hashes.into_no_null_iter().collect())
+    }
+
+    fn sketch_values(&self) -> Result<Vec<Option<f64>>> {
+        if !self.dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("approx_quantile not supported on dtype {:?}", self.dtype()).into(),
+            ));
+        }
+        let s = self.cast_with_datatype(&DataType::Float64)?;
+        Ok(Vec::from(s.f64()?))
+    }
+
+    fn hll_from_hashes(hashes: impl Iterator<Item = u64>) -> HyperLogLog {
+        let mut hll = HyperLogLog::new();
+        hashes.for_each(|hash| hll.add_hash(hash));
+        hll
+    }
+
+    fn tdigest_from_values<'a>(values: impl Iterator<Item = &'a Option<f64>>) -> TDigest {
+        TDigest::new(values.filter_map(|opt_v| *opt_v))
+    }
+
+    fn hll_sketches(&self) -> Result<Vec<HyperLogLog>> {
+        Vec::from(self.binary()?)
+            .into_iter()
+            .flatten()
+            .map(HyperLogLog::from_bytes)
+            .collect()
+    }
+
+    fn tdigest_sketches(&self) -> Result<Vec<TDigest>> {
+        Vec::from(self.binary()?)
+            .into_iter()
+            .flatten()
+            .map(TDigest::from_bytes)
+            .collect()
+    }
+
+    /// Estimate the number of unique values with a HyperLogLog sketch. Like `n_unique`, null
+    /// counts as a value. The relative error is about 1.6%.
+    pub fn approx_n_unique(&self) -> Result<u32> {
+        let hashes = self.sketch_hashes()?;
+        Ok(Self::hll_from_hashes(hashes.into_iter()).estimate() as u32)
+    }
+
+    /// Estimate a quantile of a numeric Series with a t-digest. Nulls and NaNs are ignored.
+    pub fn approx_quantile(&self, quantile: f64) -> Result<Option<f64>> {
+        check_approx_quantile(quantile)?;
+        let values = self.sketch_values()?;
+        Ok(Self::tdigest_from_values(values.iter()).quantile(quantile))
+    }
+
+    /// Create a serialized HyperLogLog sketch of this Series. The sketches of multiple Series can
+    /// be combined with [merge_hll_sketches](#method.merge_hll_sketches).
+    pub fn hll_sketch(&self) -> Result<Series> {
+        let hashes = self.sketch_hashes()?;
+        let hll = Self::hll_from_hashes(hashes.into_iter());
+        let mut ca: BinaryChunked = std::iter::once(hll.to_bytes()).collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    /// Create a serialized t-digest of this Series. The sketches of multiple Series can
+    /// be combined with [merge_tdigest_sketches](#method.merge_tdigest_sketches).
+    pub fn tdigest_sketch(&self) -> Result<Series> {
+        let values = self.sketch_values()?;
+        let digest = Self::tdigest_from_values(values.iter());
+        let mut ca: BinaryChunked = std::iter::once(digest.to_bytes()).collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    /// Merge all the HyperLogLog sketches in this `Binary` Series and estimate the number of
+    /// unique values.
+    pub fn merge_hll_sketches(&self) -> Result<u32> {
+        let mut hll = HyperLogLog::new();
+        self.hll_sketches()?
+            .iter()
+            .for_each(|other| hll.merge(other));
+        Ok(hll.estimate() as u32)
+    }
+
+    /// Merge all the t-digests in this `Binary` Series and estimate a quantile.
+    pub fn merge_tdigest_sketches(&self, quantile: f64) -> Result<Option<f64>> {
+        check_approx_quantile(quantile)?;
+        let mut digest = TDigest::new(std::iter::empty());
+        self.tdigest_sketches()?
+            .iter()
+            .for_each(|other| digest.merge(other));
+        Ok(digest.quantile(quantile))
+    }
+
+    /// Estimate the number of unique values per group.
+    pub fn agg_approx_n_unique(&self, groups: &[(usize, Vec<usize>)]) -> Result<Series> {
+        let hashes = self.sketch_hashes()?;
+        let mut ca: NoNull<UInt32Chunked> = groups
+            .into_par_iter()
+            .map(|(_, idx)| Self::hll_from_hashes(idx.iter().map(|i| hashes[*i])).estimate() as u32)
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_inner().into_series())
+    }
+
+    /// Estimate a quantile per group.
+    pub fn agg_approx_quantile(
+        &self,
+        groups: &[(usize, Vec<usize>)],
+        quantile: f64,
+    ) -> Result<Series> {
+        check_approx_quantile(quantile)?;
+        let values = self.sketch_values()?;
+        let mut ca: Float64Chunked = groups
+            .into_par_iter()
+            .map(|(_, idx)| {
+                Self::tdigest_from_values(idx.iter().map(|i| &values[*i])).quantile(quantile)
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    /// Create a serialized HyperLogLog sketch per group.
+    pub fn agg_hll_sketches(&self, groups: &[(usize, Vec<usize>)]) -> Result<Series> {
+        let hashes = self.sketch_hashes()?;
+        let mut ca: BinaryChunked = groups
+            .into_par_iter()
+            .map(|(_, idx)| Self::hll_from_hashes(idx.iter().map(|i| hashes[*i])).to_bytes())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    /// Create a serialized t-digest per group.
+    pub fn agg_tdigest_sketches(&self, groups: &[(usize, Vec<usize>)]) -> Result<Series> {
+        let values = self.sketch_values()?;
+        let mut ca: BinaryChunked = groups
+            .into_par_iter()
+            .map(|(_, idx)| Self::tdigest_from_values(idx.iter().map(|i| &values[*i])).to_bytes())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    /// Merge the HyperLogLog sketches of this `Binary` Series per group and estimate the
+    /// number of unique values.
+    pub fn agg_merge_hll_sketches(&self, groups: &[(usize, Vec<usize>)]) -> Result<Series> {
+        let sketches = self.hll_sketches()?;
+        if sketches.len() != self.len() {
+            return Err(PolarsError::ValueError("sketches may not be null".into()));
+        }
+        let mut ca: NoNull<UInt32Chunked> = groups
+            .into_par_iter()
+            .map(|(_, idx)| {
+                let mut hll = HyperLogLog::new();
+                idx.iter().for_each(|i| hll.merge(&sketches[*i]));
+                hll.estimate() as u32
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_inner().into_series())
+    }
+
+    /// Merge the t-digests of this `Binary` Series per group and estimate a quantile.
+    pub fn agg_merge_tdigest_sketches(
+        &self,
+        groups: &[(usize, Vec<usize>)],
+        quantile: f64,
+    ) -> Result<Series> {
+        check_approx_quantile(quantile)?;
+        let sketches = self.tdigest_sketches()?;
+        if sketches.len() != self.len() {
+            return Err(PolarsError::ValueError("sketches may not be null".into()));
+        }
+        let mut ca: Float64Chunked = groups
+            .into_par_iter()
+            .map(|(_, idx)| {
+                let mut digest = TDigest::new(std::iter::empty());
+                idx.iter().for_each(|i| digest.merge(&sketches[*i]));
+                digest.quantile(quantile)
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+}
+
+fn check_approx_quantile(quantile: f64) -> Result<()> {
+    if !(0.0..=1.0).contains(&quantile) {
+        Err(PolarsError::ValueError(
+            "quantile should be within 0.0 and 1.0".into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_approx_aggregations() {
+        let values = (0..10_000).map(|v| (v % 1000) as i32).collect::<Vec<_>>();
+        let s = Series::new("a", &values);
+        let estimate = s.approx_n_unique().unwrap() as f64;
+        assert!((estimate - 1000.0).abs() < 50.0);
+        let median = s.approx_quantile(0.5).unwrap().unwrap();
+        assert!((median - 500.0).abs() < 10.0);
+        assert!(s.approx_quantile(1.5).is_err());
+        assert!(Series::new("a", &["a", "b"]).approx_quantile(0.5).is_err());
+
+        // merging the sketches of two halves equals the estimate of the whole
+        let first = s.slice(0, 5000).unwrap();
+        let second = s.slice(5000, 5000).unwrap();
+        let mut sketches = first.hll_sketch().unwrap();
+        sketches.append(&second.hll_sketch().unwrap()).unwrap();
+        assert_eq!(
+            sketches.merge_hll_sketches().unwrap(),
+            s.approx_n_unique().unwrap()
+        );
+        let mut sketches = first.tdigest_sketch().unwrap();
+        sketches.append(&second.tdigest_sketch().unwrap()).unwrap();
+        let median = sketches.merge_tdigest_sketches(0.5).unwrap().unwrap();
+        assert!((median - 500.0).abs() < 10.0);
+
+        let s = Series::new("a", &[Some("a"), Some("b"), None, Some("a")]);
+        assert_eq!(s.approx_n_unique().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_agg_approx_aggregations() {
+        let s = Series::new("a", &[1, 2, 2, 3, 4, 5, 6]);
+        let groups = vec![(0, vec![0, 1, 2]), (3, vec![3, 4, 5, 6])];
+        let n_unique = s.agg_approx_n_unique(&groups).unwrap();
+        assert_eq!(Vec::from(n_unique.u32().unwrap()), &[Some(2), Some(4)]);
+        let median = s.agg_approx_quantile(&groups, 0.5).unwrap();
+        assert_eq!(Vec::from(median.f64().unwrap()), &[Some(2.0), Some(4.5)]);
+
+        let sketches = s.agg_hll_sketches(&groups).unwrap();
+        assert_eq!(sketches.dtype(), &DataType::Binary);
+        let merged = sketches.agg_merge_hll_sketches(&[(0, vec![0, 1])]).unwrap();
+        assert_eq!(Vec::from(merged.u32().unwrap()), &[Some(6)]);
+        let sketches = s.agg_tdigest_sketches(&groups).unwrap();
+        let merged = sketches
+            .agg_merge_tdigest_sketches(&[(0, vec![0, 1])], 0.5)
+            .unwrap();
+        assert_eq!(Vec::from(merged.f64().unwrap()), &[Some(3.0)]);
+    }
+}
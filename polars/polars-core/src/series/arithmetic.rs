@@ -1,5 +1,6 @@
+use crate::chunked_array::decimal::{integer_precision, OnOverflow};
 use crate::prelude::*;
-use crate::utils::get_supertype;
+use crate::utils::{get_supertype, get_temporal_arithmetic_type};
 use num::{Num, NumCast};
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -67,27 +68,27 @@ where
 {
     fn subtract(&self, rhs: &Series) -> Result<Series> {
         let rhs = self.unpack_series_matching_type(rhs)?;
-        let out = self - rhs;
+        let out = (self - rhs).restore_logical_dtype(self);
         Ok(out.into_series())
     }
     fn add_to(&self, rhs: &Series) -> Result<Series> {
         let rhs = self.unpack_series_matching_type(rhs)?;
-        let out = self + rhs;
+        let out = (self + rhs).restore_logical_dtype(self);
         Ok(out.into_series())
     }
     fn multiply(&self, rhs: &Series) -> Result<Series> {
         let rhs = self.unpack_series_matching_type(rhs)?;
-        let out = self * rhs;
+        let out = (self * rhs).restore_logical_dtype(self);
         Ok(out.into_series())
     }
     fn divide(&self, rhs: &Series) -> Result<Series> {
         let rhs = self.unpack_series_matching_type(rhs)?;
-        let out = self / rhs;
+        let out = (self / rhs).restore_logical_dtype(self);
         Ok(out.into_series())
     }
     fn remainder(&self, rhs: &Series) -> Result<Series> {
         let rhs = self.unpack_series_matching_type(rhs)?;
-        let out = self % rhs;
+        let out = (self % rhs).restore_logical_dtype(self);
         Ok(out.into_series())
     }
 }
@@ -103,6 +104,27 @@ impl NumOpsDispatch for BooleanChunked {}
 impl NumOpsDispatch for ListChunked {}
 impl NumOpsDispatch for CategoricalChunked {}
 
+/// Results that don't fit in the precision of the output are null. Use the `try_*` methods of
+/// [DecimalChunked] to error instead.
+impl NumOpsDispatch for DecimalChunked {
+    fn subtract(&self, rhs: &Series) -> Result<Series> {
+        self.sub_with(rhs.decimal()?, OnOverflow::Null)
+            .map(|ca| ca.into_series())
+    }
+    fn add_to(&self, rhs: &Series) -> Result<Series> {
+        self.add_with(rhs.decimal()?, OnOverflow::Null)
+            .map(|ca| ca.into_series())
+    }
+    fn multiply(&self, rhs: &Series) -> Result<Series> {
+        self.mul_with(rhs.decimal()?, OnOverflow::Null)
+            .map(|ca| ca.into_series())
+    }
+    fn divide(&self, rhs: &Series) -> Result<Series> {
+        self.div_with(rhs.decimal()?, OnOverflow::Null)
+            .map(|ca| ca.into_series())
+    }
+}
+
 pub(crate) fn coerce_lhs_rhs<'a>(
     lhs: &'a Series,
     rhs: &'a Series,
@@ -121,10 +143,102 @@ pub(crate) fn coerce_lhs_rhs<'a>(
     Ok((left, right))
 }
 
+/// Decimals are combined with decimals and integers exactly, so they are not coerced to a common
+/// type. Integers are converted to decimals without digits after the decimal point.
+/// Returns `None` if no side is a decimal or if the other side is not an integer.
+#[allow(clippy::type_complexity)]
+fn decimal_operands<'a>(
+    lhs: &'a Series,
+    rhs: &'a Series,
+) -> Option<Result<(Cow<'a, Series>, Cow<'a, Series>)>> {
+    let is_decimal = |s: &Series| matches!(s.dtype(), DataType::Decimal(_, _));
+    if !is_decimal(lhs) && !is_decimal(rhs) {
+        return None;
+    }
+    let to_decimal = |s: &'a Series| match s.dtype() {
+        DataType::Decimal(_, _) => Some(Ok(Cow::Borrowed(s))),
+        dt => integer_precision(dt).map(|precision| {
+            s.cast_with_datatype(&DataType::Decimal(precision, 0))
+                .map(Cow::Owned)
+        }),
+    };
+    let left = to_decimal(lhs)?;
+    let right = to_decimal(rhs)?;
+    Some(left.and_then(|left| right.map(|right| (left, right))))
+}
+
+/// Number of nanoseconds in a single unit of a temporal type.
+fn temporal_unit_ns(dtype: &DataType) -> i64 {
+    let time_unit_ns = |tu: &TimeUnit| match tu {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+    match dtype {
+        DataType::Date32 => 86_400_000_000_000,
+        DataType::Date64 => 1_000_000,
+        DataType::Timestamp(tu, _) | DataType::Duration(tu) => time_unit_ns(tu),
+        dt => unreachable!("{:?} is not a temporal type", dt),
+    }
+}
+
+/// Get the values of a temporal Series as the number of `unit_ns` nanoseconds.
+fn temporal_to_i64(s: &Series, unit_ns: i64) -> Result<Int64Chunked> {
+    let from_ns = temporal_unit_ns(s.dtype());
+    let s = match s.dtype() {
+        DataType::Date32 => s.cast::<Int32Type>()?.cast::<Int64Type>()?,
+        _ => s.cast::<Int64Type>()?,
+    };
+    let ca = s.i64()?;
+    if from_ns >= unit_ns {
+        let factor = from_ns / unit_ns;
+        Ok(ca.apply(|v| v * factor))
+    } else {
+        let factor = unit_ns / from_ns;
+        Ok(ca.apply(|v| v.div_euclid(factor)))
+    }
+}
+
+/// Add or subtract temporal Series that don't have a common supertype, e.g. a datetime and a
+/// duration. Returns `None` if the operands are not such temporal types.
+fn temporal_arithmetic(lhs: &Series, rhs: &Series, subtract: bool) -> Option<Result<Series>> {
+    let dtype = get_temporal_arithmetic_type(lhs.dtype(), rhs.dtype(), subtract)?;
+    let f = || {
+        let unit_ns = temporal_unit_ns(&dtype);
+        let l = temporal_to_i64(lhs, unit_ns)?;
+        let r = temporal_to_i64(rhs, unit_ns)?;
+        let out = if subtract { &l - &r } else { &l + &r };
+        let mut out = match &dtype {
+            DataType::Duration(TimeUnit::Nanosecond) => out
+                .into_iter()
+                .collect::<DurationNanosecondChunked>()
+                .into_series(),
+            DataType::Duration(TimeUnit::Millisecond) => out
+                .into_iter()
+                .collect::<DurationMillisecondChunked>()
+                .into_series(),
+            dt => out.into_series().cast_with_datatype(dt)?,
+        };
+        out.rename(lhs.name());
+        Ok(out)
+    };
+    Some(f())
+}
+
 impl ops::Sub for &Series {
     type Output = Series;
 
     fn sub(self, rhs: Self) -> Self::Output {
+        if let Some(operands) = decimal_operands(self, rhs) {
+            let (lhs, rhs) = operands.expect("cannot coerce datatypes");
+            return lhs
+                .subtract(rhs.as_ref())
+                .expect("decimal arithmetic failed");
+        }
+        if let Some(out) = temporal_arithmetic(self, rhs, true) {
+            return out.expect("data types don't match");
+        }
         let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
         lhs.subtract(rhs.as_ref()).expect("data types don't match")
     }
@@ -134,6 +248,13 @@ impl ops::Add for &Series {
     type Output = Series;
 
     fn add(self, rhs: Self) -> Self::Output {
+        if let Some(operands) = decimal_operands(self, rhs) {
+            let (lhs, rhs) = operands.expect("cannot coerce datatypes");
+            return lhs.add_to(rhs.as_ref()).expect("decimal arithmetic failed");
+        }
+        if let Some(out) = temporal_arithmetic(self, rhs, false) {
+            return out.expect("data types don't match");
+        }
         let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
         lhs.add_to(rhs.as_ref()).expect("data types don't match")
     }
@@ -148,6 +269,12 @@ impl std::ops::Mul for &Series {
     /// let out = &s * &s;
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
+        if let Some(operands) = decimal_operands(self, rhs) {
+            let (lhs, rhs) = operands.expect("cannot coerce datatypes");
+            return lhs
+                .multiply(rhs.as_ref())
+                .expect("decimal arithmetic failed");
+        }
         let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
         lhs.multiply(rhs.as_ref()).expect("data types don't match")
     }
@@ -162,6 +289,10 @@ impl std::ops::Div for &Series {
     /// let out = &s / &s;
     /// ```
     fn div(self, rhs: Self) -> Self::Output {
+        if let Some(operands) = decimal_operands(self, rhs) {
+            let (lhs, rhs) = operands.expect("cannot coerce datatypes");
+            return lhs.divide(rhs.as_ref()).expect("decimal arithmetic failed");
+        }
         let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
         lhs.divide(rhs.as_ref()).expect("data types don't match")
     }
@@ -223,7 +354,7 @@ where
             .map(|opt_v| opt_v.map(|v| v - rhs))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
 
     fn add_number<N: Num + NumCast>(&self, rhs: N) -> Series {
@@ -234,7 +365,7 @@ where
             .map(|opt_v| opt_v.map(|v| v + rhs))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
     fn multiply_number<N: Num + NumCast>(&self, rhs: N) -> Series {
         let rhs: T::Native =
@@ -244,7 +375,7 @@ where
             .map(|opt_v| opt_v.map(|v| v * rhs))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
     fn divide_number<N: Num + NumCast>(&self, rhs: N) -> Series {
         let rhs: T::Native =
@@ -254,7 +385,7 @@ where
             .map(|opt_v| opt_v.map(|v| v / rhs))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
 }
 
@@ -389,7 +520,7 @@ where
             .map(|opt_v| opt_v.map(|v| lhs - v))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
 
     fn lhs_add_number<N: Num + NumCast>(&self, lhs: N) -> Series {
@@ -400,7 +531,7 @@ where
             .map(|opt_v| opt_v.map(|v| lhs + v))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
     fn lhs_multiply_number<N: Num + NumCast>(&self, lhs: N) -> Series {
         let lhs: T::Native =
@@ -410,7 +541,7 @@ where
             .map(|opt_v| opt_v.map(|v| lhs * v))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
     fn lhs_divide_number<N: Num + NumCast>(&self, lhs: N) -> Series {
         let lhs: T::Native =
@@ -420,7 +551,7 @@ where
             .map(|opt_v| opt_v.map(|v| lhs / v))
             .collect();
         ca.rename(self.name());
-        ca.into_series()
+        ca.restore_logical_dtype(self).into_series()
     }
 }
 
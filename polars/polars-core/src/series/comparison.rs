@@ -4,12 +4,15 @@ use super::Series;
 use crate::apply_method_numeric_series;
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
+use std::borrow::Cow;
 
 macro_rules! impl_compare {
     ($self:expr, $rhs:expr, $method:ident) => {{
         match $self.dtype() {
             DataType::Boolean => $self.bool().unwrap().$method($rhs.bool().unwrap()),
             DataType::Utf8 => $self.utf8().unwrap().$method($rhs.utf8().unwrap()),
+            DataType::Binary => $self.binary().unwrap().$method($rhs.binary().unwrap()),
+            DataType::Decimal(_, _) => $self.decimal().unwrap().$method($rhs.decimal().unwrap()),
             DataType::UInt8 => $self.u8().unwrap().$method($rhs.u8().unwrap()),
             DataType::UInt16 => $self.u16().unwrap().$method($rhs.u16().unwrap()),
             DataType::UInt32 => $self.u32().unwrap().$method($rhs.u32().unwrap()),
@@ -34,51 +37,86 @@ macro_rules! impl_compare {
                 .duration_millisecond()
                 .unwrap()
                 .$method($rhs.duration_millisecond().unwrap()),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => $self
+                .timestamp_nanosecond()
+                .unwrap()
+                .$method($rhs.timestamp_nanosecond().unwrap()),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => $self
+                .timestamp_microsecond()
+                .unwrap()
+                .$method($rhs.timestamp_microsecond().unwrap()),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => $self
+                .timestamp_millisecond()
+                .unwrap()
+                .$method($rhs.timestamp_millisecond().unwrap()),
             DataType::List(_) => $self.list().unwrap().$method($rhs.list().unwrap()),
+            DataType::Categorical => $self
+                .categorical()
+                .unwrap()
+                .$method($rhs.categorical().unwrap()),
             _ => unimplemented!(),
         }
     }};
 }
 
+/// Categoricals are compared by the ordering of their categories, so strings are converted to
+/// categoricals instead of the other way around.
+fn coerce_cmp_operands<'a>(
+    lhs: &'a Series,
+    rhs: &'a Series,
+) -> Result<(Cow<'a, Series>, Cow<'a, Series>)> {
+    match (lhs.dtype(), rhs.dtype()) {
+        (DataType::Categorical, DataType::Utf8) => Ok((
+            Cow::Borrowed(lhs),
+            Cow::Owned(rhs.cast_with_datatype(&DataType::Categorical)?),
+        )),
+        (DataType::Utf8, DataType::Categorical) => Ok((
+            Cow::Owned(lhs.cast_with_datatype(&DataType::Categorical)?),
+            Cow::Borrowed(rhs),
+        )),
+        _ => coerce_lhs_rhs(lhs, rhs),
+    }
+}
+
 impl ChunkCompare<&Series> for Series {
     fn eq_missing(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_operands(self, rhs).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), eq_missing)
     }
 
     /// Create a boolean mask by checking for equality.
     fn eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_operands(self, rhs).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), eq)
     }
 
     /// Create a boolean mask by checking for inequality.
     fn neq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_operands(self, rhs).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), neq)
     }
 
     /// Create a boolean mask by checking if lhs > rhs.
     fn gt(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_operands(self, rhs).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), gt)
     }
 
     /// Create a boolean mask by checking if lhs >= rhs.
     fn gt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_operands(self, rhs).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), gt_eq)
     }
 
     /// Create a boolean mask by checking if lhs < rhs.
     fn lt(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_operands(self, rhs).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), lt)
     }
 
     /// Create a boolean mask by checking if lhs <= rhs.
     fn lt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_operands(self, rhs).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), lt_eq)
     }
 }
@@ -124,6 +162,8 @@ impl ChunkCompare<&str> for Series {
     fn eq(&self, rhs: &str) -> BooleanChunked {
         if let Ok(a) = self.utf8() {
             a.eq(rhs)
+        } else if let Ok(a) = self.categorical() {
+            a.eq(rhs)
         } else {
             std::iter::repeat(false).take(self.len()).collect()
         }
@@ -132,6 +172,8 @@ impl ChunkCompare<&str> for Series {
     fn neq(&self, rhs: &str) -> BooleanChunked {
         if let Ok(a) = self.utf8() {
             a.neq(rhs)
+        } else if let Ok(a) = self.categorical() {
+            a.neq(rhs)
         } else {
             std::iter::repeat(false).take(self.len()).collect()
         }
@@ -140,6 +182,8 @@ impl ChunkCompare<&str> for Series {
     fn gt(&self, rhs: &str) -> BooleanChunked {
         if let Ok(a) = self.utf8() {
             a.gt(rhs)
+        } else if let Ok(a) = self.categorical() {
+            a.gt(rhs)
         } else {
             std::iter::repeat(false).take(self.len()).collect()
         }
@@ -148,6 +192,8 @@ impl ChunkCompare<&str> for Series {
     fn gt_eq(&self, rhs: &str) -> BooleanChunked {
         if let Ok(a) = self.utf8() {
             a.gt_eq(rhs)
+        } else if let Ok(a) = self.categorical() {
+            a.gt_eq(rhs)
         } else {
             std::iter::repeat(false).take(self.len()).collect()
         }
@@ -156,6 +202,8 @@ impl ChunkCompare<&str> for Series {
     fn lt(&self, rhs: &str) -> BooleanChunked {
         if let Ok(a) = self.utf8() {
             a.lt(rhs)
+        } else if let Ok(a) = self.categorical() {
+            a.lt(rhs)
         } else {
             std::iter::repeat(false).take(self.len()).collect()
         }
@@ -164,6 +212,8 @@ impl ChunkCompare<&str> for Series {
     fn lt_eq(&self, rhs: &str) -> BooleanChunked {
         if let Ok(a) = self.utf8() {
             a.lt_eq(rhs)
+        } else if let Ok(a) = self.categorical() {
+            a.lt_eq(rhs)
         } else {
             std::iter::repeat(false).take(self.len()).collect()
         }
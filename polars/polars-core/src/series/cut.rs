@@ -0,0 +1,283 @@
+//! Bin the values of a numeric Series.
+use crate::chunked_array::builder::CategoricalChunkedBuilder;
+use crate::prelude::*;
+
+fn check_breaks(breaks: &[f64], what: &str) -> Result<()> {
+    if breaks.len() < 2 {
+        return Err(PolarsError::ValueError(
+            format!("at least two {} are needed to define a bin", what).into(),
+        ));
+    }
+    if breaks.iter().any(|b| b.is_nan()) || breaks.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(PolarsError::ValueError(
+            format!("{} must be strictly increasing", what).into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Get the bin of every value. Bins are closed on the right, `(breaks[i], breaks[i + 1]]`.
+/// Values outside of the bins are null.
+fn bin_index(ca: &Float64Chunked, breaks: &[f64], include_lowest: bool) -> Vec<Option<usize>> {
+    let first = breaks[0];
+    let last = breaks[breaks.len() - 1];
+    ca.into_iter()
+        .map(|opt_v| {
+            let v = opt_v?;
+            if include_lowest && v == first {
+                Some(0)
+            } else if v > first && v <= last {
+                // the bin ends at the first break that is larger or equal
+                let end = breaks
+                    .binary_search_by(|b| b.partial_cmp(&v).unwrap())
+                    .unwrap_or_else(|idx| idx);
+                Some(end - 1)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn fmt_interval(lower: f64, upper: f64, closed_left: bool) -> String {
+    let open = if closed_left { '[' } else { '(' };
+    format!("{}{:?}, {:?}]", open, lower, upper)
+}
+
+impl Series {
+    fn bin_values(&self, op: &str) -> Result<Series> {
+        if !self.dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("{} not supported on dtype {:?}", op, self.dtype()).into(),
+            ));
+        }
+        self.cast_with_datatype(&DataType::Float64)
+    }
+
+    fn cut_impl(&self, breaks: &[f64], labels: &[String], include_lowest: bool) -> Result<Series> {
+        let s = self.bin_values("cut")?;
+        let bins = bin_index(s.f64().unwrap(), breaks, include_lowest);
+        let mut builder = CategoricalChunkedBuilder::new(self.name(), self.len());
+        // the categories are in bin order, also the bins that are empty
+        builder.register_categories(labels.iter().map(|l| l.as_str()));
+        builder.append_values(
+            bins.into_iter()
+                .map(|opt_bin| opt_bin.map(|bin| labels[bin].as_str())),
+        );
+        Ok(builder.finish().into_series())
+    }
+
+    /// Bin the values into the intervals `(breaks[i], breaks[i + 1]]`. The result is a
+    /// `Categorical` Series with a label per bin, by default the interval, e.g. `"(0.0, 1.5]"`.
+    /// Values outside of the bins and null values are null.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let s = Series::new("age", &[3, 17, 42, 70]);
+    /// let bins = s.cut(&[0.0, 18.0, 65.0, 120.0], Some(&["minor", "adult", "senior"])).unwrap();
+    /// assert_eq!(bins.dtype(), &DataType::Categorical);
+    /// ```
+    pub fn cut(&self, breaks: &[f64], labels: Option<&[&str]>) -> Result<Series> {
+        check_breaks(breaks, "breaks")?;
+        let labels = match labels {
+            Some(labels) if labels.len() != breaks.len() - 1 => {
+                return Err(PolarsError::ValueError(
+                    format!(
+                        "{} bins need {} labels, got {}",
+                        breaks.len() - 1,
+                        breaks.len() - 1,
+                        labels.len()
+                    )
+                    .into(),
+                ))
+            }
+            Some(labels) => labels.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            None => breaks
+                .windows(2)
+                .map(|w| fmt_interval(w[0], w[1], false))
+                .collect(),
+        };
+        self.cut_impl(breaks, &labels, false)
+    }
+
+    /// Bin the values by quantiles, e.g. `&[0.0, 0.25, 0.5, 0.75, 1.0]` for quartiles. The bin
+    /// edges are computed with linear interpolation, like
+    /// [quantile](crate::prelude::ChunkAgg::quantile) with [QuantileInterpolOptions::Linear],
+    /// and the lowest edge is included in the first bin. The result is a `Categorical` Series
+    /// labeled by the intervals. Returns an error if the quantiles lead to duplicate bin edges.
+    pub fn qcut(&self, quantiles: &[f64]) -> Result<Series> {
+        check_breaks(quantiles, "quantiles")?;
+        let s = self.bin_values("qcut")?;
+        let ca = s.f64().unwrap();
+        let breaks = match ca
+            .quantiles(quantiles, QuantileInterpolOptions::Linear)?
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(breaks) => breaks,
+            // only null values
+            None => return self.cut_impl(quantiles, &[], false),
+        };
+        if breaks.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(PolarsError::ValueError(
+                format!("the quantiles lead to duplicate bin edges: {:?}", breaks).into(),
+            ));
+        }
+        let labels = breaks
+            .windows(2)
+            .enumerate()
+            .map(|(i, w)| fmt_interval(w[0], w[1], i == 0))
+            .collect::<Vec<_>>();
+        self.cut_impl(&breaks, &labels, true)
+    }
+
+    /// Count the values in `bins` bins of equal width between the minimum and the maximum.
+    /// The bins are closed on the left, except for the last bin, which includes the maximum.
+    /// The result has the columns `"lower_bound"`, `"upper_bound"` and `"count"`. Null and
+    /// NaN values are not counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1.0, 2.0, 2.5, 4.0]);
+    /// let hist = s.hist(3).unwrap();
+    /// assert_eq!(hist.get_column_names(), &["lower_bound", "upper_bound", "count"]);
+    /// ```
+    pub fn hist(&self, bins: usize) -> Result<DataFrame> {
+        if bins == 0 {
+            return Err(PolarsError::ValueError(
+                "hist needs at least one bin".into(),
+            ));
+        }
+        let s = self.bin_values("hist")?;
+        let values = s
+            .f64()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .filter(|v| !v.is_nan())
+            .collect::<Vec<_>>();
+        if values.iter().any(|v| v.is_infinite()) {
+            return Err(PolarsError::ValueError(
+                "hist is not defined for infinite values".into(),
+            ));
+        }
+        let (min, max) = values
+            .iter()
+            .fold(None, |acc: Option<(f64, f64)>, &v| match acc {
+                Some((min, max)) => Some((min.min(v), max.max(v))),
+                None => Some((v, v)),
+            })
+            .unwrap_or((0.0, 1.0));
+        // a single value is centered in a range of width 1, like numpy does
+        let (min, max) = if min == max {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        };
+        let width = (max - min) / bins as f64;
+
+        let mut counts = vec![0u32; bins];
+        for v in values {
+            let bin = ((v - min) / width) as usize;
+            counts[std::cmp::min(bin, bins - 1)] += 1;
+        }
+        let lower = (0..bins)
+            .map(|i| min + i as f64 * width)
+            .collect::<Vec<_>>();
+        let upper = (1..=bins)
+            .map(|i| {
+                if i == bins {
+                    max
+                } else {
+                    min + i as f64 * width
+                }
+            })
+            .collect::<Vec<_>>();
+        DataFrame::new(vec![
+            Series::new("lower_bound", &lower),
+            Series::new("upper_bound", &upper),
+            Series::new("count", &counts),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_cut() {
+        let s = Series::new(
+            "a",
+            &[Some(0.0), Some(1.0), None, Some(2.5), Some(3.0), Some(4.0)],
+        );
+        let out = s.cut(&[0.0, 1.0, 3.0], None).unwrap();
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        assert_eq!(out.name(), "a");
+        let out = out.cast_with_datatype(&DataType::Utf8).unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[
+                None,
+                Some("(0.0, 1.0]"),
+                None,
+                Some("(1.0, 3.0]"),
+                Some("(1.0, 3.0]"),
+                None
+            ]
+        );
+        let out = s.cut(&[0.0, 1.0, 3.0], Some(&["low", "high"])).unwrap();
+        let out = out.cast_with_datatype(&DataType::Utf8).unwrap();
+        assert_eq!(out.utf8().unwrap().get(1), Some("low"));
+        assert!(s.cut(&[0.0, 1.0, 3.0], Some(&["low"])).is_err());
+
+        // the categories are in bin order, not in order of appearance
+        let _lock = crate::SINGLE_LOCK.lock();
+        let s = Series::new("a", &[2.0, 0.5]);
+        let out = s.cut(&[0.0, 1.0, 3.0, 5.0], None).unwrap();
+        let rev_map = out.categorical().unwrap().get_categorical_map().unwrap();
+        assert_eq!(rev_map.len(), 3);
+        assert_eq!(rev_map.get(0), Some("(0.0, 1.0]"));
+        assert_eq!(rev_map.get(1), Some("(1.0, 3.0]"));
+        assert_eq!(rev_map.get(2), Some("(3.0, 5.0]"));
+        assert!(s.cut(&[1.0, 0.0], None).is_err());
+    }
+
+    #[test]
+    fn test_qcut() {
+        let s = Series::new("a", &[1, 2, 3, 4, 5]);
+        let out = s.qcut(&[0.0, 0.5, 1.0]).unwrap();
+        let out = out.cast_with_datatype(&DataType::Utf8).unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[
+                Some("[1.0, 3.0]"),
+                Some("[1.0, 3.0]"),
+                Some("[1.0, 3.0]"),
+                Some("(3.0, 5.0]"),
+                Some("(3.0, 5.0]")
+            ]
+        );
+        let s = Series::new("a", &[1, 1, 1, 2]);
+        assert!(s.qcut(&[0.0, 0.5, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_hist() {
+        let s = Series::new("a", &[Some(1.0), Some(2.0), None, Some(2.5), Some(4.0)]);
+        let out = s.hist(3).unwrap();
+        assert_eq!(
+            Vec::from(out.column("lower_bound").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(2.0), Some(3.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("count").unwrap().u32().unwrap()),
+            &[Some(1), Some(2), Some(1)]
+        );
+        assert!(s.hist(0).is_err());
+    }
+}
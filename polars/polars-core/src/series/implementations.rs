@@ -1,6 +1,8 @@
 use super::private;
 use super::SeriesTrait;
 use crate::chunked_array::{
+    categorical::check_categorical_src,
+    decimal::{cast_to_decimal, format_decimal, MAX_PRECISION},
     ops::aggregate::{ChunkAggSeries, VarAggSeries},
     AsSinglePtr,
 };
@@ -8,7 +10,6 @@ use crate::fmt::FmtList;
 use crate::frame::group_by::*;
 use crate::frame::hash_join::{HashJoin, ZipOuterJoinColumn};
 use crate::prelude::*;
-#[cfg(feature = "object")]
 use crate::series::private::PrivateSeries;
 use ahash::RandomState;
 use arrow::array::{ArrayDataRef, ArrayRef};
@@ -42,7 +43,9 @@ where
     fn as_ref(&self) -> &ChunkedArray<T> {
         if &T::get_dtype() == self.dtype() ||
             // needed because we want to get ref of List no matter what the inner type is.
-            (matches!(T::get_dtype(), DataType::List(_)) && matches!(self.dtype(), DataType::List(_)) )
+            (matches!(T::get_dtype(), DataType::List(_)) && matches!(self.dtype(), DataType::List(_)) ) ||
+            // the time zone is not part of the type of a timestamp array.
+            matches!((T::get_dtype(), self.dtype()), (DataType::Timestamp(l, _), DataType::Timestamp(r, _)) if &l == r)
         {
             unsafe { &*(self as *const dyn SeriesTrait as *const ChunkedArray<T>) }
         } else {
@@ -55,6 +58,40 @@ where
     }
 }
 
+/// Set the time zone of a timestamp array that was cast with its `PolarsDataType`.
+fn with_time_zone<T: PolarsTimestampType>(
+    mut ca: ChunkedArray<T>,
+    tz: &Option<String>,
+) -> ChunkedArray<T> {
+    if ca.dtype() != &DataType::Timestamp(T::time_unit(), tz.clone()) {
+        ca.set_logical_dtype(DataType::Timestamp(T::time_unit(), tz.clone()));
+    }
+    ca
+}
+
+#[cfg(feature = "temporal")]
+macro_rules! apply_timestamp_method {
+    ($self:expr, $method:ident) => {
+        match $self.dtype() {
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => $self
+                .timestamp_nanosecond()?
+                .$method()
+                .map(|ca| ca.into_series()),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => $self
+                .timestamp_microsecond()?
+                .$method()
+                .map(|ca| ca.into_series()),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => $self
+                .timestamp_millisecond()?
+                .$method()
+                .map(|ca| ca.into_series()),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", dt).into(),
+            )),
+        }
+    };
+}
+
 macro_rules! impl_dyn_series {
     ($ca: ident) => {
         impl private::PrivateSeries for Wrap<$ca> {
@@ -106,8 +143,18 @@ macro_rules! impl_dyn_series {
                 &self,
                 groups: &[(usize, Vec<usize>)],
                 quantile: f64,
+                interpol: QuantileInterpolOptions,
             ) -> Option<Series> {
-                self.0.agg_quantile(groups, quantile)
+                self.0.agg_quantile(groups, quantile, interpol)
+            }
+
+            fn agg_quantiles(
+                &self,
+                groups: &[(usize, Vec<usize>)],
+                quantiles: &[f64],
+                interpol: QuantileInterpolOptions,
+            ) -> Option<Vec<Series>> {
+                self.0.agg_quantiles(groups, quantiles, interpol)
             }
 
             fn agg_median(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
@@ -181,6 +228,10 @@ macro_rules! impl_dyn_series {
                 self.0.cum_sum(reverse).into_series()
             }
 
+            fn cum_prod(&self, reverse: bool) -> Series {
+                self.0.cum_prod(reverse).into_series()
+            }
+
             fn rename(&mut self, name: &str) {
                 self.0.rename(name);
             }
@@ -496,6 +547,69 @@ macro_rules! impl_dyn_series {
                 }
             }
 
+            fn timestamp_nanosecond(&self) -> Result<&TimestampNanosecondChunked> {
+                if matches!(self.0.dtype(), DataType::Timestamp(TimeUnit::Nanosecond, _)) {
+                    unsafe {
+                        Ok(&*(self as *const dyn SeriesTrait as *const TimestampNanosecondChunked))
+                    }
+                } else {
+                    Err(PolarsError::DataTypeMisMatch(
+                        format!(
+                            "cannot unpack Series: {:?} of type {:?} into timestamp_nanosecond",
+                            self.name(),
+                            self.dtype(),
+                        )
+                        .into(),
+                    ))
+                }
+            }
+
+            fn timestamp_microsecond(&self) -> Result<&TimestampMicrosecondChunked> {
+                if matches!(
+                    self.0.dtype(),
+                    DataType::Timestamp(TimeUnit::Microsecond, _)
+                ) {
+                    unsafe {
+                        Ok(
+                            &*(self as *const dyn SeriesTrait
+                                as *const TimestampMicrosecondChunked),
+                        )
+                    }
+                } else {
+                    Err(PolarsError::DataTypeMisMatch(
+                        format!(
+                            "cannot unpack Series: {:?} of type {:?} into timestamp_microsecond",
+                            self.name(),
+                            self.dtype(),
+                        )
+                        .into(),
+                    ))
+                }
+            }
+
+            fn timestamp_millisecond(&self) -> Result<&TimestampMillisecondChunked> {
+                if matches!(
+                    self.0.dtype(),
+                    DataType::Timestamp(TimeUnit::Millisecond, _)
+                ) {
+                    unsafe {
+                        Ok(
+                            &*(self as *const dyn SeriesTrait
+                                as *const TimestampMillisecondChunked),
+                        )
+                    }
+                } else {
+                    Err(PolarsError::DataTypeMisMatch(
+                        format!(
+                            "cannot unpack Series: {:?} of type {:?} into timestamp_millisecond",
+                            self.name(),
+                            self.dtype(),
+                        )
+                        .into(),
+                    ))
+                }
+            }
+
             fn append_array(&mut self, other: ArrayRef) -> Result<()> {
                 self.0.append_array(other)
             }
@@ -507,7 +621,11 @@ macro_rules! impl_dyn_series {
             fn append(&mut self, other: &Series) -> Result<()> {
                 if self.0.dtype() == other.dtype() {
                     // todo! add object
-                    self.0.append(other.as_ref().as_ref());
+                    let other: &ChunkedArray<_> = other.as_ref().as_ref();
+                    if let (Some(l), Some(r)) = (&self.0.categorical_map, &other.categorical_map) {
+                        check_categorical_src(l, r)?;
+                    }
+                    self.0.append(other);
                     Ok(())
                 } else {
                     Err(PolarsError::DataTypeMisMatch(
@@ -517,7 +635,8 @@ macro_rules! impl_dyn_series {
             }
 
             fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
-                ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+                ChunkFilter::filter(&self.0, filter)
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             fn take_iter(
@@ -525,11 +644,16 @@ macro_rules! impl_dyn_series {
                 iter: &mut dyn Iterator<Item = usize>,
                 capacity: Option<usize>,
             ) -> Series {
-                ChunkTake::take(&self.0, iter, capacity).into_series()
+                ChunkTake::take(&self.0, iter, capacity)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn take_every(&self, n: usize) -> Series {
-                self.0.take_every(n).into_series()
+                self.0
+                    .take_every(n)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             unsafe fn take_iter_unchecked(
@@ -537,11 +661,14 @@ macro_rules! impl_dyn_series {
                 iter: &mut dyn Iterator<Item = usize>,
                 capacity: Option<usize>,
             ) -> Series {
-                ChunkTake::take_unchecked(&self.0, iter, capacity).into_series()
+                ChunkTake::take_unchecked(&self.0, iter, capacity)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             unsafe fn take_from_single_chunked(&self, idx: &UInt32Chunked) -> Result<Series> {
-                ChunkTake::take_from_single_chunked(&self.0, idx).map(|ca| ca.into_series())
+                ChunkTake::take_from_single_chunked(&self.0, idx)
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             unsafe fn take_opt_iter_unchecked(
@@ -549,7 +676,9 @@ macro_rules! impl_dyn_series {
                 iter: &mut dyn Iterator<Item = Option<usize>>,
                 capacity: Option<usize>,
             ) -> Series {
-                ChunkTake::take_opt_unchecked(&self.0, iter, capacity).into_series()
+                ChunkTake::take_opt_unchecked(&self.0, iter, capacity)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn take_opt_iter(
@@ -557,7 +686,9 @@ macro_rules! impl_dyn_series {
                 iter: &mut dyn Iterator<Item = Option<usize>>,
                 capacity: Option<usize>,
             ) -> Series {
-                ChunkTake::take_opt(&self.0, iter, capacity).into_series()
+                ChunkTake::take_opt(&self.0, iter, capacity)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn len(&self) -> usize {
@@ -565,19 +696,27 @@ macro_rules! impl_dyn_series {
             }
 
             fn rechunk(&self) -> Result<Series> {
-                ChunkOps::rechunk(&self.0).map(|ca| ca.into_series())
+                ChunkOps::rechunk(&self.0).map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             fn head(&self, length: Option<usize>) -> Series {
-                self.0.head(length).into_series()
+                self.0
+                    .head(length)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn tail(&self, length: Option<usize>) -> Series {
-                self.0.tail(length).into_series()
+                self.0
+                    .tail(length)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn expand_at_index(&self, index: usize, length: usize) -> Series {
-                ChunkExpandAtIndex::expand_at_index(&self.0, index, length).into_series()
+                ChunkExpandAtIndex::expand_at_index(&self.0, index, length)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn cast_with_datatype(&self, data_type: &DataType) -> Result<Series> {
@@ -608,10 +747,26 @@ macro_rules! impl_dyn_series {
                         ChunkCast::cast::<DurationMillisecondType>(&self.0)
                             .map(|ca| ca.into_series())
                     }
+                    Timestamp(TimeUnit::Nanosecond, tz) => {
+                        ChunkCast::cast::<TimestampNanosecondType>(&self.0)
+                            .map(|ca| with_time_zone(ca, tz).into_series())
+                    }
+                    Timestamp(TimeUnit::Microsecond, tz) => {
+                        ChunkCast::cast::<TimestampMicrosecondType>(&self.0)
+                            .map(|ca| with_time_zone(ca, tz).into_series())
+                    }
+                    Timestamp(TimeUnit::Millisecond, tz) => {
+                        ChunkCast::cast::<TimestampMillisecondType>(&self.0)
+                            .map(|ca| with_time_zone(ca, tz).into_series())
+                    }
                     List(_) => ChunkCast::cast::<ListType>(&self.0).map(|ca| ca.into_series()),
                     Categorical => {
                         ChunkCast::cast::<CategoricalType>(&self.0).map(|ca| ca.into_series())
                     }
+                    Decimal(precision, scale) => {
+                        cast_to_decimal(&self.0.clone().into_series(), *precision, *scale)
+                            .map(|ca| ca.into_series())
+                    }
                     dt => Err(PolarsError::Other(
                         format!("Casting to {:?} is not supported", dt).into(),
                     )),
@@ -639,7 +794,9 @@ macro_rules! impl_dyn_series {
             }
 
             fn sort(&self, reverse: bool) -> Series {
-                ChunkSort::sort(&self.0, reverse).into_series()
+                ChunkSort::sort(&self.0, reverse)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn argsort(&self, reverse: bool) -> Vec<usize> {
@@ -651,7 +808,8 @@ macro_rules! impl_dyn_series {
             }
 
             fn unique(&self) -> Result<Series> {
-                ChunkUnique::unique(&self.0).map(|ca| ca.into_series())
+                ChunkUnique::unique(&self.0)
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             fn n_unique(&self) -> Result<usize> {
@@ -688,7 +846,9 @@ macro_rules! impl_dyn_series {
             }
 
             fn reverse(&self) -> Series {
-                ChunkReverse::reverse(&self.0).into_series()
+                ChunkReverse::reverse(&self.0)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn as_single_ptr(&mut self) -> Result<usize> {
@@ -696,16 +856,24 @@ macro_rules! impl_dyn_series {
             }
 
             fn shift(&self, periods: i64) -> Series {
-                ChunkShift::shift(&self.0, periods).into_series()
+                ChunkShift::shift(&self.0, periods)
+                    .restore_logical_dtype(&self.0)
+                    .into_series()
             }
 
             fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Series> {
-                ChunkFillNone::fill_none(&self.0, strategy).map(|ca| ca.into_series())
+                ChunkFillNone::fill_none(&self.0, strategy)
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
+            }
+
+            fn interpolate_by(&self, by: &Series) -> Result<Series> {
+                ChunkFillNone::interpolate_by(&self.0, by.date64()?)
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             fn zip_with(&self, mask: &BooleanChunked, other: &Series) -> Result<Series> {
                 ChunkZip::zip_with(&self.0, mask, other.as_ref().as_ref())
-                    .map(|ca| ca.into_series())
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             fn sum_as_series(&self) -> Series {
@@ -729,44 +897,63 @@ macro_rules! impl_dyn_series {
             fn std_as_series(&self) -> Series {
                 VarAggSeries::std_as_series(&self.0)
             }
-            fn quantile_as_series(&self, quantile: f64) -> Result<Series> {
-                ChunkAggSeries::quantile_as_series(&self.0, quantile)
-            }
-            fn rolling_mean(
+            fn quantile_as_series(
                 &self,
-                window_size: usize,
-                weight: Option<&[f64]>,
-                ignore_null: bool,
+                quantile: f64,
+                interpol: QuantileInterpolOptions,
             ) -> Result<Series> {
-                ChunkWindow::rolling_mean(&self.0, window_size, weight, ignore_null)
-                    .map(|ca| ca.into_series())
+                ChunkAggSeries::quantile_as_series(&self.0, quantile, interpol)
             }
-            fn rolling_sum(
-                &self,
-                window_size: usize,
-                weight: Option<&[f64]>,
-                ignore_null: bool,
-            ) -> Result<Series> {
-                ChunkWindow::rolling_sum(&self.0, window_size, weight, ignore_null)
-                    .map(|ca| ca.into_series())
+            fn rolling_mean(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_mean(&self.0, options).map(|ca| ca.into_series())
             }
-            fn rolling_min(
-                &self,
-                window_size: usize,
-                weight: Option<&[f64]>,
-                ignore_null: bool,
-            ) -> Result<Series> {
-                ChunkWindow::rolling_min(&self.0, window_size, weight, ignore_null)
-                    .map(|ca| ca.into_series())
+            fn rolling_sum(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_sum(&self.0, options).map(|ca| ca.into_series())
             }
-            fn rolling_max(
-                &self,
-                window_size: usize,
-                weight: Option<&[f64]>,
-                ignore_null: bool,
-            ) -> Result<Series> {
-                ChunkWindow::rolling_max(&self.0, window_size, weight, ignore_null)
-                    .map(|ca| ca.into_series())
+            fn rolling_min(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_min(&self.0, options).map(|ca| ca.into_series())
+            }
+            fn rolling_max(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_max(&self.0, options).map(|ca| ca.into_series())
+            }
+            fn rolling_var(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_var(&self.0, options)
+            }
+            fn rolling_std(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_std(&self.0, options)
+            }
+            fn rolling_median(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_median(&self.0, options)
+            }
+            fn rolling_quantile(&self, quantile: f64, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_quantile(&self.0, quantile, options)
+            }
+            fn rolling_skew(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_skew(&self.0, options)
+            }
+            fn rolling_count(&self, options: &RollingOptions) -> Result<Series> {
+                ChunkWindow::rolling_count(&self.0, options).map(|ca| ca.into_series())
+            }
+            fn ewm_mean(&self, options: &EWMOptions) -> Result<Series> {
+                ChunkEwm::ewm_mean(&self.0, options)
+            }
+            fn ewm_std(&self, options: &EWMOptions) -> Result<Series> {
+                ChunkEwm::ewm_std(&self.0, options)
+            }
+            fn ewm_var(&self, options: &EWMOptions) -> Result<Series> {
+                ChunkEwm::ewm_var(&self.0, options)
+            }
+
+            fn rank(&self, options: RankOptions) -> Result<Series> {
+                ChunkRank::rank(&self.0, options)
+            }
+
+            fn diff(&self, n: usize, null_behavior: NullBehavior) -> Result<Series> {
+                ChunkDiff::diff(&self.0, n, null_behavior)
+            }
+
+            fn pct_change(&self, n: usize) -> Result<Series> {
+                ChunkDiff::pct_change(&self.0, n)
             }
 
             fn fmt_list(&self) -> String {
@@ -776,25 +963,37 @@ macro_rules! impl_dyn_series {
             #[cfg(feature = "temporal")]
             #[doc(cfg(feature = "temporal"))]
             fn hour(&self) -> Result<Series> {
-                self.date64().map(|ca| ca.hour().into_series())
+                match self.0.dtype() {
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, hour),
+                    _ => self.date64().map(|ca| ca.hour().into_series()),
+                }
             }
 
             #[cfg(feature = "temporal")]
             #[doc(cfg(feature = "temporal"))]
             fn minute(&self) -> Result<Series> {
-                self.date64().map(|ca| ca.minute().into_series())
+                match self.0.dtype() {
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, minute),
+                    _ => self.date64().map(|ca| ca.minute().into_series()),
+                }
             }
 
             #[cfg(feature = "temporal")]
             #[doc(cfg(feature = "temporal"))]
             fn second(&self) -> Result<Series> {
-                self.date64().map(|ca| ca.second().into_series())
+                match self.0.dtype() {
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, second),
+                    _ => self.date64().map(|ca| ca.second().into_series()),
+                }
             }
 
             #[cfg(feature = "temporal")]
             #[doc(cfg(feature = "temporal"))]
             fn nanosecond(&self) -> Result<Series> {
-                self.date64().map(|ca| ca.nanosecond().into_series())
+                match self.0.dtype() {
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, nanosecond),
+                    _ => self.date64().map(|ca| ca.nanosecond().into_series()),
+                }
             }
 
             #[cfg(feature = "temporal")]
@@ -803,6 +1002,7 @@ macro_rules! impl_dyn_series {
                 match self.0.dtype() {
                     DataType::Date32 => self.date32().map(|ca| ca.day().into_series()),
                     DataType::Date64 => self.date64().map(|ca| ca.day().into_series()),
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, day),
                     _ => Err(PolarsError::InvalidOperation(
                         format!("operation not supported on dtype {:?}", self.dtype()).into(),
                     )),
@@ -815,6 +1015,7 @@ macro_rules! impl_dyn_series {
                 match self.0.dtype() {
                     DataType::Date32 => self.date32().map(|ca| ca.ordinal().into_series()),
                     DataType::Date64 => self.date64().map(|ca| ca.ordinal().into_series()),
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, ordinal),
                     _ => Err(PolarsError::InvalidOperation(
                         format!("operation not supported on dtype {:?}", self.dtype()).into(),
                     )),
@@ -827,6 +1028,7 @@ macro_rules! impl_dyn_series {
                 match self.0.dtype() {
                     DataType::Date32 => self.date32().map(|ca| ca.month().into_series()),
                     DataType::Date64 => self.date64().map(|ca| ca.month().into_series()),
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, month),
                     _ => Err(PolarsError::InvalidOperation(
                         format!("operation not supported on dtype {:?}", self.dtype()).into(),
                     )),
@@ -839,6 +1041,7 @@ macro_rules! impl_dyn_series {
                 match self.0.dtype() {
                     DataType::Date32 => self.date32().map(|ca| ca.year().into_series()),
                     DataType::Date64 => self.date64().map(|ca| ca.year().into_series()),
+                    DataType::Timestamp(_, _) => apply_timestamp_method!(self, year),
                     _ => Err(PolarsError::InvalidOperation(
                         format!("operation not supported on dtype {:?}", self.dtype()).into(),
                     )),
@@ -853,7 +1056,7 @@ macro_rules! impl_dyn_series {
             fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
                 self.0
                     .sample_n(n, with_replacement)
-                    .map(|ca| ca.into_series())
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             #[cfg(feature = "random")]
@@ -861,7 +1064,7 @@ macro_rules! impl_dyn_series {
             fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
                 self.0
                     .sample_frac(frac, with_replacement)
-                    .map(|ca| ca.into_series())
+                    .map(|ca| ca.restore_logical_dtype(&self.0).into_series())
             }
 
             /// Raise a numeric series to the power of exponent.
@@ -900,6 +1103,9 @@ impl_dyn_series!(DurationMillisecondChunked);
 impl_dyn_series!(Date32Chunked);
 impl_dyn_series!(Date64Chunked);
 impl_dyn_series!(Time64NanosecondChunked);
+impl_dyn_series!(TimestampNanosecondChunked);
+impl_dyn_series!(TimestampMicrosecondChunked);
+impl_dyn_series!(TimestampMillisecondChunked);
 impl_dyn_series!(CategoricalChunked);
 
 #[cfg(feature = "object")]
@@ -1127,3 +1333,635 @@ where
         ObjectChunked::get_as_any(&self.0, index)
     }
 }
+
+impl PrivateSeries for Wrap<StructChunked> {}
+
+impl SeriesTrait for Wrap<StructChunked> {
+    fn rename(&mut self, name: &str) {
+        StructChunked::rename(&mut self.0, name)
+    }
+
+    fn array_data(&self) -> Vec<ArrayDataRef> {
+        StructChunked::array_data(&self.0)
+    }
+
+    fn chunk_lengths(&self) -> &Vec<usize> {
+        StructChunked::chunk_id(&self.0)
+    }
+
+    fn name(&self) -> &str {
+        StructChunked::name(&self.0)
+    }
+
+    fn field(&self) -> &Field {
+        StructChunked::ref_field(&self.0)
+    }
+
+    fn dtype(&self) -> &DataType {
+        StructChunked::dtype(&self.0)
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        StructChunked::chunks(&self.0)
+    }
+
+    fn struct_(&self) -> Result<&StructChunked> {
+        Ok(&self.0)
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        StructChunked::append_array(&mut self.0, other)
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Result<Series> {
+        StructChunked::slice(&self.0, offset, length).map(|ca| ca.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        if self.dtype() == other.dtype() {
+            StructChunked::append(&mut self.0, other.struct_()?);
+            Ok(())
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; data types don't match".into(),
+            ))
+        }
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>, capacity: Option<usize>) -> Series {
+        ChunkTake::take(&self.0, iter, capacity).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = usize>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter, capacity).into_series()
+    }
+
+    unsafe fn take_from_single_chunked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        ChunkTake::take_from_single_chunked(&self.0, idx).map(|ca| ca.into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_opt_unchecked(&self.0, iter, capacity).into_series()
+    }
+
+    fn take_opt_iter(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_opt(&self.0, iter, capacity).into_series()
+    }
+
+    fn len(&self) -> usize {
+        StructChunked::len(&self.0)
+    }
+
+    fn rechunk(&self) -> Result<Series> {
+        ChunkOps::rechunk(&self.0).map(|ca| ca.into_series())
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        StructChunked::head(&self.0, length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        StructChunked::tail(&self.0, length).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        ChunkTakeEvery::take_every(&self.0, n).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        ChunkExpandAtIndex::expand_at_index(&self.0, index, length).into_series()
+    }
+
+    fn cast_with_datatype(&self, data_type: &DataType) -> Result<Series> {
+        if self.dtype() == data_type {
+            Ok(self.0.clone().into_series())
+        } else {
+            Err(PolarsError::InvalidOperation(
+                format!("cannot cast a struct to {:?}", data_type).into(),
+            ))
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        StructChunked::get_any_value(&self.0, index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        StructChunked::get_any_value_unchecked(&self.0, index)
+    }
+
+    fn null_count(&self) -> usize {
+        StructChunked::null_count(&self.0)
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        StructChunked::is_null(&self.0)
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        StructChunked::is_not_null(&self.0)
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        StructChunked::null_bits(&self.0)
+    }
+
+    fn reverse(&self) -> Series {
+        ChunkReverse::reverse(&self.0).into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        ChunkShift::shift(&self.0, periods).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(Wrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[doc(cfg(feature = "random"))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        StructChunked::sample_n(&self.0, n, with_replacement).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "random")]
+    #[doc(cfg(feature = "random"))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        StructChunked::sample_frac(&self.0, frac, with_replacement).map(|ca| ca.into_series())
+    }
+}
+
+impl PrivateSeries for Wrap<BinaryChunked> {
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        self.0.vec_hash(random_state)
+    }
+
+    fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.0.agg_first(groups)
+    }
+
+    fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.0.agg_last(groups)
+    }
+
+    fn hash_join_inner(&self, other: &Series) -> Vec<(usize, usize)> {
+        HashJoin::hash_join_inner(&self.0, other.as_ref().as_ref())
+    }
+
+    fn hash_join_left(&self, other: &Series) -> Vec<(usize, Option<usize>)> {
+        HashJoin::hash_join_left(&self.0, other.as_ref().as_ref())
+    }
+
+    fn hash_join_outer(&self, other: &Series) -> Vec<(Option<usize>, Option<usize>)> {
+        HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
+    }
+
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<usize>, Option<usize>)],
+    ) -> Series {
+        ZipOuterJoinColumn::zip_outer_join_column(&self.0, right_column, opt_join_tuples)
+    }
+
+    fn group_tuples(&self, multithreaded: bool) -> Vec<(usize, Vec<usize>)> {
+        IntoGroupTuples::group_tuples(&self.0, multithreaded)
+    }
+}
+
+impl SeriesTrait for Wrap<BinaryChunked> {
+    fn rename(&mut self, name: &str) {
+        BinaryChunked::rename(&mut self.0, name)
+    }
+
+    fn array_data(&self) -> Vec<ArrayDataRef> {
+        BinaryChunked::array_data(&self.0)
+    }
+
+    fn chunk_lengths(&self) -> &Vec<usize> {
+        BinaryChunked::chunk_id(&self.0)
+    }
+
+    fn name(&self) -> &str {
+        BinaryChunked::name(&self.0)
+    }
+
+    fn field(&self) -> &Field {
+        BinaryChunked::ref_field(&self.0)
+    }
+
+    fn dtype(&self) -> &DataType {
+        BinaryChunked::dtype(&self.0)
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        BinaryChunked::chunks(&self.0)
+    }
+
+    fn binary(&self) -> Result<&BinaryChunked> {
+        Ok(&self.0)
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        BinaryChunked::append_array(&mut self.0, other)
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Result<Series> {
+        BinaryChunked::slice(&self.0, offset, length).map(|ca| ca.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        if self.dtype() == other.dtype() {
+            BinaryChunked::append(&mut self.0, other.binary()?);
+            Ok(())
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; data types don't match".into(),
+            ))
+        }
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>, capacity: Option<usize>) -> Series {
+        ChunkTake::take(&self.0, iter, capacity).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = usize>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter, capacity).into_series()
+    }
+
+    unsafe fn take_from_single_chunked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        ChunkTake::take_from_single_chunked(&self.0, idx).map(|ca| ca.into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_opt_unchecked(&self.0, iter, capacity).into_series()
+    }
+
+    fn take_opt_iter(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_opt(&self.0, iter, capacity).into_series()
+    }
+
+    fn len(&self) -> usize {
+        BinaryChunked::len(&self.0)
+    }
+
+    fn rechunk(&self) -> Result<Series> {
+        ChunkOps::rechunk(&self.0).map(|ca| ca.into_series())
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        BinaryChunked::head(&self.0, length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        BinaryChunked::tail(&self.0, length).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        ChunkTakeEvery::take_every(&self.0, n).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        ChunkExpandAtIndex::expand_at_index(&self.0, index, length).into_series()
+    }
+
+    fn cast_with_datatype(&self, data_type: &DataType) -> Result<Series> {
+        if self.dtype() == data_type {
+            Ok(self.0.clone().into_series())
+        } else {
+            Err(PolarsError::InvalidOperation(
+                format!("cannot cast binary to {:?}", data_type).into(),
+            ))
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        BinaryChunked::get_any_value(&self.0, index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        BinaryChunked::get_any_value_unchecked(&self.0, index)
+    }
+
+    fn null_count(&self) -> usize {
+        BinaryChunked::null_count(&self.0)
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        BinaryChunked::is_null(&self.0)
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        BinaryChunked::is_not_null(&self.0)
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        BinaryChunked::null_bits(&self.0)
+    }
+
+    fn reverse(&self) -> Series {
+        ChunkReverse::reverse(&self.0).into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        ChunkShift::shift(&self.0, periods).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(Wrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[doc(cfg(feature = "random"))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        BinaryChunked::sample_n(&self.0, n, with_replacement).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "random")]
+    #[doc(cfg(feature = "random"))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        BinaryChunked::sample_frac(&self.0, frac, with_replacement).map(|ca| ca.into_series())
+    }
+}
+
+impl PrivateSeries for Wrap<DecimalChunked> {
+    fn agg_mean(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
+        NumericAggSync::agg_mean(&self.0, groups)
+    }
+
+    fn agg_sum(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
+        NumericAggSync::agg_sum(&self.0, groups)
+    }
+
+    fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.0
+            .take(groups.iter().map(|(first, _)| *first), Some(groups.len()))
+            .into_series()
+    }
+
+    fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.0
+            .take(
+                groups.iter().map(|(_, idx)| idx[idx.len() - 1]),
+                Some(groups.len()),
+            )
+            .into_series()
+    }
+
+    fn subtract(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::subtract(&self.0, rhs)
+    }
+
+    fn add_to(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::add_to(&self.0, rhs)
+    }
+
+    fn multiply(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::multiply(&self.0, rhs)
+    }
+
+    fn divide(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::divide(&self.0, rhs)
+    }
+}
+
+impl SeriesTrait for Wrap<DecimalChunked> {
+    fn rename(&mut self, name: &str) {
+        DecimalChunked::rename(&mut self.0, name)
+    }
+
+    fn array_data(&self) -> Vec<ArrayDataRef> {
+        DecimalChunked::array_data(&self.0)
+    }
+
+    fn chunk_lengths(&self) -> &Vec<usize> {
+        DecimalChunked::chunk_id(&self.0)
+    }
+
+    fn name(&self) -> &str {
+        DecimalChunked::name(&self.0)
+    }
+
+    fn field(&self) -> &Field {
+        DecimalChunked::ref_field(&self.0)
+    }
+
+    fn dtype(&self) -> &DataType {
+        DecimalChunked::dtype(&self.0)
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        DecimalChunked::chunks(&self.0)
+    }
+
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        Ok(&self.0)
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        DecimalChunked::append_array(&mut self.0, other)
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Result<Series> {
+        DecimalChunked::slice(&self.0, offset, length).map(|ca| ca.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        if self.dtype() == other.dtype() {
+            DecimalChunked::append(&mut self.0, other.decimal()?);
+            Ok(())
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; data types don't match".into(),
+            ))
+        }
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>, capacity: Option<usize>) -> Series {
+        ChunkTake::take(&self.0, iter, capacity).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = usize>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter, capacity).into_series()
+    }
+
+    unsafe fn take_from_single_chunked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        ChunkTake::take_from_single_chunked(&self.0, idx).map(|ca| ca.into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_opt_unchecked(&self.0, iter, capacity).into_series()
+    }
+
+    fn take_opt_iter(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+        capacity: Option<usize>,
+    ) -> Series {
+        ChunkTake::take_opt(&self.0, iter, capacity).into_series()
+    }
+
+    fn len(&self) -> usize {
+        DecimalChunked::len(&self.0)
+    }
+
+    fn rechunk(&self) -> Result<Series> {
+        ChunkOps::rechunk(&self.0).map(|ca| ca.into_series())
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        DecimalChunked::head(&self.0, length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        DecimalChunked::tail(&self.0, length).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        ChunkTakeEvery::take_every(&self.0, n).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        ChunkExpandAtIndex::expand_at_index(&self.0, index, length).into_series()
+    }
+
+    fn cast_with_datatype(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::Decimal(precision, scale) => {
+                cast_to_decimal(&self.0.clone().into_series(), *precision, *scale)
+                    .map(|ca| ca.into_series())
+            }
+            DataType::Utf8 => {
+                let scale = self.0.scale();
+                let mut ca: Utf8Chunked = self
+                    .0
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| format_decimal(v, scale)))
+                    .collect();
+                ca.rename(self.name());
+                Ok(ca.into_series())
+            }
+            dt => self.0.to_f64().into_series().cast_with_datatype(dt),
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        DecimalChunked::get_any_value(&self.0, index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        DecimalChunked::get_any_value_unchecked(&self.0, index)
+    }
+
+    fn null_count(&self) -> usize {
+        DecimalChunked::null_count(&self.0)
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        DecimalChunked::is_null(&self.0)
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        DecimalChunked::is_not_null(&self.0)
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        DecimalChunked::null_bits(&self.0)
+    }
+
+    fn reverse(&self) -> Series {
+        ChunkReverse::reverse(&self.0).into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        ChunkShift::shift(&self.0, periods).into_series()
+    }
+
+    fn sum_as_series(&self) -> Series {
+        let v = self.0.try_sum().ok().flatten();
+        DecimalChunked::new_from_opt_iter(
+            self.name(),
+            MAX_PRECISION,
+            self.0.scale(),
+            std::iter::once(v),
+        )
+        .into_series()
+    }
+
+    fn mean_as_series(&self) -> Series {
+        let v = self.0.try_mean().ok().flatten();
+        self.0.new_with_same_type(std::iter::once(v)).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(Wrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[doc(cfg(feature = "random"))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        DecimalChunked::sample_n(&self.0, n, with_replacement).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "random")]
+    #[doc(cfg(feature = "random"))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        DecimalChunked::sample_frac(&self.0, frac, with_replacement).map(|ca| ca.into_series())
+    }
+}
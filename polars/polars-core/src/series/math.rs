@@ -0,0 +1,279 @@
+//! Elementwise math functions on numeric Series.
+use crate::prelude::*;
+use crate::series::arithmetic::coerce_lhs_rhs;
+
+macro_rules! unsupported {
+    ($op:expr, $dtype:expr) => {
+        Err(PolarsError::InvalidOperation(
+            format!("{} not supported on dtype {:?}", $op, $dtype).into(),
+        ))
+    };
+}
+
+macro_rules! apply_int_method {
+    ($self:expr, $op:expr, |$ca:ident| $body:expr) => {
+        match $self.dtype() {
+            DataType::UInt8 => {
+                let $ca = $self.u8().unwrap();
+                $body
+            }
+            DataType::UInt16 => {
+                let $ca = $self.u16().unwrap();
+                $body
+            }
+            DataType::UInt32 => {
+                let $ca = $self.u32().unwrap();
+                $body
+            }
+            DataType::UInt64 => {
+                let $ca = $self.u64().unwrap();
+                $body
+            }
+            DataType::Int8 => {
+                let $ca = $self.i8().unwrap();
+                $body
+            }
+            DataType::Int16 => {
+                let $ca = $self.i16().unwrap();
+                $body
+            }
+            DataType::Int32 => {
+                let $ca = $self.i32().unwrap();
+                $body
+            }
+            DataType::Int64 => {
+                let $ca = $self.i64().unwrap();
+                $body
+            }
+            dt => unsupported!($op, dt),
+        }
+    };
+}
+
+macro_rules! apply_num_method {
+    ($self:expr, $op:expr, |$ca:ident| $body:expr) => {
+        match $self.dtype() {
+            DataType::Float32 => {
+                let $ca = $self.f32().unwrap();
+                $body
+            }
+            DataType::Float64 => {
+                let $ca = $self.f64().unwrap();
+                $body
+            }
+            _ => apply_int_method!($self, $op, |$ca| $body),
+        }
+    };
+}
+
+/// Integers are cast to `Float64`.
+macro_rules! apply_float_method {
+    ($self:expr, $op:expr, |$ca:ident| $body:expr) => {
+        match $self.dtype() {
+            DataType::Float32 => {
+                let $ca = $self.f32().unwrap();
+                Ok($body.into_series())
+            }
+            DataType::Float64 => {
+                let $ca = $self.f64().unwrap();
+                Ok($body.into_series())
+            }
+            dt if dt.is_numeric() => {
+                let s = $self.cast_with_datatype(&DataType::Float64)?;
+                let $ca = s.f64().unwrap();
+                Ok($body.into_series())
+            }
+            dt => unsupported!($op, dt),
+        }
+    };
+}
+
+/// Integers are already rounded and are returned unchanged.
+macro_rules! apply_rounding_method {
+    ($self:expr, $op:expr, |$ca:ident| $body:expr) => {
+        match $self.dtype() {
+            DataType::Float32 => {
+                let $ca = $self.f32().unwrap();
+                Ok($body.into_series())
+            }
+            DataType::Float64 => {
+                let $ca = $self.f64().unwrap();
+                Ok($body.into_series())
+            }
+            dt if dt.is_numeric() => Ok($self.clone()),
+            dt => unsupported!($op, dt),
+        }
+    };
+}
+
+impl Series {
+    /// Take the square root. Integers are cast to `Float64`.
+    pub fn sqrt(&self) -> Result<Series> {
+        apply_float_method!(self, "sqrt", |ca| ca.sqrt())
+    }
+
+    /// Raise `e` to the power of the values. Integers are cast to `Float64`.
+    pub fn exp(&self) -> Result<Series> {
+        apply_float_method!(self, "exp", |ca| ca.exp())
+    }
+
+    /// Take the natural logarithm. Integers are cast to `Float64`.
+    pub fn log(&self) -> Result<Series> {
+        apply_float_method!(self, "log", |ca| ca.log())
+    }
+
+    /// Take the base 10 logarithm. Integers are cast to `Float64`.
+    pub fn log10(&self) -> Result<Series> {
+        apply_float_method!(self, "log10", |ca| ca.log10())
+    }
+
+    /// Compute `ln(1 + x)`. Integers are cast to `Float64`.
+    pub fn log1p(&self) -> Result<Series> {
+        apply_float_method!(self, "log1p", |ca| ca.log1p())
+    }
+
+    /// Compute the sine. Integers are cast to `Float64`.
+    pub fn sin(&self) -> Result<Series> {
+        apply_float_method!(self, "sin", |ca| ca.sin())
+    }
+
+    /// Compute the cosine. Integers are cast to `Float64`.
+    pub fn cos(&self) -> Result<Series> {
+        apply_float_method!(self, "cos", |ca| ca.cos())
+    }
+
+    /// Compute the tangent. Integers are cast to `Float64`.
+    pub fn tan(&self) -> Result<Series> {
+        apply_float_method!(self, "tan", |ca| ca.tan())
+    }
+
+    /// Compute the inverse sine. Integers are cast to `Float64`.
+    pub fn arcsin(&self) -> Result<Series> {
+        apply_float_method!(self, "arcsin", |ca| ca.arcsin())
+    }
+
+    /// Compute the inverse cosine. Integers are cast to `Float64`.
+    pub fn arccos(&self) -> Result<Series> {
+        apply_float_method!(self, "arccos", |ca| ca.arccos())
+    }
+
+    /// Compute the inverse tangent. Integers are cast to `Float64`.
+    pub fn arctan(&self) -> Result<Series> {
+        apply_float_method!(self, "arctan", |ca| ca.arctan())
+    }
+
+    /// Compute the hyperbolic sine. Integers are cast to `Float64`.
+    pub fn sinh(&self) -> Result<Series> {
+        apply_float_method!(self, "sinh", |ca| ca.sinh())
+    }
+
+    /// Compute the hyperbolic cosine. Integers are cast to `Float64`.
+    pub fn cosh(&self) -> Result<Series> {
+        apply_float_method!(self, "cosh", |ca| ca.cosh())
+    }
+
+    /// Compute the hyperbolic tangent. Integers are cast to `Float64`.
+    pub fn tanh(&self) -> Result<Series> {
+        apply_float_method!(self, "tanh", |ca| ca.tanh())
+    }
+
+    /// Round down to the nearest integer. Integer Series are returned unchanged.
+    pub fn floor(&self) -> Result<Series> {
+        apply_rounding_method!(self, "floor", |ca| ca.floor())
+    }
+
+    /// Round up to the nearest integer. Integer Series are returned unchanged.
+    pub fn ceil(&self) -> Result<Series> {
+        apply_rounding_method!(self, "ceil", |ca| ca.ceil())
+    }
+
+    /// Round to a number of decimals. Integer Series are returned unchanged.
+    /// See [round](#method.round) to round dates.
+    pub fn round_decimals(&self, decimals: u32) -> Result<Series> {
+        apply_rounding_method!(self, "round_decimals", |ca| ca.round(decimals))
+    }
+
+    /// Take the absolute value. The minimum value of a signed integer becomes null.
+    pub fn abs(&self) -> Result<Series> {
+        apply_num_method!(self, "abs", |ca| Ok(ca.abs().into_series()))
+    }
+
+    /// Get `-1` for negative values, `1` for positive values and `0` for zero.
+    pub fn sign(&self) -> Result<Series> {
+        apply_num_method!(self, "sign", |ca| Ok(ca.sign().into_series()))
+    }
+
+    /// Limit the values to the range `[min, max]`.
+    pub fn clip(&self, min: f64, max: f64) -> Result<Series> {
+        apply_num_method!(self, "clip", |ca| ca
+            .clip(min, max)
+            .map(|ca| ca.into_series()))
+    }
+
+    /// Bitwise and of two integer Series. They are cast to their supertype first.
+    pub fn bit_and(&self, rhs: &Series) -> Result<Series> {
+        let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+        apply_int_method!(lhs, "bit_and", |ca| ca
+            .bit_and((*rhs).as_ref().as_ref())
+            .map(|ca| ca.into_series()))
+    }
+
+    /// Bitwise or of two integer Series. They are cast to their supertype first.
+    pub fn bit_or(&self, rhs: &Series) -> Result<Series> {
+        let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+        apply_int_method!(lhs, "bit_or", |ca| ca
+            .bit_or((*rhs).as_ref().as_ref())
+            .map(|ca| ca.into_series()))
+    }
+
+    /// Bitwise exclusive or of two integer Series. They are cast to their supertype first.
+    pub fn xor(&self, rhs: &Series) -> Result<Series> {
+        let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+        apply_int_method!(lhs, "xor", |ca| ca
+            .xor((*rhs).as_ref().as_ref())
+            .map(|ca| ca.into_series()))
+    }
+
+    /// Shift the bits of an integer Series `n` positions to the left.
+    pub fn shl(&self, n: u32) -> Result<Series> {
+        apply_int_method!(self, "shl", |ca| Ok(ca.shl(n).into_series()))
+    }
+
+    /// Shift the bits of an integer Series `n` positions to the right.
+    pub fn shr(&self, n: u32) -> Result<Series> {
+        apply_int_method!(self, "shr", |ca| Ok(ca.shr(n).into_series()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_math() {
+        let s = Series::new("a", &[1, 4, 9]);
+        let out = s.sqrt().unwrap();
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(2.0), Some(3.0)]
+        );
+        assert!(s.floor().unwrap().series_equal(&s));
+        assert!(Series::new("a", &["a"]).sqrt().is_err());
+
+        let s = Series::new("a", &[1.5f32, -2.5]);
+        assert_eq!(s.round_decimals(0).unwrap().dtype(), &DataType::Float32);
+        assert_eq!(
+            Vec::from(s.clip(0.0, 1.0).unwrap().f32().unwrap()),
+            &[Some(1.0), Some(0.0)]
+        );
+
+        // the operands are cast to their supertype
+        let a = Series::new("a", &[0b1100i32, 0b0011]);
+        let b = Series::new("b", &[0b1010i64, 0b0001]);
+        let out = a.bit_or(&b).unwrap();
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(Vec::from(out.i64().unwrap()), &[Some(0b1110), Some(0b0011)]);
+        assert!(Series::new("a", &[1.0]).shl(1).is_err());
+    }
+}
@@ -2,15 +2,23 @@
 pub use crate::prelude::ChunkCompare;
 use crate::prelude::*;
 use arrow::{array::ArrayRef, buffer::Buffer};
+mod approx;
 pub(crate) mod arithmetic;
 mod comparison;
+mod cut;
 pub mod implementations;
 pub(crate) mod iterator;
+mod math;
+mod moment;
+#[cfg(feature = "temporal")]
+mod temporal;
 
 use crate::chunked_array::builder::get_list_builder;
+use crate::chunked_array::categorical;
 use crate::chunked_array::float::IsNan;
 use crate::chunked_array::kernels::cast::cast;
-use arrow::array::ArrayDataRef;
+use crate::chunked_array::struct_::struct_column;
+use arrow::array::{Array, ArrayDataRef, BinaryArray, StructArray};
 use itertools::Itertools;
 use num::NumCast;
 use std::any::Any;
@@ -57,7 +65,20 @@ pub(crate) mod private {
         fn agg_list(&self, _groups: &[(usize, Vec<usize>)]) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_quantile(&self, _groups: &[(usize, Vec<usize>)], _quantile: f64) -> Option<Series> {
+        fn agg_quantile(
+            &self,
+            _groups: &[(usize, Vec<usize>)],
+            _quantile: f64,
+            _interpol: QuantileInterpolOptions,
+        ) -> Option<Series> {
+            unimplemented!()
+        }
+        fn agg_quantiles(
+            &self,
+            _groups: &[(usize, Vec<usize>)],
+            _quantiles: &[f64],
+            _interpol: QuantileInterpolOptions,
+        ) -> Option<Vec<Series>> {
             unimplemented!()
         }
         fn agg_median(&self, _groups: &[(usize, Vec<usize>)]) -> Option<Series> {
@@ -136,6 +157,11 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         panic!("operation cum_sum not supported for this dtype")
     }
 
+    /// Get an array with the cumulative product computed at every element
+    fn cum_prod(&self, _reverse: bool) -> Series {
+        panic!("operation cum_prod not supported for this dtype")
+    }
+
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
@@ -270,6 +296,20 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         ))
     }
 
+    /// Unpack to ChunkedArray of dtype binary
+    fn binary(&self) -> Result<&BinaryChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != binary", self.dtype()).into(),
+        ))
+    }
+
+    /// Unpack to ChunkedArray of dtype decimal
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != decimal", self.dtype()).into(),
+        ))
+    }
+
     /// Unpack to ChunkedArray of dtype date32
     fn date32(&self) -> Result<&Date32Chunked> {
         Err(PolarsError::DataTypeMisMatch(
@@ -305,6 +345,27 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         ))
     }
 
+    /// Unpack to ChunkedArray of dtype timestamp_nanosecond
+    fn timestamp_nanosecond(&self) -> Result<&TimestampNanosecondChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != timestamp_nanosecond", self.dtype()).into(),
+        ))
+    }
+
+    /// Unpack to ChunkedArray of dtype timestamp_microsecond
+    fn timestamp_microsecond(&self) -> Result<&TimestampMicrosecondChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != timestamp_microsecond", self.dtype()).into(),
+        ))
+    }
+
+    /// Unpack to ChunkedArray of dtype timestamp_millisecond
+    fn timestamp_millisecond(&self) -> Result<&TimestampMillisecondChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != timestamp_millisecond", self.dtype()).into(),
+        ))
+    }
+
     /// Unpack to ChunkedArray of dtype list
     fn list(&self) -> Result<&ListChunked> {
         Err(PolarsError::DataTypeMisMatch(
@@ -319,6 +380,13 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         ))
     }
 
+    /// Unpack to ChunkedArray of dtype struct
+    fn struct_(&self) -> Result<&StructChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != struct", self.dtype()).into(),
+        ))
+    }
+
     /// Append Arrow array of same dtype to this Series.
     fn append_array(&mut self, _other: ArrayRef) -> Result<()> {
         unimplemented!()
@@ -609,6 +677,9 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// * Mean fill (replace None with the mean of the whole array)
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
+    /// * Zero fill (replace None with zero)
+    /// * One fill (replace None with one)
+    /// * Linear fill (interpolate linearly between the previous and the next value)
     ///
     /// *NOTE: If you want to fill the Nones with a value use the
     /// [`fill_none` operation on `ChunkedArray<T>`](../chunked_array/ops/trait.ChunkFillNone.html)*.
@@ -620,10 +691,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// fn example() -> Result<()> {
     ///     let s = Series::new("some_missing", &[Some(1), None, Some(2)]);
     ///
-    ///     let filled = s.fill_none(FillNoneStrategy::Forward)?;
+    ///     let filled = s.fill_none(FillNoneStrategy::Forward(None))?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(1), Some(2)]);
     ///
-    ///     let filled = s.fill_none(FillNoneStrategy::Backward)?;
+    ///     let filled = s.fill_none(FillNoneStrategy::Backward(None))?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(2), Some(2)]);
     ///
     ///     let filled = s.fill_none(FillNoneStrategy::Min)?;
@@ -635,6 +706,9 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     ///     let filled = s.fill_none(FillNoneStrategy::Mean)?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(1), Some(2)]);
     ///
+    ///     let filled = s.fill_none(FillNoneStrategy::Linear)?;
+    ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(2), Some(2)]);
+    ///
     ///     Ok(())
     /// }
     /// example();
@@ -643,6 +717,14 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
 
+    /// Linearly interpolate the None values, weighted by the time that passed between the
+    /// values of the `Date64` Series `by`.
+    fn interpolate_by(&self, _by: &Series) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("interpolate_by not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+
     /// Create a new ChunkedArray with values from self where the mask evaluates `true` and values
     /// from `other` where the mask evaluates `false`
     fn zip_with(&self, _mask: &BooleanChunked, _other: &Series) -> Result<Series> {
@@ -678,48 +760,132 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
     /// Get the quantile of the ChunkedArray as a new Series of length 1.
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         unimplemented!()
     }
     /// Apply a rolling mean to a Series. See:
     /// [ChunkedArray::rolling_mean](crate::prelude::ChunkWindow::rolling_mean).
-    fn rolling_mean(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Series> {
-        unimplemented!()
+    fn rolling_mean(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_mean not supported for dtype {:?}", self.dtype()).into(),
+        ))
     }
     /// Apply a rolling sum to a Series. See:
-    /// [ChunkedArray::rolling_mean](crate::prelude::ChunkWindow::rolling_sum).
-    fn rolling_sum(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Series> {
-        unimplemented!()
+    /// [ChunkedArray::rolling_sum](crate::prelude::ChunkWindow::rolling_sum).
+    fn rolling_sum(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_sum not supported for dtype {:?}", self.dtype()).into(),
+        ))
     }
     /// Apply a rolling min to a Series. See:
-    /// [ChunkedArray::rolling_mean](crate::prelude::ChunkWindow::rolling_min).
-    fn rolling_min(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Series> {
-        unimplemented!()
+    /// [ChunkedArray::rolling_min](crate::prelude::ChunkWindow::rolling_min).
+    fn rolling_min(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_min not supported for dtype {:?}", self.dtype()).into(),
+        ))
     }
     /// Apply a rolling max to a Series. See:
-    /// [ChunkedArray::rolling_mean](crate::prelude::ChunkWindow::rolling_max).
-    fn rolling_max(
-        &self,
-        _window_size: usize,
-        _weight: Option<&[f64]>,
-        _ignore_null: bool,
-    ) -> Result<Series> {
-        unimplemented!()
+    /// [ChunkedArray::rolling_max](crate::prelude::ChunkWindow::rolling_max).
+    fn rolling_max(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_max not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling variance to a Series. See:
+    /// [ChunkedArray::rolling_var](crate::prelude::ChunkWindow::rolling_var).
+    fn rolling_var(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_var not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling standard deviation to a Series. See:
+    /// [ChunkedArray::rolling_std](crate::prelude::ChunkWindow::rolling_std).
+    fn rolling_std(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_std not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling median to a Series. See:
+    /// [ChunkedArray::rolling_median](crate::prelude::ChunkWindow::rolling_median).
+    fn rolling_median(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_median not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling quantile to a Series. See:
+    /// [ChunkedArray::rolling_quantile](crate::prelude::ChunkWindow::rolling_quantile).
+    fn rolling_quantile(&self, _quantile: f64, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!(
+                "rolling_quantile not supported for dtype {:?}",
+                self.dtype()
+            )
+            .into(),
+        ))
+    }
+    /// Apply a rolling skew to a Series. See:
+    /// [ChunkedArray::rolling_skew](crate::prelude::ChunkWindow::rolling_skew).
+    fn rolling_skew(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_skew not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Count the non null values in a rolling window. See:
+    /// [ChunkedArray::rolling_count](crate::prelude::ChunkWindow::rolling_count).
+    fn rolling_count(&self, _options: &RollingOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_count not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+
+    /// Get the exponentially weighted moving mean. See:
+    /// [ChunkedArray::ewm_mean](crate::prelude::ChunkEwm::ewm_mean).
+    fn ewm_mean(&self, _options: &EWMOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("ewm_mean not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Get the exponentially weighted moving standard deviation. See:
+    /// [ChunkedArray::ewm_std](crate::prelude::ChunkEwm::ewm_std).
+    fn ewm_std(&self, _options: &EWMOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("ewm_std not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Get the exponentially weighted moving variance. See:
+    /// [ChunkedArray::ewm_var](crate::prelude::ChunkEwm::ewm_var).
+    fn ewm_var(&self, _options: &EWMOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("ewm_var not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+
+    /// Rank the values. See:
+    /// [ChunkedArray::rank](crate::prelude::ChunkRank::rank).
+    fn rank(&self, _options: RankOptions) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rank not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+
+    /// Compute the difference with the value `n` positions before. See:
+    /// [ChunkedArray::diff](crate::prelude::ChunkDiff::diff).
+    fn diff(&self, _n: usize, _null_behavior: NullBehavior) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("diff not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+
+    /// Compute the change as a fraction with the value `n` positions before. See:
+    /// [ChunkedArray::pct_change](crate::prelude::ChunkDiff::pct_change).
+    fn pct_change(&self, _n: usize) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("pct_change not supported for dtype {:?}", self.dtype()).into(),
+        ))
     }
 
     fn fmt_list(&self) -> String {
@@ -803,6 +969,15 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.str_fmt(fmt).into_series()),
             DataType::Date64 => self.date64().map(|ca| ca.str_fmt(fmt).into_series()),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => self
+                .timestamp_nanosecond()
+                .and_then(|ca| ca.str_fmt(fmt).map(|ca| ca.into_series())),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => self
+                .timestamp_microsecond()
+                .and_then(|ca| ca.str_fmt(fmt).map(|ca| ca.into_series())),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => self
+                .timestamp_millisecond()
+                .and_then(|ca| ca.str_fmt(fmt).map(|ca| ca.into_series())),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -974,6 +1149,15 @@ impl Series {
         Ok(self)
     }
 
+    /// Get a chunk as the arrow array that is written to IPC and Parquet. This is the same as
+    /// the underlying chunk, except for categoricals, which are converted to dictionary arrays.
+    pub fn to_arrow(&self, chunk_idx: usize) -> ArrayRef {
+        match self.dtype() {
+            DataType::Categorical => self.categorical().unwrap().to_dictionary_array(chunk_idx),
+            _ => self.chunks()[chunk_idx].clone(),
+        }
+    }
+
     /// Sort in place.
     pub fn sort_in_place(&mut self, reverse: bool) -> &mut Self {
         self.get_inner_mut().sort_in_place(reverse);
@@ -993,6 +1177,55 @@ impl Series {
     {
         self.0.cast_with_datatype(&N::get_dtype())
     }
+
+    /// Set the time zone of a timestamp Series. The instants in time don't change, only the
+    /// local time in which they are displayed.
+    #[cfg(feature = "temporal")]
+    #[doc(cfg(feature = "temporal"))]
+    pub fn convert_time_zone(&self, tz: &str) -> Result<Self> {
+        match self.dtype() {
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => self
+                .timestamp_nanosecond()?
+                .convert_time_zone(tz)
+                .map(|ca| ca.into_series()),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => self
+                .timestamp_microsecond()?
+                .convert_time_zone(tz)
+                .map(|ca| ca.into_series()),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => self
+                .timestamp_millisecond()?
+                .convert_time_zone(tz)
+                .map(|ca| ca.into_series()),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("cannot convert the time zone of dtype {:?}", dt).into(),
+            )),
+        }
+    }
+
+    /// Keep the local time of a timestamp Series, but interpret it in another time zone.
+    /// If `tz` is `None`, the local time is interpreted as UTC.
+    #[cfg(feature = "temporal")]
+    #[doc(cfg(feature = "temporal"))]
+    pub fn replace_time_zone(&self, tz: Option<&str>) -> Result<Self> {
+        match self.dtype() {
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => self
+                .timestamp_nanosecond()?
+                .replace_time_zone(tz)
+                .map(|ca| ca.into_series()),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => self
+                .timestamp_microsecond()?
+                .replace_time_zone(tz)
+                .map(|ca| ca.into_series()),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => self
+                .timestamp_millisecond()?
+                .replace_time_zone(tz)
+                .map(|ca| ca.into_series()),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("cannot replace the time zone of dtype {:?}", dt).into(),
+            )),
+        }
+    }
+
     /// Returns `None` if the array is empty or only contains null values.
     /// ```
     /// # use polars_core::prelude::*;
@@ -1055,6 +1288,30 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Get an array with the number of non null values up to and including every element.
+    /// Null values get the count of the values before them.
+    pub fn cum_count(&self, reverse: bool) -> Series {
+        let mask = self.is_not_null();
+        let iter: Box<dyn Iterator<Item = Option<bool>>> = match reverse {
+            false => Box::new(mask.into_iter()),
+            true => Box::new(mask.into_iter().rev()),
+        };
+        let ca: NoNull<UInt32Chunked> = iter
+            .scan(0u32, |count, valid| {
+                if valid == Some(true) {
+                    *count += 1
+                }
+                Some(*count)
+            })
+            .collect();
+        let mut ca = ca.into_inner();
+        if reverse {
+            ca = ca.reverse()
+        }
+        ca.rename(self.name());
+        ca.into_series()
+    }
+
     /// Explode a list or utf8 Series. This expands every item to a new row..
     pub fn explode(&self) -> Result<Series> {
         match self.dtype() {
@@ -1070,6 +1327,16 @@ impl Series {
         }
     }
 
+    /// Replace the NaN values of a float Series with `value` (note this is different than
+    /// missing/ null). Series of other types are returned unchanged.
+    pub fn fill_nan(&self, value: f64) -> Series {
+        match self.dtype() {
+            DataType::Float32 => self.f32().unwrap().fill_nan(value as f32).into_series(),
+            DataType::Float64 => self.f64().unwrap().fill_nan(value).into_series(),
+            _ => self.clone(),
+        }
+    }
+
     /// Check if float value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> Result<BooleanChunked> {
         match self.dtype() {
@@ -1171,6 +1438,21 @@ impl<'a, T: AsRef<[Option<&'a str>]>> NamedFrom<T, [Option<&'a str>]> for Series
     }
 }
 
+impl<'a, T: AsRef<[&'a [u8]]>> NamedFrom<T, [&'a [u8]]> for Series {
+    fn new(name: &str, v: T) -> Self {
+        let mut ca: BinaryChunked = v.as_ref().iter().copied().collect();
+        ca.rename(name);
+        ca.into_series()
+    }
+}
+impl<'a, T: AsRef<[Option<&'a [u8]>]>> NamedFrom<T, [Option<&'a [u8]>]> for Series {
+    fn new(name: &str, v: T) -> Self {
+        let mut ca: BinaryChunked = v.as_ref().iter().copied().collect();
+        ca.rename(name);
+        ca.into_series()
+    }
+}
+
 impl_named_from!([String], Utf8Type, new_from_slice);
 impl_named_from!([bool], BooleanType, new_from_slice);
 impl_named_from!([u8], UInt8Type, new_from_slice);
@@ -1242,6 +1524,30 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
                     .collect_vec();
                 Ok(Utf8Chunked::new_from_chunks(name, chunks).into_series())
             }
+            ArrowDataType::LargeBinary => {
+                Ok(BinaryChunked::new_from_chunks(name, chunks).into_series())
+            }
+            ArrowDataType::Decimal(_, _) => {
+                Ok(DecimalChunked::new_from_chunks(name, chunks).into_series())
+            }
+            ArrowDataType::Binary => {
+                let mut builder = BinaryChunkedBuilder::new(
+                    name,
+                    chunks.iter().map(|arr| arr.len()).sum(),
+                    chunks.iter().map(|arr| arr.get_values_size()).sum(),
+                );
+                for arr in &chunks {
+                    let arr = arr.as_any().downcast_ref::<BinaryArray>().unwrap();
+                    for i in 0..arr.len() {
+                        if arr.is_null(i) {
+                            builder.append_null()
+                        } else {
+                            builder.append_value(arr.value(i))
+                        }
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
             ArrowDataType::List(_) => {
                 let chunks = chunks
                     .iter()
@@ -1287,9 +1593,47 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
             ArrowDataType::Duration(TimeUnit::Millisecond) => {
                 Ok(DurationMillisecondChunked::new_from_chunks(name, chunks).into_series())
             }
+            ArrowDataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                Ok(TimestampNanosecondChunked::new_from_chunks(name, chunks).into_series())
+            }
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, _) => {
+                Ok(TimestampMicrosecondChunked::new_from_chunks(name, chunks).into_series())
+            }
+            ArrowDataType::Timestamp(TimeUnit::Millisecond, _) => {
+                Ok(TimestampMillisecondChunked::new_from_chunks(name, chunks).into_series())
+            }
             ArrowDataType::LargeList(_) => {
                 Ok(ListChunked::new_from_chunks(name, chunks).into_series())
             }
+            ArrowDataType::Struct(_) => {
+                // rebuild the struct from its fields so that the fields get polars dtypes
+                let mut cas = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                        let fields = arr
+                            .column_names()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, field_name)| {
+                                Series::try_from((field_name, struct_column(arr, i)))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        StructChunked::new(name, &fields)
+                    })
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter();
+                let mut out = cas.next().unwrap();
+                for ca in cas {
+                    out.append(&ca)
+                }
+                Ok(out.into_series())
+            }
+            ArrowDataType::Dictionary(_, values)
+                if matches!(**values, ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) =>
+            {
+                Ok(categorical::from_dictionary_arrays(name, &chunks)?.into_series())
+            }
             dt => Err(PolarsError::InvalidOperation(
                 format!("Cannot create polars series from {:?}", dt).into(),
             )),
@@ -1366,4 +1710,14 @@ mod test {
         let s2 = Series::new("b", &[3.0]);
         assert!(s1.append(&s2).is_err())
     }
+
+    #[test]
+    fn series_cum_count() {
+        let s = Series::new("a", &[Some("a"), None, Some("c")]);
+        let out = s.cum_count(false);
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.u32().unwrap()), &[Some(1), Some(1), Some(2)]);
+        let out = s.cum_count(true);
+        assert_eq!(Vec::from(out.u32().unwrap()), &[Some(2), Some(1), Some(1)]);
+    }
 }
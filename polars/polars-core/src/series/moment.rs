@@ -0,0 +1,167 @@
+//! Skewness, kurtosis and mode of a Series and of the groups of a Series.
+use crate::chunked_array::ops::moment::{kurtosis, mode_idx, skew};
+use crate::prelude::*;
+use rayon::prelude::*;
+
+/// Find the mode of the values at some indexes of an array.
+trait ModeIndex {
+    fn mode_index(&self, idx: &mut dyn Iterator<Item = usize>) -> Option<usize>;
+}
+
+impl<T> ModeIndex for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    fn mode_index(&self, idx: &mut dyn Iterator<Item = usize>) -> Option<usize> {
+        mode_idx(idx.filter_map(|i| self.get(i).map(|v| (i, v))).collect())
+    }
+}
+
+impl ModeIndex for Utf8Chunked {
+    fn mode_index(&self, idx: &mut dyn Iterator<Item = usize>) -> Option<usize> {
+        mode_idx(idx.filter_map(|i| self.get(i).map(|v| (i, v))).collect())
+    }
+}
+
+impl ModeIndex for BooleanChunked {
+    fn mode_index(&self, idx: &mut dyn Iterator<Item = usize>) -> Option<usize> {
+        mode_idx(idx.filter_map(|i| self.get(i).map(|v| (i, v))).collect())
+    }
+}
+
+impl ModeIndex for CategoricalChunked {
+    fn mode_index(&self, idx: &mut dyn Iterator<Item = usize>) -> Option<usize> {
+        mode_idx(idx.filter_map(|i| self.get(i).map(|v| (i, v))).collect())
+    }
+}
+
+impl Series {
+    fn moment_values(&self, op: &str) -> Result<Series> {
+        if !self.dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("{} not supported on dtype {:?}", op, self.dtype()).into(),
+            ));
+        }
+        self.cast_with_datatype(&DataType::Float64)
+    }
+
+    fn mode_index(&self, idx: &mut dyn Iterator<Item = usize>) -> Result<Option<usize>> {
+        use DataType::*;
+        match self.dtype() {
+            Utf8 => Ok(self.utf8().unwrap().mode_index(idx)),
+            Boolean => Ok(self.bool().unwrap().mode_index(idx)),
+            Categorical => Ok(self.categorical().unwrap().mode_index(idx)),
+            UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | Float32
+            | Float64
+            | Date32
+            | Date64
+            | Time64(TimeUnit::Nanosecond)
+            | Duration(TimeUnit::Nanosecond)
+            | Duration(TimeUnit::Millisecond)
+            | Timestamp(TimeUnit::Nanosecond, _)
+            | Timestamp(TimeUnit::Microsecond, _)
+            | Timestamp(TimeUnit::Millisecond, _) => {
+                Ok(apply_method_numeric_series!(self, mode_index, idx))
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("mode not supported on dtype {:?}", dt).into(),
+            )),
+        }
+    }
+
+    /// Compute the sample skewness of a numeric Series.
+    /// See [ChunkedArray::skew](crate::prelude::ChunkMoment::skew).
+    pub fn skew(&self, bias: bool) -> Result<Option<f64>> {
+        let s = self.moment_values("skew")?;
+        Ok(s.f64().unwrap().skew(bias))
+    }
+
+    /// Compute the sample kurtosis of a numeric Series.
+    /// See [ChunkedArray::kurtosis](crate::prelude::ChunkMoment::kurtosis).
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Result<Option<f64>> {
+        let s = self.moment_values("kurtosis")?;
+        Ok(s.f64().unwrap().kurtosis(fisher, bias))
+    }
+
+    /// Get the most frequent value as a Series of length 1. If multiple values are the most
+    /// frequent, the smallest is returned. See [ChunkedArray::mode](crate::prelude::ChunkMode::mode).
+    pub fn mode(&self) -> Result<Series> {
+        let opt_idx = self.mode_index(&mut (0..self.len()))?;
+        Ok(self.take_opt_iter(&mut std::iter::once(opt_idx), Some(1)))
+    }
+
+    /// Compute the sample skewness per group.
+    pub fn agg_skew(&self, groups: &[(usize, Vec<usize>)], bias: bool) -> Result<Series> {
+        let s = self.moment_values("skew")?;
+        let ca = s.f64().unwrap();
+        let mut out: Float64Chunked = groups
+            .into_par_iter()
+            .map(|(_, idx)| skew(idx.iter().filter_map(|&i| ca.get(i)), bias))
+            .collect();
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+
+    /// Compute the sample kurtosis per group.
+    pub fn agg_kurtosis(
+        &self,
+        groups: &[(usize, Vec<usize>)],
+        fisher: bool,
+        bias: bool,
+    ) -> Result<Series> {
+        let s = self.moment_values("kurtosis")?;
+        let ca = s.f64().unwrap();
+        let mut out: Float64Chunked = groups
+            .into_par_iter()
+            .map(|(_, idx)| kurtosis(idx.iter().filter_map(|&i| ca.get(i)), fisher, bias))
+            .collect();
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+
+    /// Get the most frequent value per group.
+    pub fn agg_mode(&self, groups: &[(usize, Vec<usize>)]) -> Result<Series> {
+        let idx = groups
+            .iter()
+            .map(|(first, idx)| {
+                // a group without valid values only has nulls, so the first row is a null
+                self.mode_index(&mut idx.iter().copied())
+                    .map(|opt_idx| opt_idx.unwrap_or(*first))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.take_iter(&mut idx.into_iter(), Some(groups.len())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_moments() {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(3), Some(10), Some(3)]);
+        assert_eq!(s.mode().unwrap().i32().unwrap().get(0), Some(3));
+        assert!(s.skew(false).unwrap().unwrap() > 0.0);
+        assert!(Series::new("a", &["a"]).skew(false).is_err());
+
+        let groups = vec![(0, vec![0, 1, 2, 3]), (4, vec![4, 5]), (2, vec![2])];
+        let modes = s.agg_mode(&groups).unwrap();
+        assert_eq!(Vec::from(modes.i32().unwrap()), &[Some(1), Some(3), None]);
+        let skew = s.agg_skew(&groups, true).unwrap();
+        assert_eq!(
+            Vec::from(skew.f64().unwrap()),
+            &[Some(0.0), Some(0.0), None]
+        );
+
+        let s = Series::new("a", &["b", "a", "b"]);
+        assert_eq!(s.mode().unwrap().utf8().unwrap().get(0), Some("b"));
+    }
+}
@@ -0,0 +1,70 @@
+//! Calendar operations on Date32/Date64 Series.
+use crate::prelude::*;
+
+macro_rules! apply_date_method {
+    ($self:expr, |$ca:ident| $body:expr) => {
+        match $self.dtype() {
+            DataType::Date32 => {
+                let $ca = $self.date32().unwrap();
+                $body
+            }
+            DataType::Date64 => {
+                let $ca = $self.date64().unwrap();
+                $body
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", dt).into(),
+            )),
+        }
+    };
+}
+
+impl Series {
+    /// Truncate a Date32/Date64 Series to a multiple of the interval `every`, e.g. `"1h"`.
+    /// See [Date64Chunked::truncate](crate::datatypes::Date64Chunked::truncate).
+    pub fn truncate(&self, every: &str) -> Result<Series> {
+        apply_date_method!(self, |ca| ca.truncate(every).map(|ca| ca.into_series()))
+    }
+
+    /// Round a Date32/Date64 Series to the nearest multiple of the interval `every`.
+    /// See [Date64Chunked::round](crate::datatypes::Date64Chunked::round).
+    /// See [round_decimals](#method.round_decimals) to round numbers.
+    pub fn round(&self, every: &str) -> Result<Series> {
+        apply_date_method!(self, |ca| ca.round(every).map(|ca| ca.into_series()))
+    }
+
+    /// Add a calendar aware interval like `"1mo"` to a Date32/Date64 Series.
+    pub fn offset_by(&self, by: &str) -> Result<Series> {
+        apply_date_method!(self, |ca| ca.offset_by(by).map(|ca| ca.into_series()))
+    }
+
+    /// Get the ISO day of the week of a Date32/Date64 Series, starting at 0 for Monday.
+    pub fn weekday(&self) -> Result<Series> {
+        apply_date_method!(self, |ca| Ok(ca.weekday().into_series()))
+    }
+
+    /// Get the ISO week number of a Date32/Date64 Series.
+    pub fn week(&self) -> Result<Series> {
+        apply_date_method!(self, |ca| Ok(ca.week().into_series()))
+    }
+
+    /// Get the quarter of a Date32/Date64 Series.
+    pub fn quarter(&self) -> Result<Series> {
+        apply_date_method!(self, |ca| Ok(ca.quarter().into_series()))
+    }
+
+    /// Check if the years of a Date32/Date64 Series are leap years.
+    pub fn is_leap_year(&self) -> Result<Series> {
+        apply_date_method!(self, |ca| Ok(ca.is_leap_year().into_series()))
+    }
+
+    /// Move a Date32/Date64 Series to the first day of the month.
+    pub fn month_start(&self) -> Result<Series> {
+        apply_date_method!(self, |ca| Ok(ca.month_start().into_series()))
+    }
+
+    /// Move a Date32/Date64 Series to the last day of the month.
+    pub fn month_end(&self) -> Result<Series> {
+        apply_date_method!(self, |ca| Ok(ca.month_end().into_series()))
+    }
+}
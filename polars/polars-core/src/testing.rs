@@ -10,6 +10,13 @@ impl Series {
         if self.null_count() != other.null_count() {
             return false;
         }
+        if let (Ok(l), Ok(r)) = (self.struct_(), other.struct_()) {
+            return l.struct_fields() == r.struct_fields()
+                && l.fields()
+                    .iter()
+                    .zip(r.fields())
+                    .all(|(l, r)| l.series_equal(&r));
+        }
         match self.eq(other).sum() {
             None => false,
             Some(sum) => sum as usize == self.len(),
@@ -28,6 +35,13 @@ impl Series {
         if self.null_count() == self.len() {
             return true;
         }
+        if let (Ok(l), Ok(r)) = (self.struct_(), other.struct_()) {
+            return l.struct_fields() == r.struct_fields()
+                && l.fields()
+                    .iter()
+                    .zip(r.fields())
+                    .all(|(l, r)| l.series_equal_missing(&r));
+        }
         match self.eq_missing(other).sum() {
             None => false,
             Some(sum) => sum as usize == self.len(),
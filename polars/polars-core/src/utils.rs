@@ -1,3 +1,4 @@
+use crate::chunked_array::decimal::{integer_precision, MAX_PRECISION};
 use crate::prelude::*;
 use crate::POOL;
 pub use chrono;
@@ -239,6 +240,9 @@ macro_rules! match_arrow_data_type_apply_macro {
             DataType::Time64(TimeUnit::Nanosecond) => $macro!(Time64NanosecondType $(, $opt_args)*),
             DataType::Duration(TimeUnit::Nanosecond) => $macro!(DurationNanosecondType $(, $opt_args)*),
             DataType::Duration(TimeUnit::Millisecond) => $macro!(DurationMillisecondType $(, $opt_args)*),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => $macro!(TimestampNanosecondType $(, $opt_args)*),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => $macro!(TimestampMicrosecondType $(, $opt_args)*),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => $macro!(TimestampMillisecondType $(, $opt_args)*),
             _ => unimplemented!(),
         }
     }};
@@ -265,6 +269,9 @@ macro_rules! apply_method_all_arrow_series {
             DataType::Time64(TimeUnit::Nanosecond) => $self.time64_nanosecond().unwrap().$method($($args),*),
             DataType::Duration(TimeUnit::Nanosecond) => $self.duration_nanosecond().unwrap().$method($($args),*),
             DataType::Duration(TimeUnit::Millisecond) => $self.duration_millisecond().unwrap().$method($($args),*),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => $self.timestamp_nanosecond().unwrap().$method($($args),*),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => $self.timestamp_microsecond().unwrap().$method($($args),*),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => $self.timestamp_millisecond().unwrap().$method($($args),*),
             DataType::List(_) => $self.list().unwrap().$method($($args),*),
             _ => unimplemented!()
         }
@@ -292,6 +299,9 @@ macro_rules! apply_method_numeric_series {
             DataType::Time64(TimeUnit::Nanosecond) => $self.time64_nanosecond().unwrap().$method($($args),*),
             DataType::Duration(TimeUnit::Nanosecond) => $self.duration_nanosecond().unwrap().$method($($args),*),
             DataType::Duration(TimeUnit::Millisecond) => $self.duration_millisecond().unwrap().$method($($args),*),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => $self.timestamp_nanosecond().unwrap().$method($($args),*),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => $self.timestamp_microsecond().unwrap().$method($($args),*),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => $self.timestamp_millisecond().unwrap().$method($($args),*),
 
             _ => unimplemented!(),
         }
@@ -352,6 +362,44 @@ macro_rules! df {
     }
 }
 
+/// Get the output type of adding or subtracting temporal types, e.g. a datetime minus a
+/// datetime is a duration and a datetime plus a duration is a datetime. Returns `None` if this
+/// is not such an operation and the operands should be cast to their supertype instead.
+pub fn get_temporal_arithmetic_type(
+    l: &DataType,
+    r: &DataType,
+    subtract: bool,
+) -> Option<DataType> {
+    use DataType::*;
+    let is_datetime = |dt: &DataType| matches!(dt, Date32 | Date64 | Timestamp(_, _));
+    // Date32 has no time of the day, so we add durations to a Date64.
+    let datetime_type = |dt: &DataType| match dt {
+        Date32 => Date64,
+        dt => dt.clone(),
+    };
+    // durations are only supported in nanoseconds and milliseconds
+    let has_sub_ms_unit = |dt: &DataType| {
+        matches!(
+            dt,
+            Timestamp(TimeUnit::Microsecond, _) | Timestamp(TimeUnit::Nanosecond, _)
+        )
+    };
+
+    match (l, r) {
+        (l, r) if subtract && is_datetime(l) && is_datetime(r) => {
+            if has_sub_ms_unit(l) || has_sub_ms_unit(r) {
+                Some(Duration(TimeUnit::Nanosecond))
+            } else {
+                Some(Duration(TimeUnit::Millisecond))
+            }
+        }
+        (l, Duration(_)) if is_datetime(l) => Some(datetime_type(l)),
+        (Duration(_), r) if !subtract && is_datetime(r) => Some(datetime_type(r)),
+        (Duration(l), Duration(r)) if l != r => Some(Duration(TimeUnit::Nanosecond)),
+        _ => None,
+    }
+}
+
 /// Given two datatypes, determine the supertype that both types can safely be cast to
 pub fn get_supertype(l: &DataType, r: &DataType) -> Result<DataType> {
     match _get_supertype(l, r) {
@@ -507,6 +555,34 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         (Date64, Float32) => Some(Float64),
         (Date64, Float64) => Some(Float64),
 
+        (Binary, Binary) => Some(Binary),
+
+        (Categorical, Categorical) => Some(Categorical),
+
+        (Decimal(l_precision, l_scale), Decimal(r_precision, r_scale)) => {
+            let scale = std::cmp::max(*l_scale, *r_scale);
+            let int_digits = std::cmp::max(
+                l_precision.saturating_sub(*l_scale),
+                r_precision.saturating_sub(*r_scale),
+            );
+            Some(Decimal(
+                std::cmp::min(MAX_PRECISION, int_digits + scale),
+                *scale,
+            ))
+        }
+        (Decimal(precision, scale), dt) if integer_precision(dt).is_some() => {
+            let int_digits = std::cmp::max(
+                precision.saturating_sub(*scale),
+                integer_precision(dt).unwrap(),
+            );
+            Some(Decimal(
+                std::cmp::min(MAX_PRECISION, int_digits + scale),
+                *scale,
+            ))
+        }
+        (Decimal(_, _), Float32) => Some(Float64),
+        (Decimal(_, _), Float64) => Some(Float64),
+
         (Utf8, _) => Some(Utf8),
         (_, Utf8) => Some(Utf8),
 
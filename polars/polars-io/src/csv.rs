@@ -547,4 +547,25 @@ mod test {
             .unwrap()
             .series_equal(&Series::new("column_3", &[3, 3])));
     }
+
+    #[test]
+    fn test_read_decimal() {
+        let csv = r#"item,price
+        apple,0.10
+        pear,1.255
+        kiwi,"#;
+
+        let file = Cursor::new(csv);
+        let schema = Schema::new(vec![Field::new("price", DataType::Decimal(10, 2))]);
+        let df = CsvReader::new(file)
+            .with_dtype_overwrite(Some(&schema))
+            .finish()
+            .unwrap();
+        let price = df.column("price").unwrap();
+        assert_eq!(price.dtype(), &DataType::Decimal(10, 2));
+        assert_eq!(price.get(0), AnyValue::Decimal(10, 2));
+        assert_eq!(price.get(1), AnyValue::Decimal(126, 2));
+        assert_eq!(price.get(2), AnyValue::Null);
+        assert_eq!(price.sum_as_series().get(0), AnyValue::Decimal(136, 2));
+    }
 }
@@ -1,5 +1,6 @@
 use crate::csv::CsvEncoding;
 use crate::csv_core::parser::skip_whitespace;
+use polars_core::chunked_array::decimal::{fits_precision, parse_decimal};
 use polars_core::prelude::*;
 use std::fmt::Debug;
 
@@ -172,6 +173,40 @@ impl ParsedBuffer<BooleanType> for Vec<Option<bool>> {
     }
 }
 
+/// Parse a decimal like `-123.45`. Returns `None` if the value is not a valid decimal or if it
+/// has more than `precision` digits.
+pub(crate) fn parse_decimal_bytes(bytes: &[u8], precision: usize, scale: usize) -> Option<i128> {
+    let s = std::str::from_utf8(bytes).ok()?;
+    parse_decimal(s, scale).filter(|v| fits_precision(*v, precision))
+}
+
+fn parse_decimal_buffer(
+    buf: &mut Vec<Option<i128>>,
+    bytes: &[u8],
+    ignore_errors: bool,
+    start_pos: usize,
+    precision: usize,
+    scale: usize,
+) -> Result<()> {
+    let (bytes, _) = skip_whitespace(bytes);
+    match parse_decimal_bytes(bytes, precision, scale) {
+        Some(v) => buf.push(Some(v)),
+        None if ignore_errors || bytes.is_empty() => buf.push(None),
+        None => {
+            return Err(PolarsError::Other(
+                format!(
+                    "Error while parsing value {} at byte position {} as {:?}",
+                    String::from_utf8_lossy(bytes),
+                    start_pos,
+                    DataType::Decimal(precision, scale)
+                )
+                .into(),
+            ))
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn init_buffers(
     projection: &[usize],
     capacity: usize,
@@ -195,6 +230,9 @@ fn field_to_builder(i: usize, capacity: usize, schema: &SchemaRef) -> Result<Buf
         &DataType::Float32 => Buffer::Float32(Vec::with_capacity(capacity)),
         &DataType::Float64 => Buffer::Float64(Vec::with_capacity(capacity)),
         &DataType::Utf8 => Buffer::Utf8(Vec::with_capacity(capacity), 0),
+        &DataType::Decimal(precision, scale) => {
+            Buffer::Decimal(Vec::with_capacity(capacity), precision, scale)
+        }
         other => {
             return Err(PolarsError::Other(
                 format!("Unsupported data type {:?} when reading a csv", other).into(),
@@ -215,6 +253,8 @@ pub(crate) enum Buffer {
     Float64(Vec<Option<f64>>),
     /// Stores the Utf8 fields and the total string length seen for that column
     Utf8(Vec<Utf8Field>, usize),
+    /// Stores the decimal values with their precision and scale
+    Decimal(Vec<Option<i128>>, usize, usize),
 }
 
 impl Default for Buffer {
@@ -275,6 +315,9 @@ impl Buffer {
                 ignore_errors,
                 start_pos,
             ),
+            Decimal(buf, precision, scale) => {
+                parse_decimal_buffer(buf, bytes, ignore_errors, start_pos, *precision, *scale)
+            }
             Utf8(buf, len) => {
                 *len += bytes.len();
 
@@ -379,6 +422,18 @@ where
                 .collect();
             Ok(ca.into_series())
         }
+        Buffer::Decimal(_, precision, scale) => {
+            let (precision, scale) = (*precision, *scale);
+            let values = buffers
+                .into_iter()
+                .filter_map(|buf| match buf {
+                    Buffer::Decimal(buf, _, _) => Some(buf),
+                    _ => None,
+                })
+                .flat_map(|v| v.into_iter());
+            let ca = DecimalChunked::new_from_opt_iter("", precision, scale, values);
+            Ok(ca.into_series())
+        }
         Buffer::Utf8(_, _) => {
             let buffers = buffers
                 .into_iter()
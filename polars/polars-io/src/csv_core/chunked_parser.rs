@@ -1,5 +1,5 @@
 use crate::csv::CsvEncoding;
-use crate::csv_core::buffer::PrimitiveParser;
+use crate::csv_core::buffer::{parse_decimal_bytes, PrimitiveParser};
 use crate::csv_core::utils::parse_bytes_with_encoding;
 use crate::PhysicalIOExpr;
 use crate::ScanAggregation;
@@ -31,6 +31,9 @@ fn field_to_builder(i: usize, capacity: usize, schema: &SchemaRef) -> Result<Bui
         &DataType::Float32 => Builder::Float32(PrimitiveChunkedBuilder::new(name, capacity)),
         &DataType::Float64 => Builder::Float64(PrimitiveChunkedBuilder::new(name, capacity)),
         &DataType::Utf8 => Builder::Utf8(Utf8ChunkedBuilder::new(name, capacity, capacity * 32)),
+        &DataType::Decimal(precision, scale) => {
+            Builder::Decimal(DecimalChunkedBuilder::new(name, capacity, precision, scale))
+        }
         other => {
             return Err(PolarsError::Other(
                 format!("Unsupported data type {:?} when reading a csv", other).into(),
@@ -73,6 +76,14 @@ pub(crate) fn add_to_builders_core(
                 add_to_primitive_core(rows, *i, builder.f64(), ignore_parser_error)
             }
             DataType::Utf8 => add_to_utf8_builder_core(rows, *i, builder.utf8(), encoding),
+            DataType::Decimal(precision, scale) => add_to_decimal_core(
+                rows,
+                *i,
+                builder.decimal(),
+                *precision,
+                *scale,
+                ignore_parser_error,
+            ),
             _ => panic!("datatype not supported"),
         }
     };
@@ -188,6 +199,44 @@ fn add_to_bool_core(
     Ok(())
 }
 
+#[inline]
+fn add_to_decimal_core(
+    rows: &[PolarsCsvRecord],
+    col_idx: usize,
+    builder: &mut DecimalChunkedBuilder,
+    precision: usize,
+    scale: usize,
+    ignore_parser_errors: bool,
+) -> Result<()> {
+    for row in rows.iter() {
+        match row.get(col_idx) {
+            Some(bytes) => {
+                if bytes.is_empty() {
+                    builder.append_null();
+                    continue;
+                }
+                match parse_decimal_bytes(bytes, precision, scale) {
+                    Some(v) => builder.append_value(v),
+                    None if ignore_parser_errors => builder.append_null(),
+                    None => {
+                        return Err(PolarsError::Other(
+                            format!(
+                                "Error while parsing value {} for column {} as {:?}",
+                                String::from_utf8_lossy(bytes),
+                                col_idx,
+                                DataType::Decimal(precision, scale)
+                            )
+                            .into(),
+                        ));
+                    }
+                }
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub(crate) struct PolarsCsvRecord {
     out: Vec<u8>,
@@ -319,6 +368,7 @@ pub(crate) enum Builder {
     Float32(PrimitiveChunkedBuilder<Float32Type>),
     Float64(PrimitiveChunkedBuilder<Float64Type>),
     Utf8(Utf8ChunkedBuilder),
+    Decimal(DecimalChunkedBuilder),
 }
 
 impl Builder {
@@ -371,6 +421,13 @@ impl Builder {
         }
     }
 
+    fn decimal(&mut self) -> &mut DecimalChunkedBuilder {
+        match self {
+            Builder::Decimal(builder) => builder,
+            _ => panic!("implementation error"),
+        }
+    }
+
     fn into_series(self) -> Series {
         use Builder::*;
         match self {
@@ -384,6 +441,7 @@ impl Builder {
             Float32(b) => b.finish().into_series(),
             Float64(b) => b.finish().into_series(),
             Boolean(b) => b.finish().into_series(),
+            Decimal(b) => b.finish().into_series(),
         }
     }
 }
@@ -131,4 +131,84 @@ mod test {
         let df_read = IPCReader::new(buf).finish().unwrap();
         assert!(df.frame_equal(&df_read));
     }
+
+    #[test]
+    fn write_and_read_ipc_time_zone() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let s = TimestampMillisecondChunked::new_from_slice("t", &[1616891400000, 1616895000000])
+            .into_series()
+            .convert_time_zone("Europe/Amsterdam")
+            .unwrap();
+        let mut df = DataFrame::new(vec![s]).unwrap();
+
+        IPCWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IPCReader::new(buf).finish().unwrap();
+        assert_eq!(
+            df_read.column("t").unwrap().dtype(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("Europe/Amsterdam".into()))
+        );
+        assert!(df.frame_equal(&df_read));
+    }
+
+    #[test]
+    fn write_and_read_ipc_struct() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let fields = [
+            Series::new("a", &[1, 2, 3]),
+            Series::new("b", &["x", "y", "z"]),
+        ];
+        let s = StructChunked::new("s", &fields).unwrap().into_series();
+        let mut df = DataFrame::new(vec![Series::new("id", &[0, 1, 2]), s]).unwrap();
+
+        IPCWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IPCReader::new(buf).finish().unwrap();
+        assert_eq!(df_read.schema(), df.schema());
+        assert!(df.frame_equal(&df_read));
+    }
+
+    #[test]
+    fn write_and_read_ipc_binary() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let values: &[Option<&[u8]>] = &[Some(b"\x00\x01"), None, Some(b"\xff")];
+        let mut df = DataFrame::new(vec![Series::new("bin", values)]).unwrap();
+
+        IPCWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IPCReader::new(buf).finish().unwrap();
+        assert_eq!(df_read.column("bin").unwrap().dtype(), &DataType::Binary);
+        assert!(df.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    fn write_and_read_ipc_categorical() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let s = Series::new("cat", &[Some("b"), None, Some("a"), Some("b")])
+            .cast::<CategoricalType>()
+            .unwrap();
+        let mut df = DataFrame::new(vec![s]).unwrap();
+
+        IPCWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IPCReader::new(buf).finish().unwrap();
+        let s_read = df_read.column("cat").unwrap();
+        assert_eq!(s_read.dtype(), &DataType::Categorical);
+        assert!(s_read
+            .cast::<Utf8Type>()
+            .unwrap()
+            .series_equal_missing(&df.column("cat").unwrap().cast::<Utf8Type>().unwrap()));
+    }
 }
@@ -166,4 +166,22 @@ mod test {
         assert_eq!("d", df.get_columns()[3].name());
         assert_eq!((12, 4), df.shape());
     }
+
+    #[test]
+    fn read_json_nested() {
+        let nested_json = r#"{"a":1, "b":{"c":"x", "d":1.5}}
+{"a":2, "b":{"c":"y", "d":-3.0}}"#;
+        let file = Cursor::new(nested_json);
+        let df = JsonReader::new(file).infer_schema(None).finish().unwrap();
+
+        assert_eq!(
+            df.column("b").unwrap().dtype(),
+            &DataType::Struct(vec![
+                Field::new("c", DataType::Utf8),
+                Field::new("d", DataType::Float64)
+            ])
+        );
+        let df = df.unnest("b").unwrap();
+        assert_eq!(df.get_column_names(), &["a", "c", "d"]);
+    }
 }
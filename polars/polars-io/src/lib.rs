@@ -155,6 +155,17 @@ pub enum ScanAggregation {
         column: String,
         alias: Option<String>,
     },
+    /// The batches produce HyperLogLog sketches, that are merged when finished.
+    ApproxNUnique {
+        column: String,
+        alias: Option<String>,
+    },
+    /// The batches produce t-digests, that are merged when finished.
+    ApproxQuantile {
+        column: String,
+        quantile: f64,
+        alias: Option<String>,
+    },
 }
 
 impl ScanAggregation {
@@ -167,6 +178,8 @@ impl ScanAggregation {
             Max { column, .. } => df.column(column)?.max_as_series(),
             First { column, .. } => df.column(column)?.head(Some(1)),
             Last { column, .. } => df.column(column)?.tail(Some(1)),
+            ApproxNUnique { column, .. } => df.column(column)?.hll_sketch()?,
+            ApproxQuantile { column, .. } => df.column(column)?.tdigest_sketch()?,
         };
         Ok(s)
     }
@@ -210,6 +223,20 @@ impl ScanAggregation {
                 }
                 Ok(s)
             }
+            ApproxNUnique { column, alias } => {
+                let n_unique = df.column(column)?.merge_hll_sketches()?;
+                let name = alias.as_ref().unwrap_or(column);
+                Ok(UInt32Chunked::new_from_slice(name, &[n_unique]).into_series())
+            }
+            ApproxQuantile {
+                column,
+                quantile,
+                alias,
+            } => {
+                let opt_q = df.column(column)?.merge_tdigest_sketches(*quantile)?;
+                let name = alias.as_ref().unwrap_or(column);
+                Ok(Float64Chunked::new_from_opt_slice(name, &[opt_q]).into_series())
+            }
         }
     }
 }
@@ -17,6 +17,13 @@
 use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
 use crate::prelude::*;
 use crate::{PhysicalIOExpr, ScanAggregation};
+use arrow::array::{
+    make_array, Array, ArrayData, ArrayRef, DecimalBuilder, FixedSizeBinaryArray, Int32Array,
+    Int64Array, StructArray,
+};
+use arrow::compute::cast;
+use arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use arrow::error::ArrowError;
 use arrow::record_batch::RecordBatchReader;
 use parquet_lib::file::reader::{FileReader, SerializedFileReader};
 pub use parquet_lib::file::serialized_reader::SliceableCursor;
@@ -78,6 +85,7 @@ where
         let batch_size = set_batch_size(batch_size, self.stop_after_n_rows);
 
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
+        let file_schema = Arc::new(arrow_reader.get_schema()?);
         let record_reader = match projection {
             Some(projection) => {
                 arrow_reader.get_record_reader_by_columns(projection.iter().copied(), batch_size)
@@ -85,7 +93,7 @@ where
             None => arrow_reader.get_record_reader(batch_size),
         }?;
         finish_reader(
-            record_reader,
+            ParquetBatchReader::new(record_reader, file_schema),
             rechunk,
             self.stop_after_n_rows,
             predicate,
@@ -118,6 +126,177 @@ impl ArrowReader for ParquetRecordBatchReader {
     }
 }
 
+/// Cast an array returned by the parquet record reader to the arrow data type that is stored in
+/// the file metadata. The record reader only knows the parquet types, so e.g. dictionaries are
+/// returned as strings, timestamps without their time zone and decimals as their unscaled
+/// integers. The fields of structs are restored recursively.
+fn restore_arrow_dtype(arr: &ArrayRef, dtype: &ArrowDataType) -> ArrowResult<ArrayRef> {
+    match (arr.data_type(), dtype) {
+        (ArrowDataType::Int32, ArrowDataType::Decimal(precision, scale)) => {
+            let arr = arr.as_any().downcast_ref::<Int32Array>().unwrap();
+            let iter = (0..arr.len()).map(|i| match arr.is_null(i) {
+                true => None,
+                false => Some(arr.value(i) as i128),
+            });
+            decimal_array(iter, *precision, *scale)
+        }
+        (ArrowDataType::Int64, ArrowDataType::Decimal(precision, scale)) => {
+            let arr = arr.as_any().downcast_ref::<Int64Array>().unwrap();
+            let iter = (0..arr.len()).map(|i| match arr.is_null(i) {
+                true => None,
+                false => Some(arr.value(i) as i128),
+            });
+            decimal_array(iter, *precision, *scale)
+        }
+        (ArrowDataType::FixedSizeBinary(_), ArrowDataType::Decimal(precision, scale)) => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            let iter = (0..arr.len()).map(|i| match arr.is_null(i) {
+                true => None,
+                false => Some(decimal_from_be_bytes(arr.value(i))),
+            });
+            decimal_array(iter, *precision, *scale)
+        }
+        // same physical representation, only set the time zone
+        (ArrowDataType::Timestamp(tu, _), ArrowDataType::Timestamp(file_tu, _))
+            if tu == file_tu =>
+        {
+            let data = arr.data();
+            Ok(make_array(Arc::new(ArrayData::new(
+                dtype.clone(),
+                arr.len(),
+                Some(arr.null_count()),
+                data.null_bitmap()
+                    .clone()
+                    .map(|bitmap| bitmap.into_buffer()),
+                data.offset(),
+                data.buffers().to_vec(),
+                data.child_data().to_vec(),
+            ))))
+        }
+        (ArrowDataType::Timestamp(_, _), ArrowDataType::Timestamp(_, _)) => cast(arr, dtype),
+        (ArrowDataType::Struct(_), ArrowDataType::Struct(file_fields)) => {
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = file_fields
+                .iter()
+                .map(|file_field| {
+                    let field_arr = arr.column_by_name(file_field.name()).ok_or_else(|| {
+                        ArrowError::SchemaError(format!(
+                            "struct field {} not found",
+                            file_field.name()
+                        ))
+                    })?;
+                    let field_arr = restore_arrow_dtype(field_arr, file_field.data_type())?;
+                    let field = ArrowField::new(
+                        file_field.name(),
+                        field_arr.data_type().clone(),
+                        file_field.is_nullable(),
+                    );
+                    Ok((field, field_arr))
+                })
+                .collect::<ArrowResult<Vec<_>>>()?;
+            Ok(Arc::new(StructArray::from(fields)))
+        }
+        (ArrowDataType::Utf8, ArrowDataType::Dictionary(keys, values))
+        | (ArrowDataType::LargeUtf8, ArrowDataType::Dictionary(keys, values))
+            if matches!(**values, ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) =>
+        {
+            // arrow only builds string dictionaries from `Utf8` arrays
+            let arr = cast(arr, &ArrowDataType::Utf8)?;
+            cast(
+                &arr,
+                &ArrowDataType::Dictionary(keys.clone(), Box::new(ArrowDataType::Utf8)),
+            )
+        }
+        _ => Ok(arr.clone()),
+    }
+}
+
+/// Parquet stores decimals in a fixed length byte array as a big-endian two's complement
+/// integer.
+fn decimal_from_be_bytes(bytes: &[u8]) -> i128 {
+    let sign = match bytes.first() {
+        Some(b) if b & 0x80 != 0 => -1,
+        _ => 0,
+    };
+    bytes.iter().fold(sign, |acc, b| (acc << 8) | *b as i128)
+}
+
+fn decimal_array(
+    iter: impl Iterator<Item = Option<i128>>,
+    precision: usize,
+    scale: usize,
+) -> ArrowResult<ArrayRef> {
+    let mut builder = DecimalBuilder::new(iter.size_hint().0, precision, scale);
+    for opt_v in iter {
+        match opt_v {
+            Some(v) => builder.append_value(v)?,
+            None => builder.append_null()?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Wraps the parquet record reader and restores the arrow data types of the file schema.
+struct ParquetBatchReader {
+    reader: ParquetRecordBatchReader,
+    file_schema: ArrowSchemaRef,
+}
+
+impl ParquetBatchReader {
+    fn new(reader: ParquetRecordBatchReader, file_schema: ArrowSchemaRef) -> Self {
+        ParquetBatchReader {
+            reader,
+            file_schema,
+        }
+    }
+
+    fn restore_batch(&self, batch: RecordBatch) -> ArrowResult<RecordBatch> {
+        let schema = batch.schema();
+        let mut fields = Vec::with_capacity(batch.num_columns());
+        let mut columns = Vec::with_capacity(batch.num_columns());
+
+        for (field, arr) in schema.fields().iter().zip(batch.columns()) {
+            let arr = match self.file_schema.field_with_name(field.name()) {
+                Ok(file_field) if file_field.data_type() != field.data_type() => {
+                    restore_arrow_dtype(arr, file_field.data_type())?
+                }
+                _ => arr.clone(),
+            };
+            fields.push(ArrowField::new(
+                field.name(),
+                arr.data_type().clone(),
+                field.is_nullable(),
+            ));
+            columns.push(arr);
+        }
+        RecordBatch::try_new(Arc::new(ArrowSchema::new(fields)), columns)
+    }
+}
+
+impl ArrowReader for ParquetBatchReader {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        match self.reader.next_record_batch()? {
+            Some(batch) => self.restore_batch(batch).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        let schema = ArrowReader::schema(&self.reader);
+        let fields = schema
+            .fields()
+            .iter()
+            .map(
+                |field| match self.file_schema.field_with_name(field.name()) {
+                    Ok(file_field) => file_field.into(),
+                    Err(_) => field.clone(),
+                },
+            )
+            .collect();
+        Arc::new(Schema::new(fields))
+    }
+}
+
 impl<R> ParquetReader<R> {}
 
 impl<R> SerReader<R> for ParquetReader<R>
@@ -143,8 +322,15 @@ where
         let n_rows = file_reader.metadata().file_metadata().num_rows() as usize;
         let batch_size = set_batch_size(n_rows, self.stop_after_n_rows);
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
+        let file_schema = Arc::new(arrow_reader.get_schema()?);
         let record_reader = arrow_reader.get_record_reader(batch_size)?;
-        finish_reader(record_reader, rechunk, self.stop_after_n_rows, None, None)
+        finish_reader(
+            ParquetBatchReader::new(record_reader, file_schema),
+            rechunk,
+            self.stop_after_n_rows,
+            None,
+            None,
+        )
     }
 }
 
@@ -200,4 +386,128 @@ mod test {
             Err(_) => {}
         }
     }
+
+    #[test]
+    fn test_parquet_binary_round_trip() {
+        let path = std::env::temp_dir().join("polars_binary_round_trip.parquet");
+        let values: &[Option<&[u8]>] = &[Some(b"\x08\x96\x01"), None, Some(b"")];
+        let mut df = DataFrame::new(vec![Series::new("bin", values)]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(df_read.column("bin").unwrap().dtype(), &DataType::Binary);
+        assert!(df.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    fn test_parquet_struct_round_trip() {
+        let path = std::env::temp_dir().join("polars_struct_round_trip.parquet");
+        let fields = [
+            Series::new("a", &[Some(1), None, Some(3)]),
+            Series::new("b", &[Some("x"), Some("y"), None]),
+        ];
+        let s = StructChunked::new("s", &fields).unwrap().into_series();
+        let mut df = DataFrame::new(vec![Series::new("id", &[0, 1, 2]), s]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(df_read.schema(), df.schema());
+        assert!(df.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    fn test_parquet_decimal_round_trip() {
+        let path = std::env::temp_dir().join("polars_decimal_round_trip.parquet");
+        let values = vec![Some(12345i128), None, Some(-5), Some(-99_999_999_99)];
+        let s = DecimalChunked::new_from_opt_iter("d", 12, 2, values.into_iter()).into_series();
+        let mut df = DataFrame::new(vec![s]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            df_read.column("d").unwrap().dtype(),
+            &DataType::Decimal(12, 2)
+        );
+        assert!(df.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    fn test_decimal_from_be_bytes() {
+        assert_eq!(super::decimal_from_be_bytes(&[0x30, 0x39]), 12345);
+        assert_eq!(super::decimal_from_be_bytes(&[0xff, 0xfb]), -5);
+        assert_eq!(
+            super::decimal_from_be_bytes(&[0x80, 0x00, 0x00]),
+            -8_388_608
+        );
+        assert_eq!(super::decimal_from_be_bytes(&[]), 0);
+    }
+
+    #[test]
+    fn test_parquet_time_zone_round_trip() {
+        let path = std::env::temp_dir().join("polars_time_zone_round_trip.parquet");
+        let s = TimestampMillisecondChunked::new_from_opt_slice(
+            "t",
+            &[Some(1616891400000), None, Some(1616895000000)],
+        )
+        .into_series()
+        .convert_time_zone("Europe/Amsterdam")
+        .unwrap();
+        let mut df = DataFrame::new(vec![s]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            df_read.column("t").unwrap().dtype(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("Europe/Amsterdam".into()))
+        );
+        assert!(df.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    fn test_parquet_categorical_round_trip() {
+        let path = std::env::temp_dir().join("polars_categorical_round_trip.parquet");
+        let s = Series::new("cat", &[Some("b"), None, Some("a"), Some("b")])
+            .cast::<CategoricalType>()
+            .unwrap();
+        let mut df = DataFrame::new(vec![s]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let s_read = df_read.column("cat").unwrap();
+        assert_eq!(s_read.dtype(), &DataType::Categorical);
+        assert!(s_read
+            .cast::<Utf8Type>()
+            .unwrap()
+            .series_equal_missing(&df.column("cat").unwrap().cast::<Utf8Type>().unwrap()));
+    }
 }
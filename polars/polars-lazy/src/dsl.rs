@@ -7,7 +7,7 @@ use polars_core::{
     prelude::*,
     utils::{
         chrono::{NaiveDate, NaiveDateTime},
-        get_supertype,
+        get_supertype, get_temporal_arithmetic_type,
     },
 };
 use std::fmt::{Debug, Formatter};
@@ -19,9 +19,23 @@ use std::{
 };
 // reexport the lazy method
 pub use crate::frame::IntoLazy;
+mod list;
+#[cfg(feature = "strings")]
+mod string;
+mod struct_;
+pub use list::ListNameSpace;
+#[cfg(feature = "strings")]
+pub use string::StringNameSpace;
+pub use struct_::{as_struct, StructNameSpace};
 
 pub trait SeriesUdf: Send + Sync {
     fn call_udf(&self, s: Series) -> Result<Series>;
+
+    /// In a [window](Expr::over), apply the function on the values of every group instead of
+    /// on the aggregated list of every group.
+    fn applies_per_group(&self) -> bool {
+        false
+    }
 }
 
 impl<F> SeriesUdf for F
@@ -33,12 +47,161 @@ where
     }
 }
 
+/// A function, like an exponentially weighted statistic, that is applied per group in a window.
+struct PerGroupUdf<F>(F);
+
+impl<F> SeriesUdf for PerGroupUdf<F>
+where
+    F: Fn(Series) -> Result<Series> + Send + Sync,
+{
+    fn call_udf(&self, s: Series) -> Result<Series> {
+        (self.0)(s)
+    }
+
+    fn applies_per_group(&self) -> bool {
+        true
+    }
+}
+
 impl Debug for dyn SeriesUdf {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "SeriesUdf")
     }
 }
 
+/// The output dtype of a [map](Expr::map). The dtype can depend on the input dtype, which is only
+/// known once the input schema is known.
+#[derive(Clone)]
+pub enum GetOutput {
+    /// The output has the dtype of the input.
+    SameType,
+    /// The output has a fixed dtype.
+    DataType(DataType),
+    /// The output dtype is a function of the input dtype.
+    MapDataType(Arc<dyn Fn(&DataType) -> DataType + Send + Sync>),
+    /// The output field is a function of the input field.
+    MapField(Arc<dyn Fn(&Field) -> Field + Send + Sync>),
+    /// The output differs per context, e.g. because the function returns a list per group in
+    /// an aggregation context.
+    ByContext {
+        other: Box<GetOutput>,
+        aggregation: Box<GetOutput>,
+    },
+}
+
+impl GetOutput {
+    /// Get the output field given the field of the input and the context of the expression.
+    pub fn get_field(&self, input_field: Field, ctxt: Context) -> Field {
+        match self {
+            GetOutput::SameType => input_field,
+            GetOutput::DataType(dt) => Field::new(input_field.name(), dt.clone()),
+            GetOutput::MapDataType(f) => Field::new(input_field.name(), f(input_field.data_type())),
+            GetOutput::MapField(f) => f(&input_field),
+            GetOutput::ByContext { other, aggregation } => match ctxt {
+                Context::Other => other.get_field(input_field, ctxt),
+                Context::Aggregation => aggregation.get_field(input_field, ctxt),
+            },
+        }
+    }
+}
+
+/// Float input keeps its precision, all other input is aggregated to `Float64`.
+fn float_output() -> GetOutput {
+    GetOutput::MapDataType(Arc::new(|dt| match dt {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    }))
+}
+
+/// The output type of [ChunkDiff::diff](polars_core::prelude::ChunkDiff::diff).
+fn diff_output() -> GetOutput {
+    GetOutput::MapDataType(Arc::new(|dt| match dt {
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => DataType::Int64,
+        DataType::Date32 => DataType::Int32,
+        DataType::Date64 | DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            DataType::Duration(TimeUnit::Millisecond)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) | DataType::Time64(_) => {
+            DataType::Duration(TimeUnit::Nanosecond)
+        }
+        DataType::Timestamp(_, _) => DataType::Int64,
+        dt => dt.clone(),
+    }))
+}
+
+/// The output of a function that is applied with [apply_per_group]. In an aggregation context the
+/// values of `dtype` are collected per group in a list, categoricals as `Utf8`.
+fn per_group_output(dtype: DataType) -> GetOutput {
+    let list_dtype = match &dtype {
+        DataType::Categorical => DataType::List(ArrowDataType::Utf8),
+        dt => DataType::List(dt.to_arrow()),
+    };
+    GetOutput::ByContext {
+        other: Box::new(GetOutput::DataType(dtype)),
+        aggregation: Box::new(GetOutput::DataType(list_dtype)),
+    }
+}
+
+/// Apply `f` to a Series, or to every list of a List Series. In an aggregation context the
+/// input is a list of the values per group, so `f` is applied per group. Categorical outputs
+/// are collected as `Utf8`, as lists of categoricals are not supported.
+fn apply_per_group<F>(s: Series, f: F) -> Result<Series>
+where
+    F: Fn(&Series) -> Result<Series>,
+{
+    match s.dtype() {
+        DataType::List(_) => {
+            let mut out = s
+                .list()?
+                .into_iter()
+                .map(|opt_s| {
+                    opt_s
+                        .map(|s| match f(&s)? {
+                            out if out.dtype() == &DataType::Categorical => {
+                                out.cast_with_datatype(&DataType::Utf8)
+                            }
+                            out => Ok(out),
+                        })
+                        .transpose()
+                })
+                .collect::<Result<ListChunked>>()?;
+            out.rename(s.name());
+            Ok(out.into_series())
+        }
+        _ => f(&s),
+    }
+}
+
+impl From<Option<DataType>> for GetOutput {
+    fn from(opt_dt: Option<DataType>) -> Self {
+        match opt_dt {
+            Some(dt) => GetOutput::DataType(dt),
+            None => GetOutput::SameType,
+        }
+    }
+}
+
+/// How to fill the None values in [LazyFrame::fill_none](crate::frame::LazyFrame::fill_none).
+#[derive(Clone, Debug)]
+pub enum FillNone {
+    /// Replace None with the value of an expression.
+    Value(Expr),
+    /// Replace None with a [FillNoneStrategy], e.g. a forward fill or a linear interpolation.
+    Strategy(FillNoneStrategy),
+}
+
+impl From<Expr> for FillNone {
+    fn from(expr: Expr) -> Self {
+        FillNone::Value(expr)
+    }
+}
+
+impl From<FillNoneStrategy> for FillNone {
+    fn from(strategy: FillNoneStrategy) -> Self {
+        FillNone::Strategy(strategy)
+    }
+}
+
 pub trait SeriesBinaryUdf: Send + Sync {
     fn call_udf(&self, a: Series, b: Series) -> Result<Series>;
 }
@@ -94,11 +257,35 @@ pub enum AggExpr {
     Mean(Box<Expr>),
     List(Box<Expr>),
     Count(Box<Expr>),
-    Quantile { expr: Box<Expr>, quantile: f64 },
+    Quantile {
+        expr: Box<Expr>,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
+    Quantiles {
+        expr: Box<Expr>,
+        quantiles: Vec<f64>,
+        interpol: QuantileInterpolOptions,
+    },
     Sum(Box<Expr>),
     AggGroups(Box<Expr>),
     Std(Box<Expr>),
     Var(Box<Expr>),
+    ApproxNUnique(Box<Expr>),
+    ApproxQuantile {
+        expr: Box<Expr>,
+        quantile: f64,
+    },
+    Skew {
+        expr: Box<Expr>,
+        bias: bool,
+    },
+    Kurtosis {
+        expr: Box<Expr>,
+        fisher: bool,
+        bias: bool,
+    },
+    Mode(Box<Expr>),
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -115,10 +302,16 @@ impl AsRef<Expr> for AggExpr {
             List(e) => e,
             Count(e) => e,
             Quantile { expr, .. } => expr,
+            Quantiles { expr, .. } => expr,
             Sum(e) => e,
             AggGroups(e) => e,
             Std(e) => e,
             Var(e) => e,
+            ApproxNUnique(e) => e,
+            ApproxQuantile { expr, .. } => expr,
+            Skew { expr, .. } => expr,
+            Kurtosis { expr, .. } => expr,
+            Mode(e) => e,
         }
     }
 }
@@ -161,12 +354,17 @@ pub enum Expr {
     Udf {
         input: Box<Expr>,
         function: Arc<dyn SeriesUdf>,
-        output_type: Option<DataType>,
+        output_type: GetOutput,
     },
     Shift {
         input: Box<Expr>,
         periods: i64,
     },
+    /// An elementwise math function.
+    Math {
+        input: Box<Expr>,
+        function: MathFunction,
+    },
     Reverse(Box<Expr>),
     Duplicated(Box<Expr>),
     Unique(Box<Expr>),
@@ -238,11 +436,53 @@ impl PartialEq for Expr {
                         AggExpr::Count(left) => impl_partial_eq_agg!(Count, left, other),
                         AggExpr::Std(left) => impl_partial_eq_agg!(Std, left, other),
                         AggExpr::Var(left) => impl_partial_eq_agg!(Var, left, other),
-                        AggExpr::Quantile { expr, quantile } => {
+                        AggExpr::ApproxNUnique(left) => {
+                            impl_partial_eq_agg!(ApproxNUnique, left, other)
+                        }
+                        AggExpr::ApproxQuantile { expr, quantile } => {
                             let left = expr;
                             let left_q = quantile;
-                            if let AggExpr::Quantile { expr, quantile } = other {
-                                if left_q == quantile {
+                            if let AggExpr::ApproxQuantile { expr, quantile } = other {
+                                left_q == quantile && left.eq(expr)
+                            } else {
+                                false
+                            }
+                        }
+                        AggExpr::Skew { expr, bias } => {
+                            let left = expr;
+                            let left_bias = bias;
+                            if let AggExpr::Skew { expr, bias } = other {
+                                left_bias == bias && left.eq(expr)
+                            } else {
+                                false
+                            }
+                        }
+                        AggExpr::Kurtosis { expr, fisher, bias } => {
+                            let left = expr;
+                            let left_fisher = fisher;
+                            let left_bias = bias;
+                            if let AggExpr::Kurtosis { expr, fisher, bias } = other {
+                                left_fisher == fisher && left_bias == bias && left.eq(expr)
+                            } else {
+                                false
+                            }
+                        }
+                        AggExpr::Mode(left) => impl_partial_eq_agg!(Mode, left, other),
+                        AggExpr::Quantile {
+                            expr,
+                            quantile,
+                            interpol,
+                        } => {
+                            let left = expr;
+                            let left_q = quantile;
+                            let left_interpol = interpol;
+                            if let AggExpr::Quantile {
+                                expr,
+                                quantile,
+                                interpol,
+                            } = other
+                            {
+                                if left_q == quantile && left_interpol == interpol {
                                     left.eq(expr)
                                 } else {
                                     false
@@ -251,6 +491,25 @@ impl PartialEq for Expr {
                                 false
                             }
                         }
+                        AggExpr::Quantiles {
+                            expr,
+                            quantiles,
+                            interpol,
+                        } => {
+                            let left = expr;
+                            let left_q = quantiles;
+                            let left_interpol = interpol;
+                            if let AggExpr::Quantiles {
+                                expr,
+                                quantiles,
+                                interpol,
+                            } = other
+                            {
+                                left_q == quantiles && left_interpol == interpol && left.eq(expr)
+                            } else {
+                                false
+                            }
+                        }
                     }
                 } else {
                     false
@@ -344,6 +603,15 @@ impl PartialEq for Expr {
                     false
                 }
             }
+            Expr::Math { input, function } => {
+                let left = input;
+                let left_function = function;
+                if let Expr::Math { input, function } = other {
+                    left_function == function && left.eq(input)
+                } else {
+                    false
+                }
+            }
             Expr::Slice {
                 input: left,
                 offset: offset_left,
@@ -408,13 +676,19 @@ impl Expr {
                     | Operator::Or
                     | Operator::NotLike
                     | Operator::Like => DataType::Boolean,
+                    Operator::Plus | Operator::Minus => get_temporal_arithmetic_type(
+                        &left_type,
+                        &right_type,
+                        matches!(op, Operator::Minus),
+                    )
+                    .map_or_else(|| get_supertype(&left_type, &right_type), Ok)?,
                     _ => get_supertype(&left_type, &right_type)?,
                 };
 
                 use Operator::*;
                 let out_field;
                 let out_name = match op {
-                    Plus | Minus | Multiply | Divide | Modulus => {
+                    Plus | Minus | Multiply | Divide | Modulus | BitAnd | BitOr | BitXor => {
                         out_field = left.to_field(schema, ctxt)?;
                         out_field.name().as_str()
                     }
@@ -494,11 +768,45 @@ impl Expr {
                         let new_name = fmt_groupby_column(field.name(), GroupByMethod::Groups);
                         Field::new(&new_name, DataType::List(ArrowDataType::UInt32))
                     }
-                    Quantile { expr, quantile } => field_by_context(
-                        expr.to_field(schema, ctxt)?,
-                        ctxt,
-                        GroupByMethod::Quantile(*quantile),
-                    ),
+                    Quantile {
+                        expr,
+                        quantile,
+                        interpol,
+                    } => {
+                        let field = expr.to_field(schema, ctxt)?;
+                        let field =
+                            Field::new(field.name(), interpol.quantile_dtype(field.data_type()));
+                        field_by_context(field, ctxt, GroupByMethod::Quantile(*quantile))
+                    }
+                    Quantiles { expr, interpol, .. } => {
+                        let field = expr.to_field(schema, ctxt)?;
+                        let dtype = interpol.quantile_dtype(field.data_type());
+                        let field = Field::new(field.name(), DataType::List(dtype.to_arrow()));
+                        field_by_context(field, ctxt, GroupByMethod::Quantiles)
+                    }
+                    ApproxNUnique(expr) => {
+                        let field = expr.to_field(schema, ctxt)?;
+                        let field = Field::new(field.name(), DataType::UInt32);
+                        field_by_context(field, ctxt, GroupByMethod::ApproxNUnique)
+                    }
+                    ApproxQuantile { expr, quantile } => {
+                        let field = expr.to_field(schema, ctxt)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::ApproxQuantile(*quantile))
+                    }
+                    Skew { expr, bias } => {
+                        let field = expr.to_field(schema, ctxt)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::Skew(*bias))
+                    }
+                    Kurtosis { expr, fisher, bias } => {
+                        let field = expr.to_field(schema, ctxt)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::Kurtosis(*fisher, *bias))
+                    }
+                    Mode(expr) => {
+                        field_by_context(expr.to_field(schema, ctxt)?, ctxt, GroupByMethod::Mode)
+                    }
                 };
                 Ok(field)
             }
@@ -509,13 +817,7 @@ impl Expr {
             Ternary { truthy, .. } => truthy.to_field(schema, ctxt),
             Udf {
                 output_type, input, ..
-            } => match output_type {
-                None => input.to_field(schema, ctxt),
-                Some(output_type) => {
-                    let input_field = input.to_field(schema, ctxt)?;
-                    Ok(Field::new(input_field.name(), output_type.clone()))
-                }
-            },
+            } => Ok(output_type.get_field(input.to_field(schema, ctxt)?, ctxt)),
             BinaryFunction {
                 input_a,
                 input_b,
@@ -536,6 +838,13 @@ impl Expr {
                     }))
             }
             Shift { input, .. } => input.to_field(schema, ctxt),
+            Math { input, function } => {
+                let field = input.to_field(schema, ctxt)?;
+                Ok(Field::new(
+                    field.name(),
+                    function.get_type(field.data_type()),
+                ))
+            }
             Slice { input, .. } => input.to_field(schema, ctxt),
             Wildcard => panic!("should be no wildcard at this point"),
             Except(_) => panic!("should be no except at this point"),
@@ -588,6 +897,12 @@ impl fmt::Debug for Expr {
                     Var(expr) => write!(f, "AGG VAR {:?}", expr),
                     Std(expr) => write!(f, "AGG STD {:?}", expr),
                     Quantile { expr, .. } => write!(f, "AGG QUANTILE {:?}", expr),
+                    Quantiles { expr, .. } => write!(f, "AGG QUANTILES {:?}", expr),
+                    ApproxNUnique(expr) => write!(f, "AGG APPROX N UNIQUE {:?}", expr),
+                    ApproxQuantile { expr, .. } => write!(f, "AGG APPROX QUANTILE {:?}", expr),
+                    Skew { expr, .. } => write!(f, "AGG SKEW {:?}", expr),
+                    Kurtosis { expr, .. } => write!(f, "AGG KURTOSIS {:?}", expr),
+                    Mode(expr) => write!(f, "AGG MODE {:?}", expr),
                 }
             }
             Cast { expr, data_type } => write!(f, "CAST {:?} TO {:?}", expr, data_type),
@@ -605,6 +920,7 @@ impl fmt::Debug for Expr {
                 input_a, input_b, ..
             } => write!(f, "BinaryFunction({:?}, {:?})", input_a, input_b),
             Shift { input, periods, .. } => write!(f, "SHIFT {:?} by {}", input, periods),
+            Math { input, function } => write!(f, "{:?}({:?})", function, input),
             Slice {
                 input,
                 offset,
@@ -657,6 +973,91 @@ pub enum Operator {
     Not,
     Like,
     NotLike,
+    /// Bitwise and of integers.
+    BitAnd,
+    /// Bitwise or of integers.
+    BitOr,
+    /// Bitwise exclusive or of integers.
+    BitXor,
+}
+
+/// Elementwise math functions of [Expr::Math].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MathFunction {
+    Abs,
+    Sqrt,
+    Exp,
+    Log,
+    Log10,
+    Log1p,
+    Sin,
+    Cos,
+    Tan,
+    ArcSin,
+    ArcCos,
+    ArcTan,
+    Sinh,
+    Cosh,
+    Tanh,
+    /// Round to a number of decimals.
+    Round(u32),
+    Floor,
+    Ceil,
+    Sign,
+    Clip {
+        min: f64,
+        max: f64,
+    },
+    /// Shift the bits of an integer to the left.
+    Shl(u32),
+    /// Shift the bits of an integer to the right.
+    Shr(u32),
+}
+
+impl MathFunction {
+    /// The output type given the input type. Float functions cast integers to `Float64`, the
+    /// other functions keep the input type.
+    pub fn get_type(&self, input_type: &DataType) -> DataType {
+        use MathFunction::*;
+        match self {
+            Sqrt | Exp | Log | Log10 | Log1p | Sin | Cos | Tan | ArcSin | ArcCos | ArcTan
+            | Sinh | Cosh | Tanh => match input_type {
+                DataType::Float32 => DataType::Float32,
+                _ => DataType::Float64,
+            },
+            Abs | Round(_) | Floor | Ceil | Sign | Clip { .. } | Shl(_) | Shr(_) => {
+                input_type.clone()
+            }
+        }
+    }
+
+    pub fn evaluate(&self, s: &Series) -> Result<Series> {
+        use MathFunction::*;
+        match *self {
+            Abs => s.abs(),
+            Sqrt => s.sqrt(),
+            Exp => s.exp(),
+            Log => s.log(),
+            Log10 => s.log10(),
+            Log1p => s.log1p(),
+            Sin => s.sin(),
+            Cos => s.cos(),
+            Tan => s.tan(),
+            ArcSin => s.arcsin(),
+            ArcCos => s.arccos(),
+            ArcTan => s.arctan(),
+            Sinh => s.sinh(),
+            Cosh => s.cosh(),
+            Tanh => s.tanh(),
+            Round(decimals) => s.round_decimals(decimals),
+            Floor => s.floor(),
+            Ceil => s.ceil(),
+            Sign => s.sign(),
+            Clip { min, max } => s.clip(min, max),
+            Shl(n) => s.shl(n),
+            Shr(n) => s.shr(n),
+        }
+    }
 }
 
 pub fn binary_expr(l: Expr, op: Operator, r: Expr) -> Expr {
@@ -808,14 +1209,69 @@ impl Expr {
     }
 
     /// Compute the quantile per group.
-    pub fn quantile(self, quantile: f64) -> Self {
+    pub fn quantile(self, quantile: f64, interpol: QuantileInterpolOptions) -> Self {
         AggExpr::Quantile {
             expr: Box::new(self),
             quantile,
+            interpol,
+        }
+        .into()
+    }
+
+    /// Compute multiple quantiles, collected in a list in the order of `quantiles`. The values
+    /// are only sorted once. In an aggregation context every group gets a list.
+    pub fn quantiles(self, quantiles: &[f64], interpol: QuantileInterpolOptions) -> Self {
+        AggExpr::Quantiles {
+            expr: Box::new(self),
+            quantiles: quantiles.to_vec(),
+            interpol,
+        }
+        .into()
+    }
+
+    /// Estimate the number of unique values in the groups with a HyperLogLog sketch. This uses a
+    /// fixed amount of memory per group and can be computed in parallel on partitions.
+    pub fn approx_n_unique(self) -> Self {
+        AggExpr::ApproxNUnique(Box::new(self)).into()
+    }
+
+    /// Estimate the quantile per group with a t-digest. This uses a bounded amount of memory per
+    /// group and can be computed in parallel on partitions.
+    pub fn approx_quantile(self, quantile: f64) -> Self {
+        AggExpr::ApproxQuantile {
+            expr: Box::new(self),
+            quantile,
+        }
+        .into()
+    }
+
+    /// Compute the sample skewness per group.
+    /// See [the eager implementation](polars_core::prelude::ChunkMoment::skew).
+    pub fn skew(self, bias: bool) -> Self {
+        AggExpr::Skew {
+            expr: Box::new(self),
+            bias,
         }
         .into()
     }
 
+    /// Compute the sample kurtosis per group.
+    /// See [the eager implementation](polars_core::prelude::ChunkMoment::kurtosis).
+    pub fn kurtosis(self, fisher: bool, bias: bool) -> Self {
+        AggExpr::Kurtosis {
+            expr: Box::new(self),
+            fisher,
+            bias,
+        }
+        .into()
+    }
+
+    /// Get the most frequent value per group.
+    /// See [the eager implementation](polars_core::prelude::ChunkMode::mode).
+    pub fn mode(self) -> Self {
+        AggExpr::Mode(Box::new(self)).into()
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Box::new(self)).into()
@@ -871,6 +1327,15 @@ impl Expr {
     /// It is the responsibility of the caller that the schema is correct by giving
     /// the correct output_type. If None given the output type of the input expr is used.
     pub fn map<F>(self, function: F, output_type: Option<DataType>) -> Self
+    where
+        F: SeriesUdf + 'static,
+    {
+        self.map_with_output(function, output_type.into())
+    }
+
+    /// Apply a function/closure once the logical plan get executed. The output dtype is
+    /// determined by [GetOutput], e.g. from the dtype of the input expr.
+    pub fn map_with_output<F>(self, function: F, output_type: GetOutput) -> Self
     where
         F: SeriesUdf + 'static,
     {
@@ -881,6 +1346,16 @@ impl Expr {
         }
     }
 
+    /// Like [map_with_output](Expr::map_with_output), but in a [window](Expr::over) the function
+    /// is applied on the values of every group and the results are put back at the rows of
+    /// their group.
+    fn map_per_group<F>(self, function: F, output_type: GetOutput) -> Self
+    where
+        F: Fn(Series) -> Result<Series> + Send + Sync + 'static,
+    {
+        self.map_with_output(PerGroupUdf(function), output_type)
+    }
+
     /// Get mask of finite values if dtype is Float
     #[allow(clippy::wrong_self_convention)]
     pub fn is_finite(self) -> Self {
@@ -925,19 +1400,177 @@ impl Expr {
         }
     }
 
-    /// Get an array with the cumulative sum computed at every element
+    /// Get an array with the cumulative sum computed at every element. In a [window](Expr::over)
+    /// the cumulative aggregations are computed within the groups.
     pub fn cum_sum(self, reverse: bool) -> Self {
-        self.map(move |s: Series| Ok(s.cum_sum(reverse)), None)
+        self.map_per_group(move |s: Series| Ok(s.cum_sum(reverse)), GetOutput::SameType)
     }
 
     /// Get an array with the cumulative min computed at every element
     pub fn cum_min(self, reverse: bool) -> Self {
-        self.map(move |s: Series| Ok(s.cum_min(reverse)), None)
+        self.map_per_group(move |s: Series| Ok(s.cum_min(reverse)), GetOutput::SameType)
     }
 
     /// Get an array with the cumulative max computed at every element
     pub fn cum_max(self, reverse: bool) -> Self {
-        self.map(move |s: Series| Ok(s.cum_max(reverse)), None)
+        self.map_per_group(move |s: Series| Ok(s.cum_max(reverse)), GetOutput::SameType)
+    }
+
+    /// Get an array with the cumulative product computed at every element
+    pub fn cum_prod(self, reverse: bool) -> Self {
+        self.map_per_group(
+            move |s: Series| Ok(s.cum_prod(reverse)),
+            GetOutput::SameType,
+        )
+    }
+
+    /// Get an array with the number of non null values up to and including every element
+    pub fn cum_count(self, reverse: bool) -> Self {
+        self.map_per_group(
+            move |s: Series| Ok(s.cum_count(reverse)),
+            GetOutput::DataType(DataType::UInt32),
+        )
+    }
+
+    /// Apply a rolling sum. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_sum).
+    pub fn rolling_sum(self, options: RollingOptions) -> Self {
+        self.map(move |s: Series| s.rolling_sum(&options), None)
+    }
+
+    /// Apply a rolling mean. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_mean).
+    pub fn rolling_mean(self, options: RollingOptions) -> Self {
+        self.map(move |s: Series| s.rolling_mean(&options), None)
+    }
+
+    /// Apply a rolling min. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_min).
+    pub fn rolling_min(self, options: RollingOptions) -> Self {
+        self.map(move |s: Series| s.rolling_min(&options), None)
+    }
+
+    /// Apply a rolling max. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_max).
+    pub fn rolling_max(self, options: RollingOptions) -> Self {
+        self.map(move |s: Series| s.rolling_max(&options), None)
+    }
+
+    /// Apply a rolling variance. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_var).
+    pub fn rolling_var(self, options: RollingOptions) -> Self {
+        self.map_with_output(move |s: Series| s.rolling_var(&options), float_output())
+    }
+
+    /// Apply a rolling standard deviation. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_std).
+    pub fn rolling_std(self, options: RollingOptions) -> Self {
+        self.map_with_output(move |s: Series| s.rolling_std(&options), float_output())
+    }
+
+    /// Apply a rolling median. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_median).
+    pub fn rolling_median(self, options: RollingOptions) -> Self {
+        self.map_with_output(move |s: Series| s.rolling_median(&options), float_output())
+    }
+
+    /// Apply a rolling quantile. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_quantile).
+    pub fn rolling_quantile(self, quantile: f64, options: RollingOptions) -> Self {
+        self.map_with_output(
+            move |s: Series| s.rolling_quantile(quantile, &options),
+            float_output(),
+        )
+    }
+
+    /// Apply a rolling skew. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_skew).
+    pub fn rolling_skew(self, options: RollingOptions) -> Self {
+        self.map_with_output(move |s: Series| s.rolling_skew(&options), float_output())
+    }
+
+    /// Count the non null values in a rolling window. See [the eager implementation](polars_core::prelude::ChunkWindow::rolling_count).
+    pub fn rolling_count(self, options: RollingOptions) -> Self {
+        self.map(
+            move |s: Series| s.rolling_count(&options),
+            Some(DataType::UInt32),
+        )
+    }
+
+    /// Get the exponentially weighted moving mean. See [the eager implementation](polars_core::prelude::ChunkEwm::ewm_mean).
+    pub fn ewm_mean(self, options: EWMOptions) -> Self {
+        self.map_per_group(move |s: Series| s.ewm_mean(&options), float_output())
+    }
+
+    /// Get the exponentially weighted moving standard deviation. See [the eager implementation](polars_core::prelude::ChunkEwm::ewm_std).
+    pub fn ewm_std(self, options: EWMOptions) -> Self {
+        self.map_per_group(move |s: Series| s.ewm_std(&options), float_output())
+    }
+
+    /// Get the exponentially weighted moving variance. See [the eager implementation](polars_core::prelude::ChunkEwm::ewm_var).
+    pub fn ewm_var(self, options: EWMOptions) -> Self {
+        self.map_per_group(move |s: Series| s.ewm_var(&options), float_output())
+    }
+
+    /// Rank the values. Used in a window function, e.g. `col("a").rank(options).over(col("b"))`,
+    /// the values are ranked within their group.
+    /// See [the eager implementation](polars_core::prelude::ChunkRank::rank).
+    pub fn rank(self, options: RankOptions) -> Self {
+        let output = match options.method {
+            RankMethod::Average => DataType::Float64,
+            _ => DataType::UInt32,
+        };
+        self.map_per_group(
+            move |s: Series| s.rank(options),
+            GetOutput::DataType(output),
+        )
+    }
+
+    /// Compute the difference with the value `n` positions before.
+    /// See [the eager implementation](polars_core::prelude::ChunkDiff::diff).
+    ///
+    /// In a [window](Expr::over) the differences are computed within the groups.
+    /// [NullBehavior::Drop] changes the length of the groups and returns an error in a window.
+    pub fn diff(self, n: usize, null_behavior: NullBehavior) -> Self {
+        self.map_per_group(move |s: Series| s.diff(n, null_behavior), diff_output())
+    }
+
+    /// Compute the change as a fraction with the value `n` positions before.
+    /// See [the eager implementation](polars_core::prelude::ChunkDiff::pct_change).
+    pub fn pct_change(self, n: usize) -> Self {
+        self.map_per_group(move |s: Series| s.pct_change(n), float_output())
+    }
+
+    /// Bin the values into the intervals `(breaks[i], breaks[i + 1]]`. In an aggregation context
+    /// the labels are collected per group as `Utf8`.
+    /// See [the eager implementation](polars_core::series::Series::cut).
+    pub fn cut(self, breaks: Vec<f64>, labels: Option<Vec<String>>) -> Self {
+        self.map_with_output(
+            move |s: Series| {
+                let labels = labels
+                    .as_ref()
+                    .map(|labels| labels.iter().map(|l| l.as_str()).collect::<Vec<_>>());
+                apply_per_group(s, |s| s.cut(&breaks, labels.as_deref()))
+            },
+            per_group_output(DataType::Categorical),
+        )
+    }
+
+    /// Bin the values by quantiles. In an aggregation context the quantiles are computed per
+    /// group and the labels are collected per group as `Utf8`.
+    /// See [the eager implementation](polars_core::series::Series::qcut).
+    pub fn qcut(self, quantiles: Vec<f64>) -> Self {
+        self.map_with_output(
+            move |s: Series| apply_per_group(s, |s| s.qcut(&quantiles)),
+            per_group_output(DataType::Categorical),
+        )
+    }
+
+    /// Count the values in `bins` bins of equal width. Only the counts are returned, so in an
+    /// aggregation context every group gets a list of counts.
+    /// See [the eager implementation](polars_core::series::Series::hist).
+    pub fn hist(self, bins: usize) -> Self {
+        self.map_with_output(
+            move |s: Series| {
+                apply_per_group(s, |s| {
+                    let mut counts = s.hist(bins)?.column("count")?.clone();
+                    counts.rename(s.name());
+                    Ok(counts)
+                })
+            },
+            per_group_output(DataType::UInt32),
+        )
     }
 
     /// Apply window function over a subgroup.
@@ -1016,6 +1649,32 @@ impl Expr {
             .alias(&*name)
     }
 
+    /// Replace the None values with a [FillNoneStrategy]. See [the eager implementation](polars_core::series::SeriesTrait::fill_none).
+    pub fn fill_none_with_strategy(self, strategy: FillNoneStrategy) -> Self {
+        self.map(move |s: Series| s.fill_none(strategy), None)
+    }
+
+    /// Replace the NaN values of a float column with `fill_value` (note this is different than
+    /// missing/ null).
+    pub fn fill_nan(self, fill_value: Expr) -> Self {
+        let name = output_name(&self).unwrap();
+        when(self.clone().is_nan())
+            .then(fill_value)
+            .otherwise(self)
+            .alias(&*name)
+    }
+
+    /// Linearly interpolate the None values, weighted by the time that passed between the values
+    /// of the `Date64` expression `by`.
+    pub fn interpolate_by(self, by: Expr) -> Self {
+        map_binary_lazy_field(
+            self,
+            by,
+            |s, by| s.interpolate_by(&by),
+            |_, _, field, _| Some(field.clone()),
+        )
+    }
+
     /// Count the values of the Series
     /// or
     /// Get counts of the group by operation.
@@ -1055,6 +1714,139 @@ impl Expr {
         binary_expr(self, Operator::Or, expr)
     }
 
+    fn math(self, function: MathFunction) -> Self {
+        Expr::Math {
+            input: Box::new(self),
+            function,
+        }
+    }
+
+    /// Take the absolute value.
+    pub fn abs(self) -> Self {
+        self.math(MathFunction::Abs)
+    }
+
+    /// Take the square root. Integers are cast to `Float64`.
+    pub fn sqrt(self) -> Self {
+        self.math(MathFunction::Sqrt)
+    }
+
+    /// Raise `e` to the power of the values. Integers are cast to `Float64`.
+    pub fn exp(self) -> Self {
+        self.math(MathFunction::Exp)
+    }
+
+    /// Take the natural logarithm. Integers are cast to `Float64`.
+    pub fn log(self) -> Self {
+        self.math(MathFunction::Log)
+    }
+
+    /// Take the base 10 logarithm. Integers are cast to `Float64`.
+    pub fn log10(self) -> Self {
+        self.math(MathFunction::Log10)
+    }
+
+    /// Compute `ln(1 + x)`. Integers are cast to `Float64`.
+    pub fn log1p(self) -> Self {
+        self.math(MathFunction::Log1p)
+    }
+
+    /// Compute the sine. Integers are cast to `Float64`.
+    pub fn sin(self) -> Self {
+        self.math(MathFunction::Sin)
+    }
+
+    /// Compute the cosine. Integers are cast to `Float64`.
+    pub fn cos(self) -> Self {
+        self.math(MathFunction::Cos)
+    }
+
+    /// Compute the tangent. Integers are cast to `Float64`.
+    pub fn tan(self) -> Self {
+        self.math(MathFunction::Tan)
+    }
+
+    /// Compute the inverse sine. Integers are cast to `Float64`.
+    pub fn arcsin(self) -> Self {
+        self.math(MathFunction::ArcSin)
+    }
+
+    /// Compute the inverse cosine. Integers are cast to `Float64`.
+    pub fn arccos(self) -> Self {
+        self.math(MathFunction::ArcCos)
+    }
+
+    /// Compute the inverse tangent. Integers are cast to `Float64`.
+    pub fn arctan(self) -> Self {
+        self.math(MathFunction::ArcTan)
+    }
+
+    /// Compute the hyperbolic sine. Integers are cast to `Float64`.
+    pub fn sinh(self) -> Self {
+        self.math(MathFunction::Sinh)
+    }
+
+    /// Compute the hyperbolic cosine. Integers are cast to `Float64`.
+    pub fn cosh(self) -> Self {
+        self.math(MathFunction::Cosh)
+    }
+
+    /// Compute the hyperbolic tangent. Integers are cast to `Float64`.
+    pub fn tanh(self) -> Self {
+        self.math(MathFunction::Tanh)
+    }
+
+    /// Round to a number of decimals. Integers are unchanged.
+    /// Dates are rounded with [round](Expr::round).
+    pub fn round_decimals(self, decimals: u32) -> Self {
+        self.math(MathFunction::Round(decimals))
+    }
+
+    /// Round down to the nearest integer. Integers are unchanged.
+    pub fn floor(self) -> Self {
+        self.math(MathFunction::Floor)
+    }
+
+    /// Round up to the nearest integer. Integers are unchanged.
+    pub fn ceil(self) -> Self {
+        self.math(MathFunction::Ceil)
+    }
+
+    /// Get `-1` for negative values, `1` for positive values and `0` for zero.
+    pub fn sign(self) -> Self {
+        self.math(MathFunction::Sign)
+    }
+
+    /// Limit the values to the range `[min, max]`.
+    pub fn clip(self, min: f64, max: f64) -> Self {
+        self.math(MathFunction::Clip { min, max })
+    }
+
+    /// Bitwise and of integers.
+    pub fn bit_and(self, expr: Expr) -> Self {
+        binary_expr(self, Operator::BitAnd, expr)
+    }
+
+    /// Bitwise or of integers.
+    pub fn bit_or(self, expr: Expr) -> Self {
+        binary_expr(self, Operator::BitOr, expr)
+    }
+
+    /// Bitwise exclusive or of integers.
+    pub fn xor(self, expr: Expr) -> Self {
+        binary_expr(self, Operator::BitXor, expr)
+    }
+
+    /// Shift the bits of an integer `n` positions to the left.
+    pub fn shl(self, n: u32) -> Self {
+        self.math(MathFunction::Shl(n))
+    }
+
+    /// Shift the bits of an integer `n` positions to the right.
+    pub fn shr(self, n: u32) -> Self {
+        self.math(MathFunction::Shr(n))
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow(self, exponent: f64) -> Self {
         self.map(move |s: Series| s.pow(exponent), Some(DataType::Float64))
@@ -1102,6 +1894,65 @@ impl Expr {
         let function = move |s: Series| s.nanosecond();
         self.map(function, Some(DataType::UInt32))
     }
+
+    /// Truncate a Date32/Date64 to a multiple of the interval `every`, e.g. `"1h"` or `"1mo"`.
+    pub fn truncate(self, every: &str) -> Expr {
+        let every = every.to_string();
+        let function = move |s: Series| s.truncate(&every);
+        self.map(function, None)
+    }
+
+    /// Round a Date32/Date64 to the nearest multiple of the interval `every`.
+    /// Numbers are rounded with [round_decimals](Expr::round_decimals).
+    pub fn round(self, every: &str) -> Expr {
+        let every = every.to_string();
+        let function = move |s: Series| s.round(&every);
+        self.map(function, None)
+    }
+
+    /// Add a calendar aware interval like `"1mo"` or `"-2d"` to a Date32/Date64.
+    pub fn offset_by(self, by: &str) -> Expr {
+        let by = by.to_string();
+        let function = move |s: Series| s.offset_by(&by);
+        self.map(function, None)
+    }
+
+    /// Get the ISO day of the week of a Date32/Date64, starting at 0 for Monday
+    pub fn weekday(self) -> Expr {
+        let function = move |s: Series| s.weekday();
+        self.map(function, Some(DataType::UInt32))
+    }
+
+    /// Get the ISO week number of a Date32/Date64
+    pub fn week(self) -> Expr {
+        let function = move |s: Series| s.week();
+        self.map(function, Some(DataType::UInt32))
+    }
+
+    /// Get the quarter of a Date32/Date64
+    pub fn quarter(self) -> Expr {
+        let function = move |s: Series| s.quarter();
+        self.map(function, Some(DataType::UInt32))
+    }
+
+    /// Check if the year of a Date32/Date64 is a leap year
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_leap_year(self) -> Expr {
+        let function = move |s: Series| s.is_leap_year();
+        self.map(function, Some(DataType::Boolean))
+    }
+
+    /// Move a Date32/Date64 to the first day of its month
+    pub fn month_start(self) -> Expr {
+        let function = move |s: Series| s.month_start();
+        self.map(function, None)
+    }
+
+    /// Move a Date32/Date64 to the last day of its month
+    pub fn month_end(self) -> Expr {
+        let function = move |s: Series| s.month_end();
+        self.map(function, None)
+    }
 }
 
 /// Create a Column Expression based on a column name.
@@ -1151,8 +2002,8 @@ pub fn median(name: &str) -> Expr {
 }
 
 /// Find a specific quantile of all the values in this Expression.
-pub fn quantile(name: &str, quantile: f64) -> Expr {
-    col(name).quantile(quantile)
+pub fn quantile(name: &str, quantile: f64, interpol: QuantileInterpolOptions) -> Expr {
+    col(name).quantile(quantile, interpol)
 }
 
 /// Apply a closure on the two columns that are evaluated from `Expr` a and `Expr` b.
@@ -1344,6 +2195,44 @@ pub fn range<T: Range<T>>(low: T, high: T) -> Expr {
     low.into_range(high)
 }
 
+#[cfg(feature = "temporal")]
+pub trait DateRange {
+    fn into_date_range(self, high: Self, every: &str, closed: ClosedWindow) -> Expr;
+}
+
+#[cfg(feature = "temporal")]
+impl DateRange for NaiveDate {
+    fn into_date_range(self, high: Self, every: &str, closed: ClosedWindow) -> Expr {
+        Expr::Literal(LiteralValue::DateRange {
+            low: self.and_hms(0, 0, 0),
+            high: high.and_hms(0, 0, 0),
+            every: every.to_string(),
+            closed,
+            data_type: DataType::Date32,
+        })
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl DateRange for NaiveDateTime {
+    fn into_date_range(self, high: Self, every: &str, closed: ClosedWindow) -> Expr {
+        Expr::Literal(LiteralValue::DateRange {
+            low: self,
+            high,
+            every: every.to_string(),
+            closed,
+            data_type: DataType::Date64,
+        })
+    }
+}
+
+/// Create a date range literal from `low` to `high` with steps of `every`, e.g. `"1d"` or `"1mo"`.
+/// Dates give a `Date32` range, datetimes a `Date64` range.
+#[cfg(feature = "temporal")]
+pub fn date_range<T: DateRange>(low: T, high: T, every: &str, closed: ClosedWindow) -> Expr {
+    low.into_date_range(high, every, closed)
+}
+
 // Arithmetic ops
 impl Add for Expr {
     type Output = Expr;
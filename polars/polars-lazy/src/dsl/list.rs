@@ -0,0 +1,153 @@
+//! List expressions, available under [Expr::arr](crate::dsl::Expr::arr).
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+fn inner_dtype(dt: &DataType) -> DataType {
+    match dt {
+        DataType::List(inner) => DataType::from(inner),
+        dt => dt.clone(),
+    }
+}
+
+/// Specialized expressions for List Series.
+pub struct ListNameSpace(Expr);
+
+impl ListNameSpace {
+    fn map_list<F>(self, f: F, output_type: GetOutput) -> Expr
+    where
+        F: Fn(&ListChunked) -> Result<Series> + Send + Sync + 'static,
+    {
+        self.0
+            .map_with_output(move |s: Series| f(s.list()?), output_type)
+    }
+
+    fn map_list_to_inner<F>(self, f: F) -> Expr
+    where
+        F: Fn(&ListChunked) -> Result<Series> + Send + Sync + 'static,
+    {
+        self.map_list(f, GetOutput::MapDataType(Arc::new(inner_dtype)))
+    }
+
+    /// Get the number of values in every list.
+    pub fn lengths(self) -> Expr {
+        self.map_list(
+            |ca| Ok(ca.lst_lengths().into_series()),
+            GetOutput::DataType(DataType::UInt32),
+        )
+    }
+
+    /// Get the value at index `idx` of every list. Negative indexes count from the end.
+    pub fn get(self, idx: i64) -> Expr {
+        self.map_list_to_inner(move |ca| ca.lst_get(idx))
+    }
+
+    /// Get the first value of every list.
+    pub fn first(self) -> Expr {
+        self.get(0)
+    }
+
+    /// Get the last value of every list.
+    pub fn last(self) -> Expr {
+        self.get(-1)
+    }
+
+    /// Get the sum of every list.
+    pub fn sum(self) -> Expr {
+        self.map_list_to_inner(|ca| ca.lst_sum())
+    }
+
+    /// Get the mean of every list.
+    pub fn mean(self) -> Expr {
+        self.map_list(|ca| ca.lst_mean(), GetOutput::DataType(DataType::Float64))
+    }
+
+    /// Get the minimum of every list.
+    pub fn min(self) -> Expr {
+        self.map_list_to_inner(|ca| ca.lst_min())
+    }
+
+    /// Get the maximum of every list.
+    pub fn max(self) -> Expr {
+        self.map_list_to_inner(|ca| ca.lst_max())
+    }
+
+    /// Get the index of the minimal value of every list.
+    pub fn arg_min(self) -> Expr {
+        self.map_list(
+            |ca| ca.lst_arg_min().map(|ca| ca.into_series()),
+            GetOutput::DataType(DataType::UInt32),
+        )
+    }
+
+    /// Get the index of the maximal value of every list.
+    pub fn arg_max(self) -> Expr {
+        self.map_list(
+            |ca| ca.lst_arg_max().map(|ca| ca.into_series()),
+            GetOutput::DataType(DataType::UInt32),
+        )
+    }
+
+    /// Sort the values of every list.
+    pub fn sort(self, reverse: bool) -> Expr {
+        self.map_list(
+            move |ca| ca.lst_sort(reverse).map(|ca| ca.into_series()),
+            GetOutput::SameType,
+        )
+    }
+
+    /// Get the unique values of every list.
+    pub fn unique(self) -> Expr {
+        self.map_list(
+            |ca| ca.lst_unique().map(|ca| ca.into_series()),
+            GetOutput::SameType,
+        )
+    }
+
+    /// Take a slice of every list. A negative `offset` counts from the end of the list.
+    pub fn slice(self, offset: i64, length: usize) -> Expr {
+        self.map_list(
+            move |ca| ca.lst_slice(offset, length).map(|ca| ca.into_series()),
+            GetOutput::SameType,
+        )
+    }
+
+    /// Join the values of every list of strings with a separator.
+    pub fn join(self, separator: &str) -> Expr {
+        let separator = separator.to_string();
+        self.map_list(
+            move |ca| ca.lst_join(&separator).map(|ca| ca.into_series()),
+            GetOutput::DataType(DataType::Utf8),
+        )
+    }
+
+    /// Check if the lists contain the value of `value`, e.g. a literal.
+    pub fn contains(self, value: Expr) -> Expr {
+        map_binary_lazy_field(
+            self.0,
+            value,
+            |s, value| s.list()?.lst_contains(&value).map(|ca| ca.into_series()),
+            |_, _, field, _| Some(Field::new(field.name(), DataType::Boolean)),
+        )
+    }
+
+    /// Concatenate the lists with the lists of another expression.
+    pub fn concat(self, other: Expr) -> Expr {
+        map_binary_lazy_field(
+            self.0,
+            other,
+            |s, other| {
+                s.list()?
+                    .lst_concat(other.list()?)
+                    .map(|ca| ca.into_series())
+            },
+            |_, _, field, _| Some(field.clone()),
+        )
+    }
+}
+
+impl Expr {
+    /// Get the [list namespace](ListNameSpace) of the expression.
+    pub fn arr(self) -> ListNameSpace {
+        ListNameSpace(self)
+    }
+}
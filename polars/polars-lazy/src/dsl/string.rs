@@ -0,0 +1,238 @@
+//! String expressions, available under [Expr::str](crate::dsl::Expr::str).
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+/// Specialized expressions for Utf8 Series.
+pub struct StringNameSpace(Expr);
+
+impl StringNameSpace {
+    fn map_utf8<F>(self, f: F, output_type: DataType) -> Expr
+    where
+        F: Fn(&Utf8Chunked) -> Result<Series> + Send + Sync + 'static,
+    {
+        self.0.map(move |s: Series| f(s.utf8()?), Some(output_type))
+    }
+
+    /// Get the length of the string values in bytes.
+    pub fn lengths(self) -> Expr {
+        self.map_utf8(|ca| Ok(ca.str_lengths().into_series()), DataType::UInt32)
+    }
+
+    /// Get the number of unicode characters of the string values.
+    pub fn n_chars(self) -> Expr {
+        self.map_utf8(|ca| Ok(ca.n_chars().into_series()), DataType::UInt32)
+    }
+
+    /// Check if the strings contain a regex pattern.
+    pub fn contains(self, pat: &str) -> Expr {
+        let pat = pat.to_string();
+        self.map_utf8(
+            move |ca| ca.contains(&pat).map(|ca| ca.into_series()),
+            DataType::Boolean,
+        )
+    }
+
+    /// Check if the strings start with a (non regex) prefix.
+    pub fn starts_with(self, prefix: &str) -> Expr {
+        let prefix = prefix.to_string();
+        self.map_utf8(
+            move |ca| Ok(ca.starts_with(&prefix).into_series()),
+            DataType::Boolean,
+        )
+    }
+
+    /// Check if the strings end with a (non regex) suffix.
+    pub fn ends_with(self, suffix: &str) -> Expr {
+        let suffix = suffix.to_string();
+        self.map_utf8(
+            move |ca| Ok(ca.ends_with(&suffix).into_series()),
+            DataType::Boolean,
+        )
+    }
+
+    /// Replace the leftmost match of a regex pattern by `val`.
+    pub fn replace(self, pat: &str, val: &str) -> Expr {
+        let pat = pat.to_string();
+        let val = val.to_string();
+        self.map_utf8(
+            move |ca| ca.replace(&pat, &val).map(|ca| ca.into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Replace all the matches of a regex pattern by `val`.
+    pub fn replace_all(self, pat: &str, val: &str) -> Expr {
+        let pat = pat.to_string();
+        let val = val.to_string();
+        self.map_utf8(
+            move |ca| ca.replace_all(&pat, &val).map(|ca| ca.into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Modify the strings to their lowercase equivalent.
+    pub fn to_lowercase(self) -> Expr {
+        self.map_utf8(|ca| Ok(ca.to_lowercase().into_series()), DataType::Utf8)
+    }
+
+    /// Modify the strings to their uppercase equivalent.
+    pub fn to_uppercase(self) -> Expr {
+        self.map_utf8(|ca| Ok(ca.to_uppercase().into_series()), DataType::Utf8)
+    }
+
+    /// Modify the strings to title case.
+    pub fn to_titlecase(self) -> Expr {
+        self.map_utf8(|ca| Ok(ca.to_titlecase().into_series()), DataType::Utf8)
+    }
+
+    /// Concat with the strings of another expression.
+    pub fn concat(self, other: Expr) -> Expr {
+        self.0 + other
+    }
+
+    /// Split the strings by a (non regex) separator into a list of substrings.
+    pub fn split(self, by: &str) -> Expr {
+        let by = by.to_string();
+        self.map_utf8(
+            move |ca| Ok(ca.split(&by).into_series()),
+            DataType::List(ArrowDataType::LargeUtf8),
+        )
+    }
+
+    /// Split the strings by a (non regex) separator into lists of exactly `n + 1` substrings.
+    pub fn split_exact(self, by: &str, n: usize) -> Expr {
+        let by = by.to_string();
+        self.map_utf8(
+            move |ca| Ok(ca.split_exact(&by, n).into_series()),
+            DataType::List(ArrowDataType::LargeUtf8),
+        )
+    }
+
+    /// Remove leading and trailing whitespace, or the characters in `matches` if given.
+    pub fn strip(self, matches: Option<&str>) -> Expr {
+        let matches = matches.map(|s| s.to_string());
+        self.map_utf8(
+            move |ca| Ok(ca.strip(matches.as_deref()).into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Remove leading whitespace, or the characters in `matches` if given.
+    pub fn lstrip(self, matches: Option<&str>) -> Expr {
+        let matches = matches.map(|s| s.to_string());
+        self.map_utf8(
+            move |ca| Ok(ca.lstrip(matches.as_deref()).into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Remove trailing whitespace, or the characters in `matches` if given.
+    pub fn rstrip(self, matches: Option<&str>) -> Expr {
+        let matches = matches.map(|s| s.to_string());
+        self.map_utf8(
+            move |ca| Ok(ca.rstrip(matches.as_deref()).into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Take a substring of `length` unicode characters from `start`. A negative `start` counts
+    /// from the end of the string.
+    pub fn slice(self, start: i64, length: Option<u64>) -> Expr {
+        self.map_utf8(
+            move |ca| Ok(ca.str_slice(start, length).into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Pad the start of the strings with `fill_char` until they are `width` characters long.
+    pub fn pad_start(self, width: usize, fill_char: char) -> Expr {
+        self.map_utf8(
+            move |ca| Ok(ca.pad_start(width, fill_char).into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Pad the end of the strings with `fill_char` until they are `width` characters long.
+    pub fn pad_end(self, width: usize, fill_char: char) -> Expr {
+        self.map_utf8(
+            move |ca| Ok(ca.pad_end(width, fill_char).into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Extract the capture group `group_index` of the first match of a regex pattern.
+    pub fn extract(self, pat: &str, group_index: usize) -> Expr {
+        let pat = pat.to_string();
+        self.map_utf8(
+            move |ca| ca.extract(&pat, group_index).map(|ca| ca.into_series()),
+            DataType::Utf8,
+        )
+    }
+
+    /// Extract all the matches of a regex pattern into a list of substrings.
+    pub fn extract_all(self, pat: &str) -> Expr {
+        let pat = pat.to_string();
+        self.map_utf8(
+            move |ca| ca.extract_all(&pat).map(|ca| ca.into_series()),
+            DataType::List(ArrowDataType::LargeUtf8),
+        )
+    }
+
+    /// Count the matches of a regex pattern.
+    pub fn count_matches(self, pat: &str) -> Expr {
+        let pat = pat.to_string();
+        self.map_utf8(
+            move |ca| ca.count_matches(&pat).map(|ca| ca.into_series()),
+            DataType::UInt32,
+        )
+    }
+
+    /// Parse the strings to `Int64`. If `strict`, values that cannot be parsed return an
+    /// error, otherwise they are null.
+    pub fn parse_int(self, strict: bool) -> Expr {
+        self.parse_int_with_format(strict, NumberFormat::default())
+    }
+
+    /// Parse the strings to `Int64` with a custom [NumberFormat].
+    pub fn parse_int_with_format(self, strict: bool, format: NumberFormat) -> Expr {
+        self.map_utf8(
+            move |ca| {
+                ca.parse_with_format::<Int64Type>(strict, &format)
+                    .map(|ca| ca.into_series())
+            },
+            DataType::Int64,
+        )
+    }
+
+    /// Parse the strings to `Float64`. If `strict`, values that cannot be parsed return an
+    /// error, otherwise they are null.
+    pub fn parse_float(self, strict: bool) -> Expr {
+        self.parse_float_with_format(strict, NumberFormat::default())
+    }
+
+    /// Parse the strings to `Float64` with a custom [NumberFormat], e.g. a decimal comma.
+    pub fn parse_float_with_format(self, strict: bool, format: NumberFormat) -> Expr {
+        self.map_utf8(
+            move |ca| {
+                ca.parse_with_format::<Float64Type>(strict, &format)
+                    .map(|ca| ca.into_series())
+            },
+            DataType::Float64,
+        )
+    }
+
+    /// Parse the strings `"true"`/`"false"` and `"1"`/`"0"` to booleans.
+    pub fn parse_bool(self, strict: bool) -> Expr {
+        self.map_utf8(
+            move |ca| ca.parse::<BooleanType>(strict).map(|ca| ca.into_series()),
+            DataType::Boolean,
+        )
+    }
+}
+
+impl Expr {
+    /// Get the [string namespace](StringNameSpace) of the expression.
+    pub fn str(self) -> StringNameSpace {
+        StringNameSpace(self)
+    }
+}
@@ -0,0 +1,76 @@
+//! Struct expressions, available under [Expr::struct_](crate::dsl::Expr::struct_).
+use crate::prelude::*;
+use crate::utils::output_name;
+use polars_core::prelude::*;
+
+/// Specialized expressions for Struct Series.
+pub struct StructNameSpace(Expr);
+
+impl StructNameSpace {
+    /// Get the field `name` of the struct. The output is named after the field.
+    pub fn field(self, name: &str) -> Expr {
+        let name = name.to_string();
+        let name_dtype = name.clone();
+        let alias = name.clone();
+        self.0
+            .map_with_output(
+                move |s: Series| s.struct_()?.field_by_name(&name),
+                GetOutput::MapDataType(Arc::new(move |dt| match dt {
+                    DataType::Struct(fields) => fields
+                        .iter()
+                        .find(|fld| fld.name() == &name_dtype)
+                        .map(|fld| fld.data_type().clone())
+                        .unwrap_or(DataType::Null),
+                    _ => DataType::Null,
+                })),
+            )
+            .alias(&alias)
+    }
+}
+
+impl Expr {
+    /// Get the [struct namespace](StructNameSpace) of the expression.
+    pub fn struct_(self) -> StructNameSpace {
+        StructNameSpace(self)
+    }
+}
+
+/// Combine the outputs of `exprs` into a struct column, named after the first expression. If
+/// the name of the first expression can't be determined from the expression itself, the struct
+/// is named `"binary_function"`, like other functions of multiple expressions.
+pub fn as_struct(exprs: Vec<Expr>) -> Expr {
+    let mut exprs = exprs.into_iter();
+    let first = exprs
+        .next()
+        .expect("a struct should have at least one field");
+    let name = output_name(&first).ok();
+    let acc = first.map_with_output(
+        |s: Series| Ok(StructChunked::new(s.name(), &[s])?.into_series()),
+        GetOutput::MapField(Arc::new(|fld| {
+            Field::new(fld.name(), DataType::Struct(vec![fld.clone()]))
+        })),
+    );
+    let out = exprs.fold(acc, |acc, e| {
+        map_binary_lazy_field(
+            acc,
+            e,
+            |acc, s| {
+                let mut fields = acc.struct_()?.fields();
+                fields.push(s);
+                Ok(StructChunked::new(acc.name(), &fields)?.into_series())
+            },
+            |_, _, fld_acc, fld| match fld_acc.data_type() {
+                DataType::Struct(fields) => {
+                    let mut fields = fields.clone();
+                    fields.push(fld.clone());
+                    Some(Field::new("binary_function", DataType::Struct(fields)))
+                }
+                _ => None,
+            },
+        )
+    });
+    match name {
+        Some(name) => out.alias(&name),
+        None => out,
+    }
+}
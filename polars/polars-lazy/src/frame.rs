@@ -10,7 +10,7 @@ use crate::{logical_plan::FETCH_ROWS, prelude::*};
 use ahash::RandomState;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
-use polars_core::toggle_string_cache;
+use polars_core::StringCacheHolder;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -348,10 +348,26 @@ impl LazyFrame {
         self.select_local(vec![col("*").shift(periods)])
     }
 
-    /// Fill none values in the DataFrame
-    pub fn fill_none(self, fill_value: Expr) -> LazyFrame {
+    /// Fill none values in the DataFrame, either with the value of an expression or with a
+    /// [FillNoneStrategy].
+    pub fn fill_none<F: Into<FillNone>>(self, fill: F) -> LazyFrame {
         let opt_state = self.get_opt_state();
-        let lp = self.get_plan_builder().fill_none(fill_value).build();
+        let lp = self.get_plan_builder().fill_none(fill.into()).build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
+    /// Fill the NaN values of the float columns in the DataFrame.
+    pub fn fill_nan(self, fill_value: Expr) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self.get_plan_builder().fill_nan(fill_value).build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
+    /// Linearly interpolate the None values of the numeric columns, weighted by the time that
+    /// passed between the values of the `Date64` column `by`.
+    pub fn interpolate_by(self, by: &str) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self.get_plan_builder().interpolate_by(by).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
@@ -478,18 +494,19 @@ impl LazyFrame {
         let use_string_cache = self.opt_state.global_string_cache;
         let logical_plan = self.optimize()?;
 
-        toggle_string_cache(use_string_cache);
+        // keeps the string cache alive until the result is produced
+        let _holder = if use_string_cache {
+            Some(StringCacheHolder::new())
+        } else {
+            None
+        };
         let planner = DefaultPlanner::default();
         let mut physical_plan = planner.create_physical_plan(logical_plan)?;
         let cache = Arc::new(Mutex::new(HashMap::with_capacity_and_hasher(
             64,
             RandomState::default(),
         )));
-        let out = physical_plan.execute(&cache);
-        if use_string_cache {
-            toggle_string_cache(!use_string_cache);
-        }
-        out
+        physical_plan.execute(&cache)
     }
 
     /// Filter by some predicate expression.
@@ -569,7 +586,9 @@ impl LazyFrame {
     ///        .agg(vec![
     ///            col("rain").min(),
     ///            col("rain").sum(),
-    ///            col("rain").quantile(0.5).alias("median_rain"),
+    ///            col("rain")
+    ///                .quantile(0.5, QuantileInterpolOptions::default())
+    ///                .alias("median_rain"),
     ///        ])
     ///        .sort("date", false)
     /// }
@@ -580,6 +599,67 @@ impl LazyFrame {
             logical_plan: self.logical_plan,
             opt_state,
             keys: by,
+            dynamic_options: None,
+        }
+    }
+
+    /// Group by time windows of length `period` that start every `every`, shifted by `offset`.
+    /// The windows may overlap. The key column contains the start of every window.
+    ///
+    /// The time column must be sorted in ascending order.
+    /// See [DataFrame::groupby_dynamic](polars_core::frame::DataFrame::groupby_dynamic).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///        .groupby_dynamic("date", "1d", "7d", "0d", ClosedWindow::Left)
+    ///        .agg(vec![
+    ///            col("rain").mean(),
+    ///            col("rain").max(),
+    ///        ])
+    /// }
+    /// ```
+    pub fn groupby_dynamic(
+        self,
+        time_column: &str,
+        every: &str,
+        period: &str,
+        offset: &str,
+        closed: ClosedWindow,
+    ) -> LazyGroupBy {
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: vec![col(time_column)],
+            dynamic_options: Some(DynamicGroupOptions::Dynamic {
+                every: every.to_string(),
+                period: period.to_string(),
+                offset: offset.to_string(),
+                closed,
+            }),
+        }
+    }
+
+    /// Group by a trailing time window of length `period` for every row. The key column is the
+    /// original time column.
+    ///
+    /// The time column must be sorted in ascending order.
+    /// See [DataFrame::groupby_rolling](polars_core::frame::DataFrame::groupby_rolling).
+    pub fn groupby_rolling(self, time_column: &str, period: &str) -> LazyGroupBy {
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: vec![col(time_column)],
+            dynamic_options: Some(DynamicGroupOptions::Rolling {
+                period: period.to_string(),
+            }),
         }
     }
 
@@ -773,8 +853,8 @@ impl LazyFrame {
     }
 
     /// Aggregate all the columns as their quantile values.
-    pub fn quantile(self, quantile: f64) -> LazyFrame {
-        self.select_local(vec![col("*").quantile(quantile)])
+    pub fn quantile(self, quantile: f64, interpol: QuantileInterpolOptions) -> LazyFrame {
+        self.select_local(vec![col("*").quantile(quantile, interpol)])
     }
 
     /// Aggregate all the columns as their standard deviation values.
@@ -886,6 +966,7 @@ pub struct LazyGroupBy {
     pub(crate) logical_plan: LogicalPlan,
     opt_state: OptState,
     keys: Vec<Expr>,
+    dynamic_options: Option<DynamicGroupOptions>,
 }
 
 impl LazyGroupBy {
@@ -906,14 +987,16 @@ impl LazyGroupBy {
     ///        .agg(vec![
     ///            col("rain").min(),
     ///            col("rain").sum(),
-    ///            col("rain").quantile(0.5).alias("median_rain"),
+    ///            col("rain")
+    ///                .quantile(0.5, QuantileInterpolOptions::default())
+    ///                .alias("median_rain"),
     ///        ])
     ///        .sort("date", false)
     /// }
     /// ```
     pub fn agg(self, aggs: Vec<Expr>) -> LazyFrame {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), aggs, None)
+            .groupby(Arc::new(self.keys), aggs, None, self.dynamic_options)
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -923,7 +1006,12 @@ impl LazyGroupBy {
         F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
     {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), vec![], Some(Arc::new(f)))
+            .groupby(
+                Arc::new(self.keys),
+                vec![],
+                Some(Arc::new(f)),
+                self.dynamic_options,
+            )
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -1132,7 +1220,9 @@ mod test {
             .agg(vec![
                 col("rain").min(),
                 col("rain").sum(),
-                col("rain").quantile(0.5).alias("median_rain"),
+                col("rain")
+                    .quantile(0.5, QuantileInterpolOptions::default())
+                    .alias("median_rain"),
             ])
             .sort("date", false);
 
@@ -1452,10 +1542,316 @@ mod test {
         assert!(df
             .clone()
             .lazy()
-            .quantile(0.5)
+            .quantile(0.5, QuantileInterpolOptions::Linear)
+            .collect()
+            .unwrap()
+            .frame_equal_missing(&df.quantile(0.5, QuantileInterpolOptions::Linear).unwrap()));
+    }
+
+    #[test]
+    fn test_lazy_groupby_quantile_interpol() {
+        let df = df! {
+            "groups" => &[1, 1, 1, 2, 2],
+            "values" => &[1.0, 3.0, 2.0, 4.0, 6.0]
+        }
+        .unwrap();
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![
+                col("values")
+                    .quantile(0.25, QuantileInterpolOptions::Linear)
+                    .alias("linear"),
+                col("values")
+                    .quantile(0.25, QuantileInterpolOptions::Higher)
+                    .alias("higher"),
+            ])
+            .sort("groups", false)
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("linear").unwrap().f64().unwrap()),
+            &[Some(1.5), Some(4.5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("higher").unwrap().f64().unwrap()),
+            &[Some(2.0), Some(6.0)]
+        );
+
+        // multiple quantiles are collected in a list per group
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![col("values").quantiles(
+                &[0.0, 0.5, 1.0],
+                QuantileInterpolOptions::Linear,
+            )])
+            .sort("groups", false)
+            .collect()
+            .unwrap();
+        let quantiles = out.column("values_quantiles").unwrap().list().unwrap();
+        assert_eq!(
+            Vec::from(quantiles.get(0).unwrap().f64().unwrap()),
+            &[Some(1.0), Some(2.0), Some(3.0)]
+        );
+        assert_eq!(
+            Vec::from(quantiles.get(1).unwrap().f64().unwrap()),
+            &[Some(4.0), Some(5.0), Some(6.0)]
+        );
+        let out = df
+            .lazy()
+            .select(&[col("values").quantiles(&[0.25, 0.5], QuantileInterpolOptions::Lower)])
+            .collect()
+            .unwrap();
+        let quantiles = out.column("values").unwrap().list().unwrap();
+        assert_eq!(quantiles.len(), 1);
+        assert_eq!(
+            Vec::from(quantiles.get(0).unwrap().f64().unwrap()),
+            &[Some(2.0), Some(3.0)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_approx_aggregations() {
+        let df = df! {
+            "groups" => &["a", "a", "b", "a", "b", "a"],
+            "values" => &[1.0, 3.0, 10.0, 2.0, 20.0, 4.0]
+        }
+        .unwrap();
+        // a single aggregation on a single key is partitioned and merges the sketches
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![col("values").approx_quantile(0.5)])
+            .sort("groups", false)
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(
+                out.column("values_approx_quantile_0.50")
+                    .unwrap()
+                    .f64()
+                    .unwrap()
+            ),
+            &[Some(2.5), Some(15.0)]
+        );
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![col("values").approx_n_unique()])
+            .sort("groups", false)
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("values_approx_n_unique").unwrap().u32().unwrap()),
+            &[Some(4), Some(2)]
+        );
+
+        // the batches of a scan produce sketches that are merged
+        let out = scan_foods_csv()
+            .with_aggregate_pushdown(true)
+            .select(&[
+                col("calories").approx_n_unique(),
+                col("fats_g").approx_quantile(0.5),
+            ])
+            .collect()
+            .unwrap();
+        let df = scan_foods_csv().collect().unwrap();
+        assert_eq!(
+            out.column("calories").unwrap().u32().unwrap().get(0),
+            Some(df.column("calories").unwrap().approx_n_unique().unwrap())
+        );
+        assert_eq!(
+            out.column("fats_g").unwrap().f64().unwrap().get(0),
+            df.column("fats_g").unwrap().approx_quantile(0.5).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lazy_math() {
+        let df = df! {
+            "a" => &[-4i64, 9, 16],
+            "b" => &[0.25, -1.5, 2.755]
+        }
+        .unwrap();
+        let out = df
+            .lazy()
+            .select(&[
+                col("a").abs().sqrt().alias("sqrt"),
+                col("a").clip(0.0, 10.0).alias("clip"),
+                col("a").bit_and(lit(12)).alias("bit_and"),
+                col("a").shl(2).alias("shl"),
+                col("b").round_decimals(1).alias("round"),
+                col("b").sign().alias("sign"),
+                col("b").floor().alias("floor"),
+            ])
+            .collect()
+            .unwrap();
+        assert_eq!(out.column("sqrt").unwrap().dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.column("sqrt").unwrap().f64().unwrap()),
+            &[Some(2.0), Some(3.0), Some(4.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("clip").unwrap().i64().unwrap()),
+            &[Some(0), Some(9), Some(10)]
+        );
+        // the literal is cast to the type of the column
+        assert_eq!(
+            Vec::from(out.column("bit_and").unwrap().i64().unwrap()),
+            &[Some(12), Some(8), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("shl").unwrap().i64().unwrap()),
+            &[Some(-16), Some(36), Some(64)]
+        );
+        assert_eq!(
+            Vec::from(out.column("round").unwrap().f64().unwrap()),
+            &[Some(0.3), Some(-1.5), Some(2.8)]
+        );
+        assert_eq!(
+            Vec::from(out.column("sign").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(-1.0), Some(1.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("floor").unwrap().f64().unwrap()),
+            &[Some(0.0), Some(-2.0), Some(2.0)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_rank_diff() {
+        let df = df! {
+            "groups" => &["a", "a", "b", "a", "b"],
+            "values" => &[3, 1, 10, 2, 5]
+        }
+        .unwrap();
+        // a window function ranks within the groups
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[col("values")
+                .rank(RankOptions::default().with_method(RankMethod::Dense))
+                .over(col("groups"))
+                .alias("rank")])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("rank").unwrap().u32().unwrap()),
+            &[Some(3), Some(1), Some(2), Some(2), Some(1)]
+        );
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[
+                col("values").diff(1, NullBehavior::Ignore).alias("diff"),
+                col("values").pct_change(2).alias("pct_change"),
+            ])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("diff").unwrap().i32().unwrap()),
+            &[None, Some(-2), Some(9), Some(-8), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("pct_change").unwrap().f64().unwrap()),
+            &[None, None, Some(7.0 / 3.0), Some(1.0), Some(-0.5)]
+        );
+
+        // the differences are computed within the groups, dropping values changes the length
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[col("values")
+                .diff(1, NullBehavior::Ignore)
+                .over(col("groups"))
+                .alias("diff")])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("diff").unwrap().i32().unwrap()),
+            &[None, Some(-2), None, Some(1), Some(-5)]
+        );
+        let out = df
+            .lazy()
+            .select(&[col("values")
+                .diff(1, NullBehavior::Drop)
+                .over(col("groups"))
+                .alias("diff")])
+            .collect();
+        assert!(out.is_err());
+    }
+
+    #[test]
+    fn test_lazy_binning() {
+        let df = df! {
+            "groups" => &["a", "a", "a", "b", "b"],
+            "values" => &[1, 2, 3, 10, 20]
+        }
+        .unwrap();
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[col("values").cut(
+                vec![0.0, 2.0, 100.0],
+                Some(vec!["low".to_string(), "high".to_string()]),
+            )])
             .collect()
+            .unwrap();
+        let out = out
+            .column("values")
             .unwrap()
-            .frame_equal_missing(&df.quantile(0.5).unwrap()));
+            .cast_with_datatype(&DataType::Utf8)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[
+                Some("low"),
+                Some("low"),
+                Some("high"),
+                Some("high"),
+                Some("high")
+            ]
+        );
+
+        // the quantiles and the histograms are computed per group
+        let lf = df
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![
+                col("values").qcut(vec![0.0, 0.5, 1.0]).alias("qcut"),
+                col("values").hist(2).alias("hist"),
+            ])
+            .sort("groups", false);
+        let schema = lf.logical_plan.schema();
+        assert_eq!(
+            schema.field_with_name("qcut").unwrap().data_type(),
+            &DataType::List(ArrowDataType::Utf8)
+        );
+        assert_eq!(
+            schema.field_with_name("hist").unwrap().data_type(),
+            &DataType::List(ArrowDataType::UInt32)
+        );
+        let out = lf.collect().unwrap();
+        let qcut = out.column("qcut").unwrap().list().unwrap().get(0).unwrap();
+        assert_eq!(
+            Vec::from(qcut.utf8().unwrap()),
+            &[Some("[1.0, 2.0]"), Some("[1.0, 2.0]"), Some("(2.0, 3.0]")]
+        );
+        let hist = out.column("hist").unwrap().list().unwrap();
+        assert_eq!(
+            Vec::from(hist.get(0).unwrap().u32().unwrap()),
+            &[Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(hist.get(1).unwrap().u32().unwrap()),
+            &[Some(1), Some(1)]
+        );
     }
 
     #[test]
@@ -1491,6 +1887,54 @@ mod test {
         assert_eq!(out.get_column_names(), vec!["a", "b"])
     }
 
+    #[test]
+    fn test_lazy_fill_none_strategy() {
+        let df = df! {
+            "a" => &[Some(1.0), None, None, Some(4.0)],
+            "b" => &[Some(1.0), None, Some(f64::NAN), None]
+        }
+        .unwrap();
+        let out = df
+            .clone()
+            .lazy()
+            .fill_none(FillNoneStrategy::Linear)
+            .collect()
+            .unwrap();
+        let a = out.column("a").unwrap().f64().unwrap();
+        assert_eq!(Vec::from(a), &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]);
+
+        let out = df
+            .clone()
+            .lazy()
+            .fill_none(FillNoneStrategy::Forward(Some(1)))
+            .fill_nan(lit(0.0))
+            .collect()
+            .unwrap();
+        let a = out.column("a").unwrap().f64().unwrap();
+        assert_eq!(Vec::from(a), &[Some(1.0), Some(1.0), None, Some(4.0)]);
+        let b = out.column("b").unwrap().f64().unwrap();
+        assert_eq!(Vec::from(b), &[Some(1.0), Some(1.0), Some(0.0), Some(0.0)]);
+
+        let c = Series::new("c", &[1.0f32, f32::NAN]);
+        let out = DataFrame::new(vec![c])
+            .unwrap()
+            .lazy()
+            .fill_nan(lit(0.0))
+            .collect()
+            .unwrap();
+        let c = out.column("c").unwrap().f32().unwrap();
+        assert_eq!(Vec::from(c), &[Some(1.0), Some(0.0)]);
+
+        let t = Date64Chunked::new_from_slice("t", &[0, 1, 3, 4]).into_series();
+        let df = df.with_column(t).unwrap();
+        let out = df.lazy().interpolate_by("t").collect().unwrap();
+        let a = out.column("a").unwrap().f64().unwrap();
+        assert_eq!(
+            Vec::from(a),
+            &[Some(1.0), Some(1.75), Some(3.25), Some(4.0)]
+        );
+    }
+
     #[test]
     fn test_lazy_window_functions() {
         let df = df! {
@@ -1634,4 +2078,409 @@ mod test {
 
         assert_eq!(out.get_column_names(), &["ham", "bar"]);
     }
+
+    #[test]
+    fn test_lazy_groupby_dynamic() {
+        // 2021-01-01 00:00 until 2021-01-01 05:00, every hour
+        let t = Date64Chunked::new_from_slice(
+            "t",
+            &[
+                1609459200000,
+                1609462800000,
+                1609466400000,
+                1609470000000,
+                1609473600000,
+                1609477200000,
+            ],
+        )
+        .into_series();
+        let vals = Series::new("vals", [1, 2, 3, 4, 5, 6].as_ref());
+        let df = DataFrame::new(vec![t, vals]).unwrap();
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby_dynamic("t", "2h", "3h", "0h", ClosedWindow::Left)
+            .agg(vec![col("vals").sum(), col("vals").first().alias("first")])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("vals_sum").unwrap().i32().unwrap()),
+            &[Some(6), Some(12), Some(11)]
+        );
+        assert_eq!(
+            Vec::from(out.column("first").unwrap().i32().unwrap()),
+            &[Some(1), Some(3), Some(5)]
+        );
+
+        let out = df
+            .lazy()
+            .groupby_rolling("t", "2h")
+            .agg(vec![col("vals").max()])
+            .collect()
+            .unwrap();
+        assert_eq!(out.height(), 6);
+        assert_eq!(
+            Vec::from(out.column("vals_max").unwrap().i32().unwrap()),
+            &[Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_temporal_truncate() {
+        // 2021-01-31 10:40:00 and 2020-02-15 23:59:00
+        let t = Date64Chunked::new_from_slice("t", &[1612089600000, 1581811140000]).into_series();
+        let df = DataFrame::new(vec![t]).unwrap();
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("t").truncate("1h").alias("truncated"),
+                col("t").offset_by("1mo").month_end().alias("month_end"),
+                col("t").quarter().alias("quarter"),
+            ])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("truncated").unwrap().date64().unwrap()),
+            &[Some(1612087200000), Some(1581807600000)]
+        );
+        // 2021-02-28 10:40:00 and 2020-03-31 23:59:00
+        assert_eq!(
+            Vec::from(out.column("month_end").unwrap().date64().unwrap()),
+            &[Some(1614508800000), Some(1585699140000)]
+        );
+        assert_eq!(
+            Vec::from(out.column("quarter").unwrap().u32().unwrap()),
+            &[Some(1), Some(1)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_date_range_duration() {
+        use polars_core::utils::chrono::NaiveDate;
+        let start = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+        let stop = NaiveDate::from_ymd(2021, 1, 1).and_hms(4, 0, 0);
+        let t = Date64Chunked::date_range("t", start, stop, "2h", ClosedWindow::Both)
+            .unwrap()
+            .into_series();
+        let df = DataFrame::new(vec![t]).unwrap();
+
+        let out = df
+            .lazy()
+            .select(vec![(col("t")
+                - date_range(start, stop, "1h", ClosedWindow::Right))
+            .alias("diff")])
+            .collect()
+            .unwrap();
+        let diff = out.column("diff").unwrap();
+        assert_eq!(diff.dtype(), &DataType::Duration(TimeUnit::Millisecond));
+        assert_eq!(
+            Vec::from(diff.duration_millisecond().unwrap()),
+            &[Some(-3_600_000), Some(0), Some(3_600_000)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_lazy_str_namespace() {
+        let df = DataFrame::new(vec![Series::new("variety", &["Setosa", "Virginica"])]).unwrap();
+        let out = df
+            .lazy()
+            .select(vec![
+                col("variety").str().to_uppercase().alias("upper"),
+                col("variety").str().slice(0, Some(3)).alias("slice"),
+                col("variety").str().extract("(t+)", 1).alias("extract"),
+                col("variety").str().split("t").alias("split"),
+            ])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            out.column("upper").unwrap().utf8().unwrap().get(0),
+            Some("SETOSA")
+        );
+        assert_eq!(
+            out.column("slice").unwrap().utf8().unwrap().get(0),
+            Some("Set")
+        );
+        assert_eq!(
+            out.column("extract").unwrap().utf8().unwrap().get(0),
+            Some("t")
+        );
+        assert_eq!(
+            out.column("split").unwrap().dtype(),
+            &DataType::List(ArrowDataType::LargeUtf8)
+        );
+    }
+
+    #[test]
+    fn test_lazy_list_namespace() {
+        let df = df! {
+            "groups" => &["a", "a", "b", "a"],
+            "values" => &[3, 1, 2, 5]
+        }
+        .unwrap();
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![col("values").list()])
+            .sort("groups", false)
+            .select(vec![
+                col("values_agg_list").arr().lengths().alias("lengths"),
+                col("values_agg_list").arr().max().alias("max"),
+                col("values_agg_list")
+                    .arr()
+                    .sort(false)
+                    .arr()
+                    .first()
+                    .alias("first"),
+                col("values_agg_list")
+                    .arr()
+                    .contains(lit(5))
+                    .alias("contains"),
+            ])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("lengths").unwrap().u32().unwrap()),
+            &[Some(3), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("max").unwrap().i32().unwrap()),
+            &[Some(5), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("first").unwrap().i32().unwrap()),
+            &[Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("contains").unwrap().bool().unwrap()),
+            &[Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_struct() {
+        let df = df! {
+            "a" => &[1, 2, 3],
+            "b" => &["x", "y", "z"]
+        }
+        .unwrap();
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![as_struct(vec![col("a"), col("b")]).alias("s")])
+            .with_column(col("s").struct_().field("b"))
+            .collect()
+            .unwrap();
+        assert_eq!(out.get_column_names(), &["s", "b"]);
+        assert_eq!(
+            Vec::from(out.column("b").unwrap().utf8().unwrap()),
+            &[Some("x"), Some("y"), Some("z")]
+        );
+
+        let out = out.drop("b").unwrap().unnest("s").unwrap();
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(
+            Vec::from(out.column("a").unwrap().i32().unwrap()),
+            &[Some(1), Some(2), Some(3)]
+        );
+
+        // without an alias the struct is named after the first expression, and other binary
+        // functions keep their default name
+        let lf = df.lazy().select(vec![
+            as_struct(vec![col("a"), col("b")]),
+            map_binary(
+                col("a"),
+                col("a"),
+                |a, b| Ok(&a + &b),
+                Some(Field::new("binary_function", DataType::Int32)),
+            ),
+        ]);
+        let schema = lf.logical_plan.schema().clone();
+        let out = lf.collect().unwrap();
+        assert_eq!(out.get_column_names(), &["a", "binary_function"]);
+        assert_eq!(schema.field(0).unwrap().name(), "a");
+        assert_eq!(
+            out.column("a").unwrap().dtype(),
+            schema.field(0).unwrap().data_type()
+        );
+    }
+
+    #[test]
+    fn test_lazy_rolling() {
+        let df = df! {
+            "values" => &[1, 4, 2, 8]
+        }
+        .unwrap();
+
+        let options = RollingOptions::new(2);
+        let out = df
+            .lazy()
+            .select(vec![
+                col("values").rolling_sum(options.clone()).alias("sum"),
+                col("values").rolling_std(options).alias("std"),
+                col("values")
+                    .rolling_median(RollingOptions::new(3).with_center(true).with_min_periods(1))
+                    .alias("median"),
+            ])
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            Vec::from(out.column("sum").unwrap().i32().unwrap()),
+            &[None, Some(5), Some(6), Some(10)]
+        );
+        assert_eq!(out.column("std").unwrap().dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.column("median").unwrap().f64().unwrap()),
+            &[Some(2.5), Some(2.0), Some(4.0), Some(5.0)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_map_over() {
+        let df = df! {
+            "groups" => &["a", "a", "b"],
+            "values" => &[1, 2, 3]
+        }
+        .unwrap();
+
+        // a function that doesn't apply per group gets the aggregated list of every group
+        let out = df
+            .lazy()
+            .select(vec![col("values")
+                .map(
+                    |s: Series| {
+                        assert!(matches!(s.dtype(), DataType::List(_)));
+                        Ok(s)
+                    },
+                    Some(DataType::List(ArrowDataType::Int32)),
+                )
+                .over(col("groups"))
+                .alias("lists")])
+            .collect()
+            .unwrap();
+        let lengths = out
+            .column("lists")
+            .unwrap()
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, &[Some(2), Some(2), Some(1)]);
+    }
+
+    #[test]
+    fn test_lazy_ewm_over() {
+        let df = df! {
+            "groups" => &["a", "a", "b", "a", "b"],
+            "values" => &[1, 2, 10, 4, 20]
+        }
+        .unwrap();
+
+        let out = df
+            .lazy()
+            .select(vec![col("values")
+                .ewm_mean(EWMOptions::default())
+                .over(col("groups"))
+                .alias("ewm")])
+            .collect()
+            .unwrap();
+
+        let ewm = out.column("ewm").unwrap().f64().unwrap();
+        let expected = [1.0, 5.0 / 3.0, 10.0, 3.0, 50.0 / 3.0];
+        for (a, b) in ewm.into_iter().zip(expected.iter()) {
+            assert!((a.unwrap() - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_lazy_cum_agg_over() {
+        let df = df! {
+            "customer" => &["a", "b", "a", "b", "a"],
+            "amount" => &[1, 2, 3, 4, 5]
+        }
+        .unwrap();
+
+        // the running totals are computed per customer and keep the row order
+        let out = df
+            .lazy()
+            .select(vec![
+                col("amount")
+                    .cum_sum(false)
+                    .over(col("customer"))
+                    .alias("cum_sum"),
+                col("amount")
+                    .cum_prod(true)
+                    .over(col("customer"))
+                    .alias("cum_prod"),
+                col("amount")
+                    .cum_count(false)
+                    .over(col("customer"))
+                    .alias("cum_count"),
+                sum("amount").over(col("customer")).alias("sum"),
+            ])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("cum_sum").unwrap().i32().unwrap()),
+            &[Some(1), Some(2), Some(4), Some(6), Some(9)]
+        );
+        assert_eq!(
+            Vec::from(out.column("cum_prod").unwrap().i32().unwrap()),
+            &[Some(15), Some(8), Some(15), Some(4), Some(5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("cum_count").unwrap().u32().unwrap()),
+            &[Some(1), Some(1), Some(2), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("sum").unwrap().i32().unwrap()),
+            &[Some(9), Some(6), Some(9), Some(6), Some(9)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_moments() {
+        let df = df! {
+            "a" => [1, 1, 1, 1, 2, 2],
+            "b" => [1, 2, 3, 10, 5, 5]
+        }
+        .unwrap();
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("a")])
+            .agg(vec![
+                col("b").skew(false),
+                col("b").kurtosis(true, false),
+                col("b").mode(),
+            ])
+            .sort("a", false)
+            .collect()
+            .unwrap();
+        let skew = out.column("b_skew").unwrap().f64().unwrap();
+        assert!((skew.get(0).unwrap() - 1.763_632_6).abs() < 1e-6);
+        assert_eq!(
+            out.column("b_kurtosis").unwrap().dtype(),
+            &DataType::Float64
+        );
+        assert_eq!(
+            Vec::from(out.column("b_mode").unwrap().i32().unwrap()),
+            &[Some(1), Some(5)]
+        );
+
+        let out = df
+            .lazy()
+            .select(vec![col("b").skew(true).alias("skew"), col("b").mode()])
+            .collect()
+            .unwrap();
+        assert_eq!(out.shape(), (1, 2));
+        assert_eq!(out.column("b").unwrap().i32().unwrap().get(0), Some(5));
+    }
 }
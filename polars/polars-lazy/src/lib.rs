@@ -114,7 +114,9 @@
 //!     .agg(vec![
 //!         col("rain").min(),
 //!         col("rain").sum(),
-//!         col("rain").quantile(0.5).alias("median_rain"),
+//!         col("rain")
+//!             .quantile(0.5, QuantileInterpolOptions::default())
+//!             .alias("median_rain"),
 //!     ])
 //!     .sort("date", false)
 //!     .collect()
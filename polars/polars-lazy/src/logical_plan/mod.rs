@@ -81,6 +81,14 @@ pub enum LiteralValue {
     },
     #[cfg(feature = "temporal")]
     DateTime(NaiveDateTime),
+    #[cfg(feature = "temporal")]
+    DateRange {
+        low: NaiveDateTime,
+        high: NaiveDateTime,
+        every: String,
+        closed: ClosedWindow,
+        data_type: DataType,
+    },
 }
 
 impl LiteralValue {
@@ -102,6 +110,8 @@ impl LiteralValue {
             LiteralValue::Range { data_type, .. } => data_type.clone(),
             #[cfg(feature = "temporal")]
             LiteralValue::DateTime(_) => DataType::Date64,
+            #[cfg(feature = "temporal")]
+            LiteralValue::DateRange { data_type, .. } => data_type.clone(),
             _ => panic!("Cannot treat {:?} as scalar value", self),
         }
     }
@@ -170,6 +180,7 @@ pub enum LogicalPlan {
         aggs: Vec<Expr>,
         schema: SchemaRef,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        dynamic_options: Option<DynamicGroupOptions>,
     },
     Join {
         input_left: Box<LogicalPlan>,
@@ -680,9 +691,23 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             AggExpr::AggGroups(e) => {
                 AggExpr::AggGroups(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
-            AggExpr::Quantile { expr, quantile } => AggExpr::Quantile {
+            AggExpr::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => AggExpr::Quantile {
                 expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
                 quantile,
+                interpol,
+            },
+            AggExpr::Quantiles {
+                expr,
+                quantiles,
+                interpol,
+            } => AggExpr::Quantiles {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                quantiles,
+                interpol,
             },
             AggExpr::List(e) => {
                 AggExpr::List(Box::new(replace_wildcard_with_column(*e, column_name)))
@@ -693,12 +718,35 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             AggExpr::Std(e) => {
                 AggExpr::Std(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
+            AggExpr::ApproxNUnique(e) => {
+                AggExpr::ApproxNUnique(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::ApproxQuantile { expr, quantile } => AggExpr::ApproxQuantile {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                quantile,
+            },
+            AggExpr::Skew { expr, bias } => AggExpr::Skew {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                bias,
+            },
+            AggExpr::Kurtosis { expr, fisher, bias } => AggExpr::Kurtosis {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                fisher,
+                bias,
+            },
+            AggExpr::Mode(e) => {
+                AggExpr::Mode(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
         }
         .into(),
         Expr::Shift { input, periods } => Expr::Shift {
             input: Box::new(replace_wildcard_with_column(*input, column_name)),
             periods,
         },
+        Expr::Math { input, function } => Expr::Math {
+            input: Box::new(replace_wildcard_with_column(*input, column_name)),
+            function,
+        },
         Expr::Slice {
             input,
             offset,
@@ -936,17 +984,57 @@ impl LogicalPlanBuilder {
         }
     }
 
-    pub fn fill_none(self, fill_value: Expr) -> Self {
+    pub fn fill_none(self, fill: FillNone) -> Self {
         let schema = self.0.schema();
         let exprs = schema
             .fields()
             .iter()
             .map(|field| {
                 let name = field.name();
-                when(col(name).is_null())
-                    .then(fill_value.clone())
-                    .otherwise(col(name))
-                    .alias(name)
+                match &fill {
+                    FillNone::Value(fill_value) => when(col(name).is_null())
+                        .then(fill_value.clone())
+                        .otherwise(col(name))
+                        .alias(name),
+                    FillNone::Strategy(strategy) => {
+                        col(name).fill_none_with_strategy(*strategy).alias(name)
+                    }
+                }
+            })
+            .collect();
+        self.project_local(exprs)
+    }
+
+    pub fn fill_nan(self, fill_value: Expr) -> Self {
+        let schema = self.0.schema();
+        let exprs = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let name = field.name();
+                if field.data_type().is_float() {
+                    // cast so that a Float32 column stays Float32
+                    col(name).fill_nan(fill_value.clone().cast(field.data_type().clone()))
+                } else {
+                    col(name)
+                }
+            })
+            .collect();
+        self.project_local(exprs)
+    }
+
+    pub fn interpolate_by(self, by: &str) -> Self {
+        let schema = self.0.schema();
+        let exprs = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let name = field.name();
+                if name != by && field.data_type().is_numeric() {
+                    col(name).interpolate_by(col(by)).alias(name)
+                } else {
+                    col(name)
+                }
             })
             .collect();
         self.project_local(exprs)
@@ -1000,6 +1088,7 @@ impl LogicalPlanBuilder {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        dynamic_options: Option<DynamicGroupOptions>,
     ) -> Self {
         debug_assert!(!keys.is_empty());
         let current_schema = self.0.schema();
@@ -1015,6 +1104,7 @@ impl LogicalPlanBuilder {
             aggs,
             schema: Arc::new(schema),
             apply,
+            dynamic_options,
         }
         .into()
     }
@@ -36,6 +36,11 @@ impl AggregatePushdown {
         let dummy_first = AExpr::Agg(AAggExpr::First(Node(dummy_node)));
         let dummy_last = AExpr::Agg(AAggExpr::First(Node(dummy_node)));
         let dummy_sum = AExpr::Agg(AAggExpr::Sum(Node(dummy_node)));
+        let dummy_approx_n_unique = AExpr::Agg(AAggExpr::ApproxNUnique(Node(dummy_node)));
+        let dummy_approx_quantile = AExpr::Agg(AAggExpr::ApproxQuantile {
+            expr: Node(dummy_node),
+            quantile: 0.0,
+        });
 
         // only do aggregation pushdown if all projections are aggreegations
         #[allow(clippy::blocks_in_if_conditions)]
@@ -45,7 +50,9 @@ impl AggregatePushdown {
                     || has_aexpr(*node, expr_arena, &dummy_max, true)
                     || has_aexpr(*node, expr_arena, &dummy_first, true)
                     || has_aexpr(*node, expr_arena, &dummy_sum, true)
-                    || has_aexpr(*node, expr_arena, &dummy_last, true))
+                    || has_aexpr(*node, expr_arena, &dummy_last, true)
+                    || has_aexpr(*node, expr_arena, &dummy_approx_n_unique, true)
+                    || has_aexpr(*node, expr_arena, &dummy_approx_quantile, true))
                     && {
                         let roots = aexpr_to_root_nodes(*node, expr_arena);
                         roots.len() == 1
@@ -5,7 +5,7 @@ use ahash::RandomState;
 use polars_core::frame::group_by::{fmt_groupby_column, GroupByMethod};
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
-use polars_core::utils::{get_supertype, Arena, Node};
+use polars_core::utils::{get_supertype, get_temporal_arithmetic_type, Arena, Node};
 use std::collections::HashMap;
 
 pub(crate) mod aggregate_pushdown;
@@ -223,6 +223,9 @@ impl StackOptimizer {
                             AAggExpr::Quantile { expr, .. } => {
                                 exprs.push((*expr, current_lp_node));
                             }
+                            AAggExpr::Quantiles { expr, .. } => {
+                                exprs.push((*expr, current_lp_node));
+                            }
                             AAggExpr::Sum(expr) => {
                                 exprs.push((*expr, current_lp_node));
                             }
@@ -238,10 +241,28 @@ impl StackOptimizer {
                             AAggExpr::Var(expr) => {
                                 exprs.push((*expr, current_lp_node));
                             }
+                            AAggExpr::ApproxNUnique(expr) => {
+                                exprs.push((*expr, current_lp_node));
+                            }
+                            AAggExpr::ApproxQuantile { expr, .. } => {
+                                exprs.push((*expr, current_lp_node));
+                            }
+                            AAggExpr::Skew { expr, .. } => {
+                                exprs.push((*expr, current_lp_node));
+                            }
+                            AAggExpr::Kurtosis { expr, .. } => {
+                                exprs.push((*expr, current_lp_node));
+                            }
+                            AAggExpr::Mode(expr) => {
+                                exprs.push((*expr, current_lp_node));
+                            }
                         },
                         AExpr::Shift { input, .. } => {
                             exprs.push((*input, current_lp_node));
                         }
+                        AExpr::Math { input, .. } => {
+                            exprs.push((*input, current_lp_node));
+                        }
                         AExpr::Ternary {
                             predicate,
                             truthy,
@@ -293,12 +314,36 @@ pub enum AAggExpr {
     Last(Node),
     Mean(Node),
     List(Node),
-    Quantile { expr: Node, quantile: f64 },
+    Quantile {
+        expr: Node,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
+    Quantiles {
+        expr: Node,
+        quantiles: Vec<f64>,
+        interpol: QuantileInterpolOptions,
+    },
     Sum(Node),
     Count(Node),
     Std(Node),
     Var(Node),
     AggGroups(Node),
+    ApproxNUnique(Node),
+    ApproxQuantile {
+        expr: Node,
+        quantile: f64,
+    },
+    Skew {
+        expr: Node,
+        bias: bool,
+    },
+    Kurtosis {
+        expr: Node,
+        fisher: bool,
+        bias: bool,
+    },
+    Mode(Node),
 }
 
 // AExpr representation of Nodes which are allocated in an Arena
@@ -336,12 +381,16 @@ pub enum AExpr {
     Udf {
         input: Node,
         function: Arc<dyn SeriesUdf>,
-        output_type: Option<DataType>,
+        output_type: GetOutput,
     },
     Shift {
         input: Node,
         periods: i64,
     },
+    Math {
+        input: Node,
+        function: MathFunction,
+    },
     Window {
         function: Node,
         partition_by: Node,
@@ -443,13 +492,19 @@ impl AExpr {
                     | Operator::Or
                     | Operator::NotLike
                     | Operator::Like => DataType::Boolean,
+                    Operator::Plus | Operator::Minus => get_temporal_arithmetic_type(
+                        &left_type,
+                        &right_type,
+                        matches!(op, Operator::Minus),
+                    )
+                    .map_or_else(|| get_supertype(&left_type, &right_type), Ok)?,
                     _ => get_supertype(&left_type, &right_type)?,
                 };
 
                 use Operator::*;
                 let out_field;
                 let out_name = match op {
-                    Plus | Minus | Multiply | Divide | Modulus => {
+                    Plus | Minus | Multiply | Divide | Modulus | BitAnd | BitOr | BitXor => {
                         out_field = arena.get(*left).to_field(schema, ctxt, arena)?;
                         out_field.name().as_str()
                     }
@@ -545,10 +600,46 @@ impl AExpr {
                         let new_name = fmt_groupby_column(field.name(), GroupByMethod::Groups);
                         Field::new(&new_name, DataType::List(ArrowDataType::UInt32))
                     }
-                    Quantile { expr, quantile } => field_by_context(
+                    Quantile {
+                        expr,
+                        quantile,
+                        interpol,
+                    } => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field =
+                            Field::new(field.name(), interpol.quantile_dtype(field.data_type()));
+                        field_by_context(field, ctxt, GroupByMethod::Quantile(*quantile))
+                    }
+                    Quantiles { expr, interpol, .. } => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let dtype = interpol.quantile_dtype(field.data_type());
+                        let field = Field::new(field.name(), DataType::List(dtype.to_arrow()));
+                        field_by_context(field, ctxt, GroupByMethod::Quantiles)
+                    }
+                    ApproxNUnique(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::UInt32);
+                        field_by_context(field, ctxt, GroupByMethod::ApproxNUnique)
+                    }
+                    ApproxQuantile { expr, quantile } => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::ApproxQuantile(*quantile))
+                    }
+                    Skew { expr, bias } => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::Skew(*bias))
+                    }
+                    Kurtosis { expr, fisher, bias } => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::Kurtosis(*fisher, *bias))
+                    }
+                    Mode(expr) => field_by_context(
                         arena.get(*expr).to_field(schema, ctxt, arena)?,
                         ctxt,
-                        GroupByMethod::Quantile(*quantile),
+                        GroupByMethod::Mode,
                     ),
                 };
                 Ok(field)
@@ -560,13 +651,10 @@ impl AExpr {
             Ternary { truthy, .. } => arena.get(*truthy).to_field(schema, ctxt, arena),
             Udf {
                 output_type, input, ..
-            } => match output_type {
-                None => arena.get(*input).to_field(schema, ctxt, arena),
-                Some(output_type) => {
-                    let input_field = arena.get(*input).to_field(schema, ctxt, arena)?;
-                    Ok(Field::new(input_field.name(), output_type.clone()))
-                }
-            },
+            } => {
+                let field = arena.get(*input).to_field(schema, ctxt, arena)?;
+                Ok(output_type.get_field(field, ctxt))
+            }
             BinaryFunction {
                 input_a,
                 input_b,
@@ -579,6 +667,13 @@ impl AExpr {
                 Ok(out.unwrap())
             }
             Shift { input, .. } => arena.get(*input).to_field(schema, ctxt, arena),
+            Math { input, function } => {
+                let field = arena.get(*input).to_field(schema, ctxt, arena)?;
+                Ok(Field::new(
+                    field.name(),
+                    function.get_type(field.data_type()),
+                ))
+            }
             Slice { input, .. } => arena.get(*input).to_field(schema, ctxt, arena),
             Wildcard => panic!("should be no wildcard at this point"),
         }
@@ -660,6 +755,7 @@ pub enum ALogicalPlan {
         aggs: Vec<Node>,
         schema: SchemaRef,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        dynamic_options: Option<DynamicGroupOptions>,
     },
     Join {
         input_left: Node,
@@ -773,14 +869,43 @@ fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
                 AggExpr::Mean(expr) => AAggExpr::Mean(to_aexpr(*expr, arena)),
                 AggExpr::List(expr) => AAggExpr::List(to_aexpr(*expr, arena)),
                 AggExpr::Count(expr) => AAggExpr::Count(to_aexpr(*expr, arena)),
-                AggExpr::Quantile { expr, quantile } => AAggExpr::Quantile {
+                AggExpr::Quantile {
+                    expr,
+                    quantile,
+                    interpol,
+                } => AAggExpr::Quantile {
                     expr: to_aexpr(*expr, arena),
                     quantile,
+                    interpol,
+                },
+                AggExpr::Quantiles {
+                    expr,
+                    quantiles,
+                    interpol,
+                } => AAggExpr::Quantiles {
+                    expr: to_aexpr(*expr, arena),
+                    quantiles,
+                    interpol,
                 },
                 AggExpr::Sum(expr) => AAggExpr::Sum(to_aexpr(*expr, arena)),
                 AggExpr::Std(expr) => AAggExpr::Std(to_aexpr(*expr, arena)),
                 AggExpr::Var(expr) => AAggExpr::Var(to_aexpr(*expr, arena)),
                 AggExpr::AggGroups(expr) => AAggExpr::AggGroups(to_aexpr(*expr, arena)),
+                AggExpr::ApproxNUnique(expr) => AAggExpr::ApproxNUnique(to_aexpr(*expr, arena)),
+                AggExpr::ApproxQuantile { expr, quantile } => AAggExpr::ApproxQuantile {
+                    expr: to_aexpr(*expr, arena),
+                    quantile,
+                },
+                AggExpr::Skew { expr, bias } => AAggExpr::Skew {
+                    expr: to_aexpr(*expr, arena),
+                    bias,
+                },
+                AggExpr::Kurtosis { expr, fisher, bias } => AAggExpr::Kurtosis {
+                    expr: to_aexpr(*expr, arena),
+                    fisher,
+                    bias,
+                },
+                AggExpr::Mode(expr) => AAggExpr::Mode(to_aexpr(*expr, arena)),
             };
             AExpr::Agg(a_agg)
         }
@@ -822,6 +947,10 @@ fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
             input: to_aexpr(*input, arena),
             periods,
         },
+        Expr::Math { input, function } => AExpr::Math {
+            input: to_aexpr(*input, arena),
+            function,
+        },
         Expr::Window {
             function,
             partition_by,
@@ -991,6 +1120,7 @@ pub(crate) fn to_alp(
             aggs,
             schema,
             apply,
+            dynamic_options,
         } => {
             let i = to_alp(*input, expr_arena, lp_arena);
             let aggs_new = aggs.into_iter().map(|x| to_aexpr(x, expr_arena)).collect();
@@ -1005,6 +1135,7 @@ pub(crate) fn to_alp(
                 aggs: aggs_new,
                 schema,
                 apply,
+                dynamic_options,
             }
         }
         LogicalPlan::Join {
@@ -1169,11 +1300,29 @@ fn node_to_exp(node: Node, expr_arena: &mut Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::List(Box::new(exp)).into()
             }
-            AAggExpr::Quantile { expr, quantile } => {
+            AAggExpr::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Quantile {
                     expr: Box::new(exp),
                     quantile,
+                    interpol,
+                }
+                .into()
+            }
+            AAggExpr::Quantiles {
+                expr,
+                quantiles,
+                interpol,
+            } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Quantiles {
+                    expr: Box::new(exp),
+                    quantiles,
+                    interpol,
                 }
                 .into()
             }
@@ -1193,6 +1342,39 @@ fn node_to_exp(node: Node, expr_arena: &mut Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::AggGroups(Box::new(exp)).into()
             }
+            AAggExpr::ApproxNUnique(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ApproxNUnique(Box::new(exp)).into()
+            }
+            AAggExpr::ApproxQuantile { expr, quantile } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ApproxQuantile {
+                    expr: Box::new(exp),
+                    quantile,
+                }
+                .into()
+            }
+            AAggExpr::Skew { expr, bias } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Skew {
+                    expr: Box::new(exp),
+                    bias,
+                }
+                .into()
+            }
+            AAggExpr::Kurtosis { expr, fisher, bias } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Kurtosis {
+                    expr: Box::new(exp),
+                    fisher,
+                    bias,
+                }
+                .into()
+            }
+            AAggExpr::Mode(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Mode(Box::new(exp)).into()
+            }
             AAggExpr::Count(expr) => {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Count(Box::new(exp)).into()
@@ -1205,6 +1387,13 @@ fn node_to_exp(node: Node, expr_arena: &mut Arena<AExpr>) -> Expr {
                 periods,
             }
         }
+        AExpr::Math { input, function } => {
+            let e = node_to_exp(input, expr_arena);
+            Expr::Math {
+                input: Box::new(e),
+                function,
+            }
+        }
         AExpr::Ternary {
             predicate,
             truthy,
@@ -1411,6 +1600,7 @@ pub(crate) fn node_to_lp(
             aggs,
             schema,
             apply,
+            dynamic_options,
         } => {
             let i = node_to_lp(input, expr_arena, lp_arena);
             let a = aggs.iter().map(|x| node_to_exp(*x, expr_arena)).collect();
@@ -1422,6 +1612,7 @@ pub(crate) fn node_to_lp(
                 aggs: a,
                 schema,
                 apply,
+                dynamic_options,
             }
         }
         ALogicalPlan::Join {
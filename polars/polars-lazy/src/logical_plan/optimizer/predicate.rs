@@ -382,6 +382,7 @@ impl PredicatePushDown {
                 aggs,
                 schema,
                 apply,
+                dynamic_options,
             } => {
                 // dont push down predicates. An aggregation needs all rows
                 let lp = Aggregate {
@@ -390,6 +391,7 @@ impl PredicatePushDown {
                     aggs,
                     schema,
                     apply,
+                    dynamic_options,
                 };
                 Ok(self.finish_at_leaf(lp, acc_predicates))
             }
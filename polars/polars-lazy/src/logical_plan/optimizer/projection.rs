@@ -364,6 +364,7 @@ impl ProjectionPushDown {
                 aggs,
                 apply,
                 schema,
+                dynamic_options,
             } => {
                 // the custom function may have all columns
                 if let Some(f) = apply {
@@ -373,6 +374,7 @@ impl ProjectionPushDown {
                         aggs,
                         schema,
                         apply: Some(f),
+                        dynamic_options,
                     };
 
                     let mut builder = LogicalPlanBuilder::from(lp);
@@ -397,7 +399,8 @@ impl ProjectionPushDown {
                     }
 
                     let lp = self.push_down(*input, acc_projections, names, projections_seen)?;
-                    let builder = LogicalPlanBuilder::from(lp).groupby(keys, aggs, apply);
+                    let builder =
+                        LogicalPlanBuilder::from(lp).groupby(keys, aggs, apply, dynamic_options);
                     Ok(builder.build())
                 }
             }
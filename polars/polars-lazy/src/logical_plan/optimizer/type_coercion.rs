@@ -1,6 +1,6 @@
 use crate::logical_plan::Context;
 use crate::prelude::*;
-use polars_core::utils::get_supertype;
+use polars_core::utils::{get_supertype, get_temporal_arithmetic_type};
 
 pub struct TypeCoercionRule {}
 
@@ -64,7 +64,16 @@ impl OptimizationRule for TypeCoercionRule {
                 let type_right = right
                     .get_type(input_schema, Context::Other, expr_arena)
                     .expect("could not get dtype");
-                if type_left == type_right {
+                // temporal arithmetic is dispatched on the original types, e.g. the difference
+                // of two dates is a duration
+                let temporal = matches!(op, Operator::Plus | Operator::Minus)
+                    && get_temporal_arithmetic_type(
+                        &type_left,
+                        &type_right,
+                        matches!(op, Operator::Minus),
+                    )
+                    .is_some();
+                if type_left == type_right || temporal {
                     None
                 } else {
                     let st = get_supertype(&type_left, &type_right)
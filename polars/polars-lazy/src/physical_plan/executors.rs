@@ -462,6 +462,7 @@ pub struct GroupByExec {
     keys: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Arc<dyn PhysicalExpr>>,
     apply: Option<Arc<dyn DataFrameUdf>>,
+    dynamic_options: Option<DynamicGroupOptions>,
 }
 
 impl GroupByExec {
@@ -470,12 +471,14 @@ impl GroupByExec {
         keys: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Arc<dyn PhysicalExpr>>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        dynamic_options: Option<DynamicGroupOptions>,
     ) -> Self {
         Self {
             input,
             keys,
             aggs,
             apply,
+            dynamic_options,
         }
     }
 }
@@ -485,8 +488,13 @@ fn groupby_helper(
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
     apply: Option<&Arc<dyn DataFrameUdf>>,
+    dynamic_options: Option<&DynamicGroupOptions>,
 ) -> Result<DataFrame> {
-    let gb = df.groupby_with_series(keys, true)?;
+    let gb = match dynamic_options {
+        // the time column is the only key of a dynamic groupby
+        Some(options) => df.groupby_dynamic_with_series(&keys[0], options)?,
+        None => df.groupby_with_series(keys, true)?,
+    };
     if let Some(f) = apply {
         return gb.apply(|df| f.call_udf(df));
     }
@@ -529,7 +537,13 @@ impl Executor for GroupByExec {
             .iter()
             .map(|e| e.evaluate(&df))
             .collect::<Result<_>>()?;
-        groupby_helper(df, keys, &self.aggs, self.apply.as_ref())
+        groupby_helper(
+            df,
+            keys,
+            &self.aggs,
+            self.apply.as_ref(),
+            self.dynamic_options.as_ref(),
+        )
     }
 }
 
@@ -580,7 +594,7 @@ impl Executor for PartitionGroupByExec {
             let frac = cat_map.len() as f32 / ca.len() as f32;
             // TODO! proper benchmark which boundary should be chosen.
             if frac > 0.3 {
-                return groupby_helper(original_df, keys, &self.phys_aggs, None);
+                return groupby_helper(original_df, keys, &self.phys_aggs, None, None);
             }
         }
 
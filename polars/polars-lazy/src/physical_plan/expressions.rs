@@ -76,6 +76,27 @@ impl PhysicalExpr for LiteralExpr {
                 let timestamp = naive_datetime_to_date64(ndt);
                 Date64Chunked::full("literal", timestamp, 1).into_series()
             }
+            #[cfg(feature = "temporal")]
+            DateRange {
+                low,
+                high,
+                every,
+                closed,
+                data_type,
+            } => match data_type {
+                DataType::Date32 => {
+                    Date32Chunked::date_range("literal", low.date(), high.date(), every, *closed)?
+                        .into_series()
+                }
+                DataType::Date64 => {
+                    Date64Chunked::date_range("literal", *low, *high, every, *closed)?.into_series()
+                }
+                dt => {
+                    return Err(PolarsError::InvalidOperation(
+                        format!("datatype {:?} not supported as date range", dt).into(),
+                    ))
+                }
+            },
         };
         Ok(s)
     }
@@ -100,6 +121,8 @@ impl PhysicalExpr for LiteralExpr {
             Range { data_type, .. } => Field::new(name, data_type.clone()),
             #[cfg(feature = "temporal")]
             DateTime(_) => Field::new(name, DataType::Date64),
+            #[cfg(feature = "temporal")]
+            DateRange { data_type, .. } => Field::new(name, data_type.clone()),
         };
         Ok(field)
     }
@@ -156,6 +179,9 @@ impl PhysicalExpr for BinaryExpr {
             Operator::Like => todo!(),
             Operator::NotLike => todo!(),
             Operator::Modulus => Ok(left % right),
+            Operator::BitAnd => left.bit_and(right),
+            Operator::BitOr => left.bit_or(right),
+            Operator::BitXor => left.xor(right),
         }
     }
     fn to_field(&self, _input_schema: &Schema) -> Result<Field> {
@@ -357,14 +383,6 @@ impl PhysicalExpr for IsNotNullExpr {
 }
 
 // TODO: to_field for groups and n_unique is probably wrong as the Datatype changes to Uint32
-macro_rules! impl_to_field_for_agg {
-    ($self:ident, $input_schema:ident, $groupby_method_variant:expr) => {{
-        let field = $self.expr.to_field($input_schema)?;
-        let new_name = fmt_groupby_column(field.name(), $groupby_method_variant);
-        Ok(Field::new(&new_name, field.data_type().clone()))
-    }};
-}
-
 pub(crate) struct PhysicalAggExpr {
     expr: Arc<dyn PhysicalExpr>,
     agg_type: GroupByMethod,
@@ -473,9 +491,34 @@ impl AggPhysicalExpr for PhysicalAggExpr {
                 let agg_s = series.agg_var(groups);
                 Ok(rename_option_series(agg_s, &new_name))
             }
-            GroupByMethod::Quantile(_) => {
+            GroupByMethod::Quantile(_) | GroupByMethod::Quantiles => {
                 unimplemented!()
             }
+            GroupByMethod::ApproxNUnique => {
+                let mut agg_s = series.agg_approx_n_unique(groups)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
+            GroupByMethod::ApproxQuantile(quantile) => {
+                let mut agg_s = series.agg_approx_quantile(groups, quantile)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
+            GroupByMethod::Skew(bias) => {
+                let mut agg_s = series.agg_skew(groups, bias)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
+            GroupByMethod::Kurtosis(fisher, bias) => {
+                let mut agg_s = series.agg_kurtosis(groups, fisher, bias)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
+            GroupByMethod::Mode => {
+                let mut agg_s = series.agg_mode(groups)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
         }
     }
 
@@ -511,6 +554,21 @@ impl AggPhysicalExpr for PhysicalAggExpr {
                     vec![s]
                 }))
             }
+            // the partitions produce sketches, these are merged in the final aggregation
+            GroupByMethod::ApproxNUnique => {
+                let series = self.expr.evaluate(df)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut agg_s = series.agg_hll_sketches(groups)?;
+                agg_s.rename(&new_name);
+                Ok(Some(vec![agg_s]))
+            }
+            GroupByMethod::ApproxQuantile(_) => {
+                let series = self.expr.evaluate(df)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut agg_s = series.agg_tdigest_sketches(groups)?;
+                agg_s.rename(&new_name);
+                Ok(Some(vec![agg_s]))
+            }
             _ => AggPhysicalExpr::evaluate(self, df, groups).map(|opt| opt.map(|s| vec![s])),
         }
     }
@@ -551,6 +609,20 @@ impl AggPhysicalExpr for PhysicalAggExpr {
                 let out = builder.finish();
                 Ok(Some(out.into_series()))
             }
+            GroupByMethod::ApproxNUnique => {
+                let series = self.expr.evaluate(final_df)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut agg_s = series.agg_merge_hll_sketches(groups)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
+            GroupByMethod::ApproxQuantile(quantile) => {
+                let series = self.expr.evaluate(final_df)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut agg_s = series.agg_merge_tdigest_sketches(groups, quantile)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
             _ => AggPhysicalExpr::evaluate(self, final_df, groups),
         }
     }
@@ -559,11 +631,20 @@ impl AggPhysicalExpr for PhysicalAggExpr {
 pub struct AggQuantileExpr {
     expr: Arc<dyn PhysicalExpr>,
     quantile: f64,
+    interpol: QuantileInterpolOptions,
 }
 
 impl AggQuantileExpr {
-    pub fn new(expr: Arc<dyn PhysicalExpr>, quantile: f64) -> Self {
-        Self { expr, quantile }
+    pub fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Self {
+        Self {
+            expr,
+            quantile,
+            interpol,
+        }
     }
 }
 
@@ -573,7 +654,12 @@ impl PhysicalExpr for AggQuantileExpr {
     }
 
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
-        impl_to_field_for_agg!(self, input_schema, GroupByMethod::Quantile(self.quantile))
+        let field = self.expr.to_field(input_schema)?;
+        let new_name = fmt_groupby_column(field.name(), GroupByMethod::Quantile(self.quantile));
+        Ok(Field::new(
+            &new_name,
+            self.interpol.quantile_dtype(field.data_type()),
+        ))
     }
 
     fn as_agg_expr(&self) -> Result<&dyn AggPhysicalExpr> {
@@ -585,7 +671,7 @@ impl AggPhysicalExpr for AggQuantileExpr {
     fn evaluate(&self, df: &DataFrame, groups: &[(usize, Vec<usize>)]) -> Result<Option<Series>> {
         let series = self.expr.evaluate(df)?;
         let new_name = fmt_groupby_column(series.name(), GroupByMethod::Quantile(self.quantile));
-        let opt_agg = series.agg_quantile(groups, self.quantile);
+        let opt_agg = series.agg_quantile(groups, self.quantile, self.interpol);
 
         let opt_agg = opt_agg.map(|mut agg| {
             agg.rename(&new_name);
@@ -596,6 +682,100 @@ impl AggPhysicalExpr for AggQuantileExpr {
     }
 }
 
+/// Compute multiple quantiles per group, collected in a list per group in the order of
+/// `quantiles`.
+pub(crate) fn agg_quantiles_list(
+    series: &Series,
+    groups: &[(usize, Vec<usize>)],
+    quantiles: &[f64],
+    interpol: QuantileInterpolOptions,
+) -> Result<Series> {
+    if quantiles.is_empty() {
+        return Err(PolarsError::ValueError(
+            "at least one quantile is needed".into(),
+        ));
+    }
+    if quantiles
+        .iter()
+        .any(|quantile| !(0.0..=1.0).contains(quantile))
+    {
+        return Err(PolarsError::ValueError(
+            "quantile should be between 0.0 and 1.0".into(),
+        ));
+    }
+    let aggs = series
+        .agg_quantiles(groups, quantiles, interpol)
+        .ok_or_else(|| {
+            PolarsError::InvalidOperation(
+                format!("quantiles not supported for dtype {:?}", series.dtype()).into(),
+            )
+        })?;
+    let n_quantiles = quantiles.len();
+    let n_groups = groups.len();
+    let mut values = aggs[0].clone();
+    for agg in &aggs[1..] {
+        values.append(agg)?;
+    }
+    // the values are ordered by quantile, take them in the order of the groups
+    let mut idx =
+        (0..n_groups).flat_map(|group| (0..n_quantiles).map(move |i| i * n_groups + group));
+    let values = values
+        .rechunk()?
+        .take_iter(&mut idx, Some(n_groups * n_quantiles));
+    let mut builder = get_list_builder(values.dtype(), values.len(), n_groups, series.name());
+    for group in 0..n_groups {
+        builder.append_series(&values.slice(group * n_quantiles, n_quantiles)?);
+    }
+    Ok(builder.finish().into_series())
+}
+
+pub struct AggQuantilesExpr {
+    expr: Arc<dyn PhysicalExpr>,
+    quantiles: Vec<f64>,
+    interpol: QuantileInterpolOptions,
+}
+
+impl AggQuantilesExpr {
+    pub fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        quantiles: Vec<f64>,
+        interpol: QuantileInterpolOptions,
+    ) -> Self {
+        Self {
+            expr,
+            quantiles,
+            interpol,
+        }
+    }
+}
+
+impl PhysicalExpr for AggQuantilesExpr {
+    fn evaluate(&self, _df: &DataFrame) -> Result<Series> {
+        unimplemented!()
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        let field = self.expr.to_field(input_schema)?;
+        let new_name = fmt_groupby_column(field.name(), GroupByMethod::Quantiles);
+        let dtype = self.interpol.quantile_dtype(field.data_type());
+        Ok(Field::new(&new_name, DataType::List(dtype.to_arrow())))
+    }
+
+    fn as_agg_expr(&self) -> Result<&dyn AggPhysicalExpr> {
+        Ok(self)
+    }
+}
+
+impl AggPhysicalExpr for AggQuantilesExpr {
+    fn evaluate(&self, df: &DataFrame, groups: &[(usize, Vec<usize>)]) -> Result<Option<Series>> {
+        let series = self.expr.evaluate(df)?;
+        let new_name = fmt_groupby_column(series.name(), GroupByMethod::Quantiles);
+        let mut agg = agg_quantiles_list(&series, groups, &self.quantiles, self.interpol)?;
+        agg.rename(&new_name);
+        Ok(Some(agg))
+    }
+}
+
 pub struct CastExpr {
     input: Arc<dyn PhysicalExpr>,
     data_type: DataType,
@@ -643,7 +823,7 @@ impl PhysicalExpr for TernaryExpr {
 pub struct ApplyExpr {
     pub input: Arc<dyn PhysicalExpr>,
     pub function: Arc<dyn SeriesUdf>,
-    pub output_type: Option<DataType>,
+    pub output_type: GetOutput,
     pub expr: Expr,
 }
 
@@ -651,7 +831,7 @@ impl ApplyExpr {
     pub fn new(
         input: Arc<dyn PhysicalExpr>,
         function: Arc<dyn SeriesUdf>,
-        output_type: Option<DataType>,
+        output_type: GetOutput,
         expr: Expr,
     ) -> Self {
         ApplyExpr {
@@ -678,13 +858,9 @@ impl PhysicalExpr for ApplyExpr {
         Ok(out)
     }
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
-        match &self.output_type {
-            Some(output_type) => {
-                let input_field = self.input.to_field(input_schema)?;
-                Ok(Field::new(input_field.name(), output_type.clone()))
-            }
-            None => self.input.to_field(input_schema),
-        }
+        Ok(self
+            .output_type
+            .get_field(self.input.to_field(input_schema)?, Context::Other))
     }
     fn as_agg_expr(&self) -> Result<&dyn AggPhysicalExpr> {
         Ok(self)
@@ -717,6 +893,62 @@ impl AggPhysicalExpr for ApplyExpr {
     }
 }
 
+pub struct MathExpr {
+    input: Arc<dyn PhysicalExpr>,
+    function: MathFunction,
+    expr: Expr,
+}
+
+impl MathExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, function: MathFunction, expr: Expr) -> Self {
+        Self {
+            input,
+            function,
+            expr,
+        }
+    }
+}
+
+impl PhysicalExpr for MathExpr {
+    fn as_expression(&self) -> &Expr {
+        &self.expr
+    }
+
+    fn evaluate(&self, df: &DataFrame) -> Result<Series> {
+        let series = self.input.evaluate(df)?;
+        self.function.evaluate(&series)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        let field = self.input.to_field(input_schema)?;
+        Ok(Field::new(
+            field.name(),
+            self.function.get_type(field.data_type()),
+        ))
+    }
+
+    fn as_agg_expr(&self) -> Result<&dyn AggPhysicalExpr> {
+        Ok(self)
+    }
+}
+
+impl AggPhysicalExpr for MathExpr {
+    fn evaluate(&self, df: &DataFrame, groups: &[(usize, Vec<usize>)]) -> Result<Option<Series>> {
+        match self.input.as_agg_expr() {
+            // layer below is also an aggregation expr.
+            Ok(expr) => {
+                let aggregated = expr.evaluate(df, groups)?;
+                aggregated.map(|s| self.function.evaluate(&s)).transpose()
+            }
+            // the function is elementwise, so it can be applied before the values are grouped
+            Err(_) => {
+                let series = self.input.evaluate(df)?;
+                Ok(self.function.evaluate(&series)?.agg_list(groups))
+            }
+        }
+    }
+}
+
 pub struct WindowExpr {
     /// the root column that the Function will be applied on.
     /// This will be used to create a smaller DataFrame to prevent taking unneeded columns by index
@@ -727,15 +959,85 @@ pub struct WindowExpr {
     pub(crate) function: Expr,
 }
 
+impl WindowExpr {
+    /// Apply a function that [applies per group](crate::dsl::SeriesUdf::applies_per_group), like
+    /// an exponentially weighted statistic, on the values of every group. The results are put
+    /// back at the rows of their group, so that no join is needed. A result of length 1 is
+    /// broadcasted to all the rows of its group.
+    fn apply_udf_per_group(
+        &self,
+        df: &DataFrame,
+        groups: &[(usize, Vec<usize>)],
+        function: &dyn SeriesUdf,
+    ) -> Result<Series> {
+        let s = df.column(self.apply_column.as_str())?;
+        if groups.is_empty() {
+            let mut out = function.call_udf(s.clone())?;
+            out.rename(self.out_name.as_str());
+            return Ok(out);
+        }
+        // take the values once in group order, so that every group is a zero copy slice
+        let sorted = s
+            .take_iter(
+                &mut groups.iter().flat_map(|(_, idx)| idx.iter().copied()),
+                Some(s.len()),
+            )
+            .rechunk()?;
+        // the position in the output of every row
+        let mut positions = vec![None; s.len()];
+        let mut offset = 0;
+        let mut outputs = Vec::with_capacity(groups.len());
+        for (_, idx) in groups {
+            let mut group_out = function.call_udf(sorted.slice(offset, idx.len())?)?;
+            if group_out.len() == 1 && idx.len() != 1 {
+                group_out = group_out.expand_at_index(0, idx.len());
+            }
+            if group_out.len() != idx.len() {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "the function applied over a window returned {} values for a group of {} rows; \
+                         functions that change the length, like a diff with NullBehavior::Drop, \
+                         are not supported in a window",
+                        group_out.len(),
+                        idx.len()
+                    )
+                    .into(),
+                ));
+            }
+            for (i, row) in idx.iter().enumerate() {
+                positions[*row] = Some(offset + i);
+            }
+            offset += idx.len();
+            outputs.push(group_out);
+        }
+        // concatenate the outputs of the groups once and put them back at their rows
+        let mut outputs = outputs.into_iter();
+        let mut out = outputs.next().unwrap();
+        for group_out in outputs {
+            out.append(&group_out)?;
+        }
+        let mut out = out
+            .rechunk()?
+            .take_opt_iter(&mut positions.into_iter(), Some(s.len()));
+        out.rename(self.out_name.as_str());
+        Ok(out)
+    }
+}
+
 impl PhysicalExpr for WindowExpr {
     // Note: this was first implemented with expression evaluation but this performed really bad.
-    // Therefore we choose the groupby -> apply -> self join approach
+    // Therefore we choose the groupby -> apply approach. The aggregated values are put back at
+    // the rows of their group with a single take, which keeps the row order without a self join.
     fn evaluate(&self, df: &DataFrame) -> Result<Series> {
         let gb = df
             .groupby(self.group_column.as_str())?
             .select(self.apply_column.as_str());
 
         let out = match &self.function {
+            Expr::Udf { function, .. } if function.applies_per_group() => {
+                return self.apply_udf_per_group(df, gb.get_groups(), function.as_ref());
+            }
+            // other functions get the aggregated list of every group
             Expr::Udf { function, .. } => {
                 let mut df = gb.agg_list()?;
                 df.may_apply_at_idx(1, |s| function.call_udf(s.clone()))?;
@@ -751,27 +1053,40 @@ impl PhysicalExpr for WindowExpr {
                 AggExpr::Last(_) => gb.last(),
                 AggExpr::Count(_) => gb.count(),
                 AggExpr::NUnique(_) => gb.n_unique(),
-                AggExpr::Quantile { quantile, .. } => gb.quantile(*quantile),
+                AggExpr::Quantile {
+                    quantile, interpol, ..
+                } => gb.quantile(*quantile, *interpol),
+                AggExpr::Quantiles { .. } => Err(PolarsError::InvalidOperation(
+                    "quantiles is not supported in a window, use quantile".into(),
+                )),
                 AggExpr::List(_) => gb.agg_list(),
                 AggExpr::AggGroups(_) => gb.groups(),
                 AggExpr::Std(_) => gb.std(),
                 AggExpr::Var(_) => gb.var(),
+                AggExpr::ApproxNUnique(_) => gb.approx_n_unique(),
+                AggExpr::ApproxQuantile { quantile, .. } => gb.approx_quantile(*quantile),
+                AggExpr::Skew { bias, .. } => gb.skew(*bias),
+                AggExpr::Kurtosis { fisher, bias, .. } => gb.kurtosis(*fisher, *bias),
+                AggExpr::Mode(_) => gb.mode(),
             },
             _ => Err(PolarsError::Other(
                 format!("{:?} function not supported", self.function).into(),
             )),
         }?;
-        let mut out = df
-            .select(self.group_column.as_str())?
-            .left_join(&out, self.group_column.as_str(), &self.group_column)?
-            .select_at_idx(1)
-            .unwrap_or_else(|| {
-                panic!(format!(
-                    "the aggregation function did not succeed on {}",
-                    self.apply_column
-                ))
-            })
-            .clone();
+        let agg = out.select_at_idx(1).unwrap_or_else(|| {
+            panic!(format!(
+                "the aggregation function did not succeed on {}",
+                self.apply_column
+            ))
+        });
+        // the aggregation has a row per group, in the order of the groups
+        let mut group_of_row = vec![None; df.height()];
+        for (group, (_, idx)) in gb.get_groups().iter().enumerate() {
+            for &row in idx {
+                group_of_row[row] = Some(group);
+            }
+        }
+        let mut out = agg.take_opt_iter(&mut group_of_row.into_iter(), Some(df.height()));
         out.rename(self.out_name.as_str());
         Ok(out)
     }
@@ -42,6 +42,15 @@ fn aggregate_expr_to_scan_agg(aggregate: Vec<Expr>) -> Vec<ScanAggregation> {
                         column: (*expr_to_root_column_name(&e).unwrap()).clone(),
                         alias,
                     },
+                    AggExpr::ApproxNUnique(e) => ScanAggregation::ApproxNUnique {
+                        column: (*expr_to_root_column_name(&e).unwrap()).clone(),
+                        alias,
+                    },
+                    AggExpr::ApproxQuantile { expr, quantile } => ScanAggregation::ApproxQuantile {
+                        column: (*expr_to_root_column_name(&expr).unwrap()).clone(),
+                        quantile,
+                        alias,
+                    },
                     _ => todo!(),
                 }
             } else {
@@ -231,6 +240,7 @@ impl DefaultPlanner {
                 keys,
                 aggs,
                 apply,
+                dynamic_options,
                 ..
             } => {
                 let input = self.create_initial_physical_plan(*input)?;
@@ -247,7 +257,10 @@ impl DefaultPlanner {
                             // | Expr::Agg(AggExpr::Count(_))
                             | Expr::Agg(AggExpr::Last(_))
                             | Expr::Agg(AggExpr::List(_))
-                            | Expr::Agg(AggExpr::First(_)) => {}
+                            | Expr::Agg(AggExpr::First(_))
+                            // the partitions produce sketches that are merged
+                            | Expr::Agg(AggExpr::ApproxNUnique(_))
+                            | Expr::Agg(AggExpr::ApproxQuantile { .. }) => {}
                             _ => {
                                 partitionable = false;
                             }
@@ -260,6 +273,10 @@ impl DefaultPlanner {
                 if apply.is_some() {
                     partitionable = false;
                 }
+                // time windows may overlap, so they cannot be partitioned.
+                if dynamic_options.is_some() {
+                    partitionable = false;
+                }
                 let phys_keys = self.create_physical_expressions(
                     Arc::try_unwrap(keys).unwrap_or_else(|keys| (&*keys).clone()),
                     Context::Other,
@@ -273,7 +290,11 @@ impl DefaultPlanner {
                 } else {
                     let phys_aggs = self.create_physical_expressions(aggs, Context::Aggregation)?;
                     Ok(Box::new(GroupByExec::new(
-                        input, phys_keys, phys_aggs, apply,
+                        input,
+                        phys_keys,
+                        phys_aggs,
+                        apply,
+                        dynamic_options,
                     )))
                 }
             }
@@ -408,7 +429,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -427,7 +448,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -446,7 +467,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -463,7 +484,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -480,7 +501,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -501,7 +522,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -518,7 +539,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -535,7 +556,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -552,7 +573,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -588,26 +609,168 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: Some(DataType::UInt32),
+                                    output_type: GetOutput::DataType(DataType::UInt32),
+                                    expr: expression,
+                                }))
+                            }
+                        }
+                    }
+                    AggExpr::Quantile {
+                        expr,
+                        quantile,
+                        interpol,
+                    } => {
+                        let input = self.create_physical_expr(*expr, ctxt)?;
+                        match ctxt {
+                            Context::Aggregation => {
+                                Ok(Arc::new(AggQuantileExpr::new(input, quantile, interpol)))
+                            }
+                            Context::Other => {
+                                let function = Arc::new(move |s: Series| {
+                                    s.quantile_as_series(quantile, interpol)
+                                });
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: GetOutput::MapDataType(Arc::new(move |dt| {
+                                        interpol.quantile_dtype(dt)
+                                    })),
+                                    expr: expression,
+                                }))
+                            }
+                        }
+                    }
+                    AggExpr::Quantiles {
+                        expr,
+                        quantiles,
+                        interpol,
+                    } => {
+                        let input = self.create_physical_expr(*expr, ctxt)?;
+                        match ctxt {
+                            Context::Aggregation => {
+                                Ok(Arc::new(AggQuantilesExpr::new(input, quantiles, interpol)))
+                            }
+                            Context::Other => {
+                                // all values are a single group
+                                let function = Arc::new(move |s: Series| {
+                                    let groups = vec![(0, (0..s.len()).collect::<Vec<_>>())];
+                                    agg_quantiles_list(&s, &groups, &quantiles, interpol)
+                                });
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: GetOutput::MapDataType(Arc::new(move |dt| {
+                                        DataType::List(interpol.quantile_dtype(dt).to_arrow())
+                                    })),
+                                    expr: expression,
+                                }))
+                            }
+                        }
+                    }
+                    AggExpr::ApproxNUnique(expr) => {
+                        let input = self.create_physical_expr(*expr, ctxt)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(PhysicalAggExpr::new(
+                                input,
+                                GroupByMethod::ApproxNUnique,
+                            ))),
+                            Context::Other => {
+                                let function = Arc::new(move |s: Series| {
+                                    s.approx_n_unique().map(|count| {
+                                        UInt32Chunked::new_from_slice(s.name(), &[count])
+                                            .into_series()
+                                    })
+                                });
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: GetOutput::DataType(DataType::UInt32),
+                                    expr: expression,
+                                }))
+                            }
+                        }
+                    }
+                    AggExpr::ApproxQuantile { expr, quantile } => {
+                        let input = self.create_physical_expr(*expr, ctxt)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(PhysicalAggExpr::new(
+                                input,
+                                GroupByMethod::ApproxQuantile(quantile),
+                            ))),
+                            Context::Other => {
+                                let function = Arc::new(move |s: Series| {
+                                    s.approx_quantile(quantile).map(|opt_q| {
+                                        Float64Chunked::new_from_opt_slice(s.name(), &[opt_q])
+                                            .into_series()
+                                    })
+                                });
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: GetOutput::DataType(DataType::Float64),
+                                    expr: expression,
+                                }))
+                            }
+                        }
+                    }
+                    AggExpr::Skew { expr, bias } => {
+                        let input = self.create_physical_expr(*expr, ctxt)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(PhysicalAggExpr::new(
+                                input,
+                                GroupByMethod::Skew(bias),
+                            ))),
+                            Context::Other => {
+                                let function = Arc::new(move |s: Series| {
+                                    s.skew(bias).map(|opt_v| {
+                                        Float64Chunked::new_from_opt_slice(s.name(), &[opt_v])
+                                            .into_series()
+                                    })
+                                });
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: GetOutput::DataType(DataType::Float64),
                                     expr: expression,
                                 }))
                             }
                         }
                     }
-                    AggExpr::Quantile { expr, quantile } => {
-                        // todo! add schema to get correct output type
+                    AggExpr::Kurtosis { expr, fisher, bias } => {
+                        let input = self.create_physical_expr(*expr, ctxt)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(PhysicalAggExpr::new(
+                                input,
+                                GroupByMethod::Kurtosis(fisher, bias),
+                            ))),
+                            Context::Other => {
+                                let function = Arc::new(move |s: Series| {
+                                    s.kurtosis(fisher, bias).map(|opt_v| {
+                                        Float64Chunked::new_from_opt_slice(s.name(), &[opt_v])
+                                            .into_series()
+                                    })
+                                });
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: GetOutput::DataType(DataType::Float64),
+                                    expr: expression,
+                                }))
+                            }
+                        }
+                    }
+                    AggExpr::Mode(expr) => {
                         let input = self.create_physical_expr(*expr, ctxt)?;
                         match ctxt {
                             Context::Aggregation => {
-                                Ok(Arc::new(AggQuantileExpr::new(input, quantile)))
+                                Ok(Arc::new(PhysicalAggExpr::new(input, GroupByMethod::Mode)))
                             }
                             Context::Other => {
-                                let function =
-                                    Arc::new(move |s: Series| s.quantile_as_series(quantile));
+                                let function = Arc::new(move |s: Series| s.mode());
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: expression,
                                 }))
                             }
@@ -638,7 +801,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     input,
                                     function,
-                                    output_type: Some(DataType::UInt32),
+                                    output_type: GetOutput::DataType(DataType::UInt32),
                                     expr: expression,
                                 }))
                             }
@@ -693,10 +856,19 @@ impl DefaultPlanner {
                     output_field,
                 }))
             }
+            Expr::Math { input, function } => {
+                let input = self.create_physical_expr(*input, ctxt)?;
+                Ok(Arc::new(MathExpr::new(input, function, expression)))
+            }
             Expr::Shift { input, periods } => {
                 let input = self.create_physical_expr(*input, ctxt)?;
                 let function = Arc::new(move |s: Series| Ok(s.shift(periods)));
-                Ok(Arc::new(ApplyExpr::new(input, function, None, expression)))
+                Ok(Arc::new(ApplyExpr::new(
+                    input,
+                    function,
+                    GetOutput::SameType,
+                    expression,
+                )))
             }
             Expr::Slice {
                 input,
@@ -713,23 +885,43 @@ impl DefaultPlanner {
             Expr::Reverse(expr) => {
                 let input = self.create_physical_expr(*expr, ctxt)?;
                 let function = Arc::new(move |s: Series| Ok(s.reverse()));
-                Ok(Arc::new(ApplyExpr::new(input, function, None, expression)))
+                Ok(Arc::new(ApplyExpr::new(
+                    input,
+                    function,
+                    GetOutput::SameType,
+                    expression,
+                )))
             }
             Expr::Duplicated(expr) => {
                 let input = self.create_physical_expr(*expr, ctxt)?;
                 let function =
                     Arc::new(move |s: Series| s.is_duplicated().map(|ca| ca.into_series()));
-                Ok(Arc::new(ApplyExpr::new(input, function, None, expression)))
+                Ok(Arc::new(ApplyExpr::new(
+                    input,
+                    function,
+                    GetOutput::SameType,
+                    expression,
+                )))
             }
             Expr::Unique(expr) => {
                 let input = self.create_physical_expr(*expr, ctxt)?;
                 let function = Arc::new(move |s: Series| s.is_unique().map(|ca| ca.into_series()));
-                Ok(Arc::new(ApplyExpr::new(input, function, None, expression)))
+                Ok(Arc::new(ApplyExpr::new(
+                    input,
+                    function,
+                    GetOutput::SameType,
+                    expression,
+                )))
             }
             Expr::Explode(expr) => {
                 let input = self.create_physical_expr(*expr, ctxt)?;
                 let function = Arc::new(move |s: Series| s.explode());
-                Ok(Arc::new(ApplyExpr::new(input, function, None, expression)))
+                Ok(Arc::new(ApplyExpr::new(
+                    input,
+                    function,
+                    GetOutput::SameType,
+                    expression,
+                )))
             }
             Expr::Wildcard => panic!("should be no wildcard at this point"),
             Expr::Except(_) => panic!("should be no except expression at this point"),
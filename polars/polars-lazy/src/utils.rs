@@ -189,6 +189,13 @@ pub(crate) fn has_aexpr(
                             has_aexpr(*expr, arena, matching_expr, follow_agg)
                         }
                     }
+                    AAggExpr::Quantiles { expr, .. } => {
+                        if matches!(tmp_matching_expr, AAggExpr::Quantiles { .. }) {
+                            true
+                        } else {
+                            has_aexpr(*expr, arena, matching_expr, follow_agg)
+                        }
+                    }
                     AAggExpr::Sum(e) => {
                         if matches!(tmp_matching_expr, AAggExpr::Sum(_)) {
                             true
@@ -217,6 +224,41 @@ pub(crate) fn has_aexpr(
                             has_aexpr(*e, arena, matching_expr, follow_agg)
                         }
                     }
+                    AAggExpr::ApproxNUnique(e) => {
+                        if matches!(tmp_matching_expr, AAggExpr::ApproxNUnique(_)) {
+                            true
+                        } else {
+                            has_aexpr(*e, arena, matching_expr, follow_agg)
+                        }
+                    }
+                    AAggExpr::ApproxQuantile { expr, .. } => {
+                        if matches!(tmp_matching_expr, AAggExpr::ApproxQuantile { .. }) {
+                            true
+                        } else {
+                            has_aexpr(*expr, arena, matching_expr, follow_agg)
+                        }
+                    }
+                    AAggExpr::Skew { expr, .. } => {
+                        if matches!(tmp_matching_expr, AAggExpr::Skew { .. }) {
+                            true
+                        } else {
+                            has_aexpr(*expr, arena, matching_expr, follow_agg)
+                        }
+                    }
+                    AAggExpr::Kurtosis { expr, .. } => {
+                        if matches!(tmp_matching_expr, AAggExpr::Kurtosis { .. }) {
+                            true
+                        } else {
+                            has_aexpr(*expr, arena, matching_expr, follow_agg)
+                        }
+                    }
+                    AAggExpr::Mode(e) => {
+                        if matches!(tmp_matching_expr, AAggExpr::Mode(_)) {
+                            true
+                        } else {
+                            has_aexpr(*e, arena, matching_expr, follow_agg)
+                        }
+                    }
                 }
             } else {
                 false
@@ -259,6 +301,13 @@ pub(crate) fn has_aexpr(
                 has_aexpr(*input, arena, matching_expr, follow_agg)
             }
         }
+        AExpr::Math { input, .. } => {
+            if matches!(matching_expr, AExpr::Math { .. }) {
+                true
+            } else {
+                has_aexpr(*input, arena, matching_expr, follow_agg)
+            }
+        }
         AExpr::Slice { input, .. } => {
             if matches!(matching_expr, AExpr::Slice { .. }) {
                 true
@@ -457,6 +506,48 @@ pub(crate) fn has_expr(current_expr: &Expr, matching_expr: &Expr) -> bool {
                             has_expr(expr, matching_expr)
                         }
                     }
+                    AggExpr::Quantiles { expr, .. } => {
+                        if matches!(tmp_matching_expr, AggExpr::Quantiles { .. }) {
+                            true
+                        } else {
+                            has_expr(expr, matching_expr)
+                        }
+                    }
+                    AggExpr::ApproxNUnique(e) => {
+                        if matches!(tmp_matching_expr, AggExpr::ApproxNUnique(_)) {
+                            true
+                        } else {
+                            has_expr(e, matching_expr)
+                        }
+                    }
+                    AggExpr::ApproxQuantile { expr, .. } => {
+                        if matches!(tmp_matching_expr, AggExpr::ApproxQuantile { .. }) {
+                            true
+                        } else {
+                            has_expr(expr, matching_expr)
+                        }
+                    }
+                    AggExpr::Skew { expr, .. } => {
+                        if matches!(tmp_matching_expr, AggExpr::Skew { .. }) {
+                            true
+                        } else {
+                            has_expr(expr, matching_expr)
+                        }
+                    }
+                    AggExpr::Kurtosis { expr, .. } => {
+                        if matches!(tmp_matching_expr, AggExpr::Kurtosis { .. }) {
+                            true
+                        } else {
+                            has_expr(expr, matching_expr)
+                        }
+                    }
+                    AggExpr::Mode(e) => {
+                        if matches!(tmp_matching_expr, AggExpr::Mode(_)) {
+                            true
+                        } else {
+                            has_expr(e, matching_expr)
+                        }
+                    }
                     AggExpr::Sum(e) => {
                         if matches!(tmp_matching_expr, AggExpr::Sum(_)) {
                             true
@@ -526,6 +617,13 @@ pub(crate) fn has_expr(current_expr: &Expr, matching_expr: &Expr) -> bool {
                 has_expr(input, matching_expr)
             }
         }
+        Expr::Math { input, .. } => {
+            if matches!(matching_expr, Expr::Math { .. }) {
+                true
+            } else {
+                has_expr(input, matching_expr)
+            }
+        }
         Expr::Slice { input, .. } => {
             if matches!(matching_expr, Expr::Slice { .. }) {
                 true
@@ -622,6 +720,7 @@ pub(crate) fn aexpr_to_root_nodes(node: Node, arena: &Arena<AExpr>) -> Vec<Node>
             AAggExpr::AggGroups(expr) => aexpr_to_root_nodes(*expr, arena),
             AAggExpr::NUnique(expr) => aexpr_to_root_nodes(*expr, arena),
             AAggExpr::Quantile { expr, .. } => aexpr_to_root_nodes(*expr, arena),
+            AAggExpr::Quantiles { expr, .. } => aexpr_to_root_nodes(*expr, arena),
             AAggExpr::Sum(expr) => aexpr_to_root_nodes(*expr, arena),
             AAggExpr::Min(expr) => aexpr_to_root_nodes(*expr, arena),
             AAggExpr::Max(expr) => aexpr_to_root_nodes(*expr, arena),
@@ -631,6 +730,11 @@ pub(crate) fn aexpr_to_root_nodes(node: Node, arena: &Arena<AExpr>) -> Vec<Node>
             AAggExpr::List(expr) => aexpr_to_root_nodes(*expr, arena),
             AAggExpr::Std(expr) => aexpr_to_root_nodes(*expr, arena),
             AAggExpr::Var(expr) => aexpr_to_root_nodes(*expr, arena),
+            AAggExpr::ApproxNUnique(expr) => aexpr_to_root_nodes(*expr, arena),
+            AAggExpr::ApproxQuantile { expr, .. } => aexpr_to_root_nodes(*expr, arena),
+            AAggExpr::Skew { expr, .. } => aexpr_to_root_nodes(*expr, arena),
+            AAggExpr::Kurtosis { expr, .. } => aexpr_to_root_nodes(*expr, arena),
+            AAggExpr::Mode(expr) => aexpr_to_root_nodes(*expr, arena),
         },
         AExpr::BinaryExpr { left, right, .. } => {
             let mut results = Vec::with_capacity(16);
@@ -640,6 +744,7 @@ pub(crate) fn aexpr_to_root_nodes(node: Node, arena: &Arena<AExpr>) -> Vec<Node>
         }
         AExpr::Sort { expr, .. } => aexpr_to_root_nodes(*expr, arena),
         AExpr::Shift { input, .. } => aexpr_to_root_nodes(*input, arena),
+        AExpr::Math { input, .. } => aexpr_to_root_nodes(*input, arena),
         AExpr::Slice { input, .. } => aexpr_to_root_nodes(*input, arena),
         AExpr::Udf { input, .. } => aexpr_to_root_nodes(*input, arena),
         AExpr::BinaryFunction {
@@ -700,6 +805,7 @@ pub(crate) fn expr_to_root_column_exprs(expr: &Expr) -> Vec<Expr> {
             AggExpr::AggGroups(expr) => expr_to_root_column_exprs(expr),
             AggExpr::NUnique(expr) => expr_to_root_column_exprs(expr),
             AggExpr::Quantile { expr, .. } => expr_to_root_column_exprs(expr),
+            AggExpr::Quantiles { expr, .. } => expr_to_root_column_exprs(expr),
             AggExpr::Sum(expr) => expr_to_root_column_exprs(expr),
             AggExpr::Min(expr) => expr_to_root_column_exprs(expr),
             AggExpr::Max(expr) => expr_to_root_column_exprs(expr),
@@ -709,6 +815,11 @@ pub(crate) fn expr_to_root_column_exprs(expr: &Expr) -> Vec<Expr> {
             AggExpr::List(expr) => expr_to_root_column_exprs(expr),
             AggExpr::Std(expr) => expr_to_root_column_exprs(expr),
             AggExpr::Var(expr) => expr_to_root_column_exprs(expr),
+            AggExpr::ApproxNUnique(expr) => expr_to_root_column_exprs(expr),
+            AggExpr::ApproxQuantile { expr, .. } => expr_to_root_column_exprs(expr),
+            AggExpr::Skew { expr, .. } => expr_to_root_column_exprs(expr),
+            AggExpr::Kurtosis { expr, .. } => expr_to_root_column_exprs(expr),
+            AggExpr::Mode(expr) => expr_to_root_column_exprs(expr),
         },
         Expr::BinaryExpr { left, right, .. } => {
             let mut results = Vec::with_capacity(16);
@@ -718,6 +829,7 @@ pub(crate) fn expr_to_root_column_exprs(expr: &Expr) -> Vec<Expr> {
         }
         Expr::Sort { expr, .. } => expr_to_root_column_exprs(expr),
         Expr::Shift { input, .. } => expr_to_root_column_exprs(input),
+        Expr::Math { input, .. } => expr_to_root_column_exprs(input),
         Expr::Slice { input, .. } => expr_to_root_column_exprs(input),
         Expr::Udf { input, .. } => expr_to_root_column_exprs(input),
         Expr::BinaryFunction {
@@ -791,15 +903,46 @@ pub(crate) fn rename_expr_root_name(expr: &Expr, new_name: Arc<String>) -> Resul
                 }
                 AggExpr::Mean(e) => AggExpr::Mean(Box::new(rename_expr_root_name(e, new_name)?)),
                 AggExpr::Count(e) => AggExpr::Count(Box::new(rename_expr_root_name(e, new_name)?)),
-                AggExpr::Quantile { expr, quantile } => AggExpr::Quantile {
+                AggExpr::Quantile {
+                    expr,
+                    quantile,
+                    interpol,
+                } => AggExpr::Quantile {
                     expr: Box::new(rename_expr_root_name(expr, new_name)?),
                     quantile: *quantile,
+                    interpol: *interpol,
+                },
+                AggExpr::Quantiles {
+                    expr,
+                    quantiles,
+                    interpol,
+                } => AggExpr::Quantiles {
+                    expr: Box::new(rename_expr_root_name(expr, new_name)?),
+                    quantiles: quantiles.clone(),
+                    interpol: *interpol,
                 },
                 AggExpr::AggGroups(e) => {
                     AggExpr::AggGroups(Box::new(rename_expr_root_name(e, new_name)?))
                 }
                 AggExpr::Std(e) => AggExpr::Std(Box::new(rename_expr_root_name(e, new_name)?)),
                 AggExpr::Var(e) => AggExpr::Var(Box::new(rename_expr_root_name(e, new_name)?)),
+                AggExpr::ApproxNUnique(e) => {
+                    AggExpr::ApproxNUnique(Box::new(rename_expr_root_name(e, new_name)?))
+                }
+                AggExpr::ApproxQuantile { expr, quantile } => AggExpr::ApproxQuantile {
+                    expr: Box::new(rename_expr_root_name(expr, new_name)?),
+                    quantile: *quantile,
+                },
+                AggExpr::Skew { expr, bias } => AggExpr::Skew {
+                    expr: Box::new(rename_expr_root_name(expr, new_name)?),
+                    bias: *bias,
+                },
+                AggExpr::Kurtosis { expr, fisher, bias } => AggExpr::Kurtosis {
+                    expr: Box::new(rename_expr_root_name(expr, new_name)?),
+                    fisher: *fisher,
+                    bias: *bias,
+                },
+                AggExpr::Mode(e) => AggExpr::Mode(Box::new(rename_expr_root_name(e, new_name)?)),
             };
             Ok(Expr::Agg(agg))
         }
@@ -859,6 +1002,10 @@ pub(crate) fn rename_expr_root_name(expr: &Expr, new_name: Arc<String>) -> Resul
         }),
         Expr::BinaryFunction { .. } => panic!("cannot rename root columns of BinaryFunction"),
         Expr::Shift { input, .. } => rename_expr_root_name(input, new_name),
+        Expr::Math { input, function } => Ok(Expr::Math {
+            input: Box::new(rename_expr_root_name(input, new_name)?),
+            function: *function,
+        }),
         Expr::Slice { input, .. } => rename_expr_root_name(input, new_name),
         Expr::Ternary { predicate, .. } => rename_expr_root_name(predicate, new_name),
         a => Err(PolarsError::Other(
@@ -206,6 +206,7 @@
 pub mod prelude;
 pub use polars_core::{
     chunked_array, datatypes, doc, error, frame, functions, series, testing, toggle_string_cache,
+    StringCacheHolder,
 };
 
 pub use polars_core::apply_method_all_arrow_series;
@@ -726,6 +726,10 @@ class DataFrame:
                 - "minute"
                 - "hour"
                 - "day"
+                - "week"
+                - "month"
+                - "quarter"
+                - "year"
 
         n
             Number of units (e.g. 5 "day", 15 "minute"
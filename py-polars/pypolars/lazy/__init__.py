@@ -721,6 +721,28 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.cum_max(reverse))
 
+    def cum_prod(self, reverse: bool):
+        """
+        Get an array with the cumulative product computed at every element
+
+        Parameters
+        ----------
+        reverse
+            reverse the operation
+        """
+        return wrap_expr(self._pyexpr.cum_prod(reverse))
+
+    def cum_count(self, reverse: bool):
+        """
+        Get an array with the number of non null values up to and including every element
+
+        Parameters
+        ----------
+        reverse
+            reverse the operation
+        """
+        return wrap_expr(self._pyexpr.cum_count(reverse))
+
     def cast(self, dtype: "DataType") -> "Expr":
         if dtype == str:
             dtype = datatypes.Utf8
@@ -795,6 +817,14 @@ class Expr:
         """Count unique values"""
         return wrap_expr(self._pyexpr.n_unique())
 
+    def approx_n_unique(self) -> "Expr":
+        """Estimate the number of unique values with a HyperLogLog sketch"""
+        return wrap_expr(self._pyexpr.approx_n_unique())
+
+    def approx_quantile(self, quantile: float) -> "Expr":
+        """Estimate a quantile with a t-digest"""
+        return wrap_expr(self._pyexpr.approx_quantile(quantile))
+
     def first(self) -> "Expr":
         """
         Get first value
@@ -511,6 +511,28 @@ class Series:
         """
         return self._s.cum_max(reverse)
 
+    def cum_prod(self, reverse: bool):
+        """
+        Get an array with the cumulative product computed at every element
+
+        Parameters
+        ----------
+        reverse
+            reverse the operation
+        """
+        return self._s.cum_prod(reverse)
+
+    def cum_count(self, reverse: bool):
+        """
+        Get an array with the number of non null values up to and including every element
+
+        Parameters
+        ----------
+        reverse
+            reverse the operation
+        """
+        return self._s.cum_count(reverse)
+
     def limit(self, num_elements: int) -> "Series":
         """
         Take n elements from this Series.
@@ -309,11 +309,14 @@ impl PyDataFrame {
 
     pub fn fill_none(&self, strategy: &str) -> PyResult<Self> {
         let strat = match strategy {
-            "backward" => FillNoneStrategy::Backward,
-            "forward" => FillNoneStrategy::Forward,
+            "backward" => FillNoneStrategy::Backward(None),
+            "forward" => FillNoneStrategy::Forward(None),
             "min" => FillNoneStrategy::Min,
             "max" => FillNoneStrategy::Max,
             "mean" => FillNoneStrategy::Mean,
+            "zero" => FillNoneStrategy::Zero,
+            "one" => FillNoneStrategy::One,
+            "linear" => FillNoneStrategy::Linear,
             s => return Err(PyPolarsEr::Other(format!("Strategy {} not supported", s)).into()),
         };
         let df = self.df.fill_none(strat).map_err(PyPolarsEr::from)?;
@@ -541,6 +544,10 @@ impl PyDataFrame {
             "minute" => SampleRule::Minute(n),
             "day" => SampleRule::Day(n),
             "hour" => SampleRule::Hour(n),
+            "week" => SampleRule::Week(n),
+            "month" => SampleRule::Month(n),
+            "quarter" => SampleRule::Quarter(n),
+            "year" => SampleRule::Year(n),
             a => {
                 return Err(PyPolarsEr::Other(format!("rule {} not supported", a)).into());
             }
@@ -613,7 +620,7 @@ impl PyDataFrame {
     ) -> PyResult<Self> {
         let gb = self.df.groupby(&by).map_err(PyPolarsEr::from)?;
         let selection = gb.select(&select);
-        let df = selection.quantile(quantile);
+        let df = selection.quantile(quantile, QuantileInterpolOptions::default());
         let df = df.map_err(PyPolarsEr::from)?;
         Ok(PyDataFrame::new(df))
     }
@@ -709,7 +716,10 @@ impl PyDataFrame {
     }
 
     pub fn quantile(&self, quantile: f64) -> PyResult<Self> {
-        let df = self.df.quantile(quantile).map_err(PyPolarsEr::from)?;
+        let df = self
+            .df
+            .quantile(quantile, QuantileInterpolOptions::default())
+            .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 
@@ -729,6 +739,7 @@ fn finish_groupby(gb: GroupBy, agg: &str) -> PyResult<PyDataFrame> {
         "sum" => gb.sum(),
         "count" => gb.count(),
         "n_unique" => gb.n_unique(),
+        "approx_n_unique" => gb.approx_n_unique(),
         "median" => gb.median(),
         "agg_list" => gb.agg_list(),
         "groups" => gb.groups(),
@@ -6,7 +6,7 @@ use polars::lazy::frame::{
     AllowedOptimizations, JoinOptions, LazyCsvReader, LazyFrame, LazyGroupBy,
 };
 use polars::lazy::prelude::col;
-use polars::prelude::{DataFrame, Field, JoinType, Schema};
+use polars::prelude::{DataFrame, Field, JoinType, QuantileInterpolOptions, Schema};
 use pyo3::prelude::*;
 
 #[pyclass]
@@ -289,7 +289,8 @@ impl PyLazyFrame {
 
     pub fn quantile(&self, quantile: f64) -> Self {
         let ldf = self.ldf.clone();
-        ldf.quantile(quantile).into()
+        ldf.quantile(quantile, QuantileInterpolOptions::default())
+            .into()
     }
 
     pub fn explode(&self, column: Vec<String>) -> Self {
@@ -111,6 +111,12 @@ impl PyExpr {
     pub fn n_unique(&self) -> PyExpr {
         self.clone().inner.n_unique().into()
     }
+    pub fn approx_n_unique(&self) -> PyExpr {
+        self.clone().inner.approx_n_unique().into()
+    }
+    pub fn approx_quantile(&self, quantile: f64) -> PyExpr {
+        self.clone().inner.approx_quantile(quantile).into()
+    }
     pub fn first(&self) -> PyExpr {
         self.clone().inner.first().into()
     }
@@ -121,7 +127,10 @@ impl PyExpr {
         self.clone().inner.list().into()
     }
     pub fn quantile(&self, quantile: f64) -> PyExpr {
-        self.clone().inner.quantile(quantile).into()
+        self.clone()
+            .inner
+            .quantile(quantile, QuantileInterpolOptions::default())
+            .into()
     }
     pub fn agg_groups(&self) -> PyExpr {
         self.clone().inner.agg_groups().into()
@@ -203,6 +212,12 @@ impl PyExpr {
     pub fn cum_min(&self, reverse: bool) -> PyExpr {
         self.clone().inner.cum_min(reverse).into()
     }
+    pub fn cum_prod(&self, reverse: bool) -> PyExpr {
+        self.clone().inner.cum_prod(reverse).into()
+    }
+    pub fn cum_count(&self, reverse: bool) -> PyExpr {
+        self.clone().inner.cum_count(reverse).into()
+    }
 
     pub fn str_parse_date32(&self, fmt: Option<String>) -> PyExpr {
         let function = move |s: Series| {
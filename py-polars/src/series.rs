@@ -63,6 +63,19 @@ impl PySeries {
     }
 }
 
+/// The python api has an `ignore_null` toggle instead of `min_periods`. Ignoring the nulls
+/// only requires a single value in the window, otherwise the window needs to be full.
+fn rolling_options(
+    window_size: usize,
+    weight: Option<Vec<f64>>,
+    ignore_null: bool,
+) -> RollingOptions {
+    let min_periods = if ignore_null { 1 } else { window_size };
+    RollingOptions::new(window_size)
+        .with_min_periods(min_periods)
+        .with_weight(weight)
+}
+
 // Init with numpy arrays
 macro_rules! init_method {
     ($name:ident, $type:ty) => {
@@ -258,6 +271,14 @@ impl PySeries {
         self.series.cum_min(reverse).into()
     }
 
+    pub fn cum_prod(&self, reverse: bool) -> Self {
+        self.series.cum_prod(reverse).into()
+    }
+
+    pub fn cum_count(&self, reverse: bool) -> Self {
+        self.series.cum_count(reverse).into()
+    }
+
     pub fn chunk_lengths(&self) -> Vec<usize> {
         self.series.chunk_lengths().clone()
     }
@@ -553,11 +574,14 @@ impl PySeries {
 
     pub fn fill_none(&self, strategy: &str) -> PyResult<Self> {
         let strat = match strategy {
-            "backward" => FillNoneStrategy::Backward,
-            "forward" => FillNoneStrategy::Forward,
+            "backward" => FillNoneStrategy::Backward(None),
+            "forward" => FillNoneStrategy::Forward(None),
             "min" => FillNoneStrategy::Min,
             "max" => FillNoneStrategy::Max,
             "mean" => FillNoneStrategy::Mean,
+            "zero" => FillNoneStrategy::Zero,
+            "one" => FillNoneStrategy::One,
+            "linear" => FillNoneStrategy::Linear,
             s => return Err(PyPolarsEr::Other(format!("Strategy {} not supported", s)).into()),
         };
         let series = self.series.fill_none(strat).map_err(PyPolarsEr::from)?;
@@ -933,7 +957,7 @@ impl PySeries {
     ) -> PyResult<Self> {
         let s = self
             .series
-            .rolling_sum(window_size, weight.as_deref(), ignore_null)
+            .rolling_sum(&rolling_options(window_size, weight, ignore_null))
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }
@@ -946,7 +970,7 @@ impl PySeries {
     ) -> PyResult<Self> {
         let s = self
             .series
-            .rolling_mean(window_size, weight.as_deref(), ignore_null)
+            .rolling_mean(&rolling_options(window_size, weight, ignore_null))
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }
@@ -959,7 +983,7 @@ impl PySeries {
     ) -> PyResult<Self> {
         let s = self
             .series
-            .rolling_max(window_size, weight.as_deref(), ignore_null)
+            .rolling_max(&rolling_options(window_size, weight, ignore_null))
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }
@@ -971,7 +995,7 @@ impl PySeries {
     ) -> PyResult<Self> {
         let s = self
             .series
-            .rolling_min(window_size, weight.as_deref(), ignore_null)
+            .rolling_min(&rolling_options(window_size, weight, ignore_null))
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }